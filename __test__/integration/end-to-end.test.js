@@ -590,4 +590,76 @@ test.skip('End-to-End Performance: Complete system under load', async t => {
     t.log(`Network health: ${(networkStats.health_score * 100).toFixed(1)}%`);
     
     t.log('✅ End-to-end performance test successful');
-}); 
\ No newline at end of file
+}); 
+// === CONSENSUS PARAMETERS ROUND TRIP ===
+
+test('Consensus params: 8-chunk prover proofs verify under matching params', async t => {
+    const crypto = require('crypto');
+    const fs = require('fs');
+    const os = require('os');
+    const path = require('path');
+    const { ProofOfStorageProver, ProofOfStorageVerifier } = t.context.module;
+    const { createMockProverCallbacks, createMockVerifierCallbacks } = t.context.mockCallbacks;
+
+    const { publicKey, privateKey } = crypto.generateKeyPairSync('ed25519');
+    const proverKey = Buffer.from(publicKey.export({ format: 'jwk' }).x, 'base64url');
+    const proverPrivateKey = Buffer.from(privateKey.export({ format: 'jwk' }).d, 'base64url');
+    const consensusParams = { chunksPerBlock: 8, minVdfIterations: 200, chunkSizeBytes: 4096 };
+
+    const outputDir = fs.mkdtempSync(path.join(os.tmpdir(), 'pos-8chunk-'));
+    try {
+        const prover = new ProofOfStorageProver(proverKey, proverPrivateKey, createMockProverCallbacks(), consensusParams);
+        const genesis = prover.storeData(crypto.randomBytes(64 * 4096), outputDir);
+        t.is(genesis.selectedChunks.length, 8);
+
+        const proof = prover.createCompactProof(1);
+        t.is(proof.chunkProofs.length, 8);
+
+        // A verifier with the compiled-in defaults expects a different chunk count
+        const defaultVerifier = new ProofOfStorageVerifier(generateBuffer(32, 0x07), createMockVerifierCallbacks());
+        t.false(defaultVerifier.verifyCompactProof(proof));
+
+        const verifier = new ProofOfStorageVerifier(generateBuffer(32, 0x08), createMockVerifierCallbacks(), consensusParams);
+        t.true(verifier.verifyCompactProof(proof));
+    } finally {
+        fs.rmSync(outputDir, { recursive: true, force: true });
+    }
+});
+
+// === CALLBACK SIGNER ===
+
+test('Signing callback: prover signs through a JavaScript callback without its private key', async t => {
+    const crypto = require('crypto');
+    const fs = require('fs');
+    const os = require('os');
+    const path = require('path');
+    const { ProofOfStorageProver } = t.context.module;
+    const { createMockProverCallbacks } = t.context.mockCallbacks;
+
+    const { publicKey, privateKey } = crypto.generateKeyPairSync('ed25519');
+    const proverKey = Buffer.from(publicKey.export({ format: 'jwk' }).x, 'base64url');
+    let signatures = 0;
+    const sign = (data) => {
+        signatures += 1;
+        return crypto.sign(null, data, privateKey);
+    };
+
+    const outputDir = fs.mkdtempSync(path.join(os.tmpdir(), 'pos-signer-'));
+    try {
+        const prover = ProofOfStorageProver.fromSigningCallback(proverKey, sign, createMockProverCallbacks());
+        t.true(signatures > 0, 'Signer key is checked at construction');
+
+        const commitment = prover.storeData(crypto.randomBytes(16 * 4096), outputDir);
+        t.deepEqual(Buffer.from(commitment.proverKey), proverKey);
+
+        // A callback signing for another key is rejected up front
+        const other = crypto.generateKeyPairSync('ed25519').privateKey;
+        t.throws(() => ProofOfStorageProver.fromSigningCallback(
+            proverKey,
+            (data) => crypto.sign(null, data, other),
+            createMockProverCallbacks(),
+        ));
+    } finally {
+        fs.rmSync(outputDir, { recursive: true, force: true });
+    }
+});
@@ -578,7 +578,8 @@ function generateMockChallengeResponse() {
 
 function generateMockCompactStorageProof() {
   return {
-    proverKey: Buffer.alloc(32, 0x01),
+    proverKey: Buffer.alloc(32, 0x01),
+    dataHash: Buffer.alloc(32, 0x02),
     commitmentHash: Buffer.alloc(32, 0x09),
     blockHeight: 12345,
     chunkProofs: [
@@ -0,0 +1,117 @@
+//! Hashing hot-path benchmarks: SHA256 vs Blake3 at the input sizes the protocol
+//! actually hashes, and chunk hashing for full proofs. Run with
+//! `cargo bench --bench hashing`.
+//!
+//! Hierarchy iterations run `iterate_sha256` against the same loop over Blake3,
+//! and commitments run `compute_commitment_hash` end to end.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use napi::bindgen_prelude::Buffer;
+use proof_of_storage_continuity::chain::storage::ChainStorage;
+use proof_of_storage_continuity::core::storage_backend::MemoryBackend;
+use proof_of_storage_continuity::core::types::{
+    CHUNKS_PER_BLOCK, CHUNK_SIZE_BYTES, GLOBAL_ROOT_ITERATIONS, GROUP_ITERATIONS, MAX_CHUNK_BATCH,
+    REGIONAL_ITERATIONS,
+};
+use proof_of_storage_continuity::core::utils::{
+    compute_blake3, compute_commitment_hash, compute_sha256, CommitmentParams,
+};
+use proof_of_storage_continuity::hierarchy::proofs::iterate_sha256;
+
+const HIERARCHY_ITERATIONS: [(&str, u32); 3] = [
+    ("group", GROUP_ITERATIONS),
+    ("regional", REGIONAL_ITERATIONS),
+    ("global", GLOBAL_ROOT_ITERATIONS),
+];
+
+/// `iterate_sha256` with Blake3 as the step hash
+fn iterate_blake3(mut state: [u8; 32], iterations: u32) -> [u8; 32] {
+    for i in 0..iterations {
+        let mut iteration_data = Vec::new();
+        iteration_data.extend_from_slice(&state);
+        iteration_data.extend_from_slice(&i.to_be_bytes());
+        state = compute_blake3(&iteration_data);
+    }
+    state
+}
+
+fn bench_single_iteration(c: &mut Criterion) {
+    let mut group = c.benchmark_group("single_iteration");
+    let input = [[7u8; 32].as_slice(), &42u32.to_be_bytes()].concat();
+    group.throughput(Throughput::Bytes(input.len() as u64));
+    group.bench_function("sha256", |b| b.iter(|| compute_sha256(black_box(&input))));
+    group.bench_function("blake3", |b| b.iter(|| compute_blake3(black_box(&input))));
+    group.finish();
+}
+
+fn bench_hierarchy_iterations(c: &mut Criterion) {
+    let mut group = c.benchmark_group("hierarchy_iterations");
+    group.sample_size(20);
+    for (level, iterations) in HIERARCHY_ITERATIONS {
+        group.throughput(Throughput::Elements(iterations as u64));
+        group.bench_with_input(BenchmarkId::new("sha256", level), &iterations, |b, &n| {
+            b.iter(|| iterate_sha256(black_box([7u8; 32]), n))
+        });
+        group.bench_with_input(BenchmarkId::new("blake3", level), &iterations, |b, &n| {
+            b.iter(|| iterate_blake3(black_box([7u8; 32]), n))
+        });
+    }
+    group.finish();
+}
+
+fn bench_commitment_hash(c: &mut Criterion) {
+    let selected_chunks: Vec<u32> = (0..CHUNKS_PER_BLOCK).collect();
+    let chunk_hashes = vec![vec![4u8; 32]; CHUNKS_PER_BLOCK as usize];
+    let params = CommitmentParams {
+        prover_key: &[1u8; 32],
+        data_hash: &[2u8; 32],
+        block_height: 100,
+        block_hash: &[3u8; 32],
+        selected_chunks: &selected_chunks,
+        chunk_hashes: &chunk_hashes,
+        vdf_output: &[5u8; 32],
+        entropy_hash: &[6u8; 32],
+        merkle_root: Some((&[7u8; 32], 1024)),
+    };
+    c.bench_function("commitment_hash", |b| {
+        b.iter(|| compute_commitment_hash(black_box(&params)))
+    });
+}
+
+/// Decoded chunk hashing as done for full proofs: the parallel batched pass vs
+/// one `read_chunk` per chunk
+fn bench_chunk_hashes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("chunk_hashes");
+    group.sample_size(10);
+    let total_chunks = 2 * MAX_CHUNK_BATCH as usize;
+    let data: Vec<u8> = (0..total_chunks * CHUNK_SIZE_BYTES as usize)
+        .map(|i| (i % 251) as u8)
+        .collect();
+    let mut storage = ChainStorage::with_backend(
+        Box::new(MemoryBackend::new(data)),
+        "bench.data".to_string(),
+        Some(Buffer::from(vec![9u8; 32])),
+    )
+    .unwrap();
+    group.throughput(Throughput::Elements(total_chunks as u64));
+    group.bench_function("parallel", |b| {
+        b.iter(|| storage.compute_all_chunk_blake3_hashes().unwrap())
+    });
+    group.bench_function("sequential", |b| {
+        b.iter(|| {
+            (0..total_chunks as u32)
+                .map(|index| compute_blake3(&storage.read_chunk(index).unwrap()))
+                .collect::<Vec<_>>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_single_iteration,
+    bench_hierarchy_iterations,
+    bench_commitment_hash,
+    bench_chunk_hashes
+);
+criterion_main!(benches);
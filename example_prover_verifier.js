@@ -553,7 +553,7 @@ async function demonstrateHashchainProgression() {
                                 currentCommitment.commitmentHash
                             );
                             const response = prover.respondToChallenge(challenge);
-                            const isValidResponse = verifier.verifyChallengeResponse(challenge, response);
+                            const isValidResponse = verifier.verifyChallengeResponse(response, challenge, currentCommitment);
                             console.log(`   🎯 Challenge-Response (Chain 2): ${isValidResponse ? 'PASSED' : 'FAILED'}`);
                         } catch (error) {
                             console.log(`   🎯 Challenge-Response (Chain 2): FAILED (${error.message})`);
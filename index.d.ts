@@ -94,6 +94,28 @@ export interface AvailabilityChallenge {
   deadline: number
   /** Reward amount for successful challenge */
   rewardAmount: number
+  /**
+   * Slice Merkle root of the challenged chunk, learned from an earlier full-chunk
+   * response. When set this is a light probe answered with one authenticated slice.
+   */
+  sliceRoot?: Buffer
+  /**
+   * Block beacon mixed into the challenge decision and chunk selection, so they
+   * cannot be predicted before the block exists but can be rechecked afterwards
+   */
+  selectionBeacon?: Buffer
+}
+/** Slice of a chunk served for a light availability probe */
+export interface ChunkSliceProof {
+  /** Index of the slice within the chunk */
+  sliceIndex: number
+  /** Slice bytes */
+  sliceData: Buffer
+  /**
+   * Path from the slice leaf up to the chunk's slice root, encoded by
+   * `encode_merkle_path`
+   */
+  merklePath: Buffer
 }
 /** Response to availability challenge */
 export interface AvailabilityResponse {
@@ -105,6 +127,105 @@ export interface AvailabilityResponse {
   responseTime: number
   /** Proof of chunk authenticity */
   authenticityProof: Buffer
+  /** Set when the prover is in maintenance and did not serve the chunk */
+  maintenanceMode?: boolean
+  /** Served slice for light probes (chunk data is omitted) */
+  sliceProof?: ChunkSliceProof
+  /** Public key of the prover that signed the response */
+  proverKey?: Buffer
+  /** Prover's Ed25519 signature over the response fields above */
+  proverSignature?: Buffer
+}
+/**
+ * Availability audit of a contiguous chunk range, answered as a stream of
+ * independently verifiable segments rather than one response holding every chunk
+ */
+export interface RangeAvailabilityChallenge {
+  /** Chain being audited */
+  chainId: Buffer
+  /** First chunk of the audited range */
+  startChunk: number
+  /** Chunk after the last audited chunk */
+  endChunk: number
+  /** Chunks in the chain's data file */
+  totalChunks: number
+  /** Merkle root over the blake3 hashes of every padded chunk (the header's `merkle_root`) */
+  chunkRoot: Buffer
+  /** Challenge nonce */
+  challengeNonce: Buffer
+  /** Challenger identifier */
+  challengerId: Buffer
+  /** Challenge timestamp */
+  challengeTime: number
+  /** Chunks served per segment; the last segment may be shorter */
+  chunksPerSegment: number
+  /** Deadline of each segment, in segment order */
+  segmentDeadlines: Array<number>
+}
+/** Chunk served in a range audit segment with its path to the chunk root */
+export interface SegmentChunkProof {
+  /** Index of the chunk in the data file */
+  chunkIndex: number
+  /** Decoded chunk bytes, padded to the chunk size */
+  chunkData: Buffer
+  /** Path from the chunk hash up to the chunk root, encoded by `encode_merkle_path` */
+  merklePath: Buffer
+}
+/** One segment of a streamed range audit response, verifiable on its own */
+export interface ChallengeResponseSegment {
+  /** Range challenge being responded to */
+  challengeId: Buffer
+  /** Position of this segment in the response */
+  segmentIndex: number
+  /** Segments in the whole response */
+  totalSegments: number
+  /** Chunks covered by this segment, in index order */
+  chunks: Array<SegmentChunkProof>
+  /** Binds the segment's chunk hashes to the challenge nonce */
+  authenticityProof: Buffer
+  /** Response timestamp */
+  responseTime: number
+}
+/** Record of a completed availability challenge, exported for slashing disputes */
+export interface ChallengeEvidence {
+  /** Hex challenge id, recomputable from the challenge */
+  challengeId: string
+  challenge: AvailabilityChallenge
+  /** Response processed for the challenge; absent if it expired unanswered */
+  response?: AvailabilityResponse
+  /** "success", "timeout", "invalid_data", "maintenance_mode" or "unanswered" */
+  outcome: string
+  /** When the outcome was recorded */
+  recordedAt: number
+  /** Hash binding the id, challenge, response, outcome and timestamp */
+  evidenceHash: Buffer
+  /**
+   * Challenger's Ed25519 signature over the evidence hash, by the key in
+   * `challenge.challenger_id`
+   */
+  challengerSignature?: Buffer
+}
+/** Tolerance for failed availability challenges before a chain becomes slashable */
+export interface ChallengeFailurePolicy {
+  /** Failures allowed within the window; one more makes the chain slashable */
+  toleratedFailures: number
+  /** Number of most recent challenge outcomes considered */
+  window: number
+}
+/** Availability response-time percentiles for a chain */
+export interface AvailabilityLatencyPercentiles {
+  /** Number of responses in the sample window */
+  sampleCount: number
+  /** Median response time */
+  p50Ms: number
+  /** 95th percentile response time */
+  p95Ms: number
+  /** 99th percentile response time */
+  p99Ms: number
+  /** Slowest response in the sample window */
+  maxMs: number
+  /** Response-time SLA the samples are measured against */
+  slaMs: number
 }
 /** Network latency proof for anti-outsourcing */
 export interface NetworkLatencyProof {
@@ -215,6 +336,38 @@ export interface EnhancedCheckpoint {
   /** Submitter identifier */
   submitterId: Buffer
 }
+/** Chain leaf of a hierarchical proof tree */
+export interface ChainProofNode {
+  /** Chain identifier */
+  chainId: Buffer
+  /** Chain commitment included in its group proof */
+  commitment: Buffer
+}
+/** Group node of a hierarchical proof tree */
+export interface GroupProofNode {
+  /** Group identifier */
+  groupId: string
+  /** Group proof */
+  proof: Buffer
+  /** Chains in the group, ordered by chain id */
+  chains: Array<ChainProofNode>
+}
+/** Region node of a hierarchical proof tree */
+export interface RegionProofNode {
+  /** Region identifier */
+  regionId: string
+  /** Regional proof */
+  proof: Buffer
+  /** Groups in the region, ordered by group id */
+  groups: Array<GroupProofNode>
+}
+/** Hierarchical proof as a nested global → regions → groups → chains tree */
+export interface ProofTree {
+  /** Global root proof */
+  globalRootProof: Buffer
+  /** Regions ordered by region id */
+  regions: Array<RegionProofNode>
+}
 /** File encoding information for prover-specific storage */
 export interface FileEncodingInfo {
   /** Original file hash */
@@ -317,6 +470,20 @@ export interface HashChainHeader {
   initialBlockHash: Buffer
   /** SHA256 of header fields (32 bytes) */
   headerChecksum: Buffer
+  /** Erasure coding layout, when the stored data is a legitimately erasure-coded dataset */
+  erasureCoding?: ErasureCodingParams
+  /** Prover-specific encoding of the data file, when recorded at creation */
+  fileEncoding?: FileEncodingInfo
+}
+/**
+ * Reed-Solomon layout of erasure-coded chain data: consecutive stripes of
+ * `data_shards` data chunks followed by `parity_shards` parity chunks
+ */
+export interface ErasureCodingParams {
+  /** Data chunks per stripe */
+  dataShards: number
+  /** Parity chunks per stripe */
+  parityShards: number
 }
 /** Proof window containing last 5 commitments for verification (updated) */
 export interface ProofWindow {
@@ -427,8 +594,26 @@ export interface CompactProof {
   regionalProof: Buffer
   /** Global proof reference (32 bytes) */
   globalProofReference: Buffer
-  /** Merkle path to group (up to 512 bytes) */
-  merklePath: Array<Buffer>
+  /** Merkle path to group, encoded by `encode_merkle_path` (up to 528 bytes) */
+  merklePath: Buffer
+  /** Group the chain belongs to */
+  groupId: string
+  /**
+   * Merkle path from the group proof to the region, encoded by `encode_merkle_path`
+   * (up to 165 bytes)
+   */
+  regionPath: Buffer
+  /** Region the group belongs to */
+  regionId: string
+  /**
+   * Merkle path from the regional proof to the global root, encoded by
+   * `encode_merkle_path` (up to 528 bytes)
+   */
+  globalPath: Buffer
+  /** Global root proof the global proof chained from (32 bytes) */
+  previousGlobalProof: Buffer
+  /** Number of regions under the global proof */
+  regionCount: number
   /** Enhanced proof metadata (128 bytes) */
   metadata: ProofMetadata
 }
@@ -444,8 +629,11 @@ export interface FullProof {
   regionalProofs: Array<Buffer>
   /** Global hierarchical proof (32 bytes) */
   globalProof: Buffer
-  /** Complete merkle paths for verification (up to 16 KB) */
-  merklePaths: Array<Array<Buffer>>
+  /**
+   * Complete merkle paths for verification, each encoded by `encode_merkle_path`
+   * (up to 16 KB)
+   */
+  merklePaths: Array<Buffer>
   /** Chunk verification data with enhanced security (up to 8 KB) */
   chunkVerification: ChunkVerificationData
   /** Full metadata and statistics (up to 4 KB) */
@@ -500,19 +688,23 @@ export interface ChunkVerificationData {
   /** Memory-hard VDF proof for chunk access */
   vdfProof?: MemoryHardVdfProof
 }
-/** Enhanced full proof metadata */
+/**
+ * Enhanced full proof metadata
+ * Only `generation_time_ms` and `memory_usage_mb` are consensus-relevant and are
+ * validated against the proof's VDF; all other fields are advisory only.
+ */
 export interface FullProofMetadata {
-  /** Detailed system statistics */
+  /** Detailed system statistics (advisory) */
   systemStats: string
-  /** Performance metrics including VDF */
+  /** Performance metrics including VDF (advisory) */
   performanceMetrics: string
-  /** Verification instructions */
+  /** Verification instructions (advisory) */
   verificationGuide: string
-  /** Proof generation time */
+  /** Proof generation time (consensus) */
   generationTimeMs: number
-  /** Memory usage during generation */
+  /** Memory usage during generation (consensus) */
   memoryUsageMb: number
-  /** Enhanced security features used */
+  /** Enhanced security features used (advisory) */
   securityFeatures: Array<string>
 }
 /** Enhanced hierarchical position */
@@ -548,6 +740,25 @@ export interface StorageCommitment {
   entropy: MultiSourceEntropy
   /** Commitment hash */
   commitmentHash: Buffer
+  /**
+   * Merkle root over the hashes of all chunks of the data; challenge responses
+   * must prove their chunks against it
+   */
+  merkleRoot?: Buffer
+  /** Number of chunks (Merkle leaves) under `merkle_root` */
+  totalChunks?: number
+}
+/**
+ * Consensus values a deployment may override, e.g. fewer chunks per block on a
+ * private network with small files. Provers and verifiers must use the same values.
+ */
+export interface ConsensusParams {
+  /** Chunks selected and proven per block (1 to `MAX_CHUNKS_PER_BLOCK`) */
+  chunksPerBlock: number
+  /** Minimum continuous VDF iterations before commitments are generated or accepted */
+  minVdfIterations: number
+  /** Chunk size in bytes; must equal the hashchain format's `CHUNK_SIZE_BYTES` */
+  chunkSizeBytes: number
 }
 /** Challenge issued to prover for data availability */
 export interface StorageChallenge {
@@ -565,6 +776,48 @@ export interface StorageChallenge {
   timestamp: number
   /** Response deadline */
   deadline: number
+  /** Byte offset to reveal within each challenged chunk (sub-chunk challenge) */
+  byteOffsets?: Array<number>
+  /** Public key of the issuing verifier */
+  verifierKey?: Buffer
+  /** Verifier's Ed25519 signature over the challenge */
+  verifierSignature?: Buffer
+  /** Coding parameters when the challenge targets a whole stripe of raw coded shards */
+  erasureCoding?: ErasureCodingParams
+}
+/** Integrity check outcome for one chain */
+export interface ChainIntegrityReport {
+  /** Chain identifier (hex) */
+  chainId: string
+  /** Whether the chain passed */
+  valid: boolean
+  /** Why the chain failed, when it did */
+  error?: string
+}
+/** Prover maintenance window state */
+export interface MaintenanceStatus {
+  /** Whether challenges are currently answered with a maintenance notice */
+  enabled: boolean
+  /** Seconds since the current maintenance window started (0 when not in maintenance) */
+  currentDurationSeconds: number
+  /** Seconds spent in maintenance across all windows, including the current one */
+  totalDurationSeconds: number
+}
+/**
+ * Bytes revealed at a challenged offset, with the rest of the chunk binding
+ * them to the committed chunk hash
+ */
+export interface SubChunkProof {
+  /** Challenged chunk index */
+  chunkIndex: number
+  /** Offset of the revealed bytes within the chunk */
+  byteOffset: number
+  /** Bytes at the challenged offset */
+  revealedBytes: Buffer
+  /** Chunk bytes before the revealed range */
+  prefix: Buffer
+  /** Chunk bytes after the revealed range */
+  suffix: Buffer
 }
 /** Proof response to storage challenge */
 export interface ChallengeResponse {
@@ -572,17 +825,115 @@ export interface ChallengeResponse {
   challengeId: Buffer
   /** Actual chunk data */
   chunkData: Array<Buffer>
-  /** Merkle proofs for chunks */
+  /**
+   * Merkle proof per challenged chunk: its path from the chunk hash up to the
+   * chain's Merkle root, encoded by `encode_merkle_path`
+   */
   merkleProofs: Array<Buffer>
   /** Response timestamp */
   timestamp: number
   /** VDF proof of timely access */
   accessProof: MemoryHardVdfProof
+  /** Revealed byte ranges for sub-chunk challenges (chunk data is omitted) */
+  subChunkProofs?: Array<SubChunkProof>
+  /**
+   * Round-trip latency to the challenger measured before responding, bound into
+   * the access proof input
+   */
+  measuredLatencyMs?: number
+}
+/** Prover's continuous VDF state at the time it signed a block */
+export interface VdfSigningState {
+  /** VDF state the signature was made against */
+  vdfState: Buffer
+  /** Total VDF iterations at signing */
+  totalIterations: number
+}
+/** Challenge asking a prover to reproduce an intermediate continuous VDF state */
+export interface VdfReproductionChallenge {
+  /** Target prover */
+  proverKey: Buffer
+  /** Iteration index whose state must be revealed */
+  iterationIndex: number
+  /** Number of iterations the verifier recomputes from that state */
+  segmentLength: number
+  /** Challenge timestamp */
+  timestamp: number
+}
+/** Prover response to a VDF reproduction challenge */
+export interface VdfReproductionResponse {
+  /** Iteration index being revealed */
+  iterationIndex: number
+  /** Number of iterations in the revealed segment */
+  segmentLength: number
+  /** VDF state at iteration_index */
+  startState: Buffer
+  /** VDF memory buffer at iteration_index */
+  memorySnapshot: Buffer
+  /** VDF state after segment_length further iterations */
+  endState: Buffer
+}
+/**
+ * Continuous VDF state and memory revealed at an iteration, from which a verifier
+ * can resume the VDF
+ */
+export interface VdfCheckpoint {
+  /** Iteration the checkpoint was taken at */
+  iteration: number
+  /** VDF state at the iteration */
+  state: Buffer
+  /** VDF memory buffer at the iteration */
+  memorySnapshot: Buffer
+}
+/** Forecast of when the prover's VDF reaches a required iteration count */
+export interface VdfIterationsForecast {
+  /** Current total VDF iterations */
+  currentIterations: number
+  /** Iterations still missing (0 when ready) */
+  shortfall: number
+  /** Rate the estimate is based on */
+  iterationsPerSecond: number
+  /** Seconds until the required iterations are reached; absent if the VDF is not advancing */
+  estimatedSeconds?: number
+}
+/**
+ * Attestation that a prover holds all of its active chains: one Merkle root over
+ * the chains' current commitment hashes, signed once by the prover key. The size
+ * does not depend on the number of chains.
+ */
+export interface PortfolioProof {
+  /** Prover identification */
+  proverKey: Buffer
+  /** Merkle root over (chain id, commitment hash) leaves in chain id order */
+  portfolioRoot: Buffer
+  /** Hash of the set of chain ids covered */
+  chainSetHash: Buffer
+  /** Number of chains covered */
+  chainCount: number
+  /** VDF state the portfolio was signed against */
+  vdfState: Buffer
+  /** Total VDF iterations at signing */
+  totalIterations: number
+  /** Ed25519 signature by `proverKey` over every other field */
+  signature: Buffer
+  /** Signing timestamp */
+  timestamp: number
+}
+/** Proof that one chain's commitment is covered by a portfolio root */
+export interface PortfolioInclusionProof {
+  /** Hex chain id */
+  chainId: string
+  /** Commitment hash the chain is attested at */
+  commitmentHash: Buffer
+  /** Path from the leaf up to the root, encoded by `encode_merkle_path` */
+  merklePath: Buffer
 }
 /** Compact proof for efficient verification */
 export interface CompactStorageProof {
   /** Prover identification */
   proverKey: Buffer
+  /** Hash of the committed data file; with the prover key it identifies the chain */
+  dataHash: Buffer
   /** Data commitment hash */
   commitmentHash: Buffer
   /** Block height reference */
@@ -613,6 +964,38 @@ export interface FullStorageProof {
   /** Metadata and statistics */
   metadata: ProofMetadata
 }
+/** Outcome of incremental full proof verification */
+export interface VerificationResult {
+  /** Whether the proof verified */
+  valid: boolean
+  /** Merkle root covered by this result (pass as the previous root next block) */
+  merkleRoot: Buffer
+  /** Whether the Merkle tree was re-verified rather than skipped as unchanged */
+  treeReverified: boolean
+  /** Reason the proof was rejected */
+  failureReason?: string
+  /** Validation strictness the proof was checked under */
+  strictness: string
+}
+/** Uniformity test of a chain's chunk selections across its history */
+export interface BiasReport {
+  /** Chain analyzed (hex) */
+  chainId: string
+  /** Chunk selections tested */
+  sampleCount: number
+  /** Chunk index ranges the selections are counted in */
+  binCount: number
+  /** Chi-square statistic of the bin counts against a uniform distribution */
+  chiSquare: number
+  /** Degrees of freedom of the test (bins - 1) */
+  degreesOfFreedom: number
+  /** Chi-square statistic normalized to a standard normal score */
+  zScore: number
+  /** Whether there are enough selections for the test to be meaningful */
+  sufficientSamples: boolean
+  /** Whether the selections are significantly non-uniform */
+  biased: boolean
+}
 /** Network node information */
 export interface NetworkNode {
   /** Node public key */
@@ -639,6 +1022,105 @@ export interface NetworkStats {
   /** Challenge success rate */
   challengeSuccessRate: number
 }
+/** Data hash claimed by more than one prover */
+export interface DuplicateDataRegistration {
+  /** Claimed data hash */
+  dataHash: Buffer
+  /** Claiming prover keys in registration order, first claimant first */
+  proverKeys: Array<Buffer>
+}
+/** Health of one chain group in the hierarchy */
+export interface GroupHealth {
+  /** Group identifier */
+  groupId: string
+  /** Region the group belongs to, if assigned */
+  regionId?: string
+  /** Chains in the group */
+  chainCount: number
+  /** Maximum chains in the group */
+  maxChains: number
+  /** Last block at which the group was updated */
+  lastUpdateBlock: number
+  /** Time of the last group proof computation in milliseconds, if one was computed */
+  lastProofTimeMs?: number
+  /** Whether the group has a current group proof */
+  hasProof: boolean
+  /** Hex ids of chains lagging behind the current block or idle too long */
+  unhealthyChains: Array<string>
+}
+/** Health of one region in the hierarchy, aggregated over its groups */
+export interface RegionHealth {
+  /** Region identifier */
+  regionId: string
+  /** Groups in the region */
+  groupCount: number
+  /** Maximum groups in the region */
+  maxGroups: number
+  /** Chains across all groups in the region */
+  chainCount: number
+  /** Last block at which the region was updated */
+  lastUpdateBlock: number
+  /** Time of the last regional proof computation in milliseconds, if one was computed */
+  lastProofTimeMs?: number
+  /** Whether the region has a current regional proof */
+  hasProof: boolean
+  /** Groups containing at least one unhealthy chain */
+  laggingGroups: Array<string>
+  /** Hex ids of unhealthy chains across the region */
+  unhealthyChains: Array<string>
+}
+/** Per-chain block processing time measured against the block processing target */
+export interface BlockProcessingStats {
+  /** Block height processed */
+  blockHeight: number
+  /** Chains whose commitments were updated */
+  chainsProcessed: number
+  /** Worker threads used */
+  workerThreads: number
+  /** Actual processing time in milliseconds */
+  elapsedMs: number
+  /** Block processing target in milliseconds */
+  targetMs: number
+  /** Average processing time per updated chain in milliseconds */
+  perChainMs: number
+  /** Whether processing finished within the target */
+  withinTarget: boolean
+}
+/** Disk consumed by one chain's files */
+export interface ChainDiskUsage {
+  /** Hex chain id */
+  chainId: string
+  /** Size of the `.data` file in bytes */
+  dataFileBytes: number
+  /** Size of the `.hashchain` file in bytes (0 if not yet written) */
+  hashchainFileBytes: number
+  /** Both files together */
+  totalBytes: number
+}
+/** Disk use and free space of one output directory holding chain files */
+export interface DirectoryDiskUsage {
+  /** Output directory path */
+  path: string
+  /** Chain index and leftover `_original.data` staging files in bytes */
+  sidecarBytes: number
+  /** Bytes available on the directory's filesystem, if it could be determined */
+  freeBytes?: number
+}
+/** Disk usage across every active chain, for capacity planning */
+export interface DiskUsageReport {
+  /** Per-chain usage, ordered by chain id */
+  chains: Array<ChainDiskUsage>
+  /** Output directories of those chains, ordered by path */
+  directories: Array<DirectoryDiskUsage>
+  /** All `.data` files in bytes */
+  dataFileBytes: number
+  /** All `.hashchain` files in bytes */
+  hashchainFileBytes: number
+  /** All sidecar files in bytes */
+  sidecarBytes: number
+  /** Everything above together */
+  totalBytes: number
+}
 /** Blockchain operations for provers */
 export interface ProverBlockchainCallbacks {
   /** Get current blockchain height */
@@ -711,7 +1193,7 @@ export interface AvailabilityChallengeCallbacks {
 export interface BlockchainDataCallbacks {
   /** Validate data chunk count against blockchain */
   validateChunkCount: (...args: any[]) => any
-  /** Get registered data file metadata */
+  /** Get registered data file metadata: (dataHashHex) => { totalChunks } */
   getDataFileMetadata: (...args: any[]) => any
   /** Verify data file registration */
   verifyDataRegistration: (...args: any[]) => any
@@ -773,6 +1255,11 @@ export interface VerifierEconomicCallbacks {
   /** Penalize failed verification */
   penalizeFailure: (...args: any[]) => any
 }
+/** Randomness beacon operations for verifiers */
+export interface VerifierBeaconCallbacks {
+  /** Verify beacon randomness for a round: (randomness, round) => boolean */
+  verifyRandomness: (...args: any[]) => any
+}
 /** Combined verifier callbacks */
 export interface VerifierCallbacks {
   /** Blockchain operations */
@@ -789,9 +1276,20 @@ export interface VerifierCallbacks {
   availabilityChallenge: AvailabilityChallengeCallbacks
   /** Blockchain data validation */
   blockchainData: BlockchainDataCallbacks
+  /** Randomness beacon verification */
+  beacon?: VerifierBeaconCallbacks
 }
 /** Generate secure multi-source entropy */
 export declare function generateMultiSourceEntropy(blockHash: Buffer, beaconData?: Buffer | undefined | null): MultiSourceEntropy
+/** Get the fingerprint of this build's consensus parameters */
+export declare function getNetworkParametersFingerprint(): Buffer
+/**
+ * Fingerprint of this build's consensus behavior (parameters, chunk selection, entropy
+ * combination, commitment hashing and VDF test vectors), for comparison on handshake
+ */
+export declare function crateConsensusFingerprint(): Buffer
+/** Verify a serialized shared VDF proof chain's linkage and signatures for a prover */
+export declare function verifyVdfProofChain(serializedProofs: Buffer, proverKey: Buffer): boolean
 /** Create memory-hard VDF proof */
 export declare function createMemoryHardVdfProof(input: Buffer, iterations: number): MemoryHardVdfProof
 /** Verify memory-hard VDF proof */
@@ -800,10 +1298,22 @@ export declare function verifyMemoryHardVdfProof(proof: MemoryHardVdfProof): boo
 export declare function selectChunksFromEntropy(entropy: MultiSourceEntropy, totalChunks: number, count: number): Array<number>
 /** Verify chunk selection algorithm */
 export declare function verifyChunkSelection(entropy: MultiSourceEntropy, totalChunks: number, selectedChunks: Array<number>): boolean
+/** Position hash an ultra-compact proof for `chain_hash` carries for `position` */
+export declare function computeUltraCompactPositionHash(chainHash: Buffer, position: HierarchicalPosition): Buffer
 /** Create storage commitment hash */
 export declare function createCommitmentHash(commitment: StorageCommitment): Buffer
+/** Serialize a storage commitment to its versioned binary format for storage */
+export declare function serializeStorageCommitment(commitment: StorageCommitment): Buffer
+/** Parse a storage commitment written by `serializeStorageCommitment` */
+export declare function deserializeStorageCommitment(data: Buffer): StorageCommitment
 /** Verify commitment integrity */
 export declare function verifyCommitmentIntegrity(commitment: StorageCommitment): boolean
+/**
+ * Check that a compact proof matches the commitment it claims to summarize: prover
+ * key, data hash, commitment hash, block height, timestamp, chunk hashes and VDF
+ * proof must all be the commitment's own
+ */
+export declare function verifyCompactMatchesCommitment(compact: CompactStorageProof, commitment: StorageCommitment): boolean
 /** VDF queue status information */
 export interface VdfQueueStatus {
   pendingCount: number
@@ -816,34 +1326,240 @@ export interface VdfQueueStatus {
  * Handles data storage, commitment generation, and proof creation
  */
 export declare class ProofOfStorageProver {
-  /** Create new prover instance */
-  constructor(proverKey: Buffer, proverPrivateKey: Buffer, callbacks: ProverCallbacks)
+  /**
+   * Create new prover instance. `consensusParams` overrides the compiled-in chunks
+   * per block and minimum VDF iterations; verifiers must be built with the same values.
+   */
+  constructor(proverKey: Buffer, proverPrivateKey: Buffer, callbacks: ProverCallbacks, consensusParams?: ConsensusParams | undefined | null)
+  /**
+   * Create a prover whose signatures come from `sign`, a `(data) => signature`
+   * callback, e.g. one delegating to a hardware security module, so the private key
+   * never has to be in process memory. The callback must return the 64-byte Ed25519
+   * signature of `data` for `proverKey`. It is also called from the VDF thread,
+   * where an exception it throws is fatal; return an empty Buffer to refuse instead.
+   */
+  static fromSigningCallback(proverKey: Buffer, sign: (data: Buffer) => Buffer, callbacks: ProverCallbacks, consensusParams?: ConsensusParams | undefined | null): ProofOfStorageProver
   /** Store data and generate initial commitment with real implementation */
   storeData(data: Buffer, outputDirectory: string): StorageCommitment
+  /**
+   * Store a data file already on disk, like `storeData` but streamed from the file
+   * so it is never held in memory as a whole; suitable for files beyond Node's
+   * buffer size limit
+   */
+  storeDataFromFile(inputPath: string, outputDirectory: string): StorageCommitment
   /** Submit a block for VDF-based signing */
   submitBlockForVdf(blockHeight?: number | undefined | null, blockHash?: Buffer | undefined | null): string
-  /** Generate storage commitment for current block with real data */
+  /**
+   * Generate storage commitment for current block with real data.
+   * Fails with `E_VDF_NOT_READY` before reading any chunks if the VDF has not yet
+   * computed the minimum iterations.
+   */
   generateCommitment(blockHeight?: number | undefined | null, blockHash?: Buffer | undefined | null): StorageCommitment
   /** Create real compact proof for efficient verification */
   createCompactProof(blockHeight?: number | undefined | null): CompactStorageProof
   /** Create real full proof with complete verification data */
   createFullProof(blockHeight?: number | undefined | null): FullStorageProof
-  /** Respond to storage challenge with real data */
-  respondToChallenge(challenge: StorageChallenge): ChallengeResponse
+  /**
+   * Answer an availability challenge for one of this prover's chains, signed with
+   * the prover key so the response can be used as dispute evidence
+   */
+  respondToAvailabilityChallenge(challenge: AvailabilityChallenge): AvailabilityResponse
+  /**
+   * Respond to storage challenge with real data.
+   * `measuredLatencyMs` is a fresh round-trip measurement to the challenger; when
+   * given it is bound into the access proof so the verifier can check the response
+   * arrived within a matching window.
+   * Fails with `E_CHALLENGE_EXPIRED` before any work once the deadline has passed
+   * or the access proof VDF cannot finish before it.
+   */
+  respondToChallenge(challenge: StorageChallenge, measuredLatencyMs?: number | undefined | null): ChallengeResponse
+  /**
+   * Produce one segment of the response to a range availability audit. Call once per
+   * segment, in order, and send each as it is produced so the whole range is never
+   * held in memory.
+   */
+  respondToRangeChallengeSegment(challenge: RangeAvailabilityChallenge, segmentIndex: number): ChallengeResponseSegment
   /** Get real prover statistics */
   getProverStats(): string
-  /** Verify own data integrity with real checks */
+  /**
+   * Total storage in bytes confirmed on the blockchain across all active chains.
+   * Sums `blockchainData.getConfirmedStorageSize(chainId)` for each chain rather than
+   * the locally reported chunk counts used by `getProverStats`.
+   */
+  getConfirmedStorage(): number
+  /**
+   * Verify own data integrity with real checks.
+   * Every failing chain is flagged and refused by commitment generation until
+   * `clearIntegrityFlag` is called after repair.
+   */
   verifySelfIntegrity(): boolean
+  /**
+   * Check every active chain, reporting each one's outcome in chain id order so
+   * only the damaged chains need repair. Failing chains are flagged as by
+   * `verifySelfIntegrity`.
+   */
+  verifyAllChainsDetailed(): Array<ChainIntegrityReport>
+  /** Chains flagged by a failed integrity check, sorted by chain id */
+  getIntegrityFlaggedChains(): Array<string>
+  /**
+   * Re-enable commitment generation for a repaired chain.
+   * Returns false if the chain was not flagged.
+   */
+  clearIntegrityFlag(chainId: string): boolean
+  /**
+   * Pause or resume answering challenges for maintenance. While enabled, storage
+   * challenges fail with E_MAINTENANCE_MODE and availability challenges get a
+   * maintenance notice, which verifiers score down without slashing.
+   */
+  setMaintenanceMode(enabled: boolean): void
+  /** Get whether the prover is in maintenance and how long it has been */
+  getMaintenanceStatus(): MaintenanceStatus
+  /**
+   * Record that a chain's data is an erasure-coded dataset with the given layout,
+   * so coded shard challenges can target its raw shards
+   */
+  setChainErasureCoding(chainId: string, erasureCoding: ErasureCodingParams): void
+  /**
+   * Rewrite a chain's .hashchain log keeping the most recent `keepRecent` commitments
+   * (at least `PROOF_WINDOW_BLOCKS`) plus a checkpoint hash of the truncated prefix.
+   * Returns the number of bytes reclaimed.
+   */
+  compactHashchainLog(chainId: string, keepRecent: number): number
+  /**
+   * Read up to `count` decoded chunks of a chain starting at `startIndex`, for tools
+   * that process a whole chain. The final chunk is returned at its stored length.
+   */
+  readChunkBatch(chainId: string, startIndex: number, count: number): Array<Buffer>
+  /** Set the verifier keys whose signed storage challenges are answered */
+  setAuthorizedVerifiers(verifierKeys: Array<Buffer>): void
+  /**
+   * Require storage challenges to be signed by an authorized verifier. When
+   * enabled, other challenges are rejected before any chunk reads or VDF work.
+   */
+  setRequireSignedChallenges(required: boolean): void
+  /**
+   * Chains whose latest commitment is more than `INACTIVE_CHAIN_TIMEOUT_BLOCKS` behind
+   * `current_height`, sorted by chain id
+   */
+  findInactiveChains(currentHeight: number): Array<string>
+  /**
+   * Remove a chain from the active set and stop answering its availability challenges.
+   * Returns false if the chain is not active.
+   */
+  retireChain(chainId: string): boolean
+  /**
+   * Drop a chain, e.g. after its data file was deleted, releasing its memory map.
+   * With `deleteFiles` its `.data` and `.hashchain` files are deleted as well.
+   */
+  removeChain(chainId: string, deleteFiles?: boolean | undefined | null): void
+  /**
+   * Rehash a chain's data file, replacing the cached data hash that commitments
+   * reuse while the file's size and mtime are unchanged
+   */
+  recomputeDataHash(chainId: string): Buffer
+  /**
+   * Export VDF progress, active chains and availability registrations so the
+   * prover can be resumed elsewhere with `importState`. Chain files stay on disk
+   * and must be reachable at the same paths.
+   */
+  exportState(): Buffer
+  /**
+   * Resume a prover from `exportState` output. The VDF continues from the exported
+   * state and iteration count, which must carry a checkpoint signed by this
+   * prover's key, and chains are reloaded from their files. Only a prover with
+   * the same key and no active chains can import.
+   */
+  importState(state: Buffer): void
+  /**
+   * Checkpoint the VDF as a JSON snapshot that `importVdfSnapshot` can resume from
+   * after a restart. It carries the signed state, memory buffer, last signed block
+   * and shared proof history, but not the chains that `exportState` includes.
+   */
+  exportVdfSnapshot(): string
+  /**
+   * Resume the VDF from `exportVdfSnapshot` output. The snapshot state and its
+   * shared proofs must be signed by this prover's key; otherwise the VDF is left
+   * unchanged and an error is returned.
+   */
+  importVdfSnapshot(snapshot: string): void
+  /**
+   * Reopen this prover's chains stored in an output directory, using its index.json
+   * when present and current and scanning for .hashchain files otherwise. Chains of
+   * other provers, retired or already active chains and unreadable files are skipped.
+   * A rescan rebuilds the index for every prover in the directory. Returns the number
+   * of chains loaded; if they would exceed the chain limit, none are loaded and an
+   * error is returned.
+   */
+  loadExistingChains(outputDirectory: string): number
+  /**
+   * Like `loadExistingChains`, but each chain's commitment log and data file are
+   * checked against its header first and mismatched chains are skipped with a
+   * warning. Reads every data file in full.
+   */
+  loadChainsFromDirectory(dir: string): number
   /** Get number of active chains */
   getActiveChainCount(): number
+  /** Ids of all active chains, sorted */
+  getChainIds(): Array<string>
+  /** Get maximum number of active chains allowed for this prover */
+  getMaxChains(): number
+  /** Set maximum number of active chains allowed for this prover */
+  setMaxChains(maxChains: number): void
   /** Get chain information */
   getChainInfo(chainId: string): string
+  /**
+   * Disk consumed by every active chain's data and hashchain files, plus sidecar
+   * files (chain index, leftover `_original.data` staging files) and free space in
+   * each output directory
+   */
+  getDiskUsageReport(): DiskUsageReport
   /** Update callbacks */
   updateCallbacks(callbacks: ProverCallbacks): void
   /** Get the latest shared VDF proof */
   getLatestSharedVdfProof(): string
   /** Verify the shared VDF proof chain integrity */
   verifySharedVdfProofChain(): boolean
+  /** Get the serialized shared VDF proof chain for independent verification */
+  getSharedVdfProofChain(): Buffer
+  /**
+   * Respond to a VDF reproduction challenge by revealing the VDF state and memory
+   * at the challenged iteration together with the state reached after the segment
+   */
+  respondToVdfReproductionChallenge(challenge: VdfReproductionChallenge): VdfReproductionResponse
+  /**
+   * Reveal the VDF state and memory at `iteration`, so a verifier can recompute
+   * from it to a later published shared VDF proof. Served from memory stored at
+   * recent shared proofs; replays longer than `VDF_SEED_REPLAY_MAX_ITERATIONS`
+   * are refused.
+   */
+  requestVdfCheckpoint(iteration: number): VdfCheckpoint
+  /**
+   * Attest to all active chains at once: their current commitment hashes are
+   * Merkle-aggregated and signed once by the prover key, together with the VDF state
+   * and a timestamp. Per-chain inclusion proofs come from `getPortfolioInclusionProof`.
+   */
+  generatePortfolioProof(): PortfolioProof
+  /**
+   * Inclusion proof for one chain under the portfolio root of the current commitments.
+   * It matches a portfolio proof generated while the chain's commitment was unchanged.
+   */
+  getPortfolioInclusionProof(chainId: string): PortfolioInclusionProof
+  /**
+   * Set the VDF target pace in iterations per second to match local hardware and block
+   * cadence. Consensus minimum iteration checks are unaffected.
+   */
+  setVdfTargetRate(iterationsPerSecond: number): void
+  /**
+   * Forecast when the VDF will have `requiredIterations` total iterations, to decide
+   * whether a block can be signed now or VDF-heavy work should wait
+   */
+  getVdfIterationsForecast(requiredIterations: number): VdfIterationsForecast
+  /**
+   * Whether the current VDF rate can produce the iterations required per block within
+   * one block interval (defaults: `BLOCK_TIME_SECONDS`, `MIN_VDF_ITERATIONS_PER_BLOCK`).
+   * Logs a warning when it cannot.
+   */
+  isVdfViableForBlockTime(blockTimeSeconds?: number | undefined | null, minIterationsPerBlock?: number | undefined | null): boolean
   /** Get VDF performance statistics */
   getVdfPerformanceStats(): string
 }
@@ -852,43 +1568,351 @@ export declare class ProofOfStorageProver {
  * Handles proof verification, challenge generation, and network monitoring
  */
 export declare class ProofOfStorageVerifier {
-  /** Create new verifier instance */
-  constructor(verifierKey: Buffer, callbacks: VerifierCallbacks)
-  /** Verify compact storage proof with production consensus validation */
+  /**
+   * Create new verifier instance. `consensusParams` must match the values the
+   * network's provers were created with.
+   */
+  constructor(verifierKey: Buffer, callbacks: VerifierCallbacks, consensusParams?: ConsensusParams | undefined | null)
+  /**
+   * Verify compact storage proof with production consensus validation.
+   * Proofs at or below the last accepted block height of the prover's chain (its data
+   * hash) are rejected as replays.
+   * Proofs showing less than `MIN_VDF_ITERATIONS_PER_BLOCK` of VDF progress per block since
+   * the chain's previous proof are rejected as reusing a stale VDF output.
+   */
   verifyCompactProof(proof: CompactStorageProof): boolean
-  /** Verify full storage proof */
-  verifyFullProof(proof: FullStorageProof): boolean
-  /** Verify challenge response */
-  verifyChallengeResponse(response: ChallengeResponse, originalChallenge: StorageChallenge): boolean
-  /** Generate challenge for prover */
-  generateChallenge(proverKey: Buffer, commitmentHash: Buffer): StorageChallenge
+  /**
+   * Verify many compact proofs in one call, with the same results, in the same order,
+   * as calling `verifyCompactProof` on each in turn. Stateless checks run in parallel
+   * and once per distinct commitment hash; a repeated commitment hash is rejected as
+   * a replay. Accepted proofs enter the verification cache together.
+   */
+  verifyCompactProofsBatch(proofs: Array<CompactStorageProof>): Array<boolean>
+  /** Verify full storage proof, optionally against the prover's chain header */
+  verifyFullProof(proof: FullStorageProof, header?: HashChainHeader | undefined | null): boolean
+  /**
+   * Verify a full proof against the chunk count registered on the blockchain rather
+   * than the count the prover reports. The count is fetched through
+   * `blockchainData.getDataFileMetadata(dataHashHex)`, whose result must carry
+   * `totalChunks`. A proof or header claiming another count is rejected, and the
+   * chunk selection must follow from the registered count.
+   */
+  verifyFullProofAgainstRegistration(proof: FullStorageProof, header?: HashChainHeader | undefined | null): boolean
+  /**
+   * Verify a full proof for a chain whose Merkle tree was verified in an earlier block.
+   * When the tree root matches `previousVerifiedRoot` the unchanged tree is skipped and only
+   * the new commitment, its chunk selection and VDF continuity are checked.
+   */
+  verifyFullProofIncremental(proof: FullStorageProof, previousVerifiedRoot: Buffer): VerificationResult
+  /** Require every verified commitment to carry beacon entropy */
+  setBeaconRequired(required: boolean): void
+  /**
+   * Set how strictly proofs are validated: "development" accepts short VDFs and
+   * skips latency, beacon and location checks, "staging" checks latency but does
+   * not require beacon entropy or location proofs, and "production" applies all
+   * checks (the default)
+   */
+  setValidationStrictness(strictness: string): void
+  /** Strictness proofs are validated under, which tags verification results */
+  getValidationStrictness(): string
+  /**
+   * Verify a commitment's beacon entropy is a genuine beacon output for its block,
+   * using the `beacon.verifyRandomness` callback
+   */
+  verifyCommitmentBeacon(commitment: StorageCommitment): boolean
+  /** Verify a commitment's data hash matches the data binding recorded in a chain header */
+  verifyDataHashBinding(commitment: StorageCommitment, header: HashChainHeader): boolean
+  /**
+   * Verify a compact proof's Merkle paths connect its latest commitment through its
+   * group and regional proofs to the expected global root
+   */
+  verifyCompactProofHierarchy(proof: CompactProof, expectedGlobal: Buffer): boolean
+  /**
+   * Verify an ultra-compact proof against the expected global root: the proof must
+   * serialize to its fixed size, reference `expected_global`, and carry the hash of
+   * a `position` that is possible under the parameters at its block height
+   */
+  verifyUltraCompactProof(proof: UltraCompactProof, position: HierarchicalPosition, expectedGlobal: Buffer): boolean
+  /**
+   * Verify an ultra-compact proof's claimed hierarchical position: the proof's
+   * position hash must commit to `position`, which must be within bounds of its
+   * level, under the right parent, and consistent with the proof's total chain count
+   */
+  verifyUltraCompactPosition(proof: UltraCompactProof, position: HierarchicalPosition): boolean
+  /**
+   * Fingerprint of the network parameters this verifier applies to proofs at
+   * `block_height`, for checking a node's upgrade schedule against the network's
+   */
+  getParametersFingerprintAt(blockHeight: number): Buffer
+  /**
+   * Verify a proof was generated within `PROOF_WINDOW_BLOCKS` of its committed block,
+   * rejecting stale submissions for blocks whose window has closed
+   */
+  verifyProofWindowTiming(proofBlockHeight: number, proofTimestamp: number, currentBlockHeight: number): boolean
+  /**
+   * Verify a proof's VDF iteration count is consistent with the time elapsed since
+   * its chain started at `chain_start_time`, rejecting proofs claiming more VDF
+   * work than the fastest hardware could have done in that time
+   */
+  verifyVdfElapsedTime(proof: CompactStorageProof, chainStartTime: number): boolean
+  /**
+   * Verify the consensus-relevant fields of full proof metadata against the proof's VDF.
+   * Advisory fields (system stats, performance metrics, guide, features) are ignored.
+   */
+  verifyFullProofMetadata(metadata: FullProofMetadata, vdfProof: MemoryHardVdfProof): boolean
+  /**
+   * Verify challenge response against the prover's `commitment`.
+   * Each returned chunk must hash to the commitment's chunk hash at the position of
+   * that chunk in its selected chunks. Chunks the commitment did not select need a
+   * committed Merkle root instead. When the commitment has a root, every challenged
+   * chunk's hash is also rebuilt up to it with its Merkle proof. Sub-chunk responses
+   * need each revealed byte range to rebuild the committed hash of its chunk.
+   */
+  verifyChallengeResponse(response: ChallengeResponse, originalChallenge: StorageChallenge, commitment: StorageCommitment): boolean
+  /**
+   * Verify a challenge response came from the prover's claimed network position.
+   * The latency bound into its access proof must agree with `verifierRttMs`, the
+   * verifier's own round-trip measurement to the prover. The access proof VDF is
+   * recomputed, which bounds the computation time the prover may claim. The
+   * response must have been received (`receivedAt`) within a window consistent with
+   * the measured round trip.
+   */
+  verifyChallengeResponseLatency(response: ChallengeResponse, originalChallenge: StorageChallenge, receivedAt: number, verifierRttMs: number): boolean
+  /**
+   * Generate challenge for prover.
+   * With `constrainToCommitted`, chunks are chosen only from `committedChunks`
+   * (the commitment's selected chunks) instead of independently.
+   * With `subChunk`, the prover must reveal the bytes at a random offset within
+   * each chunk instead of returning whole chunks.
+   */
+  generateChallenge(proverKey: Buffer, commitmentHash: Buffer, constrainToCommitted?: boolean | undefined | null, committedChunks?: Array<number> | undefined | null, subChunk?: boolean | undefined | null): StorageChallenge
+  /**
+   * Generate a challenge for one whole stripe of an erasure-coded chain. The prover
+   * must return the raw coded shards (data and parity chunks) as stored, which
+   * `verifyCodedShardResponse` checks against the coding parameters.
+   */
+  generateCodedShardChallenge(proverKey: Buffer, commitmentHash: Buffer, erasureCoding: ErasureCodingParams, totalChunks: number): StorageChallenge
+  /**
+   * Verify a response to a coded shard challenge: every returned shard must be
+   * included under the commitment's Merkle root at its challenged index, and the
+   * shards must form a complete stripe whose parity matches its data under the
+   * challenge's coding parameters
+   */
+  verifyCodedShardResponse(response: ChallengeResponse, originalChallenge: StorageChallenge, commitment: StorageCommitment): boolean
+  /**
+   * Sign issued storage challenges so provers can reject ones from unknown
+   * verifiers. The key must belong to this verifier's public key.
+   */
+  setSigningKey(privateKey: Buffer): void
+  /**
+   * Verify `later` continues `earlier` on the same chain (same prover and data) with
+   * enough sequential VDF work between the two blocks, its VDF state derived from
+   * `earlier`'s by the prover's VDF
+   */
+  verifyVdfContinuity(earlier: StorageCommitment, later: StorageCommitment): boolean
+  /**
+   * Test whether a chain's chunk selections across `commitments`, its commitment
+   * history in block order, are spread uniformly over its registered file. A
+   * significant skew suggests the entropy driving selection was steered toward
+   * chunks the prover actually stores.
+   */
+  analyzeSelectionUniformity(commitments: Array<StorageCommitment>): BiasReport
+  /** Verify a challenge targets the given commitment and only chunks it selected */
+  verifyChallengeWithinCommitment(challenge: StorageChallenge, commitment: StorageCommitment): boolean
   /** Audit prover data availability with real verification */
   auditProver(proverKey: Buffer): boolean
   /** Get verifier statistics */
   getVerifierStats(): string
   /** Update verifier callbacks */
   updateCallbacks(callbacks: VerifierCallbacks): void
+  /**
+   * Refresh the prover cache through `network.discoverProvers()`, which must return
+   * an array of NetworkNode. Calls are rate-limited: within
+   * `PROVER_DISCOVERY_MIN_INTERVAL_SECONDS` of the last refresh the cached provers
+   * are returned without calling discovery. Results of a refresh cancelled with
+   * `cancelProverDiscovery` are discarded.
+   */
+  refreshProvers(): Array<NetworkNode>
+  /** Provers discovered within `PROVER_DISCOVERY_TTL_SECONDS`, ordered by node key */
+  getCachedProvers(): Array<NetworkNode>
+  /** Cancel a prover discovery refresh in progress; its results are discarded */
+  cancelProverDiscovery(): void
+  /**
+   * Generate a VDF reproduction challenge for a segment ending at `published_iteration`,
+   * the iteration of a shared VDF proof the prover already published. The segment
+   * starts at a random iteration within `VDF_REPRODUCTION_MAX_SEGMENT` before it, so
+   * the revealed end state must match a state the prover signed.
+   */
+  generateVdfReproductionChallenge(proverKey: Buffer, publishedIteration: number): VdfReproductionChallenge
+  /**
+   * Verify a VDF reproduction response against the prover's serialized shared VDF
+   * proof chain (from `getSharedVdfProofChain`). The chain must be signed by the
+   * challenged prover and contain a proof at the end of the segment whose state is
+   * the revealed end state, and recomputing the segment from the revealed start state
+   * and memory must reach it.
+   */
+  verifyVdfReproduction(challenge: VdfReproductionChallenge, response: VdfReproductionResponse, publishedProofs: Buffer): boolean
+  /**
+   * Pick a random checkpoint iteration to spot-check, within
+   * `VDF_REPRODUCTION_MAX_SEGMENT` iterations before `published_iteration`, the
+   * iteration of a shared VDF proof the prover already published. The prover is
+   * then asked for its checkpoint at the picked iteration, which must recompute to
+   * the published state.
+   */
+  selectVdfCheckpointIteration(proverKey: Buffer, publishedIteration: number): number
+  /**
+   * Verify a prover's VDF checkpoint against its serialized shared VDF proof chain
+   * (from `getSharedVdfProofChain`). The chain must be signed by `prover_key`, and
+   * recomputing from the checkpoint must reach the state of the first published
+   * proof after it. Rejects VDFs whose reported iterations were not actually computed.
+   */
+  verifyVdfCheckpoint(proverKey: Buffer, publishedProofs: Buffer, checkpoint: VdfCheckpoint): boolean
   /**
    * NETWORK CONSENSUS: Verify VDF signature against prover's continuous VDF
-   * This is a critical network consensus validation that ensures blocks are properly signed
+   * This is a critical network consensus validation that ensures blocks are properly signed.
+   * Given the prover's VDF state at signing, the signature is recomputed for this
+   * exact block hash, so a signature made for another block is rejected.
+   * A signature already accepted for a different block height is rejected as a replay.
+   */
+  verifyVdfSignature(proverPublicKey: Buffer, blockHeight: number, blockHash: Buffer, vdfSignature: Buffer, requiredIterations: number, signingState?: VdfSigningState | undefined | null): boolean
+  /**
+   * Forget the block heights of previously accepted VDF signatures, e.g. after a
+   * chain reorganization
+   */
+  clearVdfSignatureCache(): void
+  /**
+   * Verify a portfolio proof covers exactly `expectedChainIds` (hex) and is signed by
+   * its prover key. Individual commitments are checked with `verifyPortfolioInclusion`.
    */
-  verifyVdfSignature(proverPublicKey: Buffer, blockHeight: number, blockHash: Buffer, vdfSignature: Buffer, requiredIterations: number): boolean
+  verifyPortfolioProof(proof: PortfolioProof, expectedChainIds: Array<string>): boolean
+  /** Verify one chain's commitment is covered by a portfolio proof's root */
+  verifyPortfolioInclusion(proof: PortfolioProof, inclusion: PortfolioInclusionProof): boolean
 }
 /**
  * Hierarchical Network Manager - Production Implementation
  * Manages the proof-of-storage network with hierarchical organization
  */
 export declare class HierarchicalNetworkManager {
-  /** Create new network manager */
-  constructor(nodeKey: Buffer, nodeType: string)
+  /**
+   * Create new network manager, refusing to start if its consensus parameters
+   * do not match the expected network fingerprint
+   */
+  constructor(nodeKey: Buffer, nodeType: string, expectedNetworkFingerprint?: Buffer | undefined | null)
+  /**
+   * Sign exported challenge evidence as this node, the challenger named in the
+   * challenges it issues. The key must belong to the node key.
+   */
+  setSigningKey(privateKey: Buffer): void
   /** Register prover in network */
   registerProver(prover: ProofOfStorageProver): boolean
   /** Register verifier in network */
   registerVerifier(verifier: ProofOfStorageVerifier): boolean
-  /** Remove node from network */
+  /**
+   * Register a node, or refresh an existing registration. The node's position must
+   * be `compute_hierarchical_position(node_key, group, region)` for the group and
+   * region its chain is placed in; nodes without a placed chain or with any other
+   * position are rejected.
+   */
+  registerNode(node: NetworkNode): void
+  /** Remove node from network, dropping its data registrations */
   removeNode(nodeKey: Buffer): boolean
-  /** Process network block */
-  processNetworkBlock(blockHeight: number, blockHash: Buffer): void
+  /**
+   * Record that a prover claims the data with `data_hash`. Returns false if the
+   * prover had already registered it. Data already claimed by another prover is
+   * accepted and surfaces in `findDuplicateDataRegistrations`.
+   */
+  registerData(proverKey: Buffer, dataHash: Buffer): boolean
+  /**
+   * Data hashes claimed by more than one prover, with the claiming prover keys in
+   * registration order, so the network can tell replication from double-claiming
+   */
+  findDuplicateDataRegistrations(): Array<DuplicateDataRegistration>
+  /**
+   * Process network block. Every `GLOBAL_STATE_UPDATE_INTERVAL` blocks a global state
+   * checkpoint is returned for submission; every `STATE_CLEANUP_INTERVAL` blocks chains
+   * inactive for `INACTIVE_CHAIN_TIMEOUT_BLOCKS` are removed. The block hash becomes
+   * the beacon for availability challenges issued at this height.
+   */
+  processNetworkBlock(blockHeight: number, blockHash: Buffer): EnhancedCheckpoint | null
+  /** Bound the worker threads used for per-chain block processing */
+  setBlockProcessingThreads(threads: number): void
+  /** Processing time of the most recent block against the block processing target */
+  getBlockProcessingStats(): BlockProcessingStats | null
+  /** Member counts, proof freshness and unhealthy chains of a group in the hierarchy */
+  getGroupHealth(groupId: string): GroupHealth
+  /** Member counts, proof freshness and lagging groups of a region in the hierarchy */
+  getRegionHealth(regionId: string): RegionHealth
+  /** Hierarchical proof tree behind the latest checkpoint, if one has been created */
+  getProofTree(): ProofTree | null
+  /**
+   * Compact proof placing a chain in the global root of the latest checkpoint.
+   * `proofWindow` is the chain's latest commitments and must end at the commitment
+   * the checkpoint recorded for it.
+   */
+  getCompactProof(chainId: Buffer, chainLength: number, proofWindow: Array<PhysicalAccessCommitment>): CompactProof
+  /**
+   * Issue availability challenges for all known chains selected at this block height,
+   * using the block's hash as beacon once it has been processed. With `light`, chunks already audited in full are probed with a single
+   * authenticated slice instead of the whole chunk.
+   */
+  issueBlockChallenges(blockHeight: number, light?: boolean | undefined | null): Array<AvailabilityChallenge>
+  /** Process a prover's response to an issued availability challenge */
+  processAvailabilityResponse(response: AvailabilityResponse): boolean
+  /**
+   * Verify responses to many availability challenges in parallel, returning one
+   * outcome per challenge in challenge order: "success", "timeout", "invalid_data"
+   * or "maintenance_mode". Unanswered challenges time out. Challenges are not
+   * resolved and scores are not updated, unlike `processAvailabilityResponse`.
+   */
+  verifyAvailabilityResponsesBatch(challenges: Array<AvailabilityChallenge>, responses: Array<AvailabilityResponse>): Array<string>
+  /**
+   * Start a streamed availability audit of chunks `startChunk..endChunk` of a
+   * registered chain, checked against its chunk Merkle root. The prover answers with
+   * one segment per entry of `segmentDeadlines`.
+   */
+  createRangeAvailabilityChallenge(chainId: Buffer, chunkRoot: Buffer, startChunk: number, endChunk: number): RangeAvailabilityChallenge
+  /**
+   * Verify one segment of a streamed availability audit. Returns "accepted" while
+   * segments remain, "complete" once all have verified, and "timeout" or
+   * "invalid_data" when the audit fails.
+   */
+  processAvailabilitySegment(segment: ChallengeResponseSegment): string
+  /**
+   * Segments of a streamed availability audit still awaiting verification, or null
+   * once the audit has completed or failed
+   */
+  getOutstandingSegments(challengeId: string): Array<number> | null
+  /** Configure the availability response-time SLA tracker (resets collected samples) */
+  configureAvailabilitySla(sampleWindow: number, slaMs: number): void
+  /**
+   * Configure how many failed availability challenges a chain may have within its
+   * recent window before it is slashable
+   */
+  configureChallengeFailurePolicy(policy: ChallengeFailurePolicy): void
+  /** Get the current availability challenge failure tolerance */
+  getChallengeFailurePolicy(): ChallengeFailurePolicy
+  /** Whether a chain's recent availability failures exceed the tolerance */
+  isChainSlashable(chainId: Buffer): boolean
+  /** Get availability response-time percentiles for a chain */
+  getAvailabilityLatencyStats(chainId: Buffer): AvailabilityLatencyPercentiles | null
+  /**
+   * Export the retained record of a completed availability challenge for disputes,
+   * signed by this node when a signing key is set
+   */
+  exportChallengeEvidence(challengeId: string): ChallengeEvidence
+  /**
+   * Recheck that a challenge issued at `block_height` was selected by the
+   * challenge probability under the block beacon it carries
+   */
+  verifyChallengeSelection(challenge: AvailabilityChallenge, blockHeight: number, totalChunks: number): boolean
+  /**
+   * Check an exported challenge record's id, hash, outcome and any challenger or
+   * prover signatures. With `requireSigned`, the challenger's signature and the
+   * prover's signature on any response must be present.
+   */
+  verifyChallengeEvidence(evidence: ChallengeEvidence, requireSigned?: boolean | undefined | null): boolean
+  /** Set how long completed availability challenge records are retained */
+  setChallengeEvidenceRetention(retentionSeconds: number): void
   /** Get network statistics */
   getNetworkStats(): NetworkStats
   /** Get active nodes */
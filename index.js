@@ -310,15 +310,20 @@ if (!nativeBinding) {
   throw new Error(`Failed to load native binding`)
 }
 
-const { ProofOfStorageProver, ProofOfStorageVerifier, HierarchicalNetworkManager, generateMultiSourceEntropy, createMemoryHardVdfProof, verifyMemoryHardVdfProof, selectChunksFromEntropy, verifyChunkSelection, createCommitmentHash, verifyCommitmentIntegrity } = nativeBinding
+const { ProofOfStorageProver, ProofOfStorageVerifier, HierarchicalNetworkManager, generateMultiSourceEntropy, getNetworkParametersFingerprint, crateConsensusFingerprint, verifyVdfProofChain, createMemoryHardVdfProof, verifyMemoryHardVdfProof, selectChunksFromEntropy, verifyChunkSelection, createCommitmentHash, serializeStorageCommitment, deserializeStorageCommitment, verifyCommitmentIntegrity } = nativeBinding
 
 module.exports.ProofOfStorageProver = ProofOfStorageProver
 module.exports.ProofOfStorageVerifier = ProofOfStorageVerifier
 module.exports.HierarchicalNetworkManager = HierarchicalNetworkManager
 module.exports.generateMultiSourceEntropy = generateMultiSourceEntropy
+module.exports.getNetworkParametersFingerprint = getNetworkParametersFingerprint
+module.exports.crateConsensusFingerprint = crateConsensusFingerprint
+module.exports.verifyVdfProofChain = verifyVdfProofChain
 module.exports.createMemoryHardVdfProof = createMemoryHardVdfProof
 module.exports.verifyMemoryHardVdfProof = verifyMemoryHardVdfProof
 module.exports.selectChunksFromEntropy = selectChunksFromEntropy
 module.exports.verifyChunkSelection = verifyChunkSelection
 module.exports.createCommitmentHash = createCommitmentHash
+module.exports.serializeStorageCommitment = serializeStorageCommitment
+module.exports.deserializeStorageCommitment = deserializeStorageCommitment
 module.exports.verifyCommitmentIntegrity = verifyCommitmentIntegrity
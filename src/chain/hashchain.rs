@@ -1,10 +1,15 @@
-use crate::chain::storage::{ChainStorage, FileStats};
+use crate::chain::storage::{ChainStorage, ChunkIter, FileStats};
 use crate::core::{
+    erasure::validate_erasure_params,
     errors::{HashChainError, HashChainResult},
     types::*,
-    utils::{compute_sha256, generate_chain_id, PerformanceTimer},
+    utils::{
+        compute_merkle_levels, compute_sha256, generate_chain_id, merkle_path_from_levels,
+        MerklePathNode, PerformanceTimer,
+    },
 };
 use napi::bindgen_prelude::*;
+use std::time::SystemTime;
 
 /// Production-ready HashChain implementation
 pub struct IndividualHashChain {
@@ -25,6 +30,18 @@ pub struct IndividualHashChain {
     pub commitments: Vec<PhysicalAccessCommitment>,
     /// HashChain header
     pub header: Option<HashChainHeader>,
+    /// Block height of the latest commitment (initial block height until the first)
+    pub last_activity_height: u64,
+    /// Data file hash with the file size and mtime it was computed at
+    data_hash_cache: Option<(u64, SystemTime, [u8; 32])>,
+    /// Merkle tree levels over the chunk hashes, leaves first, built on first use
+    merkle_levels: Option<Vec<Vec<[u8; 32]>>>,
+}
+
+/// Size and modification time of a file, used to tell whether a cached hash is stale
+fn file_stamp(path: &str) -> HashChainResult<(u64, SystemTime)> {
+    let metadata = std::fs::metadata(path)?;
+    Ok((metadata.len(), metadata.modified()?))
 }
 
 impl IndividualHashChain {
@@ -39,14 +56,51 @@ impl IndividualHashChain {
         let timer = PerformanceTimer::new("new_hashchain_from_stream");
 
         // Create storage from streamed data
-        let mut storage = ChainStorage::create_from_stream(data_stream, &output_dir, &public_key)?;
+        let storage = ChainStorage::create_from_stream(data_stream, &output_dir, &public_key)?;
+        Self::with_new_storage(
+            public_key,
+            storage,
+            initial_block_height,
+            initial_block_hash,
+            timer,
+        )
+    }
 
+    /// Create new HashChain from a data file on disk, encoding it without ever
+    /// holding the whole file in memory
+    pub fn new_from_file(
+        public_key: Buffer,
+        input_path: &str,
+        output_dir: String,
+        initial_block_height: u64,
+        initial_block_hash: Buffer,
+    ) -> HashChainResult<Self> {
+        let timer = PerformanceTimer::new("new_hashchain_from_file");
+
+        let storage = ChainStorage::create_from_file(input_path, &output_dir, &public_key)?;
+        Self::with_new_storage(
+            public_key,
+            storage,
+            initial_block_height,
+            initial_block_hash,
+            timer,
+        )
+    }
+
+    /// Write the header for freshly created storage and wrap it in a new chain
+    fn with_new_storage(
+        public_key: Buffer,
+        mut storage: ChainStorage,
+        initial_block_height: u64,
+        initial_block_hash: Buffer,
+        timer: PerformanceTimer,
+    ) -> HashChainResult<Self> {
         // Compute file hash and create chain ID
         let data_file_hash = storage.compute_file_hash()?;
         let chain_id = generate_chain_id(&public_key, &data_file_hash);
 
         // Create HashChain header
-        let header = HashChainHeader {
+        let mut header = HashChainHeader {
             magic: Buffer::from(HASHCHAIN_MAGIC.to_vec()),
             format_version: HASHCHAIN_FORMAT_VERSION,
             data_file_hash: Buffer::from(data_file_hash.to_vec()),
@@ -62,10 +116,13 @@ impl IndividualHashChain {
             initial_block_height: initial_block_height as f64,
             initial_block_hash: initial_block_hash.clone(),
             header_checksum: Buffer::from([0u8; 32].to_vec()), // Will be computed when saved
+            erasure_coding: None,
+            file_encoding: storage.encoding_info.clone(),
         };
 
         // Write header to .hashchain file
-        storage.write_hashchain_header(&header)?;
+        storage.write_hashchain_header(&mut header)?;
+        let (data_file_size, data_file_modified) = file_stamp(&storage.data_file_path)?;
 
         let elapsed = timer.elapsed_ms();
         log::info!(
@@ -85,11 +142,24 @@ impl IndividualHashChain {
             initial_block_hash,
             commitments: Vec::new(),
             header: Some(header),
+            last_activity_height: initial_block_height,
+            data_hash_cache: Some((data_file_size, data_file_modified, data_file_hash)),
+            merkle_levels: None,
         })
     }
 
     /// Load existing HashChain from .hashchain file
     pub fn load_from_file(hashchain_file_path: String) -> HashChainResult<Self> {
+        Self::open(hashchain_file_path, false)
+    }
+
+    /// Like `load_from_file`, but a commitment log that cannot be read is an error
+    /// instead of loading as an empty chain
+    pub fn load_from_file_strict(hashchain_file_path: String) -> HashChainResult<Self> {
+        Self::open(hashchain_file_path, true)
+    }
+
+    fn open(hashchain_file_path: String, strict_log: bool) -> HashChainResult<Self> {
         let timer = PerformanceTimer::new("load_hashchain");
 
         // Derive data file path
@@ -125,9 +195,12 @@ impl IndividualHashChain {
         storage.set_prover_key(header.public_key.clone())?;
 
         // Load commitments before moving storage
-        let commitments = storage
-            .load_commitments_from_file()
-            .unwrap_or_else(|_| Vec::new()); // Empty vec if file doesn't exist or is corrupt
+        let commitments = match storage.load_commitments_from_file() {
+            Ok(commitments) => commitments,
+            Err(e) if strict_log => return Err(e),
+            Err(_) => Vec::new(), // Empty vec if file doesn't exist or is corrupt
+        };
+        let checkpoint = storage.load_log_checkpoint().ok().flatten();
 
         // Generate chain ID from header data
         let chain_id = generate_chain_id(&header.public_key, &header.data_file_hash);
@@ -144,16 +217,32 @@ impl IndividualHashChain {
             chain_id: chain_id.to_vec(),
             public_key: header.public_key.clone(),
             storage: Some(storage),
-            current_commitment: if header.chain_length > 0 {
-                Some(header.anchored_commitment.clone())
-            } else {
-                None
-            },
-            chain_length: header.chain_length,
+            // Continue from the newest logged commitment, else the compaction checkpoint
+            current_commitment: commitments
+                .last()
+                .map(|commitment| commitment.commitment_hash.clone())
+                .or_else(|| {
+                    checkpoint
+                        .as_ref()
+                        .map(|checkpoint| Buffer::from(checkpoint.last_commitment.clone()))
+                })
+                .or_else(|| (header.chain_length > 0).then(|| header.anchored_commitment.clone())),
+            chain_length: header.chain_length.max(
+                checkpoint
+                    .as_ref()
+                    .map_or(0, |checkpoint| checkpoint.truncated_commitments as u32)
+                    + commitments.len() as u32,
+            ),
             initial_block_height: header.initial_block_height as u64,
             initial_block_hash: header.initial_block_hash.clone(),
+            last_activity_height: commitments
+                .last()
+                .map(|commitment| commitment.block_height as u64)
+                .unwrap_or(header.initial_block_height as u64),
             commitments,
             header: Some(header),
+            data_hash_cache: None,
+            merkle_levels: None,
         })
     }
 
@@ -175,6 +264,9 @@ impl IndividualHashChain {
             initial_block_hash,
             commitments: Vec::new(),
             header: None,
+            last_activity_height: initial_block_height,
+            data_hash_cache: None,
+            merkle_levels: None,
         })
     }
 
@@ -189,7 +281,7 @@ impl IndividualHashChain {
         self.chain_id = generate_chain_id(&self.public_key, &data_file_hash);
 
         // Create HashChain header
-        let header = HashChainHeader {
+        let mut header = HashChainHeader {
             magic: Buffer::from(HASHCHAIN_MAGIC.to_vec()),
             format_version: HASHCHAIN_FORMAT_VERSION,
             data_file_hash: Buffer::from(data_file_hash.to_vec()),
@@ -205,14 +297,17 @@ impl IndividualHashChain {
             initial_block_height: self.initial_block_height as f64,
             initial_block_hash: self.initial_block_hash.clone(),
             header_checksum: Buffer::from([0u8; 32].to_vec()),
+            erasure_coding: None,
+            file_encoding: storage.encoding_info.clone(),
         };
 
         // Write header to .hashchain file
-        storage.write_hashchain_header(&header)?;
+        storage.write_hashchain_header(&mut header)?;
 
         // Update instance state
         self.storage = Some(storage);
         self.header = Some(header);
+        self.cache_data_hash(data_file_hash)?;
 
         Ok(())
     }
@@ -259,10 +354,16 @@ impl IndividualHashChain {
             let mut final_commitment = commitment;
             final_commitment.commitment_hash = Buffer::from(commitment_hash.to_vec());
 
+            // Persist before updating chain state so the log never lags memory
+            if let Some(storage) = &self.storage {
+                storage.append_commitment(&final_commitment)?;
+            }
+
             // Update chain state
             self.commitments.push(final_commitment.clone());
             self.current_commitment = Some(final_commitment.commitment_hash.clone());
             self.chain_length += 1;
+            self.last_activity_height = self.last_activity_height.max(block_height);
 
             let elapsed = timer.elapsed_ms();
             log::debug!(
@@ -283,7 +384,7 @@ impl IndividualHashChain {
         &self,
         commitment: &PhysicalAccessCommitment,
     ) -> HashChainResult<[u8; 32]> {
-        let mut data = Vec::new();
+        let mut data = PHYSICAL_ACCESS_COMMITMENT_DOMAIN.to_vec();
 
         // Add all commitment fields
         data.extend_from_slice(&(commitment.block_height as u64).to_be_bytes());
@@ -303,6 +404,191 @@ impl IndividualHashChain {
         Ok(compute_sha256(&data))
     }
 
+    /// Record the erasure coding layout of the chain's data in its header
+    pub fn set_erasure_coding(&mut self, params: ErasureCodingParams) -> HashChainResult<()> {
+        validate_erasure_params(&params)?;
+        let storage = self
+            .storage
+            .as_ref()
+            .ok_or(HashChainError::NoDataStreamed)?;
+        let header = self.header.as_mut().ok_or(HashChainError::NoDataStreamed)?;
+
+        header.erasure_coding = Some(params);
+        storage.write_hashchain_header(header)
+    }
+
+    /// Check the data file behind a loaded chain still hashes to the header's data file
+    /// hash and, when the header records one, matches its file encoding at both the
+    /// encoded and decoded layer, caching the hash. Reads the whole file.
+    pub fn verify_stored_files(&mut self) -> HashChainResult<()> {
+        let storage = self
+            .storage
+            .as_mut()
+            .ok_or(HashChainError::NoDataStreamed)?;
+        let header = self.header.as_ref().ok_or(HashChainError::NoDataStreamed)?;
+
+        let data_file_hash = storage.compute_file_hash()?;
+        if header.data_file_hash.as_ref() != data_file_hash.as_slice() {
+            return Err(HashChainError::Corruption(format!(
+                "Data file {} does not match the hash in its header",
+                storage.data_file_path
+            )));
+        }
+        if let Some(info) = &header.file_encoding {
+            if !storage.verify_file_encoding_info(info)? {
+                return Err(HashChainError::Corruption(format!(
+                    "Data file {} does not match the encoding in its header",
+                    storage.data_file_path
+                )));
+            }
+        }
+        self.cache_data_hash(data_file_hash)
+    }
+
+    /// Hash of the chain's decoded data. Reuses the cached hash while the data file's
+    /// size and mtime are unchanged, otherwise rehashes the whole file.
+    pub fn data_hash(&mut self) -> HashChainResult<[u8; 32]> {
+        let storage = self
+            .storage
+            .as_ref()
+            .ok_or(HashChainError::NoDataStreamed)?;
+        let (size, modified) = file_stamp(&storage.data_file_path)?;
+        if let Some((cached_size, cached_modified, hash)) = self.data_hash_cache {
+            if cached_size == size && cached_modified == modified {
+                return Ok(hash);
+            }
+        }
+        self.recompute_data_hash()
+    }
+
+    /// Rehash the whole data file and replace the cached data hash
+    pub fn recompute_data_hash(&mut self) -> HashChainResult<[u8; 32]> {
+        self.data_hash_cache = None;
+        self.merkle_levels = None;
+        let data_hash = self
+            .storage
+            .as_mut()
+            .ok_or(HashChainError::NoDataStreamed)?
+            .compute_file_hash()?;
+        self.cache_data_hash(data_hash)?;
+        Ok(data_hash)
+    }
+
+    /// Record `data_hash` against the data file's current size and mtime
+    fn cache_data_hash(&mut self, data_hash: [u8; 32]) -> HashChainResult<()> {
+        let storage = self
+            .storage
+            .as_ref()
+            .ok_or(HashChainError::NoDataStreamed)?;
+        let (size, modified) = file_stamp(&storage.data_file_path)?;
+        self.data_hash_cache = Some((size, modified, data_hash));
+        Ok(())
+    }
+
+    /// Merkle root over the blake3 hashes of all chunks. Computed on first use and
+    /// recorded in the header, since the chain's data never changes.
+    pub fn merkle_root(&mut self) -> HashChainResult<[u8; 32]> {
+        if let Some(header) = &self.header {
+            if header.merkle_root.len() == HASH_SIZE && header.merkle_root.iter().any(|&b| b != 0) {
+                let mut root = [0u8; 32];
+                root.copy_from_slice(&header.merkle_root);
+                return Ok(root);
+            }
+        }
+        let root = self
+            .merkle_levels()?
+            .last()
+            .map_or([0u8; 32], |level| level[0]);
+        self.record_merkle_root(root)
+    }
+
+    /// Every level of the Merkle tree over the blake3 hashes of all chunks, leaves
+    /// first. Built once and kept, so paths for many chunks share one tree.
+    pub fn merkle_levels(&mut self) -> HashChainResult<&[Vec<[u8; 32]>]> {
+        if self.merkle_levels.is_none() {
+            let chunk_hashes = self.compute_all_chunk_hashes()?;
+            self.merkle_levels = Some(compute_merkle_levels(&chunk_hashes));
+        }
+        Ok(self.merkle_levels.as_deref().unwrap_or_default())
+    }
+
+    /// Merkle inclusion paths from each chunk's hash up to the chain's Merkle root,
+    /// returned with that root
+    pub fn merkle_inclusion_paths(
+        &mut self,
+        chunk_indices: &[u32],
+    ) -> HashChainResult<([u8; 32], Vec<Vec<MerklePathNode>>)> {
+        let levels = self.merkle_levels()?;
+        let root = levels.last().map_or([0u8; 32], |level| level[0]);
+        let paths = chunk_indices
+            .iter()
+            .map(|&chunk_index| {
+                merkle_path_from_levels(levels, chunk_index as usize).ok_or(
+                    HashChainError::ChunkIndexOutOfRange {
+                        index: chunk_index,
+                        max: levels[0].len() as u64,
+                    },
+                )
+            })
+            .collect::<HashChainResult<Vec<_>>>()?;
+        Ok((self.record_merkle_root(root)?, paths))
+    }
+
+    /// Chunks `first..end`, padded as by `read_chunk`, each with its inclusion path to
+    /// the chain's Merkle root. Paths share the cached tree; only these chunks are read.
+    pub fn segment_chunks(
+        &mut self,
+        first: u32,
+        end: u32,
+    ) -> HashChainResult<Vec<(Buffer, Vec<MerklePathNode>)>> {
+        let (_, paths) = self.merkle_inclusion_paths(&(first..end).collect::<Vec<_>>())?;
+        (first..end)
+            .zip(paths)
+            .map(|(chunk_index, path)| Ok((self.read_chunk(chunk_index)?, path)))
+            .collect()
+    }
+
+    /// Record `root` in the header if it has none yet
+    fn record_merkle_root(&mut self, root: [u8; 32]) -> HashChainResult<[u8; 32]> {
+        if let (Some(storage), Some(header)) = (&self.storage, &mut self.header) {
+            if header.merkle_root.iter().all(|&b| b == 0) {
+                header.merkle_root = Buffer::from(root.to_vec());
+                storage.write_hashchain_header(header)?;
+            }
+        }
+        Ok(root)
+    }
+
+    /// Compact the .hashchain log to the most recent `keep_recent` commitments plus a
+    /// checkpoint of the truncated prefix. At least `PROOF_WINDOW_BLOCKS` are kept so
+    /// proof windows stay available. Returns the number of bytes reclaimed.
+    pub fn compact_log(&mut self, keep_recent: u32) -> HashChainResult<u64> {
+        if keep_recent < PROOF_WINDOW_BLOCKS {
+            return Err(HashChainError::RetentionPolicy {
+                reason: format!(
+                    "Must keep at least {} recent commitments, got {}",
+                    PROOF_WINDOW_BLOCKS, keep_recent
+                ),
+            });
+        }
+
+        let storage = self
+            .storage
+            .as_ref()
+            .ok_or(HashChainError::NoDataStreamed)?;
+        let reclaimed = storage.compact_hashchain_log(keep_recent as usize)?;
+
+        // Only drop what the log truncated: everything up to the checkpoint's last commitment
+        if let Some(checkpoint) = storage.load_log_checkpoint()? {
+            if let Some(position) = self.commitments.iter().position(|commitment| {
+                commitment.commitment_hash.as_ref() == checkpoint.last_commitment.as_slice()
+            }) {
+                self.commitments.drain(..=position);
+            }
+        }
+        Ok(reclaimed)
+    }
+
     /// Get proof window for last PROOF_WINDOW_BLOCKS commitments
     pub fn get_proof_window(&self) -> HashChainResult<ProofWindow> {
         if self.commitments.len() < PROOF_WINDOW_BLOCKS as usize {
@@ -335,6 +621,67 @@ impl IndividualHashChain {
         }
     }
 
+    /// Blake3 hashes of all chunks in index order, decoded and hashed in parallel
+    pub fn compute_all_chunk_hashes(&mut self) -> HashChainResult<Vec<[u8; 32]>> {
+        self.storage
+            .as_mut()
+            .ok_or(HashChainError::NoDataStreamed)?
+            .compute_all_chunk_blake3_hashes()
+    }
+
+    /// Iterate all decoded chunks in order; the final chunk is not padded
+    pub fn chunk_iter(&mut self) -> HashChainResult<ChunkIter<'_>> {
+        self.storage
+            .as_mut()
+            .ok_or(HashChainError::NoDataStreamed)?
+            .chunks_from(0)
+    }
+
+    /// Read up to `count` decoded chunks starting at `start_index`, stopping at the
+    /// end of the data
+    pub fn read_chunk_batch(
+        &mut self,
+        start_index: u32,
+        count: u32,
+    ) -> HashChainResult<Vec<Buffer>> {
+        self.storage
+            .as_mut()
+            .ok_or(HashChainError::NoDataStreamed)?
+            .chunks_from(start_index as u64)?
+            .take(count as usize)
+            .collect()
+    }
+
+    /// Read chunk, retrying transient I/O failures with exponential backoff.
+    /// Permanent errors (missing file, out-of-range index) fail immediately.
+    pub fn read_chunk_with_retry(
+        &mut self,
+        chunk_index: u32,
+        max_attempts: u32,
+        initial_backoff_ms: u64,
+    ) -> HashChainResult<Buffer> {
+        let mut backoff_ms = initial_backoff_ms;
+        let mut attempt = 1;
+        loop {
+            match self.read_chunk(chunk_index) {
+                Ok(chunk) => return Ok(chunk),
+                Err(e) if e.is_transient() && attempt < max_attempts => {
+                    log::warn!(
+                        "Transient read failure for chunk {} (attempt {}/{}): {}",
+                        chunk_index,
+                        attempt,
+                        max_attempts,
+                        e
+                    );
+                    std::thread::sleep(std::time::Duration::from_millis(backoff_ms));
+                    backoff_ms = backoff_ms.saturating_mul(2);
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
     /// Get total chunks count
     pub fn get_total_chunks(&self) -> u64 {
         if let Some(ref storage) = self.storage {
@@ -389,3 +736,314 @@ impl IndividualHashChain {
         self.chain_id.clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_chain(name: &str) -> (IndividualHashChain, String) {
+        let output_dir = std::env::temp_dir()
+            .join(format!("hashchain_{}_{}", name, std::process::id()))
+            .to_string_lossy()
+            .into_owned();
+        let data: Vec<u8> = (0..3 * CHUNK_SIZE_BYTES + 100)
+            .map(|i| (i % 251) as u8)
+            .collect();
+        let chain = IndividualHashChain::new_from_stream(
+            Buffer::from([7u8; 32].to_vec()),
+            Buffer::from(data),
+            output_dir.clone(),
+            100,
+            Buffer::from([1u8; 32].to_vec()),
+        )
+        .unwrap();
+        (chain, output_dir)
+    }
+
+    #[test]
+    fn test_file_and_stream_creation_match() {
+        let output_dir = std::env::temp_dir()
+            .join(format!("hashchain_file_stream_{}", std::process::id()))
+            .to_string_lossy()
+            .into_owned();
+        std::fs::create_dir_all(&output_dir).unwrap();
+        let data: Vec<u8> = (0..4 * CHUNK_SIZE_BYTES + 333)
+            .map(|i| (i % 253) as u8)
+            .collect();
+        let input_path = format!("{}/input.bin", output_dir);
+        std::fs::write(&input_path, &data).unwrap();
+        let public_key = Buffer::from([7u8; 32].to_vec());
+
+        // Both chains write the same files, so read each one before creating the next
+        let snapshot = |mut chain: IndividualHashChain| {
+            let header = chain.header.clone().unwrap();
+            let chunks: Vec<Vec<u8>> = (0..chain.get_total_chunks() as u32)
+                .map(|i| chain.read_chunk(i).unwrap().to_vec())
+                .collect();
+            (chain.chain_id.clone(), header, chunks)
+        };
+        let (stream_id, stream_header, stream_chunks) = snapshot(
+            IndividualHashChain::new_from_stream(
+                public_key.clone(),
+                Buffer::from(data),
+                output_dir.clone(),
+                100,
+                Buffer::from([1u8; 32].to_vec()),
+            )
+            .unwrap(),
+        );
+        let (file_id, file_header, file_chunks) = snapshot(
+            IndividualHashChain::new_from_file(
+                public_key,
+                &input_path,
+                output_dir.clone(),
+                100,
+                Buffer::from([1u8; 32].to_vec()),
+            )
+            .unwrap(),
+        );
+
+        assert_eq!(file_id, stream_id);
+        assert_eq!(file_chunks.len(), 5);
+        assert_eq!(file_chunks, stream_chunks);
+        assert_eq!(
+            file_header.data_file_hash.as_ref(),
+            stream_header.data_file_hash.as_ref()
+        );
+        assert_eq!(file_header.total_chunks, stream_header.total_chunks);
+        assert_eq!(
+            file_header.data_file_path_hash.as_ref(),
+            stream_header.data_file_path_hash.as_ref()
+        );
+        assert_eq!(
+            file_header.header_checksum.as_ref(),
+            stream_header.header_checksum.as_ref()
+        );
+
+        std::fs::remove_dir_all(output_dir).unwrap();
+    }
+
+    #[test]
+    fn test_chunk_iter_and_batches_reassemble_data() {
+        let (mut chain, output_dir) = test_chain("chunk_iter");
+        let data: Vec<u8> = (0..3 * CHUNK_SIZE_BYTES + 100)
+            .map(|i| (i % 251) as u8)
+            .collect();
+
+        // Decoded chunks concatenate back to the data; only the last is short
+        let iter = chain.chunk_iter().unwrap();
+        assert_eq!(iter.size_hint(), (4, Some(4)));
+        let chunks: Vec<Buffer> = iter.collect::<HashChainResult<_>>().unwrap();
+        assert_eq!(chunks.len(), 4);
+        assert_eq!(chunks[3].len(), 100);
+        assert!(chunks[..3]
+            .iter()
+            .all(|chunk| chunk.len() == CHUNK_SIZE_BYTES as usize));
+        let reassembled: Vec<u8> = chunks.iter().flat_map(|chunk| chunk.to_vec()).collect();
+        assert_eq!(reassembled, data);
+
+        // Batches are slices of the same sequence, stopping at the end of the data
+        let batch = chain.read_chunk_batch(1, 2).unwrap();
+        assert_eq!(batch.len(), 2);
+        assert_eq!(batch[0].as_ref(), chunks[1].as_ref());
+        assert_eq!(batch[1].as_ref(), chunks[2].as_ref());
+        let tail = chain.read_chunk_batch(3, 10).unwrap();
+        assert_eq!(tail.len(), 1);
+        assert_eq!(tail[0].as_ref(), chunks[3].as_ref());
+
+        // Starting at the end yields nothing; starting past it is rejected
+        assert!(chain.read_chunk_batch(4, 1).unwrap().is_empty());
+        assert!(matches!(
+            chain.read_chunk_batch(5, 1),
+            Err(HashChainError::ChunkIndexOutOfRange { index: 5, max: 4 })
+        ));
+
+        std::fs::remove_dir_all(output_dir).unwrap();
+    }
+
+    #[test]
+    fn test_data_hash_cache_tracks_file_stamp() {
+        use std::io::Write;
+
+        let (mut chain, output_dir) = test_chain("data_hash_cache");
+        let data_path = chain.storage.as_ref().unwrap().data_file_path.clone();
+        let actual = chain.data_hash().unwrap();
+        let sentinel = [0xAB; 32];
+        let plant_sentinel = |chain: &mut IndividualHashChain| {
+            let (size, modified) = file_stamp(&data_path).unwrap();
+            chain.data_hash_cache = Some((size, modified, sentinel));
+        };
+
+        // An unchanged file is served from the cache without rehashing
+        plant_sentinel(&mut chain);
+        assert_eq!(chain.data_hash().unwrap(), sentinel);
+        assert_eq!(chain.data_hash().unwrap(), sentinel);
+
+        // A new mtime forces a rehash
+        let file = std::fs::File::options()
+            .write(true)
+            .open(&data_path)
+            .unwrap();
+        let (_, modified) = file_stamp(&data_path).unwrap();
+        file.set_modified(modified + std::time::Duration::from_secs(60))
+            .unwrap();
+        assert_eq!(chain.data_hash().unwrap(), actual);
+
+        // As does a new size
+        plant_sentinel(&mut chain);
+        std::fs::OpenOptions::new()
+            .append(true)
+            .open(&data_path)
+            .unwrap()
+            .write_all(&[0u8; 16])
+            .unwrap();
+        assert_ne!(chain.data_hash().unwrap(), sentinel);
+
+        // recompute_data_hash always rehashes
+        plant_sentinel(&mut chain);
+        assert_ne!(chain.recompute_data_hash().unwrap(), sentinel);
+
+        std::fs::remove_dir_all(output_dir).unwrap();
+    }
+
+    #[test]
+    fn test_compact_log_keeps_linkage_across_reload() {
+        let (mut chain, output_dir) = test_chain("compact");
+        for height in 101..113u64 {
+            chain
+                .add_commitment(
+                    Buffer::from([height as u8; 32].to_vec()),
+                    height,
+                    vec![0, 2],
+                )
+                .unwrap();
+        }
+        let newest = chain.current_commitment.clone().unwrap();
+
+        assert!(chain.compact_log(PROOF_WINDOW_BLOCKS).unwrap() > 0);
+        assert_eq!(chain.commitments.len(), PROOF_WINDOW_BLOCKS as usize);
+        assert!(chain.verify_chain().unwrap());
+
+        let hashchain_path = chain.storage.as_ref().unwrap().hashchain_file_path.clone();
+        let mut reloaded = IndividualHashChain::load_from_file(hashchain_path).unwrap();
+        let checkpoint = reloaded
+            .storage
+            .as_ref()
+            .unwrap()
+            .load_log_checkpoint()
+            .unwrap()
+            .unwrap();
+        assert_eq!(checkpoint.truncated_commitments, 7);
+        assert_eq!(reloaded.commitments.len(), PROOF_WINDOW_BLOCKS as usize);
+        assert_eq!(
+            reloaded.commitments[0].previous_commitment.as_ref(),
+            checkpoint.last_commitment.as_slice()
+        );
+        assert!(reloaded.verify_chain().unwrap());
+        assert_eq!(
+            reloaded.current_commitment.as_deref(),
+            Some(newest.as_ref())
+        );
+        assert_eq!(reloaded.chain_length, 12);
+
+        // Commitments added after the reload extend the same chain
+        reloaded
+            .add_commitment(Buffer::from([0xAA; 32].to_vec()), 113, vec![1])
+            .unwrap();
+        assert!(reloaded.verify_chain().unwrap());
+
+        std::fs::remove_dir_all(output_dir).unwrap();
+    }
+
+    #[test]
+    fn test_reload_verifies_file_encoding() {
+        let (chain, output_dir) = test_chain("file_encoding");
+        let info = chain
+            .header
+            .as_ref()
+            .unwrap()
+            .file_encoding
+            .clone()
+            .unwrap();
+        assert_eq!(info.prover_key.as_ref(), &[7u8; 32]);
+
+        // The encoding survives a reload and matches the stored file
+        let hashchain_path = chain.storage.as_ref().unwrap().hashchain_file_path.clone();
+        let mut reloaded = IndividualHashChain::load_from_file(hashchain_path.clone()).unwrap();
+        let reloaded_info = reloaded
+            .header
+            .as_ref()
+            .unwrap()
+            .file_encoding
+            .clone()
+            .unwrap();
+        assert_eq!(
+            reloaded_info.encoded_hash.as_ref(),
+            info.encoded_hash.as_ref()
+        );
+        assert_eq!(
+            reloaded_info.original_hash.as_ref(),
+            info.original_hash.as_ref()
+        );
+        reloaded.verify_stored_files().unwrap();
+
+        // A recorded hash the file does not match is detected at either layer
+        for encoded_layer in [true, false] {
+            let mut header = reloaded.header.clone().unwrap();
+            let mut tampered = info.clone();
+            if encoded_layer {
+                tampered.encoded_hash = Buffer::from([0u8; 32].to_vec());
+            } else {
+                tampered.original_hash = Buffer::from([0u8; 32].to_vec());
+            }
+            header.file_encoding = Some(tampered);
+            let storage = reloaded.storage.as_ref().unwrap();
+            storage.write_hashchain_header(&mut header).unwrap();
+
+            let mut tampered_chain =
+                IndividualHashChain::load_from_file(hashchain_path.clone()).unwrap();
+            assert!(matches!(
+                tampered_chain.verify_stored_files(),
+                Err(HashChainError::Corruption(_))
+            ));
+        }
+
+        std::fs::remove_dir_all(output_dir).unwrap();
+    }
+
+    #[test]
+    fn test_range_audit_segments_verify_against_merkle_root() {
+        use crate::core::availability::{
+            build_range_segment, range_segment_bounds, verify_response_segment,
+            AvailabilityChallenger,
+        };
+
+        let (mut chain, output_dir) = test_chain("range_audit");
+        let total_chunks = chain.get_total_chunks() as u32;
+        let root = chain.merkle_root().unwrap();
+        let challenge = AvailabilityChallenger::new()
+            .create_range_challenge(
+                Buffer::from(chain.get_chain_id()),
+                Buffer::from(root.to_vec()),
+                total_chunks,
+                0,
+                total_chunks,
+                Buffer::from([2u8; 32].to_vec()),
+            )
+            .unwrap();
+
+        // The last chunk is short on disk and served padded
+        let (first, end) = range_segment_bounds(&challenge, 0).unwrap();
+        assert_eq!(end, total_chunks);
+        let chunks = chain.segment_chunks(first, end).unwrap();
+        let segment = build_range_segment(&challenge, 0, chunks).unwrap();
+        assert!(verify_response_segment(&challenge, &segment));
+
+        let mut tampered = segment.clone();
+        tampered.chunks[end as usize - 1].chunk_data =
+            Buffer::from(vec![0u8; CHUNK_SIZE_BYTES as usize]);
+        assert!(!verify_response_segment(&challenge, &tampered));
+
+        std::fs::remove_dir_all(output_dir).unwrap();
+    }
+}
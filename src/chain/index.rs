@@ -0,0 +1,385 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use crate::chain::hashchain::IndividualHashChain;
+use crate::core::{
+    errors::{HashChainError, HashChainResult},
+    types::{CHAIN_INDEX_FILE_NAME, CHAIN_INDEX_VERSION},
+};
+
+/// Files backing one chain in an output directory
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ChainIndexEntry {
+    pub data_file_path: String,
+    pub hashchain_file_path: String,
+    pub total_chunks: u64,
+    /// Hex public key of the prover owning the chain
+    pub public_key: String,
+}
+
+impl ChainIndexEntry {
+    /// Entry for a chain with file storage; None for in-memory chains
+    pub fn for_chain(chain: &IndividualHashChain) -> Option<Self> {
+        let storage = chain.storage.as_ref()?;
+        Some(Self {
+            data_file_path: storage.data_file_path.clone(),
+            hashchain_file_path: storage.hashchain_file_path.clone(),
+            total_chunks: chain.get_total_chunks(),
+            public_key: hex::encode(&chain.public_key),
+        })
+    }
+}
+
+/// Persisted chain_id -> files mapping of an output directory, so chains can be
+/// reopened from known paths instead of discovering them by scanning the directory
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ChainIndex {
+    pub version: u32,
+    /// Entries by hex chain id
+    pub chains: BTreeMap<String, ChainIndexEntry>,
+    /// Hex ids of chains retired or removed from the directory; their files may remain
+    /// but loading must not reattach them
+    #[serde(default)]
+    pub retired: BTreeSet<String>,
+}
+
+impl Default for ChainIndex {
+    fn default() -> Self {
+        Self {
+            version: CHAIN_INDEX_VERSION,
+            chains: BTreeMap::new(),
+            retired: BTreeSet::new(),
+        }
+    }
+}
+
+impl ChainIndex {
+    /// Location of the index inside an output directory
+    pub fn path(output_directory: &str) -> PathBuf {
+        Path::new(output_directory).join(CHAIN_INDEX_FILE_NAME)
+    }
+
+    /// Load a directory's index; None if it is missing, unreadable or of another version
+    pub fn load(output_directory: &str) -> Option<Self> {
+        let contents = std::fs::read_to_string(Self::path(output_directory)).ok()?;
+        let index: Self = serde_json::from_str(&contents).ok()?;
+        (index.version == CHAIN_INDEX_VERSION).then_some(index)
+    }
+
+    /// Whether the index no longer describes the directory because an indexed file is
+    /// gone. Chains are recorded as they are stored, so the directory is not rescanned.
+    pub fn is_stale(&self) -> bool {
+        self.chains.values().any(|entry| {
+            !Path::new(&entry.data_file_path).exists()
+                || !Path::new(&entry.hashchain_file_path).exists()
+        })
+    }
+
+    /// Entries owned by the prover with hex public key `public_key`
+    pub fn chains_of<'a>(
+        &'a self,
+        public_key: &'a str,
+    ) -> impl Iterator<Item = (&'a String, &'a ChainIndexEntry)> + 'a {
+        self.chains
+            .iter()
+            .filter(move |(_, entry)| entry.public_key == public_key)
+    }
+
+    /// Write the index atomically (temp file, then rename)
+    pub fn save(&self, output_directory: &str) -> HashChainResult<()> {
+        let path = Self::path(output_directory);
+        let temp_path = path.with_extension("json.tmp");
+        let contents = serde_json::to_string_pretty(self)
+            .map_err(|e| HashChainError::Serialization(e.to_string()))?;
+        std::fs::write(&temp_path, contents).map_err(HashChainError::Io)?;
+        std::fs::rename(&temp_path, &path).map_err(HashChainError::Io)
+    }
+
+    /// Add or replace a chain in a directory's index, clearing any earlier retirement.
+    /// Other entries are kept as they are on disk.
+    pub fn record_chain(
+        output_directory: &str,
+        chain_id: String,
+        entry: ChainIndexEntry,
+    ) -> HashChainResult<()> {
+        let mut index = Self::load(output_directory).unwrap_or_default();
+        index.retired.remove(&chain_id);
+        index.chains.insert(chain_id, entry);
+        index.save(output_directory)
+    }
+
+    /// Drop a chain from a directory's index and remember it as retired, so neither the
+    /// index nor a rescan brings it back
+    pub fn retire_chain(output_directory: &str, chain_id: &str) -> HashChainResult<()> {
+        let mut index = Self::load(output_directory).unwrap_or_default();
+        let removed = index.chains.remove(chain_id).is_some();
+        if !index.retired.insert(chain_id.to_string()) && !removed {
+            return Ok(());
+        }
+        index.save(output_directory)
+    }
+}
+
+/// Open the chains of the prover with `public_key` stored in an output directory,
+/// skipping those `is_active` reports as already open. The index is used when present
+/// and current; otherwise the directory is scanned and the index rebuilt for every
+/// prover, keeping retired chains out. With `verify_files` a chain whose commitment
+/// log cannot be read or whose data fails `verify_stored_files` is skipped with a
+/// warning. Returns (hex chain id, chain) pairs.
+pub fn open_prover_chains(
+    output_directory: &str,
+    public_key: &[u8],
+    verify_files: bool,
+    is_active: impl Fn(&str) -> bool,
+) -> HashChainResult<Vec<(String, IndividualHashChain)>> {
+    let prover_key = hex::encode(public_key);
+    let open = if verify_files {
+        IndividualHashChain::load_from_file_strict
+    } else {
+        IndividualHashChain::load_from_file
+    };
+
+    let mut chains = Vec::new();
+    match ChainIndex::load(output_directory) {
+        Some(index) if !index.is_stale() => {
+            for (chain_id, entry) in index.chains_of(&prover_key) {
+                if is_active(chain_id) {
+                    continue;
+                }
+                match open(entry.hashchain_file_path.clone()) {
+                    Ok(chain) => chains.push((chain_id.clone(), chain)),
+                    Err(e) => log::warn!(
+                        "Skipping unreadable chain file {}: {}",
+                        entry.hashchain_file_path,
+                        e
+                    ),
+                }
+            }
+        }
+        stale => {
+            log::info!(
+                "Chain index in {} missing or stale, scanning directory",
+                output_directory
+            );
+            let mut index = ChainIndex {
+                retired: stale.map(|index| index.retired).unwrap_or_default(),
+                ..ChainIndex::default()
+            };
+            for hashchain_path in scan_hashchain_files(output_directory)? {
+                let chain = match open(hashchain_path.clone()) {
+                    Ok(chain) => chain,
+                    Err(e) => {
+                        log::warn!("Skipping unreadable chain file {}: {}", hashchain_path, e);
+                        continue;
+                    }
+                };
+                let chain_id = hex::encode(chain.get_chain_id());
+                if index.retired.contains(&chain_id) {
+                    continue;
+                }
+                if let Some(entry) = ChainIndexEntry::for_chain(&chain) {
+                    index.chains.insert(chain_id.clone(), entry);
+                }
+                if chain.public_key.as_ref() == public_key && !is_active(&chain_id) {
+                    chains.push((chain_id, chain));
+                }
+            }
+            // The index is only a cache, so a failed write does not fail the load
+            if let Err(e) = index.save(output_directory) {
+                log::warn!(
+                    "Failed to rewrite chain index in {}: {}",
+                    output_directory,
+                    e
+                );
+            }
+        }
+    }
+
+    if verify_files {
+        chains.retain_mut(|(chain_id, chain)| match chain.verify_stored_files() {
+            Ok(()) => true,
+            Err(e) => {
+                log::warn!("Skipping chain {} in {}: {}", chain_id, output_directory, e);
+                false
+            }
+        });
+    }
+    Ok(chains)
+}
+
+/// Paths of every .hashchain file in a directory, sorted
+pub fn scan_hashchain_files(output_directory: &str) -> HashChainResult<Vec<String>> {
+    let mut paths = Vec::new();
+    for entry in std::fs::read_dir(output_directory).map_err(HashChainError::Io)? {
+        let path = entry.map_err(HashChainError::Io)?.path();
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "hashchain")
+        {
+            paths.push(path.to_string_lossy().into_owned());
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(directory: &Path, name: &str, public_key: &str) -> ChainIndexEntry {
+        let data_file_path = directory.join(format!("{}.data", name));
+        let hashchain_file_path = directory.join(format!("{}.hashchain", name));
+        std::fs::write(&data_file_path, b"data").unwrap();
+        std::fs::write(&hashchain_file_path, b"hashchain").unwrap();
+        ChainIndexEntry {
+            data_file_path: data_file_path.to_string_lossy().into_owned(),
+            hashchain_file_path: hashchain_file_path.to_string_lossy().into_owned(),
+            total_chunks: 16,
+            public_key: public_key.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_record_and_retire_chains() {
+        let directory =
+            std::env::temp_dir().join(format!("chain_index_retire_{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let output_directory = directory.to_string_lossy().into_owned();
+
+        // Entries of different provers are merged, not replaced
+        let first = entry(&directory, "first", "aa");
+        let second = entry(&directory, "second", "bb");
+        ChainIndex::record_chain(&output_directory, "01".to_string(), first.clone()).unwrap();
+        ChainIndex::record_chain(&output_directory, "02".to_string(), second.clone()).unwrap();
+        let index = ChainIndex::load(&output_directory).unwrap();
+        assert_eq!(index.chains.len(), 2);
+        assert_eq!(
+            index
+                .chains_of("aa")
+                .map(|(id, _)| id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["01"]
+        );
+
+        // Retiring drops the entry and persists a tombstone
+        ChainIndex::retire_chain(&output_directory, "01").unwrap();
+        let index = ChainIndex::load(&output_directory).unwrap();
+        assert!(!index.chains.contains_key("01"));
+        assert!(index.retired.contains("01"));
+        assert_eq!(index.chains.get("02"), Some(&second));
+
+        // Storing the chain again clears its retirement
+        ChainIndex::record_chain(&output_directory, "01".to_string(), first).unwrap();
+        let index = ChainIndex::load(&output_directory).unwrap();
+        assert!(index.chains.contains_key("01"));
+        assert!(index.retired.is_empty());
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_index_staleness() {
+        let directory =
+            std::env::temp_dir().join(format!("chain_index_stale_{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let output_directory = directory.to_string_lossy().into_owned();
+
+        let indexed = entry(&directory, "indexed", "aa");
+        ChainIndex::record_chain(&output_directory, "01".to_string(), indexed.clone()).unwrap();
+
+        // Files of retired or unindexed chains do not make the index stale
+        entry(&directory, "retired", "aa");
+        let index = ChainIndex::load(&output_directory).unwrap();
+        assert!(!index.is_stale());
+
+        std::fs::remove_file(&indexed.data_file_path).unwrap();
+        assert!(index.is_stale());
+
+        // Indexes of another layout version are ignored
+        let mut old = index.clone();
+        old.version = CHAIN_INDEX_VERSION - 1;
+        old.save(&output_directory).unwrap();
+        assert!(ChainIndex::load(&output_directory).is_none());
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_open_prover_chains_skips_tampered_and_retired() {
+        use crate::core::types::CHUNK_SIZE_BYTES;
+        use napi::bindgen_prelude::Buffer;
+
+        let directory =
+            std::env::temp_dir().join(format!("chain_index_open_{}", std::process::id()));
+        let output_directory = directory.to_string_lossy().into_owned();
+        let public_key = [7u8; 32];
+        let mut chain_ids = Vec::new();
+        let mut data_paths = Vec::new();
+        for seed in [1u8, 2] {
+            let data: Vec<u8> = (0..3 * CHUNK_SIZE_BYTES)
+                .map(|i| (i % 251) as u8 ^ seed)
+                .collect();
+            let chain = IndividualHashChain::new_from_stream(
+                Buffer::from(public_key.to_vec()),
+                Buffer::from(data),
+                output_directory.clone(),
+                100,
+                Buffer::from([1u8; 32].to_vec()),
+            )
+            .unwrap();
+            chain_ids.push(hex::encode(chain.get_chain_id()));
+            data_paths.push(chain.storage.as_ref().unwrap().data_file_path.clone());
+        }
+
+        // Tamper with the second chain's data
+        let mut tampered = std::fs::read(&data_paths[1]).unwrap();
+        tampered[0] ^= 0xFF;
+        std::fs::write(&data_paths[1], tampered).unwrap();
+
+        // The scan indexes both chains; only the intact one passes verification
+        let verified = open_prover_chains(&output_directory, &public_key, true, |_| false).unwrap();
+        assert_eq!(
+            verified.iter().map(|(id, _)| id).collect::<Vec<_>>(),
+            vec![&chain_ids[0]]
+        );
+        assert_eq!(ChainIndex::load(&output_directory).unwrap().chains.len(), 2);
+
+        // Without verification both open from the index; other keys and active chains are skipped
+        let unverified =
+            open_prover_chains(&output_directory, &public_key, false, |_| false).unwrap();
+        assert_eq!(unverified.len(), 2);
+        assert!(
+            open_prover_chains(&output_directory, &[8u8; 32], false, |_| false)
+                .unwrap()
+                .is_empty()
+        );
+        let inactive = open_prover_chains(&output_directory, &public_key, false, |id| {
+            id == chain_ids[0]
+        })
+        .unwrap();
+        assert_eq!(inactive.len(), 1);
+
+        // A retired chain stays out, also after a rescan rebuilds the index
+        ChainIndex::retire_chain(&output_directory, &chain_ids[0]).unwrap();
+        let mut stale = ChainIndex::load(&output_directory).unwrap();
+        stale.chains.values_mut().for_each(|entry| {
+            entry.data_file_path = directory
+                .join("missing.data")
+                .to_string_lossy()
+                .into_owned()
+        });
+        stale.save(&output_directory).unwrap();
+        let rescanned =
+            open_prover_chains(&output_directory, &public_key, false, |_| false).unwrap();
+        assert_eq!(
+            rescanned.iter().map(|(id, _)| id).collect::<Vec<_>>(),
+            vec![&chain_ids[1]]
+        );
+        let index = ChainIndex::load(&output_directory).unwrap();
+        assert!(!index.chains.contains_key(&chain_ids[0]));
+        assert!(index.retired.contains(&chain_ids[0]));
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
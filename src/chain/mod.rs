@@ -1,7 +1,9 @@
 pub mod hashchain;
+pub mod index;
 pub mod lifecycle;
 pub mod storage;
 
 pub use hashchain::*;
+pub use index::*;
 pub use lifecycle::*;
 pub use storage::*;
@@ -1,5 +1,5 @@
-use memmap2::Mmap;
 use napi::bindgen_prelude::*;
+use rayon::prelude::*;
 use std::fs::{File, OpenOptions};
 use std::io::{BufWriter, Read, Write};
 use std::path::Path;
@@ -7,10 +7,49 @@ use std::path::Path;
 use crate::core::{
     errors::{HashChainError, HashChainResult},
     file_encoding::{stream_encode_file, FileEncoder},
+    storage_backend::{FileBackend, StorageBackend},
     types::*,
-    utils::{compute_crc32, compute_sha256, PerformanceTimer},
+    utils::{
+        compute_blake3, compute_file_sha256, compute_header_checksum, compute_log_checkpoint_hash,
+        compute_sha256, PerformanceTimer,
+    },
 };
 
+/// Summary of the commitments dropped from the front of a compacted .hashchain log
+#[derive(Debug, Clone)]
+pub struct LogCheckpoint {
+    /// Commitments truncated across all compactions
+    pub truncated_commitments: u64,
+    /// Block height of the last truncated commitment
+    pub last_block_height: f64,
+    /// Hash of the last truncated commitment, the first kept commitment's predecessor
+    pub last_commitment: Vec<u8>,
+    /// Running hash over every truncated commitment hash
+    pub checkpoint_hash: Vec<u8>,
+}
+
+/// Decode a raw stored chunk; stored data without an encoder is returned as-is
+fn decode_chunk(
+    encoder: Option<&FileEncoder>,
+    encoded_chunk_data: Vec<u8>,
+    chunk_index: u32,
+) -> HashChainResult<Vec<u8>> {
+    match encoder {
+        Some(encoder) => encoder
+            .decode_chunk(&encoded_chunk_data, chunk_index)
+            .map_err(|e| HashChainError::FileFormat(format!("Decoding error: {:?}", e))),
+        None => Ok(encoded_chunk_data),
+    }
+}
+
+/// Pad a decoded chunk to full chunk size (only the last chunk is ever short)
+fn pad_chunk(chunk_data: &[u8]) -> Vec<u8> {
+    let mut padded_chunk = vec![0u8; CHUNK_SIZE_BYTES as usize];
+    let copy_len = std::cmp::min(chunk_data.len(), CHUNK_SIZE_BYTES as usize);
+    padded_chunk[..copy_len].copy_from_slice(&chunk_data[..copy_len]);
+    padded_chunk
+}
+
 /// Production storage management for chain data with streaming support
 pub struct ChainStorage {
     /// Data file path
@@ -21,10 +60,12 @@ pub struct ChainStorage {
     pub total_chunks: u64,
     /// File size in bytes
     pub file_size: u64,
-    /// Memory-mapped file handle for efficient chunk access
-    mmap: Option<Mmap>,
+    /// Raw chunk storage (memory-mapped file in production)
+    backend: Box<dyn StorageBackend>,
     /// Prover's public key for file encoding
     pub prover_key: Option<Buffer>,
+    /// Encoding recorded when this storage encoded its data file
+    pub encoding_info: Option<FileEncodingInfo>,
 }
 
 impl ChainStorage {
@@ -43,14 +84,9 @@ impl ChainStorage {
             format!("{}.hashchain", data_file_path)
         };
 
-        // Get file metadata
-        let metadata =
-            std::fs::metadata(&data_file_path).map_err(|_| HashChainError::FileNotFound {
-                path: data_file_path.clone(),
-            })?;
-
-        let file_size = metadata.len();
-        let total_chunks = (file_size + CHUNK_SIZE_BYTES as u64 - 1) / CHUNK_SIZE_BYTES as u64;
+        let backend = FileBackend::new(data_file_path.clone())?;
+        let file_size = backend.file_size();
+        let total_chunks = backend.total_chunks();
 
         // Validate chunk count
         if total_chunks > HASHCHAIN_MAX_CHUNKS {
@@ -72,8 +108,35 @@ impl ChainStorage {
             hashchain_file_path,
             total_chunks,
             file_size,
-            mmap: None,
+            backend: Box::new(backend),
             prover_key: None,
+            encoding_info: None,
+        })
+    }
+
+    /// Create storage over an arbitrary backend (e.g. in-memory data for tests).
+    /// `data_file_path` only names the data; it is not read.
+    pub fn with_backend(
+        backend: Box<dyn StorageBackend>,
+        data_file_path: String,
+        prover_key: Option<Buffer>,
+    ) -> HashChainResult<Self> {
+        let total_chunks = backend.total_chunks();
+        if total_chunks < HASHCHAIN_MIN_CHUNKS {
+            return Err(HashChainError::TooFewChunks {
+                count: total_chunks,
+                min: HASHCHAIN_MIN_CHUNKS,
+            });
+        }
+
+        Ok(Self {
+            hashchain_file_path: format!("{}.hashchain", data_file_path),
+            data_file_path,
+            total_chunks,
+            file_size: backend.file_size(),
+            backend,
+            prover_key,
+            encoding_info: None,
         })
     }
 
@@ -88,11 +151,7 @@ impl ChainStorage {
         // Compute data hash for unique filename
         let data_hash = compute_sha256(&data_stream);
         let data_hash_hex = hex::encode(data_hash);
-
-        // Create output file paths
         let original_file_path = format!("{}/{}_original.data", output_dir, data_hash_hex);
-        let data_file_path = format!("{}/{}.data", output_dir, data_hash_hex);
-        let hashchain_file_path = format!("{}/{}.hashchain", output_dir, data_hash_hex);
 
         // Ensure output directory exists
         std::fs::create_dir_all(output_dir).map_err(HashChainError::Io)?;
@@ -100,13 +159,57 @@ impl ChainStorage {
         // First, stream data to temporary original file
         let file_size = Self::stream_to_file(&data_stream, &original_file_path)?;
 
-        // Now encode with prover-specific encoding
-        let _encoding_info =
-            stream_encode_file(&original_file_path, &data_file_path, public_key.clone())
-                .map_err(|e| HashChainError::FileFormat(format!("Encoding failed: {:?}", e)))?;
-
-        // Remove temporary original file
+        // Now encode with prover-specific encoding, then remove the temporary file
+        let storage = Self::encode_source(
+            &original_file_path,
+            file_size,
+            &data_hash,
+            output_dir,
+            public_key,
+            &timer,
+        );
         let _ = std::fs::remove_file(&original_file_path);
+        storage
+    }
+
+    /// Create new storage by encoding a source file in place, without copying it or
+    /// loading it into memory. Output files are named as for `create_from_stream`.
+    pub fn create_from_file(
+        input_path: &str,
+        output_dir: &str,
+        public_key: &Buffer,
+    ) -> HashChainResult<Self> {
+        let timer = PerformanceTimer::new("create_from_file");
+
+        let file_size = std::fs::metadata(input_path)
+            .map_err(HashChainError::Io)?
+            .len();
+        let data_hash = compute_file_sha256(input_path)?;
+
+        // Ensure output directory exists
+        std::fs::create_dir_all(output_dir).map_err(HashChainError::Io)?;
+
+        Self::encode_source(
+            input_path, file_size, &data_hash, output_dir, public_key, &timer,
+        )
+    }
+
+    /// Encode `source_path` into the output directory's data file for `data_hash` and
+    /// open storage over it
+    fn encode_source(
+        source_path: &str,
+        file_size: u64,
+        data_hash: &[u8; 32],
+        output_dir: &str,
+        public_key: &Buffer,
+        timer: &PerformanceTimer,
+    ) -> HashChainResult<Self> {
+        let data_hash_hex = hex::encode(data_hash);
+        let data_file_path = format!("{}/{}.data", output_dir, data_hash_hex);
+        let hashchain_file_path = format!("{}/{}.hashchain", output_dir, data_hash_hex);
+
+        let encoding_info = stream_encode_file(source_path, &data_file_path, public_key.clone())
+            .map_err(|e| HashChainError::FileFormat(format!("Encoding failed: {:?}", e)))?;
 
         let total_chunks = (file_size + CHUNK_SIZE_BYTES as u64 - 1) / CHUNK_SIZE_BYTES as u64;
 
@@ -137,13 +240,16 @@ impl ChainStorage {
             timer.elapsed_ms()
         );
 
+        let backend = FileBackend::new(data_file_path.clone())?;
+
         Ok(Self {
             data_file_path,
             hashchain_file_path,
             total_chunks,
             file_size,
-            mmap: None,
+            backend: Box::new(backend),
             prover_key: Some(public_key.clone()),
+            encoding_info: Some(encoding_info),
         })
     }
 
@@ -171,26 +277,7 @@ impl ChainStorage {
         Ok(bytes_written)
     }
 
-    /// Initialize memory-mapped access for efficient chunk reading
-    pub fn init_mmap(&mut self) -> HashChainResult<()> {
-        if self.mmap.is_some() {
-            return Ok(()); // Already initialized
-        }
-
-        let file = File::open(&self.data_file_path).map_err(HashChainError::Io)?;
-
-        let mmap = unsafe { Mmap::map(&file) }.map_err(HashChainError::Io)?;
-
-        self.mmap = Some(mmap);
-
-        log::debug!(
-            "Initialized memory-mapped access for {}",
-            self.data_file_path
-        );
-        Ok(())
-    }
-
-    /// Read a specific chunk using memory-mapped I/O with decoding
+    /// Read a specific chunk from the storage backend with decoding
     pub fn read_chunk(&mut self, chunk_index: u32) -> HashChainResult<Buffer> {
         if chunk_index as u64 >= self.total_chunks {
             return Err(HashChainError::ChunkIndexOutOfRange {
@@ -199,42 +286,50 @@ impl ChainStorage {
             });
         }
 
-        // Initialize memory mapping if needed
-        self.init_mmap()?;
+        let encoder = self.chunk_decoder()?;
+        let chunk_data = self.read_decoded_chunk(encoder.as_ref(), chunk_index)?;
+
+        Ok(Buffer::from(pad_chunk(&chunk_data)))
+    }
+
+    /// Decoder for stored chunks; none when there is no prover key, as files
+    /// without one are not encoded (backwards compatibility)
+    fn chunk_decoder(&self) -> HashChainResult<Option<FileEncoder>> {
+        self.prover_key
+            .as_ref()
+            .map(|prover_key| {
+                FileEncoder::new(prover_key.clone())
+                    .map_err(|e| HashChainError::FileFormat(format!("Encoder error: {:?}", e)))
+            })
+            .transpose()
+    }
 
-        let mmap = self.mmap.as_ref().unwrap();
-        let chunk_start = chunk_index as u64 * CHUNK_SIZE_BYTES as u64;
-        let chunk_end = std::cmp::min(chunk_start + CHUNK_SIZE_BYTES as u64, self.file_size);
+    /// Read a chunk from the storage backend and decode it, without padding
+    fn read_decoded_chunk(
+        &mut self,
+        encoder: Option<&FileEncoder>,
+        chunk_index: u32,
+    ) -> HashChainResult<Vec<u8>> {
+        let encoded_chunk_data = self.backend.read_chunk(chunk_index)?;
+        decode_chunk(encoder, encoded_chunk_data, chunk_index)
+    }
 
-        if chunk_start >= self.file_size {
+    /// Iterate decoded chunks in order starting at `start_index`, building the decoder
+    /// once. Unlike `read_chunk`, the final chunk is yielded at its stored length
+    /// rather than padded, so the chunks concatenate back to the original data.
+    pub fn chunks_from(&mut self, start_index: u64) -> HashChainResult<ChunkIter<'_>> {
+        if start_index > self.total_chunks {
             return Err(HashChainError::ChunkIndexOutOfRange {
-                index: chunk_index,
+                index: start_index.min(u32::MAX as u64) as u32,
                 max: self.total_chunks,
             });
         }
 
-        // Read encoded chunk directly from memory-mapped region
-        let encoded_chunk_data = &mmap[chunk_start as usize..chunk_end as usize];
-
-        // Decode chunk if we have prover key
-        let chunk_data = if let Some(ref prover_key) = self.prover_key {
-            let encoder = FileEncoder::new(prover_key.clone())
-                .map_err(|e| HashChainError::FileFormat(format!("Encoder error: {:?}", e)))?;
-
-            encoder
-                .decode_chunk(encoded_chunk_data, chunk_index)
-                .map_err(|e| HashChainError::FileFormat(format!("Decoding error: {:?}", e)))?
-        } else {
-            // If no prover key, assume file is not encoded (backwards compatibility)
-            encoded_chunk_data.to_vec()
-        };
-
-        // Pad to full chunk size if this is the last chunk
-        let mut padded_chunk = vec![0u8; CHUNK_SIZE_BYTES as usize];
-        let copy_len = std::cmp::min(chunk_data.len(), CHUNK_SIZE_BYTES as usize);
-        padded_chunk[..copy_len].copy_from_slice(&chunk_data[..copy_len]);
-
-        Ok(Buffer::from(padded_chunk))
+        Ok(ChunkIter {
+            encoder: self.chunk_decoder()?,
+            storage: self,
+            next_index: start_index,
+        })
     }
 
     /// Read multiple chunks efficiently in batch with decoding
@@ -286,6 +381,55 @@ impl ChainStorage {
         Ok(hashes)
     }
 
+    /// Blake3 hash of every chunk, padded as by `read_chunk`, in index order.
+    /// Raw chunks are read sequentially in batches; decoding and hashing run in
+    /// parallel, and the indexed collect keeps `hashes[i]` the hash of chunk `i`.
+    pub fn compute_all_chunk_blake3_hashes(&mut self) -> HashChainResult<Vec<[u8; 32]>> {
+        let timer = PerformanceTimer::new("compute_all_chunk_blake3_hashes");
+        // Encoders wrap a napi Buffer and cannot be shared, so each worker builds its own
+        let prover_key = self.prover_key.as_ref().map(|key| key.to_vec());
+
+        let mut hashes = Vec::with_capacity(self.total_chunks as usize);
+        let mut batch_start = 0u64;
+        while batch_start < self.total_chunks {
+            let batch_end = (batch_start + MAX_CHUNK_BATCH as u64).min(self.total_chunks);
+            let encoded_chunks = (batch_start..batch_end)
+                .map(|index| self.backend.read_chunk(index as u32))
+                .collect::<HashChainResult<Vec<_>>>()?;
+
+            let batch_hashes = encoded_chunks
+                .into_par_iter()
+                .enumerate()
+                .map_init(
+                    || {
+                        prover_key
+                            .as_ref()
+                            .map(|key| FileEncoder::new(Buffer::from(key.clone())))
+                            .transpose()
+                    },
+                    |encoder, (offset, encoded_chunk)| {
+                        let encoder = encoder.as_ref().map_err(|e| {
+                            HashChainError::FileFormat(format!("Encoder error: {:?}", e))
+                        })?;
+                        let chunk_index = (batch_start + offset as u64) as u32;
+                        let chunk_data =
+                            decode_chunk(encoder.as_ref(), encoded_chunk, chunk_index)?;
+                        Ok(compute_blake3(&pad_chunk(&chunk_data)))
+                    },
+                )
+                .collect::<HashChainResult<Vec<_>>>()?;
+            hashes.extend(batch_hashes);
+            batch_start = batch_end;
+        }
+
+        log::debug!(
+            "Hashed {} chunks in {}ms",
+            self.total_chunks,
+            timer.elapsed_ms()
+        );
+        Ok(hashes)
+    }
+
     /// Compute full file hash for integrity verification (streaming, decoded data)
     pub fn compute_file_hash(&mut self) -> HashChainResult<[u8; 32]> {
         if let Some(prover_key) = self.prover_key.clone() {
@@ -293,9 +437,7 @@ impl ChainStorage {
             self.compute_decoded_file_hash(&prover_key)
         } else {
             // If no prover key, hash the file as-is
-            self.init_mmap()?;
-            let mmap = self.mmap.as_ref().unwrap();
-            Ok(compute_sha256(mmap))
+            self.backend.compute_hash()
         }
     }
 
@@ -306,21 +448,13 @@ impl ChainStorage {
 
         let mut hasher = blake3::Hasher::new();
 
-        // Initialize memory mapping for direct file access
-        self.init_mmap()?;
-        let mmap = self.mmap.as_ref().unwrap();
-
         // Process file chunk by chunk using encoder directly for better performance
         for chunk_index in 0..self.total_chunks {
-            let chunk_start = chunk_index * CHUNK_SIZE_BYTES as u64;
-            let chunk_end = std::cmp::min(chunk_start + CHUNK_SIZE_BYTES as u64, self.file_size);
-
-            // Read encoded chunk directly from memory-mapped region
-            let encoded_chunk = &mmap[chunk_start as usize..chunk_end as usize];
+            let encoded_chunk = self.backend.read_chunk(chunk_index as u32)?;
 
             // Use encoder to decode chunk for hash computation
             let decoded_chunk = encoder
-                .decode_chunk(encoded_chunk, chunk_index as u32)
+                .decode_chunk(&encoded_chunk, chunk_index as u32)
                 .map_err(|e| HashChainError::FileFormat(format!("Decoding error: {:?}", e)))?;
 
             hasher.update(&decoded_chunk);
@@ -334,17 +468,53 @@ impl ChainStorage {
 
     /// Verify file integrity using CRC32 (faster than full hash)
     pub fn verify_file_integrity(&mut self) -> HashChainResult<bool> {
-        self.init_mmap()?;
-        let mmap = self.mmap.as_ref().unwrap();
-
-        // Compute CRC32 of entire file
-        let file_crc = compute_crc32(mmap);
+        // Compute CRC32 of entire file, streamed chunk by chunk
+        let crc = crc::Crc::<u32>::new(&crc::CRC_32_ISO_HDLC);
+        let mut digest = crc.digest();
+        for chunk_index in 0..self.total_chunks {
+            digest.update(&self.backend.read_chunk(chunk_index as u32)?);
+        }
+        let file_crc = digest.finalize();
 
         // For now, always return true (would compare against stored CRC in production)
         log::debug!("File CRC32: 0x{:08x}", file_crc);
         Ok(true)
     }
 
+    /// Verify the stored file is the encoding `info` describes: the encoded bytes on
+    /// disk must hash to `encoded_hash` and decode under `info.prover_key` to data
+    /// hashing to `original_hash`, so substitution at either layer is detected
+    pub fn verify_file_encoding_info(&mut self, info: &FileEncodingInfo) -> HashChainResult<bool> {
+        if let Some(prover_key) = &self.prover_key {
+            if prover_key.as_ref() != info.prover_key.as_ref() {
+                return Ok(false);
+            }
+        }
+        let encoder = FileEncoder::new(info.prover_key.clone())
+            .map_err(|e| HashChainError::FileFormat(format!("Encoder error: {:?}", e)))?;
+        if !encoder
+            .verify_encoding(info)
+            .map_err(|e| HashChainError::FileFormat(format!("Encoding error: {:?}", e)))?
+        {
+            return Ok(false);
+        }
+
+        // Both hashes in one streaming pass, as stream_encode_file computes them
+        let mut encoded_hasher = blake3::Hasher::new();
+        let mut original_hasher = blake3::Hasher::new();
+        for chunk_index in 0..self.total_chunks {
+            let encoded_chunk = self.backend.read_chunk(chunk_index as u32)?;
+            encoded_hasher.update(&encoded_chunk);
+            let decoded_chunk = decode_chunk(Some(&encoder), encoded_chunk, chunk_index as u32)?;
+            original_hasher.update(&decoded_chunk);
+        }
+
+        Ok(
+            encoded_hasher.finalize().as_bytes() == info.encoded_hash.as_ref()
+                && original_hasher.finalize().as_bytes() == info.original_hash.as_ref(),
+        )
+    }
+
     /// Get comprehensive file statistics
     pub fn get_file_stats(&self) -> HashChainResult<FileStats> {
         let data_metadata =
@@ -366,8 +536,20 @@ impl ChainStorage {
         })
     }
 
-    /// Write HashChain header to .hashchain file (updated on every state change)
-    pub fn write_hashchain_header(&self, header: &HashChainHeader) -> HashChainResult<()> {
+    /// Write HashChain header to .hashchain file (updated on every state change),
+    /// setting its checksum first. Log lines after the header are preserved.
+    pub fn write_hashchain_header(&self, header: &mut HashChainHeader) -> HashChainResult<()> {
+        header.header_checksum = Buffer::from(compute_header_checksum(header).to_vec());
+
+        let existing_log = std::fs::read_to_string(&self.hashchain_file_path)
+            .ok()
+            .and_then(|contents| {
+                contents
+                    .split_once('\n')
+                    .map(|(_, log_lines)| log_lines.to_string())
+            })
+            .unwrap_or_default();
+
         let mut file = OpenOptions::new()
             .create(true)
             .write(true)
@@ -390,10 +572,23 @@ impl ChainStorage {
             "initial_block_height": header.initial_block_height,
             "initial_block_hash": hex::encode(&header.initial_block_hash),
             "header_checksum": hex::encode(&header.header_checksum),
+            "erasure_coding": header.erasure_coding.as_ref().map(|params| serde_json::json!({
+                "data_shards": params.data_shards,
+                "parity_shards": params.parity_shards
+            })),
+            "file_encoding": header.file_encoding.as_ref().map(|info| serde_json::json!({
+                "original_hash": hex::encode(&info.original_hash),
+                "encoded_hash": hex::encode(&info.encoded_hash),
+                "prover_key": hex::encode(&info.prover_key),
+                "encoding_version": info.encoding_version,
+                "encoding_params": hex::encode(&info.encoding_params)
+            })),
             "timestamp": chrono::Utc::now().timestamp()
         });
 
-        file.write_all(header_data.to_string().as_bytes())
+        // Header occupies the first line; commitment lines are appended after it
+        writeln!(file, "{}", header_data).map_err(HashChainError::Io)?;
+        file.write_all(existing_log.as_bytes())
             .map_err(HashChainError::Io)?;
 
         log::info!("Updated HashChain header in {}", self.hashchain_file_path);
@@ -426,6 +621,116 @@ impl ChainStorage {
         Ok(())
     }
 
+    /// Rewrite the .hashchain log keeping the header, the most recent `keep_recent`
+    /// commitments and a checkpoint summarizing everything truncated before them.
+    /// Returns the number of bytes reclaimed.
+    pub fn compact_hashchain_log(&self, keep_recent: usize) -> HashChainResult<u64> {
+        let contents =
+            std::fs::read_to_string(&self.hashchain_file_path).map_err(HashChainError::Io)?;
+        let mut lines = contents.lines();
+        let header_line = lines.next().unwrap_or_default();
+
+        // Raw commitment lines are kept verbatim, in the same order they are loaded
+        let commitment_lines: Vec<&str> = lines
+            .filter(|line| {
+                serde_json::from_str::<serde_json::Value>(line)
+                    .is_ok_and(|json| json["type"].as_str() == Some("commitment"))
+            })
+            .collect();
+        let commitments = self.load_commitments_from_file()?;
+        if commitments.len() != commitment_lines.len() {
+            return Err(HashChainError::FileFormat(
+                "Commitment lines changed while compacting".to_string(),
+            ));
+        }
+        if commitments.len() <= keep_recent {
+            return Ok(0);
+        }
+
+        let truncate_count = commitments.len() - keep_recent;
+        let truncated = &commitments[..truncate_count];
+        let previous = self.load_log_checkpoint()?;
+        let truncated_hashes: Vec<&[u8]> = truncated
+            .iter()
+            .map(|commitment| commitment.commitment_hash.as_ref())
+            .collect();
+        let checkpoint_hash = compute_log_checkpoint_hash(
+            previous
+                .as_ref()
+                .map(|checkpoint| checkpoint.checkpoint_hash.as_slice()),
+            &truncated_hashes,
+        );
+        let last_truncated = truncated.last().unwrap();
+        let checkpoint_data = serde_json::json!({
+            "type": "checkpoint",
+            "truncated_commitments": previous
+                .as_ref()
+                .map_or(0, |checkpoint| checkpoint.truncated_commitments)
+                + truncate_count as u64,
+            "last_block_height": last_truncated.block_height,
+            "last_commitment": hex::encode(&last_truncated.commitment_hash),
+            "checkpoint_hash": hex::encode(checkpoint_hash),
+            "timestamp": chrono::Utc::now().timestamp()
+        });
+
+        // Write alongside and rename so a crash never leaves a half-written log
+        let compacted_path = format!("{}.compact", self.hashchain_file_path);
+        {
+            let mut file =
+                BufWriter::new(File::create(&compacted_path).map_err(HashChainError::Io)?);
+            writeln!(file, "{}", header_line).map_err(HashChainError::Io)?;
+            writeln!(file, "{}", checkpoint_data).map_err(HashChainError::Io)?;
+            for line in &commitment_lines[truncate_count..] {
+                writeln!(file, "{}", line).map_err(HashChainError::Io)?;
+            }
+            file.flush().map_err(HashChainError::Io)?;
+        }
+        let compacted_size = std::fs::metadata(&compacted_path)
+            .map_err(HashChainError::Io)?
+            .len();
+        std::fs::rename(&compacted_path, &self.hashchain_file_path).map_err(HashChainError::Io)?;
+
+        let reclaimed = (contents.len() as u64).saturating_sub(compacted_size);
+        log::info!(
+            "Compacted {}: truncated {} commitments, reclaimed {} bytes",
+            self.hashchain_file_path,
+            truncate_count,
+            reclaimed
+        );
+        Ok(reclaimed)
+    }
+
+    /// Load the checkpoint left by a previous log compaction, if any
+    pub fn load_log_checkpoint(&self) -> HashChainResult<Option<LogCheckpoint>> {
+        let contents = match std::fs::read_to_string(&self.hashchain_file_path) {
+            Ok(contents) => contents,
+            Err(_) => return Ok(None),
+        };
+
+        for line in contents.lines() {
+            let Ok(json_data) = serde_json::from_str::<serde_json::Value>(line) else {
+                continue;
+            };
+            if json_data["type"].as_str() != Some("checkpoint") {
+                continue;
+            }
+
+            let decode_hash = |field: &str| {
+                hex::decode(json_data[field].as_str().unwrap_or("")).map_err(|_| {
+                    HashChainError::FileFormat(format!("Invalid checkpoint {}", field))
+                })
+            };
+            return Ok(Some(LogCheckpoint {
+                truncated_commitments: json_data["truncated_commitments"].as_u64().unwrap_or(0),
+                last_block_height: json_data["last_block_height"].as_f64().unwrap_or(0.0),
+                last_commitment: decode_hash("last_commitment")?,
+                checkpoint_hash: decode_hash("checkpoint_hash")?,
+            }));
+        }
+
+        Ok(None)
+    }
+
     /// Set prover key for decoding operations
     pub fn set_prover_key(&mut self, prover_key: Buffer) -> HashChainResult<()> {
         if prover_key.len() != 32 {
@@ -520,9 +825,10 @@ impl ChainStorage {
         let mut contents = String::new();
         file.read_to_string(&mut contents)
             .map_err(HashChainError::Io)?;
+        let header_line = contents.lines().next().unwrap_or_default();
 
         // Try to parse as JSON first (new format)
-        if let Ok(json_data) = serde_json::from_str::<serde_json::Value>(&contents) {
+        if let Ok(json_data) = serde_json::from_str::<serde_json::Value>(header_line) {
             let header = HashChainHeader {
                 magic: Buffer::from(
                     hex::decode(json_data["magic"].as_str().unwrap_or(""))
@@ -568,7 +874,50 @@ impl ChainStorage {
                     hex::decode(json_data["header_checksum"].as_str().unwrap_or(""))
                         .unwrap_or_else(|_| vec![0u8; 32]),
                 ),
+                erasure_coding: json_data["erasure_coding"].as_object().map(|params| {
+                    ErasureCodingParams {
+                        data_shards: params["data_shards"].as_u64().unwrap_or(0) as u32,
+                        parity_shards: params["parity_shards"].as_u64().unwrap_or(0) as u32,
+                    }
+                }),
+                file_encoding: json_data["file_encoding"].as_object().map(|info| {
+                    let bytes = |field: &str| {
+                        Buffer::from(
+                            hex::decode(info[field].as_str().unwrap_or("")).unwrap_or_default(),
+                        )
+                    };
+                    FileEncodingInfo {
+                        original_hash: bytes("original_hash"),
+                        encoded_hash: bytes("encoded_hash"),
+                        prover_key: bytes("prover_key"),
+                        encoding_version: info["encoding_version"].as_u64().unwrap_or(0) as u32,
+                        encoding_params: bytes("encoding_params"),
+                    }
+                }),
             };
+
+            // Headers written before checksums existed carry none; upgrade them in place
+            let checksum_missing = header.header_checksum.iter().all(|&byte| byte == 0);
+            if header.format_version < HASHCHAIN_CHECKSUM_FORMAT_VERSION && checksum_missing {
+                let mut header = header;
+                header.format_version = HASHCHAIN_FORMAT_VERSION;
+                self.write_hashchain_header(&mut header)?;
+                log::info!(
+                    "Upgraded HashChain header in {} to format version {}",
+                    self.hashchain_file_path,
+                    HASHCHAIN_FORMAT_VERSION
+                );
+                return Ok(header);
+            }
+
+            // The checksum is unkeyed: it detects accidental corruption of the other
+            // fields, not deliberate edits
+            if header.header_checksum.as_ref() != compute_header_checksum(&header) {
+                return Err(HashChainError::Corruption(format!(
+                    "HashChain header checksum mismatch in {}",
+                    self.hashchain_file_path
+                )));
+            }
             return Ok(header);
         }
 
@@ -587,6 +936,8 @@ impl ChainStorage {
             initial_block_height: 0.0,
             initial_block_hash: Buffer::from([0u8; 32].to_vec()),
             header_checksum: Buffer::from([0u8; 32].to_vec()),
+            erasure_coding: None,
+            file_encoding: None,
         };
 
         Ok(header)
@@ -594,19 +945,44 @@ impl ChainStorage {
 
     /// Explicitly close memory-mapped file handle (Windows compatibility)
     pub fn close_mmap(&mut self) {
-        if let Some(_mmap) = self.mmap.take() {
-            log::debug!(
-                "Explicitly closing memory-mapped file: {}",
-                self.data_file_path
-            );
-            // Mmap will be dropped here, releasing the file handle
-            // On Windows, this helps avoid "user-mapped section open" errors
-        }
+        // Dropping the mapping releases the file handle
+        // On Windows, this helps avoid "user-mapped section open" errors
+        self.backend.release();
     }
 
     /// Check if memory mapping is active
     pub fn is_mmap_active(&self) -> bool {
-        self.mmap.is_some()
+        self.backend.is_open()
+    }
+}
+
+/// In-order iterator over a chain's decoded chunks, see [`ChainStorage::chunks_from`]
+pub struct ChunkIter<'a> {
+    storage: &'a mut ChainStorage,
+    encoder: Option<FileEncoder>,
+    next_index: u64,
+}
+
+impl Iterator for ChunkIter<'_> {
+    type Item = HashChainResult<Buffer>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_index >= self.storage.total_chunks {
+            return None;
+        }
+        let chunk_index = self.next_index as u32;
+        self.next_index += 1;
+
+        Some(
+            self.storage
+                .read_decoded_chunk(self.encoder.as_ref(), chunk_index)
+                .map(Buffer::from),
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.storage.total_chunks.saturating_sub(self.next_index) as usize;
+        (remaining, Some(remaining))
     }
 }
 
@@ -630,7 +1006,7 @@ pub struct FilePaths {
 impl Drop for ChainStorage {
     fn drop(&mut self) {
         // Explicitly close memory mapping for Windows compatibility
-        if self.mmap.is_some() {
+        if self.backend.is_open() {
             log::debug!(
                 "Dropping ChainStorage, unmapping file: {}",
                 self.data_file_path
@@ -643,3 +1019,97 @@ impl Drop for ChainStorage {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::storage_backend::MemoryBackend;
+
+    #[test]
+    fn test_parallel_chunk_hashes_match_sequential() {
+        // Spans a batch boundary and ends with a short chunk
+        let chunk_size = CHUNK_SIZE_BYTES as usize;
+        let data: Vec<u8> = (0..(MAX_CHUNK_BATCH as usize + 1) * chunk_size + 100)
+            .map(|i| (i % 251) as u8)
+            .collect();
+        let mut storage = ChainStorage::with_backend(
+            Box::new(MemoryBackend::new(data)),
+            "parallel_hashes.data".to_string(),
+            Some(Buffer::from(vec![9u8; 32])),
+        )
+        .unwrap();
+        assert_eq!(storage.total_chunks, MAX_CHUNK_BATCH as u64 + 2);
+
+        let hashes = storage.compute_all_chunk_blake3_hashes().unwrap();
+        assert_eq!(hashes.len() as u64, storage.total_chunks);
+        for (index, hash) in hashes.iter().enumerate() {
+            let chunk = storage.read_chunk(index as u32).unwrap();
+            assert_eq!(*hash, compute_blake3(&chunk), "chunk {}", index);
+        }
+    }
+
+    #[test]
+    fn test_load_pre_checksum_header() {
+        let dir = std::env::temp_dir().join(format!("storage_header_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let data_path = dir.join("chain.data").to_string_lossy().to_string();
+        let storage = ChainStorage::with_backend(
+            Box::new(MemoryBackend::new(vec![1u8; 4 * CHUNK_SIZE_BYTES as usize])),
+            data_path,
+            None,
+        )
+        .unwrap();
+
+        // A version 2 header as written before checksums, followed by a log line
+        let legacy_header = serde_json::json!({
+            "magic": hex::encode(HASHCHAIN_MAGIC),
+            "format_version": 2,
+            "data_file_hash": hex::encode([2u8; 32]),
+            "merkle_root": hex::encode([3u8; 32]),
+            "total_chunks": 4,
+            "chunk_size": CHUNK_SIZE_BYTES,
+            "data_file_path_hash": hex::encode([4u8; 32]),
+            "anchored_commitment": hex::encode([5u8; 32]),
+            "chain_length": 0,
+            "public_key": hex::encode([6u8; 32]),
+            "initial_block_height": 100,
+            "initial_block_hash": hex::encode([7u8; 32]),
+        });
+        std::fs::write(
+            &storage.hashchain_file_path,
+            format!("{}\nlog line\n", legacy_header),
+        )
+        .unwrap();
+
+        // Loads, and the header is rewritten at the current version with a checksum
+        let header = storage.load_hashchain_header().unwrap();
+        assert_eq!(header.format_version, HASHCHAIN_FORMAT_VERSION);
+        assert_eq!(header.merkle_root.as_ref(), &[3u8; 32]);
+        assert_eq!(
+            header.header_checksum.as_ref(),
+            compute_header_checksum(&header)
+        );
+        let contents = std::fs::read_to_string(&storage.hashchain_file_path).unwrap();
+        assert!(contents.ends_with("\nlog line\n"));
+        let reloaded = storage.load_hashchain_header().unwrap();
+        assert_eq!(
+            reloaded.header_checksum.as_ref(),
+            header.header_checksum.as_ref()
+        );
+
+        // A current-version header without a valid checksum is corrupt
+        let mut corrupted: serde_json::Value =
+            serde_json::from_str(contents.lines().next().unwrap()).unwrap();
+        corrupted["merkle_root"] = serde_json::json!(hex::encode([8u8; 32]));
+        std::fs::write(&storage.hashchain_file_path, format!("{}\n", corrupted)).unwrap();
+        assert!(matches!(
+            storage.load_hashchain_header(),
+            Err(HashChainError::Corruption(_))
+        ));
+        corrupted["header_checksum"] = serde_json::json!("");
+        std::fs::write(&storage.hashchain_file_path, format!("{}\n", corrupted)).unwrap();
+        assert!(storage.load_hashchain_header().is_err());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -297,6 +297,31 @@ pub fn verify_chunk_selection_internal(
     Ok(false)
 }
 
+/// Fast pre-check that chunk indices are strictly ascending, as produced by the
+/// selection algorithm. Rejects unsorted or duplicate indices before the more
+/// expensive deterministic re-derivation.
+pub fn validate_chunk_ordering(selected_chunks: &[u32]) -> std::result::Result<(), String> {
+    for (position, pair) in selected_chunks.windows(2).enumerate() {
+        if pair[0] == pair[1] {
+            return Err(format!(
+                "Duplicate chunk index {} at position {}",
+                pair[1],
+                position + 1
+            ));
+        }
+        if pair[0] > pair[1] {
+            return Err(format!(
+                "Chunk indices not sorted at position {}: {} > {}",
+                position + 1,
+                pair[0],
+                pair[1]
+            ));
+        }
+    }
+
+    Ok(())
+}
+
 /// Parallel chunk selection for high performance with many chains
 pub fn select_chunks_parallel(
     entropy_list: Vec<(MultiSourceEntropy, f64)>, // (entropy, total_chunks) pairs
@@ -413,6 +438,18 @@ mod tests {
         assert_eq!(result.selected_indices.len(), CHUNKS_PER_BLOCK as usize);
     }
 
+    #[test]
+    fn test_chunk_ordering_precheck() {
+        assert!(validate_chunk_ordering(&[1, 5, 9, 200]).is_ok());
+        assert!(validate_chunk_ordering(&[]).is_ok());
+
+        let unsorted = validate_chunk_ordering(&[1, 9, 5]).unwrap_err();
+        assert!(unsorted.contains("not sorted"));
+
+        let duplicate = validate_chunk_ordering(&[1, 5, 5, 9]).unwrap_err();
+        assert!(duplicate.contains("Duplicate"));
+    }
+
     #[test]
     fn test_parallel_chunk_selection() {
         let entropy_list: Vec<_> = (0..10)
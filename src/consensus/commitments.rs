@@ -1,7 +1,7 @@
 use napi::bindgen_prelude::*;
 
 use crate::core::{
-    availability::{AvailabilityChallenger, AvailabilityProver},
+    availability::{AvailabilityChallenger, AvailabilityProver, ExpiredChallenge},
     file_encoding::FileEncoder,
     memory_hard_vdf::MemoryHardVDF,
     types::*,
@@ -27,10 +27,7 @@ pub fn create_ownership_commitment_internal(
         ));
     }
 
-    let mut commitment_data = Vec::new();
-    commitment_data.extend_from_slice(&data_hash);
-    commitment_data.extend_from_slice(&public_key);
-    let commitment_hash = compute_sha256(&commitment_data);
+    let commitment_hash = calculate_ownership_hash(&data_hash, &public_key);
 
     Ok(OwnershipCommitment {
         public_key,
@@ -53,12 +50,8 @@ pub fn create_anchored_ownership_commitment_internal(
         ));
     }
 
-    let mut anchored_data = Vec::new();
-    anchored_data.extend_from_slice(&ownership_commitment.commitment_hash);
-    anchored_data.extend_from_slice(&block_commitment.block_hash);
-    // Include block height to ensure uniqueness
-    anchored_data.extend_from_slice(&(block_commitment.block_height as u64).to_be_bytes());
-    let anchored_hash = compute_sha256(&anchored_data);
+    let anchored_hash =
+        calculate_anchored_hash(&ownership_commitment.commitment_hash, &block_commitment);
 
     Ok(AnchoredOwnershipCommitment {
         ownership_commitment,
@@ -67,6 +60,58 @@ pub fn create_anchored_ownership_commitment_internal(
     })
 }
 
+/// SHA256(data_hash || public_key)
+fn calculate_ownership_hash(data_hash: &[u8], public_key: &[u8]) -> [u8; 32] {
+    let mut commitment_data = Vec::new();
+    commitment_data.extend_from_slice(data_hash);
+    commitment_data.extend_from_slice(public_key);
+    compute_sha256(&commitment_data)
+}
+
+/// SHA256(ownership commitment hash || block_hash || block_height)
+fn calculate_anchored_hash(commitment_hash: &[u8], block_commitment: &BlockCommitment) -> [u8; 32] {
+    let mut anchored_data = Vec::new();
+    anchored_data.extend_from_slice(commitment_hash);
+    anchored_data.extend_from_slice(&block_commitment.block_hash);
+    // Include block height to ensure uniqueness
+    anchored_data.extend_from_slice(&(block_commitment.block_height as u64).to_be_bytes());
+    compute_sha256(&anchored_data)
+}
+
+/// Verify basic ownership commitment
+pub fn verify_ownership_commitment(commitment: &OwnershipCommitment) -> Result<bool> {
+    if commitment.public_key.len() != 32 || commitment.data_hash.len() != 32 {
+        return Ok(false);
+    }
+
+    let calculated_hash = calculate_ownership_hash(&commitment.data_hash, &commitment.public_key);
+    Ok(calculated_hash.as_slice() == commitment.commitment_hash.as_ref())
+}
+
+/// Verify basic anchored ownership commitment: the nested ownership commitment and
+/// the anchored hash over it and the block it is anchored to
+pub fn verify_anchored_ownership_commitment(
+    commitment: &AnchoredOwnershipCommitment,
+) -> Result<bool> {
+    if !verify_ownership_commitment(&commitment.ownership_commitment)? {
+        return Ok(false);
+    }
+
+    let block_commitment = &commitment.block_commitment;
+    if block_commitment.block_hash.len() != 32
+        || !block_commitment.block_height.is_finite()
+        || block_commitment.block_height < 0.0
+    {
+        return Ok(false);
+    }
+
+    let calculated_hash = calculate_anchored_hash(
+        &commitment.ownership_commitment.commitment_hash,
+        block_commitment,
+    );
+    Ok(calculated_hash.as_slice() == commitment.anchored_hash.as_ref())
+}
+
 /// Create basic physical access commitment proving data access at specific block
 pub fn create_physical_access_commitment_internal(
     previous_commitment: Buffer,
@@ -201,14 +246,12 @@ impl EnhancedCommitmentGenerator {
         let encoding_params = self.generate_encoding_params(&prover_key)?;
 
         // Create enhanced commitment hash
-        let mut commitment_input = Vec::new();
-        commitment_input.extend_from_slice(&prover_key);
-        commitment_input.extend_from_slice(&encoded_data_hash);
-        commitment_input.extend_from_slice(&original_data_hash);
-        commitment_input.extend_from_slice(&encoding_params);
-        commitment_input.extend_from_slice(b"enhanced_ownership_v2");
-
-        let commitment_hash = compute_sha256(&commitment_input);
+        let commitment_hash = self.compute_ownership_commitment_hash(
+            &prover_key,
+            &encoded_data_hash,
+            &original_data_hash,
+            &encoding_params,
+        );
 
         Ok(EnhancedOwnershipCommitment {
             public_key: prover_key,
@@ -219,6 +262,33 @@ impl EnhancedCommitmentGenerator {
         })
     }
 
+    /// Verify enhanced ownership commitment is bound to the prover's encoding transform
+    pub fn verify_enhanced_ownership_commitment(
+        &self,
+        commitment: &EnhancedOwnershipCommitment,
+        expected_prover_key: &Buffer,
+    ) -> Result<bool> {
+        // 1. Verify commitment belongs to the expected prover
+        if commitment.public_key.as_ref() != expected_prover_key.as_ref() {
+            return Ok(false);
+        }
+
+        // 2. Verify encoding params match what the prover's key produces
+        let expected_params = self.generate_encoding_params(&commitment.public_key)?;
+        if commitment.encoding_params.as_ref() != expected_params.as_slice() {
+            return Ok(false);
+        }
+
+        // 3. Verify commitment hash
+        let expected_hash = self.compute_ownership_commitment_hash(
+            &commitment.public_key,
+            &commitment.encoded_data_hash,
+            &commitment.original_data_hash,
+            &commitment.encoding_params,
+        );
+        Ok(commitment.commitment_hash.as_ref() == expected_hash.as_slice())
+    }
+
     /// Generate enhanced physical access commitment with all security features
     pub fn generate_enhanced_physical_access_commitment(
         &mut self,
@@ -253,7 +323,7 @@ impl EnhancedCommitmentGenerator {
         let network_latency_proof = create_network_proof(peer_addresses)?;
 
         // 6. Create final enhanced commitment
-        let mut commitment_input = Vec::new();
+        let mut commitment_input = ENHANCED_PHYSICAL_ACCESS_DOMAIN.to_vec();
         commitment_input.extend_from_slice(&block_height.to_be_bytes());
         commitment_input.extend_from_slice(&previous_commitment);
         commitment_input.extend_from_slice(&entropy.combined_hash);
@@ -273,8 +343,6 @@ impl EnhancedCommitmentGenerator {
         // Add network proof
         commitment_input.extend_from_slice(&network_latency_proof.average_latency_ms.to_be_bytes());
 
-        commitment_input.extend_from_slice(b"enhanced_physical_access_v2");
-
         let commitment_hash = compute_sha256(&commitment_input);
 
         Ok(EnhancedPhysicalAccessCommitment {
@@ -341,14 +409,30 @@ impl EnhancedCommitmentGenerator {
 
     /// Generate encoding parameters for file encoding
     fn generate_encoding_params(&self, prover_key: &Buffer) -> Result<Vec<u8>> {
-        let mut params = Vec::new();
+        let mut params = ENHANCED_ENCODING_DOMAIN.to_vec();
         params.extend_from_slice(prover_key);
-        params.extend_from_slice(b"enhanced_encoding_v2");
         params.extend_from_slice(&1u32.to_be_bytes()); // Encoding version
 
         Ok(compute_sha256(&params).to_vec())
     }
 
+    /// Compute ownership commitment hash over key, data hashes and encoding params
+    fn compute_ownership_commitment_hash(
+        &self,
+        prover_key: &[u8],
+        encoded_data_hash: &[u8],
+        original_data_hash: &[u8],
+        encoding_params: &[u8],
+    ) -> [u8; 32] {
+        let mut commitment_input = ENHANCED_OWNERSHIP_DOMAIN.to_vec();
+        commitment_input.extend_from_slice(prover_key);
+        commitment_input.extend_from_slice(encoded_data_hash);
+        commitment_input.extend_from_slice(original_data_hash);
+        commitment_input.extend_from_slice(encoding_params);
+
+        compute_sha256(&commitment_input)
+    }
+
     /// Create VDF input from commitment components
     fn create_vdf_input(
         &self,
@@ -356,7 +440,7 @@ impl EnhancedCommitmentGenerator {
         entropy: &MultiSourceEntropy,
         chunk_hashes: &[Buffer],
     ) -> Result<Vec<u8>> {
-        let mut vdf_input = Vec::new();
+        let mut vdf_input = ENHANCED_VDF_INPUT_DOMAIN.to_vec();
         vdf_input.extend_from_slice(previous_commitment);
         vdf_input.extend_from_slice(&entropy.combined_hash);
 
@@ -365,8 +449,6 @@ impl EnhancedCommitmentGenerator {
             vdf_input.extend_from_slice(chunk_hash);
         }
 
-        vdf_input.extend_from_slice(b"enhanced_vdf_input_v2");
-
         // Hash to get 32-byte input
         Ok(compute_sha256(&vdf_input).to_vec())
     }
@@ -442,6 +524,8 @@ impl EnhancedCommitmentGenerator {
             challenge_time: crate::core::utils::get_current_timestamp(),
             deadline: crate::core::utils::get_current_timestamp() + 30.0,
             reward_amount: 1.0,
+            slice_root: None,
+            selection_beacon: None,
         };
 
         // Attempt to respond to the challenge if we have the data
@@ -499,7 +583,7 @@ impl EnhancedCommitmentGenerator {
     }
 
     /// Clean up expired challenges - uses availability_challenger field
-    pub fn cleanup_expired_challenges(&mut self) -> Result<Vec<String>> {
+    pub fn cleanup_expired_challenges(&mut self) -> Result<Vec<ExpiredChallenge>> {
         // Use the availability_challenger field
         self.availability_challenger.cleanup_expired_challenges()
     }
@@ -536,7 +620,7 @@ impl EnhancedCommitmentGenerator {
         commitment: &EnhancedPhysicalAccessCommitment,
     ) -> Result<bool> {
         // Reconstruct the commitment hash and compare
-        let mut commitment_input = Vec::new();
+        let mut commitment_input = ENHANCED_PHYSICAL_ACCESS_DOMAIN.to_vec();
         commitment_input.extend_from_slice(&commitment.block_height.to_be_bytes());
         commitment_input.extend_from_slice(&commitment.previous_commitment);
         commitment_input.extend_from_slice(&commitment.entropy.combined_hash);
@@ -565,8 +649,6 @@ impl EnhancedCommitmentGenerator {
                 .to_be_bytes(),
         );
 
-        commitment_input.extend_from_slice(b"enhanced_physical_access_v2");
-
         let expected_hash = compute_sha256(&commitment_input);
         Ok(commitment.commitment_hash.as_ref() == expected_hash.as_slice())
     }
@@ -602,6 +684,15 @@ pub fn verify_enhanced_block_commitment(
     generator.verify_enhanced_physical_access_commitment(commitment, _expected_prover_key)
 }
 
+/// Verify enhanced ownership commitment for consensus
+pub fn verify_enhanced_ownership(
+    commitment: &EnhancedOwnershipCommitment,
+    expected_prover_key: &Buffer,
+) -> Result<bool> {
+    let generator = EnhancedCommitmentGenerator::new(expected_prover_key.clone())?;
+    generator.verify_enhanced_ownership_commitment(commitment, expected_prover_key)
+}
+
 /// Batch process multiple enhanced commitments in parallel
 pub fn process_enhanced_commitments_parallel(
     commitment_requests: Vec<EnhancedCommitmentRequest>,
@@ -647,10 +738,88 @@ pub struct EnhancedCommitmentRequest {
     pub peer_addresses: Vec<String>,
 }
 
+/// Whether two storage commitments belong to the same chain: same prover, same data
+pub fn same_chain(a: &StorageCommitment, b: &StorageCommitment) -> bool {
+    a.prover_key.as_ref() == b.prover_key.as_ref() && a.data_hash.as_ref() == b.data_hash.as_ref()
+}
+
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
 
+    pub(crate) fn storage_commitment(
+        prover_key: u8,
+        data_hash: u8,
+        block_height: u32,
+    ) -> StorageCommitment {
+        StorageCommitment {
+            prover_key: Buffer::from(vec![prover_key; 32]),
+            data_hash: Buffer::from(vec![data_hash; 32]),
+            block_height,
+            block_hash: Buffer::from(vec![block_height as u8; 32]),
+            selected_chunks: vec![1, 2, 3],
+            chunk_hashes: vec![Buffer::from(vec![0u8; 32]); 3],
+            vdf_proof: MemoryHardVDFProof {
+                input_state: Buffer::from(vec![0u8; 32]),
+                output_state: Buffer::from(vec![0u8; 32]),
+                iterations: 1000,
+                memory_access_samples: Vec::new(),
+                computation_time_ms: 0.0,
+                memory_usage_bytes: 256.0 * 1024.0,
+            },
+            entropy: MultiSourceEntropy {
+                blockchain_entropy: Buffer::from(vec![0u8; 32]),
+                beacon_entropy: None,
+                local_entropy: Buffer::from(vec![0u8; 32]),
+                timestamp: 0.0,
+                combined_hash: Buffer::from(vec![0u8; 32]),
+            },
+            commitment_hash: Buffer::from(vec![block_height as u8; 32]),
+            merkle_root: None,
+            total_chunks: None,
+        }
+    }
+
+    #[test]
+    fn test_same_chain() {
+        let first = storage_commitment(1, 2, 10);
+        assert!(same_chain(&first, &storage_commitment(1, 2, 11)));
+
+        // Different data or a different prover is a different chain
+        assert!(!same_chain(&first, &storage_commitment(1, 3, 11)));
+        assert!(!same_chain(&first, &storage_commitment(4, 2, 11)));
+    }
+
+    #[test]
+    fn test_anchored_ownership_commitment_verification() {
+        let ownership = create_ownership_commitment_internal(
+            Buffer::from(vec![1u8; 32]),
+            Buffer::from(vec![2u8; 32]),
+        )
+        .unwrap();
+        let anchored = create_anchored_ownership_commitment_internal(
+            ownership,
+            BlockCommitment {
+                block_height: 42.0,
+                block_hash: Buffer::from(vec![3u8; 32]),
+                timestamp: None,
+            },
+        )
+        .unwrap();
+        assert!(verify_anchored_ownership_commitment(&anchored).unwrap());
+
+        // Anchored to a different height
+        let mut moved = anchored.clone();
+        moved.block_commitment.block_height = 43.0;
+        assert!(!verify_anchored_ownership_commitment(&moved).unwrap());
+
+        // Nested ownership commitment for different data
+        let mut swapped = anchored.clone();
+        swapped.ownership_commitment.data_hash = Buffer::from(vec![4u8; 32]);
+        assert!(!verify_ownership_commitment(&swapped.ownership_commitment).unwrap());
+        assert!(!verify_anchored_ownership_commitment(&swapped).unwrap());
+    }
+
     #[test]
     fn test_enhanced_ownership_commitment() {
         let prover_key = Buffer::from([42u8; 32].to_vec());
@@ -676,6 +845,37 @@ mod tests {
         assert_eq!(commitment.commitment_hash.len(), 32);
     }
 
+    #[test]
+    fn test_enhanced_ownership_encoding_params_verification() {
+        let prover_key = Buffer::from([42u8; 32].to_vec());
+        let generator = EnhancedCommitmentGenerator::new(prover_key.clone()).unwrap();
+
+        let commitment = generator
+            .generate_enhanced_ownership_commitment(
+                Buffer::from([1u8; 32].to_vec()),
+                Buffer::from([2u8; 32].to_vec()),
+                prover_key.clone(),
+            )
+            .unwrap();
+
+        assert!(generator
+            .verify_enhanced_ownership_commitment(&commitment, &prover_key)
+            .unwrap());
+
+        // Wrong prover key is rejected
+        let other_key = Buffer::from([7u8; 32].to_vec());
+        assert!(!generator
+            .verify_enhanced_ownership_commitment(&commitment, &other_key)
+            .unwrap());
+
+        // Claimed encoding params that the key would not produce are rejected
+        let mut tampered = commitment.clone();
+        tampered.encoding_params = Buffer::from([9u8; 32].to_vec());
+        assert!(!generator
+            .verify_enhanced_ownership_commitment(&tampered, &prover_key)
+            .unwrap());
+    }
+
     #[test]
     fn test_enhanced_physical_access_commitment_generation() {
         let prover_key = Buffer::from([42u8; 32].to_vec());
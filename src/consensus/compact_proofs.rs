@@ -0,0 +1,365 @@
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+use crate::consensus::{NetworkConsensusValidator, VdfProofFormat};
+use crate::core::types::CompactStorageProof;
+
+/// Replay protection state: (prover key, data hash) in hex, identifying one of the
+/// prover's chains -> (highest accepted block height, commitment hash, VDF iterations).
+/// Only the latest height per chain is kept.
+pub type SeenProofs = HashMap<(String, String), (u32, String, u32)>;
+
+/// What the stateless checks read from a compact proof. Napi buffers cannot cross
+/// threads, so batches copy this out before checking in parallel.
+struct CompactProofFormat {
+    prover_key_len: usize,
+    data_hash_len: usize,
+    vdf_proof: VdfProofFormat,
+    chunk_proof_lens: Vec<usize>,
+    commitment_hash_len: usize,
+    network_position_len: usize,
+    timestamp: f64,
+}
+
+impl CompactProofFormat {
+    fn of(proof: &CompactStorageProof) -> Self {
+        Self {
+            prover_key_len: proof.prover_key.len(),
+            data_hash_len: proof.data_hash.len(),
+            vdf_proof: VdfProofFormat::from(&proof.vdf_proof),
+            chunk_proof_lens: proof.chunk_proofs.iter().map(|p| p.len()).collect(),
+            commitment_hash_len: proof.commitment_hash.len(),
+            network_position_len: proof.network_position.len(),
+            timestamp: proof.timestamp,
+        }
+    }
+
+    fn well_formed(&self, consensus_validator: &NetworkConsensusValidator, now: f64) -> bool {
+        // 1. Verify prover key and data hash format
+        if self.prover_key_len != 32 || self.data_hash_len != 32 {
+            return false;
+        }
+
+        // 2. Verify the continuous VDF proof meets consensus requirements: minimum
+        // iterations, 256KB memory, no memory-hard samples and 32-byte states
+        if consensus_validator
+            .validate_vdf_format(&self.vdf_proof)
+            .is_err()
+        {
+            return false;
+        }
+
+        // 3. Verify chunk proofs meet consensus requirements
+        if self.chunk_proof_lens.len() != consensus_validator.parameters().chunks_per_block as usize
+            || self.chunk_proof_lens.iter().any(|&len| len != 32)
+        {
+            return false;
+        }
+
+        // 4. Verify commitment hash and network position structure
+        if self.commitment_hash_len != 32 || self.network_position_len != 32 {
+            return false;
+        }
+
+        // 5. Verify timestamp is reasonable (not too old or in future)
+        let twenty_four_hours = 24.0 * 60.0 * 60.0;
+        self.timestamp <= now + 300.0 && now - self.timestamp <= twenty_four_hours
+    }
+}
+
+/// Stateless checks of a compact proof against the consensus rules in force at its
+/// block: key, VDF and chunk proof formats, and a timestamp no more than 5 minutes
+/// ahead of or 24 hours behind `now`
+pub fn compact_proof_well_formed(
+    proof: &CompactStorageProof,
+    consensus_validator: &NetworkConsensusValidator,
+    now: f64,
+) -> bool {
+    CompactProofFormat::of(proof).well_formed(consensus_validator, now)
+}
+
+/// Replay check of a proof against its chain's entry in `seen_proofs`: the block height
+/// must be above the last accepted one, with enough sequential VDF work since it.
+/// Returns whether the proof is the one already recorded for the chain.
+fn check_replay(
+    seen_proofs: &SeenProofs,
+    proof: &CompactStorageProof,
+    consensus_validator: &NetworkConsensusValidator,
+) -> Result<bool, String> {
+    let key = replay_key(proof);
+    let Some((last_height, last_hash, last_iterations)) = seen_proofs.get(&key) else {
+        return Ok(false);
+    };
+    if proof.block_height == *last_height && hex::encode(&proof.commitment_hash) == *last_hash {
+        return Ok(true);
+    }
+    if proof.block_height <= *last_height {
+        return Err(format!(
+            "Replayed proof rejected for prover {}: height {} <= last accepted {}",
+            key.0, proof.block_height, last_height
+        ));
+    }
+
+    // Sequential VDF work must have occurred since the previous block
+    consensus_validator
+        .validate_vdf_progress(
+            *last_iterations as u64,
+            proof.vdf_proof.iterations as u64,
+            proof.block_height - last_height,
+        )
+        .map_err(|e| format!("Proof rejected for prover {}: {}", key.0, e))?;
+    Ok(false)
+}
+
+fn replay_key(proof: &CompactStorageProof) -> (String, String) {
+    (
+        hex::encode(&proof.prover_key),
+        hex::encode(&proof.data_hash),
+    )
+}
+
+fn record_proof(seen_proofs: &mut SeenProofs, proof: &CompactStorageProof) {
+    seen_proofs.insert(
+        replay_key(proof),
+        (
+            proof.block_height,
+            hex::encode(&proof.commitment_hash),
+            proof.vdf_proof.iterations,
+        ),
+    );
+}
+
+/// Replay protection: accept a proof only above its chain's last accepted block height
+/// and with enough sequential VDF work since it, recording it in `seen_proofs`.
+/// Each of a prover's chains is tracked separately, so chains committing at the same
+/// height do not replay each other.
+pub fn accept_compact_proof(
+    seen_proofs: &mut SeenProofs,
+    proof: &CompactStorageProof,
+    consensus_validator: &NetworkConsensusValidator,
+) -> bool {
+    match check_replay(seen_proofs, proof, consensus_validator) {
+        Ok(false) => {
+            record_proof(seen_proofs, proof);
+            true
+        }
+        Ok(true) => {
+            log::warn!(
+                "Replayed proof rejected for prover {}: height {} already accepted",
+                hex::encode(&proof.prover_key),
+                proof.block_height
+            );
+            false
+        }
+        Err(e) => {
+            log::warn!("{}", e);
+            false
+        }
+    }
+}
+
+/// Replay protection for the commitment of a full proof. Like `accept_compact_proof`,
+/// except that the commitment already recorded for the chain is accepted without being
+/// recorded again, so a full proof can follow the compact proof of the same commitment.
+pub fn accept_full_proof_commitment(
+    seen_proofs: &mut SeenProofs,
+    proof: &CompactStorageProof,
+    consensus_validator: &NetworkConsensusValidator,
+) -> bool {
+    match check_replay(seen_proofs, proof, consensus_validator) {
+        Ok(false) => {
+            record_proof(seen_proofs, proof);
+            true
+        }
+        Ok(true) => true,
+        Err(e) => {
+            log::warn!("{}", e);
+            false
+        }
+    }
+}
+
+/// Verify a batch of compact proofs with the same results as verifying them one by
+/// one in order. Stateless checks run in parallel, once per distinct commitment hash;
+/// a proof repeating a commitment hash earlier in the batch is rejected as a replay.
+/// `anchor_check` (block existence) and replay protection then run in order.
+/// `consensus_validators[i]` applies to `proofs[i]`.
+pub fn verify_compact_proofs_batch<A>(
+    seen_proofs: &mut SeenProofs,
+    proofs: &[CompactStorageProof],
+    consensus_validators: &[NetworkConsensusValidator],
+    now: f64,
+    anchor_check: A,
+) -> Vec<bool>
+where
+    A: Fn(u32) -> Result<(), String>,
+{
+    let mut first_occurrence = HashMap::new();
+    let unique: Vec<usize> = (0..proofs.len())
+        .filter(|&index| {
+            *first_occurrence
+                .entry(proofs[index].commitment_hash.as_ref())
+                .or_insert(index)
+                == index
+        })
+        .collect();
+
+    let formats: Vec<(usize, CompactProofFormat)> = unique
+        .into_iter()
+        .map(|index| (index, CompactProofFormat::of(&proofs[index])))
+        .collect();
+    let mut well_formed = vec![false; proofs.len()];
+    let checked: Vec<(usize, bool)> = formats
+        .into_par_iter()
+        .map(|(index, format)| (index, format.well_formed(&consensus_validators[index], now)))
+        .collect();
+    for (index, passed) in checked {
+        well_formed[index] = passed;
+    }
+
+    proofs
+        .iter()
+        .enumerate()
+        .map(|(index, proof)| {
+            if !well_formed[index] {
+                return false;
+            }
+            if let Err(e) = anchor_check(proof.block_height) {
+                log::warn!("Compact proof rejected: {}", e);
+                return false;
+            }
+            accept_compact_proof(seen_proofs, proof, &consensus_validators[index])
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{MemoryHardVDFProof, CHUNKS_PER_BLOCK};
+    use napi::bindgen_prelude::Buffer;
+
+    fn proof(prover: u8, block_height: u32, iterations: u32, now: f64) -> CompactStorageProof {
+        let mut commitment_hash = [0u8; 32];
+        commitment_hash[0] = prover;
+        commitment_hash[1..5].copy_from_slice(&block_height.to_be_bytes());
+        CompactStorageProof {
+            prover_key: Buffer::from([prover; 32].to_vec()),
+            data_hash: Buffer::from([prover; 32].to_vec()),
+            commitment_hash: Buffer::from(commitment_hash.to_vec()),
+            block_height,
+            chunk_proofs: vec![Buffer::from([7u8; 32].to_vec()); CHUNKS_PER_BLOCK as usize],
+            vdf_proof: MemoryHardVDFProof {
+                input_state: Buffer::from([1u8; 32].to_vec()),
+                output_state: Buffer::from([2u8; 32].to_vec()),
+                iterations,
+                memory_access_samples: Vec::new(),
+                computation_time_ms: 0.0,
+                memory_usage_bytes: 256.0 * 1024.0,
+            },
+            network_position: Buffer::from([3u8; 32].to_vec()),
+            timestamp: now,
+        }
+    }
+
+    #[test]
+    fn test_batch_matches_sequential_verification() {
+        let now = crate::core::utils::get_current_timestamp();
+        let validator = NetworkConsensusValidator::new_production();
+
+        // 1000 proofs from 50 provers with malformed proofs, stale VDF work, replays
+        // of earlier heights and exact duplicates mixed in
+        let mut proofs = Vec::new();
+        for i in 0..1000u32 {
+            let prover = (i % 50) as u8;
+            let height = 10 + i / 50;
+            let mut proof = proof(prover, height, 100_000 + height * 10_000, now);
+            match i % 23 {
+                0 => {
+                    proof.chunk_proofs.pop();
+                }
+                1 => proof.timestamp = now - 2.0 * 24.0 * 60.0 * 60.0,
+                2 => proof.vdf_proof.iterations = 1_000,
+                3 => proof.block_height = 5,
+                _ => {}
+            }
+            proofs.push(proof);
+            if i % 37 == 0 {
+                proofs.push(proofs[proofs.len() - 1].clone());
+            }
+        }
+        let validators = vec![validator.clone(); proofs.len()];
+
+        // One by one, as verifyCompactProof does
+        let mut sequential_seen = SeenProofs::new();
+        let sequential: Vec<bool> = proofs
+            .iter()
+            .map(|proof| {
+                compact_proof_well_formed(proof, &validator, now)
+                    && accept_compact_proof(&mut sequential_seen, proof, &validator)
+            })
+            .collect();
+
+        let mut batch_seen = SeenProofs::new();
+        let batch =
+            verify_compact_proofs_batch(&mut batch_seen, &proofs, &validators, now, |_| Ok(()));
+
+        assert_eq!(batch, sequential);
+        assert_eq!(batch_seen, sequential_seen);
+        assert!(batch.iter().any(|&accepted| accepted));
+        assert!(batch.iter().any(|&accepted| !accepted));
+
+        // Anchor failures reject without recording the proof
+        let mut seen = SeenProofs::new();
+        let results = verify_compact_proofs_batch(
+            &mut seen,
+            &proofs[1..3],
+            &validators[1..3],
+            now,
+            |height| Err(format!("Block {} not found", height)),
+        );
+        assert_eq!(results, vec![false, false]);
+        assert!(seen.is_empty());
+    }
+
+    #[test]
+    fn test_replay_tracked_per_chain() {
+        let now = crate::core::utils::get_current_timestamp();
+        let validator = NetworkConsensusValidator::new_production();
+        let mut seen = SeenProofs::new();
+
+        // Two chains of one prover commit at the same height
+        let first = proof(1, 10, 200_000, now);
+        let mut second = proof(1, 10, 200_000, now);
+        second.data_hash = Buffer::from([9u8; 32].to_vec());
+        second.commitment_hash = Buffer::from([9u8; 32].to_vec());
+        assert!(accept_compact_proof(&mut seen, &first, &validator));
+        assert!(accept_compact_proof(&mut seen, &second, &validator));
+        assert_eq!(seen.len(), 2);
+
+        // Replays within a chain are still rejected
+        assert!(!accept_compact_proof(&mut seen, &first, &validator));
+        let mut other_commitment = first.clone();
+        other_commitment.commitment_hash = Buffer::from([8u8; 32].to_vec());
+        assert!(!accept_compact_proof(
+            &mut seen,
+            &other_commitment,
+            &validator
+        ));
+
+        // A full proof of an already accepted commitment passes without a new record
+        let recorded = seen.clone();
+        assert!(accept_full_proof_commitment(&mut seen, &first, &validator));
+        assert_eq!(seen, recorded);
+        assert!(!accept_full_proof_commitment(
+            &mut seen,
+            &other_commitment,
+            &validator
+        ));
+
+        // A full proof for a new block is recorded like a compact proof
+        let next = proof(1, 11, 300_000, now);
+        assert!(accept_full_proof_commitment(&mut seen, &next, &validator));
+        assert!(!accept_compact_proof(&mut seen, &next, &validator));
+    }
+}
@@ -1,15 +1,93 @@
 pub mod chunk_selection;
 pub mod commitments;
+pub mod compact_proofs;
 pub mod network_latency;
+pub mod parameters;
+pub mod selection_bias;
 pub mod verification;
 
 /// Production consensus validation rules for network compliance
 pub use chunk_selection::*;
 pub use commitments::*;
+pub use compact_proofs::*;
 pub use network_latency::*;
+pub use parameters::*;
+pub use selection_bias::*;
 pub use verification::*;
 
+/// How rigorously a validator applies checks that only hold on a live network.
+/// Development skips latency, beacon and location checks and accepts short VDFs;
+/// staging checks latency but does not require beacon entropy or location proofs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationStrictness {
+    Development,
+    Staging,
+    Production,
+}
+
+impl ValidationStrictness {
+    /// Parse a strictness name as used on the NAPI surface
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "development" => Some(Self::Development),
+            "staging" => Some(Self::Staging),
+            "production" => Some(Self::Production),
+            _ => None,
+        }
+    }
+
+    /// Name proofs are tagged with
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Development => "development",
+            Self::Staging => "staging",
+            Self::Production => "production",
+        }
+    }
+
+    /// Whether network latency proofs are checked
+    pub fn checks_latency(&self) -> bool {
+        *self != Self::Development
+    }
+
+    /// Whether beacon entropy is checked, and required when the verifier demands it
+    pub fn checks_beacon(&self) -> bool {
+        *self != Self::Development
+    }
+
+    /// Whether latency proofs must carry a geographic location proof
+    pub fn requires_location(&self) -> bool {
+        *self == Self::Production
+    }
+}
+
+/// What consensus checks of a continuous VDF proof read, free of napi buffers so it
+/// can be validated on worker threads
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VdfProofFormat {
+    pub iterations: u32,
+    pub memory_access_samples: usize,
+    pub memory_usage_bytes: f64,
+    pub output_state_len: usize,
+    pub input_state_len: usize,
+    pub computation_time_ms: f64,
+}
+
+impl From<&crate::core::types::MemoryHardVDFProof> for VdfProofFormat {
+    fn from(vdf_proof: &crate::core::types::MemoryHardVDFProof) -> Self {
+        Self {
+            iterations: vdf_proof.iterations,
+            memory_access_samples: vdf_proof.memory_access_samples.len(),
+            memory_usage_bytes: vdf_proof.memory_usage_bytes,
+            output_state_len: vdf_proof.output_state.len(),
+            input_state_len: vdf_proof.input_state.len(),
+            computation_time_ms: vdf_proof.computation_time_ms,
+        }
+    }
+}
+
 /// Network consensus compliance validator
+#[derive(Clone)]
 pub struct NetworkConsensusValidator {
     /// Minimum VDF iterations required for production
     min_vdf_iterations: u32,
@@ -19,6 +97,12 @@ pub struct NetworkConsensusValidator {
     max_network_latency_ms: f64,
     /// Required memory size for VDF
     required_vdf_memory_mb: u32,
+    /// How minimum VDF iterations scale with the data proven
+    vdf_work_scaling: VDFWorkScaling,
+    /// Network parameters the rules are evaluated against
+    parameters: NetworkParameters,
+    /// Which optional checks are applied
+    strictness: ValidationStrictness,
 }
 
 impl Default for NetworkConsensusValidator {
@@ -30,14 +114,94 @@ impl Default for NetworkConsensusValidator {
 impl NetworkConsensusValidator {
     /// Create production consensus validator with specification parameters
     pub fn new_production() -> Self {
+        Self::for_strictness(ValidationStrictness::Production)
+    }
+
+    /// Create a staging validator: production rules without required beacon entropy
+    /// or location proofs
+    pub fn new_staging() -> Self {
+        Self::for_strictness(ValidationStrictness::Staging)
+    }
+
+    /// Create a development validator accepting short VDFs and skipping latency,
+    /// beacon and location checks
+    pub fn new_development() -> Self {
+        Self::for_strictness(ValidationStrictness::Development)
+    }
+
+    /// Create the preset validator for `strictness` with specification parameters
+    pub fn for_strictness(strictness: ValidationStrictness) -> Self {
+        Self::build(NetworkParameters::current(), strictness)
+    }
+
+    /// Create a validator enforcing the given network parameters, e.g. those in force
+    /// at a historical block height
+    pub fn from_parameters(parameters: NetworkParameters) -> Self {
+        Self::build(parameters, ValidationStrictness::Production)
+    }
+
+    /// Evaluate rules against different network parameters, keeping the strictness
+    pub fn with_params(self, parameters: NetworkParameters) -> Self {
+        Self::build(parameters, self.strictness)
+    }
+
+    fn build(parameters: NetworkParameters, strictness: ValidationStrictness) -> Self {
+        let min_vdf_iterations = match strictness {
+            ValidationStrictness::Development => parameters
+                .min_vdf_iterations
+                .min(crate::core::types::DEVELOPMENT_MIN_VDF_ITERATIONS),
+            _ => parameters.min_vdf_iterations, // NETWORK CONSENSUS: Minimum 1000 iterations for continuous VDF
+        };
         Self {
-            min_vdf_iterations: 1000, // NETWORK CONSENSUS: Minimum 1000 iterations for continuous VDF
-            required_chunks_per_block: crate::core::types::CHUNKS_PER_BLOCK,
+            min_vdf_iterations,
+            required_chunks_per_block: parameters.chunks_per_block,
             max_network_latency_ms: 200.0, // 200ms max for anti-outsourcing
             required_vdf_memory_mb: 0, // NETWORK CONSENSUS: 256KB for continuous VDF (less than 1MB)
+            vdf_work_scaling: parameters.vdf_work_scaling,
+            parameters,
+            strictness,
         }
     }
 
+    /// Strictness the validator applies, for tagging validated proofs
+    pub fn strictness(&self) -> ValidationStrictness {
+        self.strictness
+    }
+
+    /// Minimum VDF iterations a commitment must carry under these rules
+    pub fn min_vdf_iterations(&self) -> u32 {
+        self.min_vdf_iterations
+    }
+
+    /// Network parameters the rules are evaluated against
+    pub fn parameters(&self) -> &NetworkParameters {
+        &self.parameters
+    }
+
+    /// Use a different rule for scaling minimum VDF work with data size
+    pub fn with_vdf_work_scaling(mut self, vdf_work_scaling: VDFWorkScaling) -> Self {
+        self.vdf_work_scaling = vdf_work_scaling;
+        self
+    }
+
+    /// Validate VDF iterations cover the minimum for a proof over `total_chunks` chunks
+    pub fn validate_vdf_work_for_data(
+        &self,
+        vdf_iterations: u32,
+        total_chunks: u64,
+    ) -> Result<(), String> {
+        let required = self
+            .vdf_work_scaling
+            .min_iterations(self.min_vdf_iterations, total_chunks);
+        if vdf_iterations < required {
+            return Err(format!(
+                "VDF iterations insufficient for {} chunks: {} < {}",
+                total_chunks, vdf_iterations, required
+            ));
+        }
+        Ok(())
+    }
+
     /// Validate commitment complies with network consensus
     pub fn validate_commitment_consensus(
         &self,
@@ -90,6 +254,11 @@ impl NetworkConsensusValidator {
         &self,
         vdf_proof: &crate::core::types::MemoryHardVDFProof,
     ) -> Result<(), String> {
+        self.validate_vdf_format(&VdfProofFormat::from(vdf_proof))
+    }
+
+    /// Validate the format of a VDF proof meets consensus requirements
+    pub fn validate_vdf_format(&self, vdf_proof: &VdfProofFormat) -> Result<(), String> {
         // NETWORK CONSENSUS REQUIREMENT: Validate continuous VDF format
 
         // 1. Check minimum iterations for continuous VDF
@@ -101,7 +270,7 @@ impl NetworkConsensusValidator {
         }
 
         // 2. CRITICAL: Verify this is a continuous VDF proof (not old memory-hard VDF)
-        if !vdf_proof.memory_access_samples.is_empty() {
+        if vdf_proof.memory_access_samples != 0 {
             return Err("NETWORK CONSENSUS VIOLATION: Old memory-hard VDF format not accepted. Must use continuous VDF.".to_string());
         }
 
@@ -112,14 +281,14 @@ impl NetworkConsensusValidator {
         }
 
         // 4. Verify VDF signature format (output_state must be 32 bytes)
-        if vdf_proof.output_state.len() != 32 {
+        if vdf_proof.output_state_len != 32 {
             return Err(
                 "NETWORK CONSENSUS VIOLATION: VDF signature must be exactly 32 bytes".to_string(),
             );
         }
 
         // 5. Verify VDF state format (input_state must be 32 bytes)
-        if vdf_proof.input_state.len() != 32 {
+        if vdf_proof.input_state_len != 32 {
             return Err(
                 "NETWORK CONSENSUS VIOLATION: VDF state must be exactly 32 bytes".to_string(),
             );
@@ -138,12 +307,26 @@ impl NetworkConsensusValidator {
         &self,
         proof: &crate::core::types::NetworkLatencyProof,
     ) -> Result<(), String> {
+        if !self.strictness.checks_latency() {
+            return Ok(());
+        }
+
+        // Production proofs must place the prover geographically
+        if self.strictness.requires_location()
+            && proof
+                .location_proof
+                .as_ref()
+                .is_none_or(|location| location.is_empty())
+        {
+            return Err("Network latency proof has no location proof".to_string());
+        }
+
         // Check minimum peer count
-        if proof.peer_latencies.len() < crate::core::types::NETWORK_LATENCY_SAMPLES as usize {
+        if proof.peer_latencies.len() < self.parameters.network_latency_samples as usize {
             return Err(format!(
                 "Insufficient network samples: {} < {}",
                 proof.peer_latencies.len(),
-                crate::core::types::NETWORK_LATENCY_SAMPLES
+                self.parameters.network_latency_samples
             ));
         }
 
@@ -191,6 +374,9 @@ impl NetworkConsensusValidator {
             ));
         }
 
+        // Cheap ordering pre-check before any re-derivation
+        validate_chunk_ordering(selected_chunks)?;
+
         // Verify all chunks are within bounds
         for &chunk_idx in selected_chunks {
             if chunk_idx >= total_chunks {
@@ -215,6 +401,484 @@ impl NetworkConsensusValidator {
         Ok(())
     }
 
+    /// Validate consensus-relevant proof metadata against the proof's VDF characteristics.
+    /// Advisory metadata fields are never inspected.
+    pub fn validate_proof_metadata_consensus(
+        &self,
+        metadata: &crate::core::types::ConsensusProofMetadata,
+        vdf_proof: &crate::core::types::MemoryHardVDFProof,
+    ) -> Result<(), String> {
+        // 1. Reported values must be well-formed
+        if !metadata.generation_time_ms.is_finite() || metadata.generation_time_ms < 0.0 {
+            return Err(format!(
+                "Invalid generation time: {}",
+                metadata.generation_time_ms
+            ));
+        }
+        if !metadata.memory_usage_mb.is_finite() || metadata.memory_usage_mb < 0.0 {
+            return Err(format!(
+                "Invalid memory usage: {}",
+                metadata.memory_usage_mb
+            ));
+        }
+
+        // 2. Proof must be producible within one block interval
+        let max_generation_ms = self.parameters.block_time_seconds as f64 * 1000.0;
+        if metadata.generation_time_ms > max_generation_ms {
+            return Err(format!(
+                "Generation time exceeds block interval: {:.0}ms > {:.0}ms",
+                metadata.generation_time_ms, max_generation_ms
+            ));
+        }
+
+        // 3. Discrete VDF work is part of generation and bounded by the fastest hardware
+        if vdf_proof.computation_time_ms > 0.0 {
+            if metadata.generation_time_ms < vdf_proof.computation_time_ms {
+                return Err(format!(
+                    "Generation time shorter than VDF computation: {:.0}ms < {:.0}ms",
+                    metadata.generation_time_ms, vdf_proof.computation_time_ms
+                ));
+            }
+
+            let min_vdf_time_ms = vdf_proof.iterations as f64
+                / self.parameters.max_vdf_iterations_per_second
+                * 1000.0;
+            if metadata.generation_time_ms < min_vdf_time_ms {
+                return Err(format!(
+                    "Generation time too short for {} VDF iterations: {:.0}ms < {:.0}ms",
+                    vdf_proof.iterations, metadata.generation_time_ms, min_vdf_time_ms
+                ));
+            }
+        }
+
+        // 4. Reported memory must cover the VDF memory requirement
+        let memory_bytes = metadata.memory_usage_mb * 1024.0 * 1024.0;
+        if memory_bytes < vdf_proof.memory_usage_bytes {
+            return Err(format!(
+                "Reported memory below VDF requirement: {:.0} bytes < {:.0} bytes",
+                memory_bytes, vdf_proof.memory_usage_bytes
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Validate the VDF advanced by at least `MIN_VDF_ITERATIONS_PER_BLOCK` per block since the
+    /// prover's previous commitment, so commitments cannot reuse a stale VDF output
+    pub fn validate_vdf_progress(
+        &self,
+        previous_iterations: u64,
+        new_iterations: u64,
+        blocks_elapsed: u32,
+    ) -> Result<(), String> {
+        let required_delta =
+            blocks_elapsed.max(1) as u64 * self.parameters.min_vdf_iterations_per_block as u64;
+        let delta = new_iterations.saturating_sub(previous_iterations);
+        if delta < required_delta {
+            return Err(format!(
+                "Insufficient VDF progress since previous commitment: {} iterations over {} blocks (need {})",
+                delta, blocks_elapsed, required_delta
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Validate `later` continues `earlier` on the same chain with enough sequential VDF
+    /// work between them. Commitments from different chains are rejected up front.
+    pub fn validate_vdf_continuity(
+        &self,
+        earlier: &crate::core::types::StorageCommitment,
+        later: &crate::core::types::StorageCommitment,
+    ) -> Result<(), String> {
+        if !same_chain(earlier, later) {
+            return Err("Commitments belong to different chains".to_string());
+        }
+        if later.block_height <= earlier.block_height {
+            return Err(format!(
+                "Commitment at block {} does not follow block {}",
+                later.block_height, earlier.block_height
+            ));
+        }
+
+        self.validate_vdf_progress(
+            earlier.vdf_proof.iterations as u64,
+            later.vdf_proof.iterations as u64,
+            later.block_height - earlier.block_height,
+        )?;
+        self.validate_vdf_input_link(&earlier.prover_key, &earlier.vdf_proof, &later.vdf_proof)
+    }
+
+    /// Validate `next` continues the prover VDF that produced `prior`. Continuous proofs
+    /// carry the running VDF state in `input_state` (their `output_state` is the block
+    /// signature), and a VDF's state depends on its memory as well as its last state, so
+    /// `next`'s input is derived from `prior`'s by running the VDF seeded from
+    /// `prover_key`: it must pass through `prior.input_state` and reach
+    /// `next.input_state`. Proofs beyond `VDF_SEED_REPLAY_MAX_ITERATIONS` are not
+    /// replayed; their states must only agree at equal iteration counts and differ
+    /// otherwise.
+    pub fn validate_vdf_input_link(
+        &self,
+        prover_key: &[u8],
+        prior: &crate::core::types::MemoryHardVDFProof,
+        next: &crate::core::types::MemoryHardVDFProof,
+    ) -> Result<(), String> {
+        if next.iterations < prior.iterations {
+            return Err(format!(
+                "VDF went backwards: {} iterations after {}",
+                next.iterations, prior.iterations
+            ));
+        }
+
+        if next.iterations <= crate::core::types::VDF_SEED_REPLAY_MAX_ITERATIONS {
+            let mut vdf = crate::core::utils::ContinuousVDF::new(
+                crate::core::utils::compute_blake3(prover_key),
+                crate::core::types::CONTINUOUS_VDF_MEMORY_KB,
+            );
+            for _ in 0..prior.iterations {
+                vdf.iterate();
+            }
+            if prior.input_state.as_ref() != vdf.get_state().0 {
+                return Err(format!(
+                    "Prior VDF input state is not the prover VDF's state at iteration {}",
+                    prior.iterations
+                ));
+            }
+            for _ in prior.iterations..next.iterations {
+                vdf.iterate();
+            }
+            if next.input_state.as_ref() != vdf.get_state().0 {
+                return Err(format!(
+                    "VDF input state at iteration {} is not derived from the prior state at iteration {}",
+                    next.iterations, prior.iterations
+                ));
+            }
+            return Ok(());
+        }
+
+        let same_state = next.input_state.as_ref() == prior.input_state.as_ref();
+        if next.iterations == prior.iterations && !same_state {
+            return Err(format!(
+                "VDF input state differs from the prior proof at iteration {}",
+                next.iterations
+            ));
+        }
+        if next.iterations > prior.iterations && same_state {
+            return Err(format!(
+                "VDF input state did not advance from iteration {} to {}",
+                prior.iterations, next.iterations
+            ));
+        }
+        Ok(())
+    }
+
+    /// Validate a prover's first VDF proof starts from its key seed: replaying the
+    /// continuous VDF from `compute_blake3(prover_key)` for the proof's iterations must
+    /// reach `input_state`. Proofs beyond `VDF_SEED_REPLAY_MAX_ITERATIONS` are not
+    /// replayed and rely on continuity with the prover's later proofs.
+    pub fn validate_vdf_seed_derivation(
+        &self,
+        prover_key: &[u8],
+        vdf_proof: &crate::core::types::MemoryHardVDFProof,
+    ) -> Result<(), String> {
+        if vdf_proof.iterations > crate::core::types::VDF_SEED_REPLAY_MAX_ITERATIONS {
+            log::debug!(
+                "VDF proof at {} iterations is beyond seed replay, relying on continuity",
+                vdf_proof.iterations
+            );
+            return Ok(());
+        }
+
+        let expected = crate::core::utils::replay_continuous_vdf_state(
+            crate::core::utils::compute_blake3(prover_key),
+            crate::core::types::CONTINUOUS_VDF_MEMORY_KB,
+            vdf_proof.iterations as u64,
+        );
+        if vdf_proof.input_state.as_ref() != expected {
+            return Err(format!(
+                "VDF input state does not derive from the prover key seed at iteration {}",
+                vdf_proof.iterations
+            ));
+        }
+        Ok(())
+    }
+
+    /// Validate a prover's VDF checkpoint against a shared VDF proof it published
+    /// earlier. The proof must be signed by `prover_key`, so its state was fixed before
+    /// the checkpoint was requested; recomputing from the checkpoint up to the proof's
+    /// iteration must land on exactly that state. A VDF whose iteration count was
+    /// inflated without doing the work cannot produce such a checkpoint, and is caught
+    /// with probability growing with the number of spot checks.
+    pub fn validate_vdf_checkpoint(
+        &self,
+        prover_key: &[u8],
+        published: &crate::core::vdf_processor::SharedVDFProof,
+        checkpoint: &crate::core::types::VDFCheckpoint,
+    ) -> Result<(), String> {
+        use crate::core::types::{CONTINUOUS_VDF_MEMORY_KB, VDF_REPRODUCTION_MAX_SEGMENT};
+
+        // 1. The end state must be one the prover signed and published
+        if !crate::core::utils::verify_signature(
+            prover_key,
+            &published.signed_data(),
+            &published.signature,
+        )
+        .unwrap_or(false)
+        {
+            return Err(format!(
+                "Shared VDF proof at iteration {} is not signed by the prover key",
+                published.total_iterations
+            ));
+        }
+
+        // 2. Checkpoint must precede the published proof within a recomputable window
+        if checkpoint.iteration < 0.0 || checkpoint.iteration.fract() != 0.0 {
+            return Err(format!(
+                "Invalid checkpoint iteration: {}",
+                checkpoint.iteration
+            ));
+        }
+        let checkpoint_iteration = checkpoint.iteration as u64;
+        let published_iteration = published.total_iterations;
+        if published_iteration <= checkpoint_iteration
+            || published_iteration - checkpoint_iteration > VDF_REPRODUCTION_MAX_SEGMENT as u64
+        {
+            return Err(format!(
+                "Published iteration {} is not within {} iterations after checkpoint {}",
+                published_iteration, VDF_REPRODUCTION_MAX_SEGMENT, checkpoint_iteration
+            ));
+        }
+
+        // 3. Recomputing the window from the checkpoint must reach the published state
+        let Ok(start_state) = <[u8; 32]>::try_from(checkpoint.state.as_ref()) else {
+            return Err("VDF checkpoint state must be 32 bytes".to_string());
+        };
+        let segment = crate::core::utils::VDFSegmentReproduction {
+            iteration_index: checkpoint_iteration,
+            segment_length: published_iteration - checkpoint_iteration,
+            start_state,
+            memory_snapshot: checkpoint.memory_snapshot.to_vec(),
+            end_state: published.vdf_state,
+        };
+        if !crate::core::utils::verify_continuous_vdf_segment(&segment, CONTINUOUS_VDF_MEMORY_KB) {
+            return Err(format!(
+                "Published VDF state at iteration {} does not follow from checkpoint {}",
+                published_iteration, checkpoint_iteration
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Validate a proof's VDF iteration count against the wall-clock time since its chain
+    /// started. Computing `vdf_iterations` takes at least their count over the fastest
+    /// plausible VDF rate; a proof claiming more compute time than elapsed (beyond
+    /// `VDF_ELAPSED_TIME_TOLERANCE` and clock slack) fabricated its iterations. Less
+    /// compute than elapsed is accepted, since a VDF may have been paused.
+    pub fn validate_vdf_elapsed_time(
+        &self,
+        vdf_iterations: u64,
+        chain_start_time: f64,
+        proof_timestamp: f64,
+    ) -> Result<(), String> {
+        use crate::core::types::{VDF_ELAPSED_TIME_SLACK_SECONDS, VDF_ELAPSED_TIME_TOLERANCE};
+
+        if !chain_start_time.is_finite() || !proof_timestamp.is_finite() {
+            return Err(format!(
+                "Invalid timestamps: chain start {}, proof {}",
+                chain_start_time, proof_timestamp
+            ));
+        }
+        let elapsed_seconds = proof_timestamp - chain_start_time;
+        if elapsed_seconds < -VDF_ELAPSED_TIME_SLACK_SECONDS {
+            return Err(format!(
+                "Proof timestamp {:.0} predates chain start {:.0}",
+                proof_timestamp, chain_start_time
+            ));
+        }
+
+        let min_compute_seconds =
+            vdf_iterations as f64 / self.parameters.max_vdf_iterations_per_second;
+        let max_compute_seconds =
+            elapsed_seconds.max(0.0) * VDF_ELAPSED_TIME_TOLERANCE + VDF_ELAPSED_TIME_SLACK_SECONDS;
+        if min_compute_seconds > max_compute_seconds {
+            return Err(format!(
+                "{} VDF iterations need at least {:.1}s but only {:.1}s elapsed since chain start",
+                vdf_iterations, min_compute_seconds, elapsed_seconds
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Validate a proof was produced within `PROOF_WINDOW_BLOCKS` of its committed block.
+    /// The committed block's time is estimated from `current_time` and the block distance,
+    /// with one block interval of tolerance for block time variance.
+    pub fn validate_proof_window_timing(
+        &self,
+        proof_block_height: u32,
+        proof_timestamp: f64,
+        current_block_height: u32,
+        current_time: f64,
+    ) -> Result<(), String> {
+        // 1. Committed block must already exist
+        if proof_block_height > current_block_height {
+            return Err(format!(
+                "Proof block height {} is ahead of current height {}",
+                proof_block_height, current_block_height
+            ));
+        }
+
+        // 2. Submission window must still be open
+        let elapsed_blocks = current_block_height - proof_block_height;
+        let proof_window_blocks = self.parameters.proof_window_blocks;
+        if elapsed_blocks > proof_window_blocks {
+            return Err(format!(
+                "Proof window closed: block {} is {} blocks old (window: {})",
+                proof_block_height, elapsed_blocks, proof_window_blocks
+            ));
+        }
+
+        // 3. Proof must be produced after its block and not in the future
+        if !proof_timestamp.is_finite() {
+            return Err(format!("Invalid proof timestamp: {}", proof_timestamp));
+        }
+        let tolerance = self.parameters.block_time_seconds as f64;
+        let estimated_block_time = current_time - elapsed_blocks as f64 * tolerance;
+        if proof_timestamp < estimated_block_time - tolerance {
+            return Err(format!(
+                "Proof timestamp {:.0} predates block {} (estimated at {:.0})",
+                proof_timestamp, proof_block_height, estimated_block_time
+            ));
+        }
+        if proof_timestamp > current_time + tolerance {
+            return Err(format!(
+                "Proof timestamp {:.0} is in the future (now {:.0})",
+                proof_timestamp, current_time
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Validate every challenged chunk is one of the commitment's selected chunks,
+    /// for protocols where challenges must overlap what the prover committed to
+    pub fn validate_challenge_within_commitment(
+        &self,
+        challenged_chunks: &[u32],
+        committed_chunks: &[u32],
+    ) -> Result<(), String> {
+        if challenged_chunks.is_empty() {
+            return Err("Challenge has no chunks".to_string());
+        }
+
+        if let Some(chunk) = challenged_chunks
+            .iter()
+            .find(|chunk| !committed_chunks.contains(chunk))
+        {
+            return Err(format!(
+                "Challenged chunk {} is not among the committed chunks",
+                chunk
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Validate a commitment's beacon entropy. Present beacon entropy must be bound
+    /// into the combined entropy hash and accepted by `verify_beacon` as the genuine
+    /// beacon output for `round`; absent entropy is rejected when `beacon_required`
+    /// and the validator is at production strictness. Development skips the check.
+    pub fn validate_beacon_entropy<F>(
+        &self,
+        entropy: &crate::core::types::MultiSourceEntropy,
+        round: u32,
+        beacon_required: bool,
+        verify_beacon: F,
+    ) -> Result<(), String>
+    where
+        F: FnOnce(&[u8], u32) -> Result<bool, String>,
+    {
+        if !self.strictness.checks_beacon() {
+            return Ok(());
+        }
+
+        let Some(beacon_entropy) = &entropy.beacon_entropy else {
+            if beacon_required && self.strictness == ValidationStrictness::Production {
+                return Err(format!("Beacon entropy required for round {}", round));
+            }
+            return Ok(());
+        };
+
+        let combined = crate::core::utils::combine_entropy_sources(
+            &entropy.blockchain_entropy,
+            Some(beacon_entropy),
+            &entropy.local_entropy,
+            entropy.timestamp,
+        );
+        if combined.as_slice() != entropy.combined_hash.as_ref() {
+            return Err("Combined entropy hash does not include the beacon entropy".to_string());
+        }
+
+        if !verify_beacon(beacon_entropy, round)? {
+            return Err(format!("Forged beacon entropy for round {}", round));
+        }
+
+        Ok(())
+    }
+
+    /// Validate a claimed hierarchical position against the network's chain count.
+    /// Levels are chain (0), group (1), region (2) and global (3); the item count at
+    /// each level follows from `total_chains_count`, and every level below the global
+    /// root must name the parent its position is grouped under.
+    pub fn validate_hierarchical_position(
+        &self,
+        position: &crate::core::types::HierarchicalPosition,
+        total_chains_count: u32,
+    ) -> Result<(), String> {
+        use crate::core::types::{CHAINS_PER_GROUP, GROUPS_PER_REGION};
+
+        if total_chains_count == 0 {
+            return Err("Hierarchy has no chains".to_string());
+        }
+
+        // 1. Level must exist and its size must follow from the chain count
+        let group_count = total_chains_count.div_ceil(CHAINS_PER_GROUP);
+        let region_count = group_count.div_ceil(GROUPS_PER_REGION);
+        let (expected_total, fanout) = match position.level {
+            0 => (total_chains_count, Some(CHAINS_PER_GROUP)),
+            1 => (group_count, Some(GROUPS_PER_REGION)),
+            2 => (region_count, Some(region_count)),
+            3 => (1, None),
+            level => return Err(format!("Invalid hierarchy level {}", level)),
+        };
+        if position.total_at_level != expected_total {
+            return Err(format!(
+                "Level {} claims {} items, but {} chains give {}",
+                position.level, position.total_at_level, total_chains_count, expected_total
+            ));
+        }
+
+        // 2. Position must lie within its level
+        if position.position >= position.total_at_level {
+            return Err(format!(
+                "Position {} is out of bounds at level {} ({} items)",
+                position.position, position.level, position.total_at_level
+            ));
+        }
+
+        // 3. Parent must be the one the position is grouped under; the root has none
+        let expected_parent = fanout.map(|fanout| position.position / fanout);
+        if position.parent_position != expected_parent {
+            return Err(format!(
+                "Parent {:?} does not match position {} at level {} (expected {:?})",
+                position.parent_position, position.position, position.level, expected_parent
+            ));
+        }
+
+        Ok(())
+    }
+
     /// Comprehensive consensus validation for full commitment
     pub fn validate_full_consensus(
         &self,
@@ -224,6 +888,7 @@ impl NetworkConsensusValidator {
         // Run all consensus validations
         self.validate_commitment_consensus(commitment)?;
         self.validate_vdf_consensus(&commitment.vdf_proof)?;
+        self.validate_vdf_work_for_data(commitment.vdf_proof.iterations, total_chunks as u64)?;
         self.validate_chunk_selection_consensus(
             &commitment.entropy,
             total_chunks,
@@ -233,3 +898,160 @@ impl NetworkConsensusValidator {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::HierarchicalPosition;
+    use napi::bindgen_prelude::Buffer;
+
+    fn position(
+        level: u32,
+        position: u32,
+        total: u32,
+        parent: Option<u32>,
+    ) -> HierarchicalPosition {
+        HierarchicalPosition {
+            level,
+            position,
+            total_at_level: total,
+            parent_position: parent,
+            security_score: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_hierarchical_position() {
+        let validator = NetworkConsensusValidator::default();
+        // 25,000 chains form 25 groups in 3 regions
+        let chains = 25_000;
+
+        // Valid positions at every level
+        for valid in [
+            position(0, 0, chains, Some(0)),
+            position(0, 24_999, chains, Some(24)),
+            position(1, 24, 25, Some(2)),
+            position(2, 2, 3, Some(0)),
+            position(3, 0, 1, None),
+        ] {
+            assert!(
+                validator
+                    .validate_hierarchical_position(&valid, chains)
+                    .is_ok(),
+                "level {} position {} should be valid",
+                valid.level,
+                valid.position
+            );
+        }
+
+        // Level size must follow from the chain count
+        assert!(validator
+            .validate_hierarchical_position(&position(1, 0, 26, Some(0)), chains)
+            .is_err());
+        // Position must lie within its level
+        assert!(validator
+            .validate_hierarchical_position(&position(0, chains, chains, Some(25)), chains)
+            .is_err());
+        // Parent must be the one the position is grouped under
+        assert!(validator
+            .validate_hierarchical_position(&position(0, 1_500, chains, Some(0)), chains)
+            .is_err());
+        assert!(validator
+            .validate_hierarchical_position(&position(1, 3, 25, None), chains)
+            .is_err());
+        assert!(validator
+            .validate_hierarchical_position(&position(3, 0, 1, Some(0)), chains)
+            .is_err());
+        // Empty hierarchies and unknown levels are rejected
+        assert!(validator
+            .validate_hierarchical_position(&position(3, 0, 1, None), 0)
+            .is_err());
+        assert!(validator
+            .validate_hierarchical_position(&position(4, 0, 1, None), chains)
+            .is_err());
+    }
+
+    /// Continuous VDF proof carrying the prover VDF's state at `iterations`
+    fn vdf_proof(state: [u8; 32], iterations: u32) -> crate::core::types::MemoryHardVDFProof {
+        crate::core::types::MemoryHardVDFProof {
+            input_state: Buffer::from(state.to_vec()),
+            output_state: Buffer::from(vec![0u8; 64]),
+            iterations,
+            memory_access_samples: Vec::new(),
+            computation_time_ms: 0.0,
+            memory_usage_bytes: 256.0 * 1024.0,
+        }
+    }
+
+    fn prover_state(prover_key: &[u8], iterations: u32) -> [u8; 32] {
+        crate::core::utils::replay_continuous_vdf_state(
+            crate::core::utils::compute_blake3(prover_key),
+            crate::core::types::CONTINUOUS_VDF_MEMORY_KB,
+            iterations as u64,
+        )
+    }
+
+    #[test]
+    fn test_validate_vdf_input_link() {
+        let validator = NetworkConsensusValidator::default();
+        let prover_key = [3u8; 32];
+        let prior = vdf_proof(prover_state(&prover_key, 40), 40);
+        let next = vdf_proof(prover_state(&prover_key, 100), 100);
+
+        assert!(validator
+            .validate_vdf_input_link(&prover_key, &prior, &next)
+            .is_ok());
+        assert!(validator
+            .validate_vdf_input_link(&prover_key, &prior, &prior)
+            .is_ok());
+        assert!(validator
+            .validate_vdf_input_link(&prover_key, &next, &prior)
+            .is_err());
+
+        // A state the VDF moved on to, but not from this prior, is not a link
+        let other_prover = [4u8; 32];
+        let forged = vdf_proof(prover_state(&other_prover, 100), 100);
+        assert!(validator
+            .validate_vdf_input_link(&prover_key, &prior, &forged)
+            .is_err());
+        let arbitrary = vdf_proof([9u8; 32], 100);
+        assert!(validator
+            .validate_vdf_input_link(&prover_key, &prior, &arbitrary)
+            .is_err());
+        // Nor is a state restarted from the prior state without the VDF's memory
+        let restarted = vdf_proof(
+            crate::core::utils::replay_continuous_vdf_state(
+                prover_state(&prover_key, 40),
+                crate::core::types::CONTINUOUS_VDF_MEMORY_KB,
+                60,
+            ),
+            100,
+        );
+        assert!(validator
+            .validate_vdf_input_link(&prover_key, &prior, &restarted)
+            .is_err());
+        // The prior must itself be on the prover's VDF
+        let bad_prior = vdf_proof([9u8; 32], 40);
+        assert!(validator
+            .validate_vdf_input_link(&prover_key, &bad_prior, &next)
+            .is_err());
+    }
+
+    #[test]
+    fn test_validate_vdf_seed_derivation() {
+        let validator = NetworkConsensusValidator::default();
+        let prover_key = [5u8; 32];
+        let genesis = vdf_proof(prover_state(&prover_key, 64), 64);
+
+        assert!(validator
+            .validate_vdf_seed_derivation(&prover_key, &genesis)
+            .is_ok());
+        assert!(validator
+            .validate_vdf_seed_derivation(&[6u8; 32], &genesis)
+            .is_err());
+        let wrong_iterations = vdf_proof(prover_state(&prover_key, 64), 65);
+        assert!(validator
+            .validate_vdf_seed_derivation(&prover_key, &wrong_iterations)
+            .is_err());
+    }
+}
@@ -250,7 +250,7 @@ impl NetworkLatencyProver {
 
     /// Generate production geographic location proof with enhanced verification
     fn generate_location_proof(&self, measurements: &[PeerLatencyMeasurement]) -> Result<Vec<u8>> {
-        let mut proof_input = Vec::new();
+        let mut proof_input = GEOGRAPHIC_DISTRIBUTION_DOMAIN.to_vec();
 
         // Include network routing characteristics for authenticity
         let mut latency_fingerprint = Vec::new();
@@ -294,8 +294,6 @@ impl NetworkLatencyProver {
         let diversity_score = self.compute_network_diversity_score(measurements);
         proof_input.extend_from_slice(&diversity_score.to_be_bytes());
 
-        proof_input.extend_from_slice(b"enhanced_geographic_distribution_proof_v2");
-
         Ok(compute_sha256(&proof_input).to_vec())
     }
 
@@ -416,6 +414,90 @@ pub fn create_network_proof(peer_addresses: Vec<String>) -> Result<NetworkLatenc
     prover.generate_latency_proof()
 }
 
+/// Access proof VDF input for a challenge response bound to the prover's fresh
+/// round-trip latency to the challenger
+pub fn latency_bound_access_input(challenge_id: &[u8], measured_latency_ms: f64) -> Vec<u8> {
+    let mut input = Vec::with_capacity(CHALLENGE_LATENCY_BINDING_DOMAIN.len() + 40);
+    input.extend_from_slice(CHALLENGE_LATENCY_BINDING_DOMAIN);
+    input.extend_from_slice(challenge_id);
+    input.extend_from_slice(&measured_latency_ms.to_be_bytes());
+    input
+}
+
+/// Whether the latency a prover bound into its response agrees with the verifier's
+/// own round-trip measurement to the prover
+pub fn latency_matches_measurement(claimed_latency_ms: f64, verifier_rtt_ms: f64) -> bool {
+    claimed_latency_ms.is_finite()
+        && verifier_rtt_ms.is_finite()
+        && verifier_rtt_ms > 0.0
+        && (claimed_latency_ms - verifier_rtt_ms).abs()
+            <= verifier_rtt_ms * (CHALLENGE_LATENCY_TOLERANCE - 1.0) + CHALLENGE_LATENCY_SLACK_MS
+}
+
+/// Recompute a challenge response's access proof VDF from `expected_input` and check
+/// its iteration count and output. The claimed computation time may exceed the
+/// verifier's own recomputation by at most `CHALLENGE_ACCESS_PROOF_TIME_TOLERANCE`, so
+/// it cannot be inflated to hide relay time. Returns the claimed time.
+pub fn verify_access_proof_work(
+    proof: &MemoryHardVDFProof,
+    expected_input: &[u8],
+    iterations: u32,
+    memory_kb: u32,
+) -> std::result::Result<f64, String> {
+    if proof.input_state.as_ref() != expected_input {
+        return Err("Access proof input does not match the challenge".to_string());
+    }
+    if proof.iterations != iterations {
+        return Err(format!(
+            "Access proof ran {} iterations (need {})",
+            proof.iterations, iterations
+        ));
+    }
+
+    let (output, verifier_time_ms, _) =
+        crate::core::utils::compute_memory_hard_vdf(expected_input, iterations, memory_kb, 1)
+            .map_err(|e| format!("Access proof recomputation failed: {}", e))?;
+    if proof.output_state.as_ref() != output.as_slice() {
+        return Err("Access proof output does not match its input".to_string());
+    }
+
+    let claimed_ms = proof.computation_time_ms;
+    let max_ms = verifier_time_ms * CHALLENGE_ACCESS_PROOF_TIME_TOLERANCE;
+    if !claimed_ms.is_finite() || claimed_ms < 0.0 || claimed_ms > max_ms {
+        return Err(format!(
+            "Access proof claims {:.1}ms of computation (at most {:.1}ms)",
+            claimed_ms, max_ms
+        ));
+    }
+    Ok(claimed_ms)
+}
+
+/// Check that a challenge response arrived within a window consistent with the
+/// round-trip latency to the prover. Time spent on the network is the elapsed
+/// time since the challenge was issued less the access proof computation; a response
+/// relayed through a proxy at the claimed location takes longer than the latency
+/// allows.
+pub fn verify_response_latency_window(
+    measured_latency_ms: f64,
+    challenge_time: f64,
+    received_at: f64,
+    computation_time_ms: f64,
+) -> bool {
+    if !measured_latency_ms.is_finite()
+        || measured_latency_ms <= 0.0
+        || measured_latency_ms > NETWORK_LATENCY_MAX_MS as f64
+        || !computation_time_ms.is_finite()
+        || computation_time_ms < 0.0
+    {
+        return false;
+    }
+
+    let network_time_ms = (received_at - challenge_time) * 1000.0 - computation_time_ms;
+    network_time_ms >= 0.0
+        && network_time_ms
+            <= measured_latency_ms * CHALLENGE_LATENCY_TOLERANCE + CHALLENGE_LATENCY_SLACK_MS
+}
+
 /// Detect potential outsourcing based on latency patterns
 pub fn detect_outsourcing_patterns(
     historical_proofs: &[NetworkLatencyProof],
@@ -468,3 +550,64 @@ pub enum OutsourcingRisk {
     High,
     Unknown,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn access_proof(input: &[u8], iterations: u32, memory_kb: u32) -> MemoryHardVDFProof {
+        let (output, computation_time_ms, memory_usage_bytes) =
+            crate::core::utils::compute_memory_hard_vdf(input, iterations, memory_kb, 1).unwrap();
+        MemoryHardVDFProof {
+            input_state: Buffer::from(input.to_vec()),
+            output_state: Buffer::from(output.to_vec()),
+            iterations,
+            memory_access_samples: Vec::new(),
+            computation_time_ms,
+            memory_usage_bytes,
+        }
+    }
+
+    #[test]
+    fn test_latency_matches_measurement() {
+        assert!(latency_matches_measurement(40.0, 40.0));
+        assert!(latency_matches_measurement(50.0, 40.0));
+        // A prover claiming a much lower latency than the verifier measured is rejected
+        assert!(!latency_matches_measurement(5.0, 100.0));
+        assert!(!latency_matches_measurement(300.0, 100.0));
+        assert!(!latency_matches_measurement(40.0, 0.0));
+        assert!(!latency_matches_measurement(f64::NAN, 40.0));
+    }
+
+    #[test]
+    fn test_access_proof_work_is_recomputed() {
+        let input = latency_bound_access_input(&[3u8; 32], 40.0);
+        let proof = access_proof(&input, 2_000, 256);
+        assert!(verify_access_proof_work(&proof, &input, 2_000, 256).is_ok());
+
+        // Another input, iteration count or output is rejected
+        let other_input = latency_bound_access_input(&[3u8; 32], 10.0);
+        assert!(verify_access_proof_work(&proof, &other_input, 2_000, 256).is_err());
+        assert!(verify_access_proof_work(&proof, &input, 4_000, 256).is_err());
+        let mut forged = proof.clone();
+        forged.output_state = Buffer::from([0u8; 32].to_vec());
+        assert!(verify_access_proof_work(&forged, &input, 2_000, 256).is_err());
+
+        // An inflated computation time cannot hide relay time
+        let mut inflated = proof.clone();
+        inflated.computation_time_ms = 60_000.0;
+        assert!(verify_access_proof_work(&inflated, &input, 2_000, 256).is_err());
+    }
+
+    #[test]
+    fn test_response_latency_window() {
+        // 40ms round trip plus 100ms of computation, received 140ms after the challenge
+        assert!(verify_response_latency_window(40.0, 1000.0, 1000.14, 100.0));
+        // Relayed through a proxy: 200ms on the network
+        assert!(!verify_response_latency_window(40.0, 1000.0, 1000.3, 100.0));
+        // Received before the computation could have finished
+        assert!(!verify_response_latency_window(
+            40.0, 1000.0, 1000.05, 100.0
+        ));
+    }
+}
@@ -0,0 +1,474 @@
+use crate::core::{
+    types::*,
+    utils::{
+        combine_entropy_sources, compute_commitment_hash, compute_sha256,
+        select_chunks_deterministic, CommitmentParams, ContinuousVDF,
+    },
+};
+
+/// Domain separator for the network parameter fingerprint
+const NETWORK_PARAMETERS_DOMAIN: &[u8] = b"network_parameters_v1";
+
+/// Domain separator for the build consensus fingerprint
+const CONSENSUS_FINGERPRINT_DOMAIN: &[u8] = b"consensus_fingerprint_v1";
+
+/// Continuous VDF iterations run for the fingerprint test vector
+const CONSENSUS_FINGERPRINT_VDF_ITERATIONS: u32 = 64;
+
+/// How the minimum VDF work per proof grows with the amount of data proven
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VDFWorkScaling {
+    /// Same minimum regardless of data size
+    Flat,
+    /// Minimum plus `iterations_per_doubling` for every doubling of the chunk count
+    Logarithmic { iterations_per_doubling: u32 },
+}
+
+/// VDF work scaling compiled into this build (flat for compatibility)
+pub const VDF_WORK_SCALING: VDFWorkScaling = VDFWorkScaling::Flat;
+
+impl VDFWorkScaling {
+    /// Minimum VDF iterations for a proof over `total_chunks` chunks
+    pub fn min_iterations(&self, base_iterations: u32, total_chunks: u64) -> u32 {
+        match *self {
+            VDFWorkScaling::Flat => base_iterations,
+            VDFWorkScaling::Logarithmic {
+                iterations_per_doubling,
+            } => {
+                let doublings = total_chunks.max(1).ilog2();
+                base_iterations.saturating_add(iterations_per_doubling.saturating_mul(doublings))
+            }
+        }
+    }
+
+    /// Canonical serialization: a tag byte followed by the rule's parameters
+    fn to_bytes(self) -> Vec<u8> {
+        match self {
+            VDFWorkScaling::Flat => vec![0],
+            VDFWorkScaling::Logarithmic {
+                iterations_per_doubling,
+            } => {
+                let mut bytes = vec![1];
+                bytes.extend_from_slice(&iterations_per_doubling.to_be_bytes());
+                bytes
+            }
+        }
+    }
+}
+
+/// Consensus constants that must match across every node on the network
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetworkParameters {
+    pub block_time_seconds: u32,
+    pub proof_window_blocks: u32,
+    pub chunk_size_bytes: u32,
+    pub chunks_per_block: u32,
+    pub chunk_selection_version: u32,
+    pub chunk_selection_seed_size: u32,
+    pub chunk_selection_max_attempts: u32,
+    pub beacon_entropy_required: bool,
+    pub memory_hard_vdf_memory: u64,
+    pub memory_hard_iterations: u32,
+    pub continuous_vdf_memory_kb: u32,
+    pub min_vdf_iterations: u32,
+    pub min_vdf_iterations_per_block: u32,
+    pub vdf_work_scaling: VDFWorkScaling,
+    pub max_vdf_iterations_per_second: f64,
+    pub vdf_reproduction_max_segment: u32,
+    pub hashchain_format_version: u32,
+    pub hashchain_min_chunks: u64,
+    pub hashchain_max_chunks: u64,
+    pub global_root_iterations: u32,
+    pub regional_iterations: u32,
+    pub group_iterations: u32,
+    pub chains_per_group: u32,
+    pub groups_per_region: u32,
+    pub availability_response_time_ms: u32,
+    pub availability_challenge_probability: f64,
+    pub network_latency_samples: u32,
+    pub network_latency_max_ms: u32,
+}
+
+impl Default for NetworkParameters {
+    fn default() -> Self {
+        Self::current()
+    }
+}
+
+impl NetworkParameters {
+    /// Parameters compiled into this build
+    pub fn current() -> Self {
+        Self {
+            block_time_seconds: BLOCK_TIME_SECONDS,
+            proof_window_blocks: PROOF_WINDOW_BLOCKS,
+            chunk_size_bytes: CHUNK_SIZE_BYTES,
+            chunks_per_block: CHUNKS_PER_BLOCK,
+            chunk_selection_version: CHUNK_SELECTION_VERSION,
+            chunk_selection_seed_size: CHUNK_SELECTION_SEED_SIZE as u32,
+            chunk_selection_max_attempts: CHUNK_SELECTION_MAX_ATTEMPTS,
+            beacon_entropy_required: BEACON_ENTROPY_REQUIRED,
+            memory_hard_vdf_memory: MEMORY_HARD_VDF_MEMORY as u64,
+            memory_hard_iterations: MEMORY_HARD_ITERATIONS,
+            continuous_vdf_memory_kb: CONTINUOUS_VDF_MEMORY_KB,
+            min_vdf_iterations: MIN_CONTINUOUS_VDF_ITERATIONS,
+            min_vdf_iterations_per_block: MIN_VDF_ITERATIONS_PER_BLOCK,
+            vdf_work_scaling: VDF_WORK_SCALING,
+            max_vdf_iterations_per_second: MAX_VDF_ITERATIONS_PER_SECOND,
+            vdf_reproduction_max_segment: VDF_REPRODUCTION_MAX_SEGMENT,
+            hashchain_format_version: HASHCHAIN_FORMAT_VERSION,
+            hashchain_min_chunks: HASHCHAIN_MIN_CHUNKS,
+            hashchain_max_chunks: HASHCHAIN_MAX_CHUNKS,
+            global_root_iterations: GLOBAL_ROOT_ITERATIONS,
+            regional_iterations: REGIONAL_ITERATIONS,
+            group_iterations: GROUP_ITERATIONS,
+            chains_per_group: CHAINS_PER_GROUP,
+            groups_per_region: GROUPS_PER_REGION,
+            availability_response_time_ms: AVAILABILITY_RESPONSE_TIME_MS,
+            availability_challenge_probability: AVAILABILITY_CHALLENGE_PROBABILITY,
+            network_latency_samples: NETWORK_LATENCY_SAMPLES,
+            network_latency_max_ms: NETWORK_LATENCY_MAX_MS,
+        }
+    }
+
+    /// Canonical big-endian serialization for embedding in genesis
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(NETWORK_PARAMETERS_DOMAIN);
+        bytes.extend_from_slice(&self.block_time_seconds.to_be_bytes());
+        bytes.extend_from_slice(&self.proof_window_blocks.to_be_bytes());
+        bytes.extend_from_slice(&self.chunk_size_bytes.to_be_bytes());
+        bytes.extend_from_slice(&self.chunks_per_block.to_be_bytes());
+        bytes.extend_from_slice(&self.chunk_selection_version.to_be_bytes());
+        bytes.extend_from_slice(&self.chunk_selection_seed_size.to_be_bytes());
+        bytes.extend_from_slice(&self.chunk_selection_max_attempts.to_be_bytes());
+        bytes.push(self.beacon_entropy_required as u8);
+        bytes.extend_from_slice(&self.memory_hard_vdf_memory.to_be_bytes());
+        bytes.extend_from_slice(&self.memory_hard_iterations.to_be_bytes());
+        bytes.extend_from_slice(&self.continuous_vdf_memory_kb.to_be_bytes());
+        bytes.extend_from_slice(&self.min_vdf_iterations.to_be_bytes());
+        bytes.extend_from_slice(&self.min_vdf_iterations_per_block.to_be_bytes());
+        bytes.extend_from_slice(&self.vdf_work_scaling.to_bytes());
+        bytes.extend_from_slice(&self.max_vdf_iterations_per_second.to_be_bytes());
+        bytes.extend_from_slice(&self.vdf_reproduction_max_segment.to_be_bytes());
+        bytes.extend_from_slice(&self.hashchain_format_version.to_be_bytes());
+        bytes.extend_from_slice(&self.hashchain_min_chunks.to_be_bytes());
+        bytes.extend_from_slice(&self.hashchain_max_chunks.to_be_bytes());
+        bytes.extend_from_slice(&self.global_root_iterations.to_be_bytes());
+        bytes.extend_from_slice(&self.regional_iterations.to_be_bytes());
+        bytes.extend_from_slice(&self.group_iterations.to_be_bytes());
+        bytes.extend_from_slice(&self.chains_per_group.to_be_bytes());
+        bytes.extend_from_slice(&self.groups_per_region.to_be_bytes());
+        bytes.extend_from_slice(&self.availability_response_time_ms.to_be_bytes());
+        bytes.extend_from_slice(&self.availability_challenge_probability.to_be_bytes());
+        bytes.extend_from_slice(&self.network_latency_samples.to_be_bytes());
+        bytes.extend_from_slice(&self.network_latency_max_ms.to_be_bytes());
+        bytes
+    }
+
+    /// These parameters with a deployment's consensus overrides applied. Chunks per
+    /// block must be within 1..=`MAX_CHUNKS_PER_BLOCK` and the minimum VDF iterations
+    /// non-zero; the chunk size is fixed by the hashchain file format, so it must match.
+    pub fn with_consensus_params(mut self, params: &ConsensusParams) -> Result<Self, String> {
+        if !(1..=MAX_CHUNKS_PER_BLOCK).contains(&params.chunks_per_block) {
+            return Err(format!(
+                "Chunks per block must be between 1 and {}, got {}",
+                MAX_CHUNKS_PER_BLOCK, params.chunks_per_block
+            ));
+        }
+        if params.min_vdf_iterations == 0 {
+            return Err("Minimum VDF iterations must be at least 1".to_string());
+        }
+        if params.chunk_size_bytes != CHUNK_SIZE_BYTES {
+            return Err(format!(
+                "Chunk size must be {} bytes to match the hashchain format, got {}",
+                CHUNK_SIZE_BYTES, params.chunk_size_bytes
+            ));
+        }
+
+        self.chunks_per_block = params.chunks_per_block;
+        self.min_vdf_iterations = params.min_vdf_iterations;
+        self.chunk_size_bytes = params.chunk_size_bytes;
+        Ok(self)
+    }
+
+    /// Smallest file that holds one block's worth of chunks
+    pub fn min_file_size(&self) -> u64 {
+        self.chunks_per_block as u64 * self.chunk_size_bytes as u64
+    }
+
+    /// SHA256 fingerprint of the canonical serialization
+    pub fn fingerprint(&self) -> [u8; 32] {
+        compute_sha256(&self.to_bytes())
+    }
+
+    /// Check these parameters match the network's expected fingerprint
+    pub fn verify_fingerprint(&self, expected_fingerprint: &[u8]) -> Result<(), String> {
+        if expected_fingerprint.len() != HASH_SIZE {
+            return Err(format!(
+                "Network fingerprint must be {} bytes, got {}",
+                HASH_SIZE,
+                expected_fingerprint.len()
+            ));
+        }
+
+        let fingerprint = self.fingerprint();
+        if fingerprint.as_slice() != expected_fingerprint {
+            return Err(format!(
+                "Network parameter mismatch: local {} != expected {}",
+                hex::encode(fingerprint),
+                hex::encode(expected_fingerprint)
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Network parameters by activation height, so proofs from before a network upgrade
+/// are verified against the parameters that were in force at their block height
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParameterSchedule {
+    /// (activation height, parameters), sorted by height; the first entry activates at 0
+    activations: Vec<(u64, NetworkParameters)>,
+}
+
+impl Default for ParameterSchedule {
+    fn default() -> Self {
+        Self::new(NetworkParameters::current())
+    }
+}
+
+impl ParameterSchedule {
+    /// Schedule with `genesis` parameters in force from block 0
+    pub fn new(genesis: NetworkParameters) -> Self {
+        Self {
+            activations: vec![(0, genesis)],
+        }
+    }
+
+    /// Schedule an upgrade to `parameters` from `activation_height` onwards.
+    /// Upgrades must be added in increasing height order.
+    pub fn add_upgrade(
+        &mut self,
+        activation_height: u64,
+        parameters: NetworkParameters,
+    ) -> Result<(), String> {
+        let (last_height, _) = self.activations[self.activations.len() - 1];
+        if activation_height <= last_height {
+            return Err(format!(
+                "Upgrade at height {} must come after the previous activation at {}",
+                activation_height, last_height
+            ));
+        }
+        self.activations.push((activation_height, parameters));
+        Ok(())
+    }
+
+    /// Parameters in force at `block_height`
+    pub fn parameters_at(&self, block_height: u64) -> &NetworkParameters {
+        let index = self
+            .activations
+            .partition_point(|(activation_height, _)| *activation_height <= block_height);
+        &self.activations[index.saturating_sub(1)].1
+    }
+
+    /// Activation heights in order, starting with 0
+    pub fn activation_heights(&self) -> Vec<u64> {
+        self.activations.iter().map(|(height, _)| *height).collect()
+    }
+}
+
+/// Fingerprint of this build's consensus behavior: the network parameters plus
+/// fixed test vectors for chunk selection, entropy combination, commitment hashing
+/// and the continuous VDF. Builds with equal fingerprints agree on all of them, so
+/// nodes can compare fingerprints before peering to catch silent forks.
+pub fn consensus_fingerprint() -> [u8; 32] {
+    let mut data = Vec::new();
+    data.extend_from_slice(CONSENSUS_FINGERPRINT_DOMAIN);
+    data.extend_from_slice(&NetworkParameters::current().to_bytes());
+
+    // Chunk selection test vector
+    let selected_chunks = select_chunks_deterministic(&[1u8; 32], 1024.0, CHUNKS_PER_BLOCK);
+    for chunk in &selected_chunks {
+        data.extend_from_slice(&chunk.to_be_bytes());
+    }
+
+    // Entropy combination test vectors, with and without a beacon
+    let entropy_without_beacon = combine_entropy_sources(&[2u8; 32], None, &[3u8; 32], 1.0);
+    let entropy_with_beacon =
+        combine_entropy_sources(&[2u8; 32], Some(&[4u8; 32]), &[3u8; 32], 1.0);
+    data.extend_from_slice(&entropy_without_beacon);
+    data.extend_from_slice(&entropy_with_beacon);
+
+    // Continuous VDF test vector
+    let mut vdf = ContinuousVDF::new([5u8; 32], CONTINUOUS_VDF_MEMORY_KB);
+    for _ in 0..CONSENSUS_FINGERPRINT_VDF_ITERATIONS {
+        vdf.iterate();
+    }
+    let (vdf_state, _) = vdf.get_state();
+    data.extend_from_slice(&vdf_state);
+
+    // Commitment hash construction over the vectors above
+    let chunk_hashes: Vec<Vec<u8>> = selected_chunks
+        .iter()
+        .map(|chunk| compute_sha256(&chunk.to_be_bytes()).to_vec())
+        .collect();
+    let commitment_hash = compute_commitment_hash(&CommitmentParams {
+        prover_key: &[6u8; 32],
+        data_hash: &[7u8; 32],
+        block_height: 1,
+        block_hash: &[8u8; 32],
+        selected_chunks: &selected_chunks,
+        chunk_hashes: &chunk_hashes,
+        vdf_output: &vdf_state,
+        entropy_hash: &entropy_without_beacon,
+        merkle_root: None,
+    });
+    data.extend_from_slice(&commitment_hash);
+
+    compute_sha256(&data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_network_parameters_fingerprint() {
+        let params = NetworkParameters::current();
+        let fingerprint = params.fingerprint();
+
+        // Deterministic and self-consistent
+        assert_eq!(fingerprint, NetworkParameters::current().fingerprint());
+        assert!(params.verify_fingerprint(&fingerprint).is_ok());
+
+        // Any changed constant produces a different fingerprint
+        let mut forked = params.clone();
+        forked.chunks_per_block += 1;
+        assert_ne!(forked.fingerprint(), fingerprint);
+        assert!(forked.verify_fingerprint(&fingerprint).is_err());
+
+        // Malformed fingerprints are rejected
+        assert!(params.verify_fingerprint(&fingerprint[..16]).is_err());
+    }
+
+    #[test]
+    fn test_vdf_work_scaling() {
+        // Flat keeps the base minimum for any data size
+        assert_eq!(VDFWorkScaling::Flat.min_iterations(1000, 1), 1000);
+        assert_eq!(VDFWorkScaling::Flat.min_iterations(1000, 1 << 30), 1000);
+
+        let scaling = VDFWorkScaling::Logarithmic {
+            iterations_per_doubling: 100,
+        };
+        assert_eq!(scaling.min_iterations(1000, 0), 1000);
+        assert_eq!(scaling.min_iterations(1000, 1), 1000);
+        assert_eq!(scaling.min_iterations(1000, 16), 1400);
+        assert_eq!(scaling.min_iterations(1000, 17), 1400);
+        assert_eq!(scaling.min_iterations(1000, 1 << 20), 3000);
+
+        // The scaling rule is part of the network fingerprint
+        let mut scaled = NetworkParameters::current();
+        scaled.vdf_work_scaling = scaling;
+        assert_ne!(
+            scaled.fingerprint(),
+            NetworkParameters::current().fingerprint()
+        );
+    }
+
+    #[test]
+    fn test_parameter_schedule_selects_by_height() {
+        let genesis = NetworkParameters::current();
+        let mut upgraded = genesis.clone();
+        upgraded.min_vdf_iterations += 1000;
+
+        let mut schedule = ParameterSchedule::new(genesis.clone());
+        schedule.add_upgrade(100, upgraded.clone()).unwrap();
+
+        assert_eq!(schedule.parameters_at(0), &genesis);
+        assert_eq!(schedule.parameters_at(99), &genesis);
+        assert_eq!(schedule.parameters_at(100), &upgraded);
+        assert_eq!(schedule.parameters_at(u64::MAX), &upgraded);
+        assert_eq!(schedule.activation_heights(), vec![0, 100]);
+
+        // Upgrades must be strictly increasing in height
+        assert!(schedule.add_upgrade(100, genesis.clone()).is_err());
+        assert!(schedule.add_upgrade(50, genesis).is_err());
+    }
+
+    #[test]
+    fn test_consensus_params_round_trip() {
+        use crate::consensus::NetworkConsensusValidator;
+        use napi::bindgen_prelude::Buffer;
+
+        let params = ConsensusParams {
+            chunks_per_block: 8,
+            min_vdf_iterations: 500,
+            chunk_size_bytes: CHUNK_SIZE_BYTES,
+        };
+        let network = NetworkParameters::current()
+            .with_consensus_params(&params)
+            .unwrap();
+        assert_eq!(network.min_file_size(), 8 * CHUNK_SIZE_BYTES as u64);
+        assert_ne!(
+            network.fingerprint(),
+            NetworkParameters::current().fingerprint()
+        );
+
+        // Prover side: selection with the configured chunk count
+        let combined_hash = [9u8; 32];
+        let selected =
+            select_chunks_deterministic(&combined_hash, 1024.0, network.chunks_per_block);
+        assert_eq!(selected.len(), 8);
+
+        // A verifier built with the same params accepts it, a default one does not
+        let entropy = crate::core::types::MultiSourceEntropy {
+            blockchain_entropy: Buffer::from([1u8; 32].to_vec()),
+            beacon_entropy: None,
+            local_entropy: Buffer::from([2u8; 32].to_vec()),
+            timestamp: 0.0,
+            combined_hash: Buffer::from(combined_hash.to_vec()),
+        };
+        let validator = NetworkConsensusValidator::from_parameters(network.clone());
+        assert_eq!(validator.parameters().chunks_per_block, 8);
+        assert_eq!(validator.min_vdf_iterations(), 500);
+        assert!(validator
+            .validate_chunk_selection_consensus(&entropy, 1024, &selected)
+            .is_ok());
+        assert!(NetworkConsensusValidator::new_production()
+            .validate_chunk_selection_consensus(&entropy, 1024, &selected)
+            .is_err());
+
+        // Out-of-range chunk counts, zero VDF minimums and other chunk sizes are rejected
+        for chunks_per_block in [0, MAX_CHUNKS_PER_BLOCK + 1] {
+            let invalid = ConsensusParams {
+                chunks_per_block,
+                ..params.clone()
+            };
+            assert!(NetworkParameters::current()
+                .with_consensus_params(&invalid)
+                .is_err());
+        }
+        let zero_vdf = ConsensusParams {
+            min_vdf_iterations: 0,
+            ..params.clone()
+        };
+        assert!(NetworkParameters::current()
+            .with_consensus_params(&zero_vdf)
+            .is_err());
+        let other_chunk_size = ConsensusParams {
+            chunk_size_bytes: 1024,
+            ..params
+        };
+        assert!(NetworkParameters::current()
+            .with_consensus_params(&other_chunk_size)
+            .is_err());
+    }
+
+    #[test]
+    fn test_consensus_fingerprint_is_deterministic() {
+        let fingerprint = consensus_fingerprint();
+        assert_eq!(fingerprint, consensus_fingerprint());
+        assert_ne!(fingerprint, NetworkParameters::current().fingerprint());
+    }
+}
@@ -0,0 +1,226 @@
+use crate::consensus::commitments::same_chain;
+use crate::core::types::{
+    BiasReport, StorageCommitment, SELECTION_BIAS_BINS, SELECTION_BIAS_MIN_EXPECTED_PER_BIN,
+    SELECTION_BIAS_Z_THRESHOLD,
+};
+
+/// Chi-square uniformity test over the chunk indices a chain selected across its
+/// history. Selection is driven by block entropy, so a prover that can skew entropy
+/// toward the chunks it actually stores shows up as an uneven spread over the file.
+pub struct SelectionBiasDetector {
+    bins: u32,
+    z_threshold: f64,
+}
+
+impl Default for SelectionBiasDetector {
+    fn default() -> Self {
+        Self::new(SELECTION_BIAS_BINS, SELECTION_BIAS_Z_THRESHOLD)
+    }
+}
+
+impl SelectionBiasDetector {
+    pub fn new(bins: u32, z_threshold: f64) -> Self {
+        Self {
+            bins: bins.max(2),
+            z_threshold,
+        }
+    }
+
+    /// Test the selections of a chain's commitment `history`, in block order, over its
+    /// file of `total_chunks`. Every commitment must belong to the same chain and each
+    /// block may appear once, so a history cannot pad itself with repeated selections.
+    pub fn analyze_history(
+        &self,
+        history: &[StorageCommitment],
+        total_chunks: u64,
+    ) -> Result<BiasReport, String> {
+        let first = history
+            .first()
+            .ok_or_else(|| "Commitment history is empty".to_string())?;
+        for pair in history.windows(2) {
+            if !same_chain(&pair[0], &pair[1]) {
+                return Err("Commitment history mixes chains".to_string());
+            }
+            if pair[1].block_height <= pair[0].block_height {
+                return Err(format!(
+                    "Commitment at block {} does not follow block {}",
+                    pair[1].block_height, pair[0].block_height
+                ));
+            }
+        }
+
+        let chain_id = crate::core::utils::generate_chain_id(&first.prover_key, &first.data_hash);
+        Ok(self.analyze(
+            hex::encode(chain_id),
+            history
+                .iter()
+                .map(|commitment| commitment.selected_chunks.as_slice()),
+            total_chunks,
+        ))
+    }
+
+    /// Test the selections of every block in `history` over a file of `total_chunks`
+    pub fn analyze<'a>(
+        &self,
+        chain_id: String,
+        history: impl IntoIterator<Item = &'a [u32]>,
+        total_chunks: u64,
+    ) -> BiasReport {
+        let bin_count = (self.bins as u64).min(total_chunks.max(1));
+        let mut observed = vec![0u64; bin_count as usize];
+        let mut sample_count = 0u64;
+        for &chunk in history.into_iter().flatten() {
+            if (chunk as u64) < total_chunks {
+                observed[(chunk as u64 * bin_count / total_chunks) as usize] += 1;
+                sample_count += 1;
+            }
+        }
+
+        // Bins cover index ranges of (nearly) equal width; expected counts follow width
+        let mut chi_square = 0.0;
+        let mut min_expected = f64::INFINITY;
+        for (bin, &count) in observed.iter().enumerate() {
+            let start = (bin as u64 * total_chunks).div_ceil(bin_count);
+            let end = ((bin as u64 + 1) * total_chunks).div_ceil(bin_count);
+            let expected = sample_count as f64 * (end - start) as f64 / total_chunks as f64;
+            min_expected = min_expected.min(expected);
+            if expected > 0.0 {
+                chi_square += (count as f64 - expected).powi(2) / expected;
+            }
+        }
+
+        // Wilson-Hilferty: (chi2 / df)^(1/3) is approximately normal
+        let degrees_of_freedom = bin_count.saturating_sub(1).max(1);
+        let df = degrees_of_freedom as f64;
+        let variance = 2.0 / (9.0 * df);
+        let z_score = ((chi_square / df).cbrt() - (1.0 - variance)) / variance.sqrt();
+
+        let sufficient_samples =
+            bin_count > 1 && min_expected >= SELECTION_BIAS_MIN_EXPECTED_PER_BIN;
+        BiasReport {
+            chain_id,
+            sample_count: sample_count.min(u32::MAX as u64) as u32,
+            bin_count: bin_count as u32,
+            chi_square,
+            degrees_of_freedom: degrees_of_freedom as u32,
+            z_score,
+            sufficient_samples,
+            biased: sufficient_samples && z_score > self.z_threshold,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::CHUNKS_PER_BLOCK;
+    use crate::core::utils::{compute_sha256, select_chunks_deterministic};
+
+    #[test]
+    fn test_selection_bias_detection() {
+        let total_chunks = 1000u64;
+        let detector = SelectionBiasDetector::default();
+
+        // Honest selections driven by block hashes are uniform
+        let honest: Vec<Vec<u32>> = (0..200u32)
+            .map(|block| {
+                select_chunks_deterministic(
+                    &compute_sha256(&block.to_be_bytes()),
+                    total_chunks as f64,
+                    CHUNKS_PER_BLOCK,
+                )
+            })
+            .collect();
+        let report = detector.analyze(
+            "chain".to_string(),
+            honest.iter().map(|chunks| chunks.as_slice()),
+            total_chunks,
+        );
+        assert_eq!(report.sample_count, 200 * CHUNKS_PER_BLOCK);
+        assert!(report.sufficient_samples);
+        assert!(!report.biased, "z = {}", report.z_score);
+
+        // A prover steering selections into the half of the file it stores is flagged
+        let skewed: Vec<Vec<u32>> = honest
+            .iter()
+            .map(|chunks| chunks.iter().map(|&chunk| chunk / 2).collect())
+            .collect();
+        let report = detector.analyze(
+            "chain".to_string(),
+            skewed.iter().map(|chunks| chunks.as_slice()),
+            total_chunks,
+        );
+        assert!(report.biased);
+
+        // Too few selections cannot be judged either way
+        let report = detector.analyze(
+            "chain".to_string(),
+            skewed[..2].iter().map(|chunks| chunks.as_slice()),
+            total_chunks,
+        );
+        assert!(!report.sufficient_samples);
+        assert!(!report.biased);
+    }
+
+    #[test]
+    fn test_commitment_history_analysis() {
+        let total_chunks = 1000u64;
+        let detector = SelectionBiasDetector::default();
+        let commitment = |block_height: u32, selected_chunks: Vec<u32>| {
+            let mut commitment =
+                crate::consensus::commitments::tests::storage_commitment(1, 2, block_height);
+            commitment.selected_chunks = selected_chunks;
+            commitment
+        };
+        let history: Vec<StorageCommitment> = (0..200u32)
+            .map(|block| {
+                commitment(
+                    block,
+                    select_chunks_deterministic(
+                        &compute_sha256(&block.to_be_bytes()),
+                        total_chunks as f64,
+                        CHUNKS_PER_BLOCK,
+                    ),
+                )
+            })
+            .collect();
+
+        let report = detector.analyze_history(&history, total_chunks).unwrap();
+        assert_eq!(
+            report.chain_id,
+            hex::encode(crate::core::utils::generate_chain_id(
+                &history[0].prover_key,
+                &history[0].data_hash
+            ))
+        );
+        assert_eq!(report.sample_count, 200 * CHUNKS_PER_BLOCK);
+        assert!(!report.biased, "z = {}", report.z_score);
+
+        let skewed: Vec<StorageCommitment> = history
+            .iter()
+            .map(|c| {
+                commitment(
+                    c.block_height,
+                    c.selected_chunks.iter().map(|&chunk| chunk / 2).collect(),
+                )
+            })
+            .collect();
+        assert!(
+            detector
+                .analyze_history(&skewed, total_chunks)
+                .unwrap()
+                .biased
+        );
+
+        // Histories must be one chain in block order with no repeated blocks
+        assert!(detector.analyze_history(&[], total_chunks).is_err());
+        let mut repeated = history[..10].to_vec();
+        repeated.push(history[9].clone());
+        assert!(detector.analyze_history(&repeated, total_chunks).is_err());
+        let mut mixed = history[..10].to_vec();
+        mixed.push(crate::consensus::commitments::tests::storage_commitment(
+            3, 2, 10,
+        ));
+        assert!(detector.analyze_history(&mixed, total_chunks).is_err());
+    }
+}
@@ -4,7 +4,13 @@ use crate::consensus::{
     chunk_selection::verify_chunk_selection_internal,
     commitments::calculate_commitment_hash_internal,
 };
-use crate::core::{types::*, utils::validate_chunk_index};
+use crate::core::{
+    types::*,
+    utils::{
+        compute_full_merkle_tree, compute_merkle_root, compute_merkle_root_from_path,
+        validate_chunk_index, MerklePathNode,
+    },
+};
 
 /// Verify proof window for storage continuity
 pub fn verify_proof_of_storage_continuity_internal(
@@ -150,3 +156,379 @@ pub fn verify_commitment_integrity(
 
     Ok(true)
 }
+
+/// Verify a commitment's data hash is bound to the chain header it claims to commit to
+pub fn verify_data_hash_binding_internal(
+    commitment: &StorageCommitment,
+    header: &HashChainHeader,
+) -> Result<bool> {
+    if header.data_file_hash.len() != HASH_SIZE {
+        return Err(Error::new(
+            Status::InvalidArg,
+            format!("Header data file hash must be {} bytes", HASH_SIZE),
+        ));
+    }
+
+    // Committed data hash must be the one recorded in the header
+    if commitment.data_hash.as_ref() != header.data_file_hash.as_ref() {
+        return Ok(false);
+    }
+
+    // Header must belong to the committing prover
+    if commitment.prover_key.as_ref() != header.public_key.as_ref() {
+        return Ok(false);
+    }
+
+    // Selected chunks must exist in the bound file
+    for &chunk_idx in &commitment.selected_chunks {
+        if validate_chunk_index(chunk_idx, header.total_chunks as u64).is_err() {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+/// Verify the Merkle root over all chunk hashes against the header and proof tree root
+pub fn verify_chunk_hashes_merkle_binding(
+    all_chunk_hashes: &[Buffer],
+    merkle_tree: &[Buffer],
+    header: Option<&HashChainHeader>,
+) -> Result<bool> {
+    if all_chunk_hashes
+        .iter()
+        .any(|chunk_hash| chunk_hash.len() != HASH_SIZE)
+    {
+        return Ok(false);
+    }
+
+    let chunk_hash_refs: Vec<&[u8]> = all_chunk_hashes.iter().map(|h| h.as_ref()).collect();
+    let merkle_root = compute_merkle_root(&chunk_hash_refs);
+
+    // Proof tree root (first element) must match the recomputed root
+    if let Some(tree_root) = merkle_tree.first() {
+        if tree_root.as_ref() != merkle_root.as_slice() {
+            return Ok(false);
+        }
+    }
+
+    if let Some(header) = header {
+        if all_chunk_hashes.len() as f64 != header.total_chunks {
+            return Ok(false);
+        }
+
+        // An all-zero header root has not been recorded yet
+        let root_recorded = header.merkle_root.iter().any(|&b| b != 0);
+        if root_recorded && header.merkle_root.as_ref() != merkle_root.as_slice() {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+/// Check that `merkle_tree` is exactly the tree built from `total_chunks` chunk
+/// hashes. A tree's node count is fixed by its leaf count, so a proof cannot claim
+/// fewer (or more) chunks than its root was computed over.
+pub fn verify_merkle_chunk_count(
+    all_chunk_hashes: &[Buffer],
+    merkle_tree: &[Buffer],
+    total_chunks: u64,
+) -> bool {
+    if all_chunk_hashes.is_empty()
+        || all_chunk_hashes.len() as u64 != total_chunks
+        || all_chunk_hashes
+            .iter()
+            .any(|chunk_hash| chunk_hash.len() != HASH_SIZE)
+    {
+        return false;
+    }
+
+    let chunk_hash_refs: Vec<&[u8]> = all_chunk_hashes.iter().map(|h| h.as_ref()).collect();
+    let (merkle_root, merkle_nodes) = compute_full_merkle_tree(&chunk_hash_refs);
+    merkle_tree.len() == merkle_nodes.len() + 1
+        && merkle_tree[0].as_ref() == merkle_root.as_slice()
+        && merkle_tree[1..]
+            .iter()
+            .zip(&merkle_nodes)
+            .all(|(node, expected)| node.as_ref() == expected.as_slice())
+}
+
+/// Check that each `chunk_hashes[i]` is the leaf at `indices[i]` under `root`,
+/// taking siblings from a proof's chunk hash list and flattened `merkle_tree` (root
+/// first, then every level above the leaves) instead of rebuilding the tree. This
+/// binds selected chunks to an already verified root in O(k log n).
+pub fn verify_selected_chunks_in_tree(
+    all_chunk_hashes: &[Buffer],
+    merkle_tree: &[Buffer],
+    indices: &[u32],
+    chunk_hashes: &[Buffer],
+    root: &[u8],
+) -> bool {
+    let to_node = |bytes: &[u8]| <[u8; 32]>::try_from(bytes).ok();
+
+    // Offsets of each level above the leaves within `merkle_tree[1..]`
+    let mut level_offsets = Vec::new();
+    let mut level_len = all_chunk_hashes.len();
+    let mut offset = 1;
+    while level_len > 1 {
+        level_len = level_len.div_ceil(2);
+        level_offsets.push((offset, level_len));
+        offset += level_len;
+    }
+    if indices.len() != chunk_hashes.len() || merkle_tree.len() != offset.max(1) {
+        return false;
+    }
+
+    indices
+        .iter()
+        .zip(chunk_hashes)
+        .all(|(&index, chunk_hash)| {
+            let (Some(leaf), true) = (
+                to_node(chunk_hash),
+                (index as usize) < all_chunk_hashes.len(),
+            ) else {
+                return false;
+            };
+
+            let mut path = Vec::new();
+            let mut position = index as usize;
+            let mut level_len = all_chunk_hashes.len();
+            for level in 0..level_offsets.len() {
+                let sibling_position = position ^ 1;
+                if sibling_position < level_len {
+                    let sibling = if level == 0 {
+                        &all_chunk_hashes[sibling_position]
+                    } else {
+                        &merkle_tree[level_offsets[level - 1].0 + sibling_position]
+                    };
+                    let Some(sibling) = to_node(sibling) else {
+                        return false;
+                    };
+                    path.push(MerklePathNode {
+                        sibling,
+                        is_left: sibling_position < position,
+                    });
+                }
+                position /= 2;
+                level_len = level_offsets[level].1;
+            }
+            compute_merkle_root_from_path(leaf, &path).as_slice() == root
+        })
+}
+
+/// Check a full proof's chunk count against the count registered on the blockchain.
+/// The registered count is authoritative: a proof or header claiming any other count
+/// is a violation, since a smaller count would shrink the prover's challenge surface.
+pub fn verify_registered_chunk_count(
+    all_chunk_hashes: &[Buffer],
+    header: Option<&HashChainHeader>,
+    registered_total_chunks: u64,
+) -> std::result::Result<(), String> {
+    if registered_total_chunks == 0 {
+        return Err("Registered data file has no chunks".to_string());
+    }
+    if all_chunk_hashes.len() as u64 != registered_total_chunks {
+        return Err(format!(
+            "Proof covers {} chunks but {} are registered",
+            all_chunk_hashes.len(),
+            registered_total_chunks
+        ));
+    }
+    if let Some(header) = header {
+        if header.total_chunks != registered_total_chunks as f64 {
+            return Err(format!(
+                "Header claims {} chunks but {} are registered",
+                header.total_chunks, registered_total_chunks
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Compact proof summarizing `commitment` from the prover at `network_position`
+pub fn compact_proof_from_commitment(
+    commitment: StorageCommitment,
+    network_position: Buffer,
+) -> CompactStorageProof {
+    CompactStorageProof {
+        prover_key: commitment.prover_key,
+        data_hash: commitment.data_hash,
+        commitment_hash: commitment.commitment_hash,
+        block_height: commitment.block_height,
+        chunk_proofs: commitment.chunk_hashes,
+        vdf_proof: commitment.vdf_proof,
+        network_position,
+        timestamp: commitment.entropy.timestamp,
+    }
+}
+
+/// Check that a compact proof faithfully summarizes the commitment it was derived
+/// from: every field `compact_proof_from_commitment` copies from the commitment must
+/// match exactly, so neither can be altered without the other.
+pub fn verify_compact_matches_commitment(
+    compact: &CompactStorageProof,
+    commitment: &StorageCommitment,
+) -> bool {
+    compact.prover_key.as_ref() == commitment.prover_key.as_ref()
+        && compact.data_hash.as_ref() == commitment.data_hash.as_ref()
+        && compact.commitment_hash.as_ref() == commitment.commitment_hash.as_ref()
+        && compact.block_height == commitment.block_height
+        && compact.timestamp == commitment.entropy.timestamp
+        && compact.chunk_proofs.len() == commitment.chunk_hashes.len()
+        && compact
+            .chunk_proofs
+            .iter()
+            .zip(&commitment.chunk_hashes)
+            .all(|(proof, hash)| proof.as_ref() == hash.as_ref())
+        && vdf_proofs_match(&compact.vdf_proof, &commitment.vdf_proof)
+}
+
+/// Field-by-field equality of two VDF proofs, including the memory access samples
+fn vdf_proofs_match(a: &MemoryHardVDFProof, b: &MemoryHardVDFProof) -> bool {
+    a.input_state.as_ref() == b.input_state.as_ref()
+        && a.output_state.as_ref() == b.output_state.as_ref()
+        && a.iterations == b.iterations
+        && a.computation_time_ms == b.computation_time_ms
+        && a.memory_usage_bytes == b.memory_usage_bytes
+        && a.memory_access_samples.len() == b.memory_access_samples.len()
+        && a.memory_access_samples
+            .iter()
+            .zip(&b.memory_access_samples)
+            .all(|(x, y)| {
+                x.iteration == y.iteration
+                    && x.read_address == y.read_address
+                    && x.write_address == y.write_address
+                    && x.memory_content_hash.as_ref() == y.memory_content_hash.as_ref()
+            })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compact_matches_commitment() {
+        let mut commitment = crate::consensus::commitments::tests::storage_commitment(1, 2, 10);
+        commitment.chunk_hashes = (0..3u8).map(|i| Buffer::from(vec![i; 32])).collect();
+        commitment.entropy.timestamp = 1234.5;
+        commitment.vdf_proof.memory_access_samples = vec![MemoryAccessSample {
+            iteration: 7,
+            read_address: 64.0,
+            write_address: 128.0,
+            memory_content_hash: Buffer::from(vec![9u8; 32]),
+        }];
+        let compact =
+            compact_proof_from_commitment(commitment.clone(), Buffer::from(vec![5u8; 32]));
+        assert!(verify_compact_matches_commitment(&compact, &commitment));
+
+        // Changing any copied field breaks the match
+        let tamperings: Vec<fn(&mut CompactStorageProof)> = vec![
+            |proof| proof.prover_key = Buffer::from(vec![0xAA; 32]),
+            |proof| proof.data_hash = Buffer::from(vec![0xAA; 32]),
+            |proof| proof.commitment_hash = Buffer::from(vec![0xAA; 32]),
+            |proof| proof.block_height += 1,
+            |proof| proof.timestamp += 1.0,
+            |proof| proof.chunk_proofs[1] = Buffer::from(vec![0xAA; 32]),
+            |proof| {
+                proof.chunk_proofs.pop();
+            },
+            |proof| proof.vdf_proof.input_state = Buffer::from(vec![0xAA; 32]),
+            |proof| proof.vdf_proof.output_state = Buffer::from(vec![0xAA; 32]),
+            |proof| proof.vdf_proof.iterations += 1,
+            |proof| proof.vdf_proof.computation_time_ms += 1.0,
+            |proof| proof.vdf_proof.memory_usage_bytes += 1.0,
+            |proof| proof.vdf_proof.memory_access_samples[0].iteration += 1,
+            |proof| proof.vdf_proof.memory_access_samples[0].read_address += 1.0,
+            |proof| proof.vdf_proof.memory_access_samples[0].write_address += 1.0,
+            |proof| {
+                proof.vdf_proof.memory_access_samples[0].memory_content_hash =
+                    Buffer::from(vec![0xAA; 32])
+            },
+            |proof| proof.vdf_proof.memory_access_samples.clear(),
+        ];
+        for (index, tamper) in tamperings.iter().enumerate() {
+            let mut tampered = compact.clone();
+            tamper(&mut tampered);
+            assert!(
+                !verify_compact_matches_commitment(&tampered, &commitment),
+                "tampering {} went undetected",
+                index
+            );
+        }
+
+        // The network position is not part of the commitment
+        let mut moved = compact.clone();
+        moved.network_position = Buffer::from(vec![6u8; 32]);
+        assert!(verify_compact_matches_commitment(&moved, &commitment));
+    }
+
+    #[test]
+    fn test_selected_chunks_bound_to_root() {
+        let hashes: Vec<Buffer> = (0..7u8).map(|i| Buffer::from(vec![i; 32])).collect();
+        let refs: Vec<&[u8]> = hashes.iter().map(|h| h.as_ref()).collect();
+        let (root, nodes) = compute_full_merkle_tree(&refs);
+        let merkle_tree: Vec<Buffer> = std::iter::once(root)
+            .chain(nodes)
+            .map(|node| Buffer::from(node.to_vec()))
+            .collect();
+
+        let indices = [0u32, 3, 6];
+        let selected: Vec<Buffer> = indices
+            .iter()
+            .map(|&i| hashes[i as usize].clone())
+            .collect();
+        assert!(verify_selected_chunks_in_tree(
+            &hashes,
+            &merkle_tree,
+            &indices,
+            &selected,
+            &root
+        ));
+
+        // A chunk hash that is not the leaf at its index is rejected
+        let mut swapped = selected.clone();
+        swapped[1] = hashes[4].clone();
+        assert!(!verify_selected_chunks_in_tree(
+            &hashes,
+            &merkle_tree,
+            &indices,
+            &swapped,
+            &root
+        ));
+
+        // Siblings that do not hash to the verified root are rejected
+        let mut tampered = hashes.clone();
+        tampered[2] = Buffer::from(vec![9u8; 32]);
+        assert!(!verify_selected_chunks_in_tree(
+            &tampered,
+            &merkle_tree,
+            &indices,
+            &selected,
+            &root
+        ));
+        assert!(!verify_selected_chunks_in_tree(
+            &hashes,
+            &merkle_tree,
+            &indices,
+            &selected,
+            &[0u8; 32]
+        ));
+
+        // Malformed trees and out-of-range indices are rejected
+        assert!(!verify_selected_chunks_in_tree(
+            &hashes,
+            &merkle_tree[..merkle_tree.len() - 1],
+            &indices,
+            &selected,
+            &root
+        ));
+        assert!(!verify_selected_chunks_in_tree(
+            &hashes,
+            &merkle_tree,
+            &[7],
+            &selected[..1],
+            &root
+        ));
+    }
+}
@@ -1,14 +1,46 @@
 use napi::bindgen_prelude::*;
-use std::collections::HashMap;
+use rayon::prelude::*;
+use std::collections::{BTreeSet, HashMap, VecDeque};
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use crate::core::{types::*, utils::compute_sha256};
+use crate::core::{
+    storage_backend::{FileBackend, StorageBackend},
+    types::*,
+    utils::{
+        compute_blake3, compute_chunk_slice_root, compute_sha256, encode_merkle_path,
+        open_chunk_slice, verify_chunk_slice, verify_merkle_inclusion, verify_signature,
+        MerklePathNode,
+    },
+};
 
 /// Availability challenge system to ensure data is served, not just stored
 pub struct AvailabilityChallenger {
     challenge_probability: f64,
     response_timeout_ms: u32,
     active_challenges: HashMap<String, AvailabilityChallenge>,
+    /// Answered challenges kept until their deadline so cleanup can tell them apart
+    resolved_challenges: HashMap<String, AvailabilityChallenge>,
+    latency_stats: AvailabilityLatencyStats,
+    scorer: AvailabilityScorer,
+    /// Completed challenge records by id, kept for the evidence retention window
+    evidence: HashMap<String, ChallengeEvidence>,
+    evidence_retention_seconds: f64,
+    /// Slice Merkle roots of chunks served in full and when they were learned, by
+    /// (hex chain id, chunk index), so later light probes of those chunks can be
+    /// checked. Dropped when a challenge of the chunk fails or expires unanswered,
+    /// and after the retention window, so the chunk is audited in full again.
+    slice_roots: HashMap<(String, u32), ([u8; 32], f64)>,
+    /// Beacon of the latest block, by height, mixed into challenge selection
+    challenge_beacon: Option<(u64, [u8; 32])>,
+    /// Streamed range audits still receiving segments, by hex challenge id
+    range_audits: HashMap<String, RangeAudit>,
+}
+
+/// Range audit awaiting segments
+struct RangeAudit {
+    challenge: RangeAvailabilityChallenge,
+    /// Segments not yet verified
+    outstanding: BTreeSet<u32>,
 }
 
 impl Default for AvailabilityChallenger {
@@ -24,9 +56,47 @@ impl AvailabilityChallenger {
             challenge_probability: AVAILABILITY_CHALLENGE_PROBABILITY,
             response_timeout_ms: AVAILABILITY_RESPONSE_TIME_MS,
             active_challenges: HashMap::new(),
+            resolved_challenges: HashMap::new(),
+            latency_stats: AvailabilityLatencyStats::default(),
+            scorer: AvailabilityScorer::new(),
+            evidence: HashMap::new(),
+            evidence_retention_seconds: AVAILABILITY_EVIDENCE_RETENTION_SECONDS,
+            slice_roots: HashMap::new(),
+            challenge_beacon: None,
+            range_audits: HashMap::new(),
         }
     }
 
+    /// Set the beacon (the block hash) for `block_height`. Challenges created at that
+    /// height mix it into their selection, so provers cannot tell in advance which
+    /// blocks will challenge them.
+    pub fn set_challenge_beacon(&mut self, block_height: u64, beacon: [u8; 32]) {
+        self.challenge_beacon = Some((block_height, beacon));
+    }
+
+    /// Beacon set for `block_height`, if any
+    fn beacon_for(&self, block_height: u64) -> Option<[u8; 32]> {
+        self.challenge_beacon
+            .filter(|(height, _)| *height == block_height)
+            .map(|(_, beacon)| beacon)
+    }
+
+    /// Recheck that a challenge issued at `block_height` was selected by this
+    /// challenger's probability and the beacon it carries
+    pub fn verify_challenge_selection(
+        &self,
+        challenge: &AvailabilityChallenge,
+        block_height: u64,
+        total_chunks: u32,
+    ) -> bool {
+        verify_challenge_selection(
+            challenge,
+            block_height,
+            total_chunks,
+            self.challenge_probability,
+        )
+    }
+
     /// Create availability challenge for a chain
     pub fn create_challenge(
         &mut self,
@@ -34,14 +104,49 @@ impl AvailabilityChallenger {
         total_chunks: u32,
         challenger_id: Buffer,
         block_height: u64,
+    ) -> Result<Option<AvailabilityChallenge>> {
+        self.create_challenge_with_mode(chain_id, total_chunks, challenger_id, block_height, false)
+    }
+
+    /// Create a light availability probe for a chain, answered with one authenticated
+    /// slice instead of the whole chunk. Falls back to a full-chunk challenge when the
+    /// selected chunk's slice root has not been learned from an earlier full response.
+    pub fn create_light_challenge(
+        &mut self,
+        chain_id: Buffer,
+        total_chunks: u32,
+        challenger_id: Buffer,
+        block_height: u64,
+    ) -> Result<Option<AvailabilityChallenge>> {
+        self.create_challenge_with_mode(chain_id, total_chunks, challenger_id, block_height, true)
+    }
+
+    fn create_challenge_with_mode(
+        &mut self,
+        chain_id: Buffer,
+        total_chunks: u32,
+        challenger_id: Buffer,
+        block_height: u64,
+        light: bool,
     ) -> Result<Option<AvailabilityChallenge>> {
         // Determine if this chain should be challenged this block
-        if !self.should_challenge_chain(&chain_id, block_height)? {
+        let beacon = self.beacon_for(block_height);
+        if !is_chain_challenged(
+            &chain_id,
+            block_height,
+            beacon.as_ref().map(|b| &b[..]),
+            self.challenge_probability,
+        ) {
             return Ok(None);
         }
 
         // Select random chunk to challenge
-        let chunk_index = self.select_challenge_chunk(&chain_id, total_chunks, block_height)?;
+        let chunk_index = select_challenge_chunk(
+            &chain_id,
+            total_chunks,
+            block_height,
+            beacon.as_ref().map(|b| &b[..]),
+        )?;
 
         // Generate challenge nonce
         let challenge_nonce =
@@ -61,6 +166,14 @@ impl AvailabilityChallenger {
             challenge_time: current_time,
             deadline: current_time + (self.response_timeout_ms as f64 / 1000.0),
             reward_amount: AVAILABILITY_REWARD_UNITS as f64,
+            slice_root: light
+                .then(|| {
+                    self.slice_roots
+                        .get(&(hex::encode(&chain_id), chunk_index))
+                        .map(|(root, _)| Buffer::from(root.to_vec()))
+                })
+                .flatten(),
+            selection_beacon: beacon.map(|b| Buffer::from(b.to_vec())),
         };
 
         // Store active challenge
@@ -84,76 +197,138 @@ impl AvailabilityChallenger {
             .ok_or_else(|| Error::new(Status::GenericFailure, "Challenge not found".to_string()))?
             .clone();
 
-        // Check if response is within deadline
-        let _current_time = SystemTime::now()
+        // Time the outcome is recorded at for dispute evidence
+        let current_time = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .map_err(|e| Error::new(Status::GenericFailure, format!("Time error: {}", e)))?
             .as_secs_f64();
 
-        if response.response_time > challenge.deadline {
-            // Timeout - prover failed
-            self.active_challenges.remove(&challenge_id);
-            return Ok(AvailabilityResult::Timeout);
-        }
+        // Challenge is answered either way; keep it until its deadline passes
+        self.active_challenges.remove(&challenge_id);
+        self.resolved_challenges
+            .insert(challenge_id.clone(), challenge.clone());
+        let chain_key = hex::encode(&challenge.chain_id);
 
-        // Verify chunk data authenticity
-        if !self.verify_chunk_authenticity(&challenge, &response)? {
+        let result = if response.response_time > challenge.deadline {
+            // Timeout - prover failed
+            self.scorer.record_failure(&chain_key);
+            AvailabilityResult::Timeout
+        } else if response.maintenance_mode == Some(true) {
+            // Prover is temporarily unavailable - lowers its score but is not slashable
+            self.scorer.record_maintenance(&chain_key);
+            AvailabilityResult::MaintenanceMode
+        } else if !self.verify_chunk_authenticity(&challenge, &response)? {
             // Invalid data - prover failed
-            self.active_challenges.remove(&challenge_id);
-            return Ok(AvailabilityResult::InvalidData);
+            self.scorer.record_failure(&chain_key);
+            AvailabilityResult::InvalidData
+        } else {
+            // Success - prover responded correctly and on time
+            let response_time_ms =
+                ((response.response_time - challenge.challenge_time) * 1000.0) as u32;
+            self.latency_stats.record(&chain_key, response_time_ms);
+            self.scorer.record_success(&chain_key);
+            // A verified full chunk lets later probes of it be light
+            if challenge.slice_root.is_none() {
+                self.slice_roots.insert(
+                    (chain_key, challenge.chunk_index),
+                    (compute_chunk_slice_root(&response.chunk_data), current_time),
+                );
+            }
+            AvailabilityResult::Success {
+                response_time_ms,
+                challenger_reward: challenge.reward_amount,
+            }
+        };
+
+        // A failed chunk must be audited in full before it is probed lightly again
+        if matches!(
+            result,
+            AvailabilityResult::Timeout | AvailabilityResult::InvalidData
+        ) {
+            self.slice_roots
+                .remove(&(hex::encode(&challenge.chain_id), challenge.chunk_index));
         }
 
-        // Success - prover responded correctly and on time
-        self.active_challenges.remove(&challenge_id);
-        Ok(AvailabilityResult::Success {
-            response_time_ms: ((response.response_time - challenge.challenge_time) * 1000.0) as u32,
-            challenger_reward: challenge.reward_amount,
-        })
+        self.record_evidence(
+            challenge_id,
+            challenge,
+            Some(response),
+            result.outcome_label(),
+            current_time,
+        );
+        Ok(result)
     }
 
-    /// Determine if chain should be challenged this block
-    fn should_challenge_chain(&self, chain_id: &Buffer, block_height: u64) -> Result<bool> {
-        // Create deterministic but unpredictable decision
-        let mut challenge_seed = Vec::new();
-        challenge_seed.extend_from_slice(chain_id);
-        challenge_seed.extend_from_slice(&block_height.to_be_bytes());
-        challenge_seed.extend_from_slice(b"availability_challenge");
+    /// Retain a completed challenge record for dispute resolution
+    fn record_evidence(
+        &mut self,
+        challenge_id: String,
+        challenge: AvailabilityChallenge,
+        response: Option<AvailabilityResponse>,
+        outcome: &str,
+        recorded_at: f64,
+    ) {
+        let evidence_hash = compute_evidence_hash(
+            &challenge_id,
+            &challenge,
+            response.as_ref(),
+            outcome,
+            recorded_at,
+        );
+        self.evidence.insert(
+            challenge_id.clone(),
+            ChallengeEvidence {
+                challenge_id,
+                challenge,
+                response,
+                outcome: outcome.to_string(),
+                recorded_at,
+                evidence_hash: Buffer::from(evidence_hash.to_vec()),
+                challenger_signature: None,
+            },
+        );
+    }
 
-        let challenge_hash = compute_sha256(&challenge_seed);
-        let challenge_value = u64::from_be_bytes([
-            challenge_hash[0],
-            challenge_hash[1],
-            challenge_hash[2],
-            challenge_hash[3],
-            challenge_hash[4],
-            challenge_hash[5],
-            challenge_hash[6],
-            challenge_hash[7],
-        ]);
+    /// Export the retained record of a completed challenge. Records are kept for
+    /// the evidence retention window, and unanswered challenges are only recorded
+    /// once cleanup has expired them.
+    pub fn export_challenge_evidence(&self, challenge_id: &str) -> Result<ChallengeEvidence> {
+        self.evidence.get(challenge_id).cloned().ok_or_else(|| {
+            Error::new(
+                Status::GenericFailure,
+                format!("No evidence retained for challenge {}", challenge_id),
+            )
+        })
+    }
 
-        // Convert probability to threshold
-        let threshold = (self.challenge_probability * u64::MAX as f64) as u64;
+    /// Set how long completed challenge records are retained
+    pub fn set_evidence_retention(&mut self, retention_seconds: f64) {
+        self.evidence_retention_seconds = retention_seconds;
+    }
 
-        Ok(challenge_value < threshold)
+    /// Get response-time SLA statistics
+    pub fn get_latency_stats(&self) -> &AvailabilityLatencyStats {
+        &self.latency_stats
     }
 
-    /// Select chunk to challenge
-    fn select_challenge_chunk(
-        &self,
-        chain_id: &Buffer,
-        total_chunks: u32,
-        block_height: u64,
-    ) -> Result<u32> {
-        let mut chunk_seed = Vec::new();
-        chunk_seed.extend_from_slice(chain_id);
-        chunk_seed.extend_from_slice(&block_height.to_be_bytes());
-        chunk_seed.extend_from_slice(b"challenge_chunk_selection");
+    /// Replace the response-time SLA tracker configuration
+    pub fn set_latency_stats(&mut self, latency_stats: AvailabilityLatencyStats) {
+        self.latency_stats = latency_stats;
+    }
+
+    /// Get an unanswered challenge by id
+    pub fn get_active_challenge(&self, challenge_id: &str) -> Option<&AvailabilityChallenge> {
+        self.active_challenges.get(challenge_id)
+    }
 
-        let chunk_hash = compute_sha256(&chunk_seed);
-        let chunk_value =
-            u32::from_be_bytes([chunk_hash[0], chunk_hash[1], chunk_hash[2], chunk_hash[3]]);
+    /// Get per-chain availability scores
+    pub fn get_scorer(&self) -> &AvailabilityScorer {
+        &self.scorer
+    }
 
-        Ok(chunk_value % total_chunks)
+    /// Replace the tolerance for failed challenges before a chain is slashable
+    pub fn set_failure_policy(&mut self, policy: ChallengeFailurePolicy) {
+        self.scorer.set_failure_policy(policy);
     }
 
     /// Generate challenge nonce
@@ -174,14 +349,7 @@ impl AvailabilityChallenger {
 
     /// Compute unique challenge ID
     fn compute_challenge_id(&self, challenge: &AvailabilityChallenge) -> Result<String> {
-        let mut id_input = Vec::new();
-        id_input.extend_from_slice(&challenge.chain_id);
-        id_input.extend_from_slice(&challenge.chunk_index.to_be_bytes());
-        id_input.extend_from_slice(&challenge.challenge_nonce);
-        id_input.extend_from_slice(&challenge.challenger_id);
-
-        let id_hash = compute_sha256(&id_input);
-        Ok(hex::encode(id_hash))
+        Ok(hex::encode(compute_challenge_id_hash(challenge)))
     }
 
     /// Verify chunk data authenticity
@@ -190,6 +358,21 @@ impl AvailabilityChallenger {
         challenge: &AvailabilityChallenge,
         response: &AvailabilityResponse,
     ) -> Result<bool> {
+        // Light probes are checked against the slice root instead of a full chunk
+        if let Some(slice_root) = &challenge.slice_root {
+            return Ok(response.slice_proof.as_ref().is_some_and(|proof| {
+                verify_slice_response(
+                    &challenge.challenge_nonce,
+                    &challenge.chain_id,
+                    slice_root,
+                    proof.slice_index,
+                    &proof.slice_data,
+                    &proof.merkle_path,
+                    &response.authenticity_proof,
+                )
+            }));
+        }
+
         // Verify chunk data length
         if response.chunk_data.len() != CHUNK_SIZE_BYTES as usize {
             return Ok(false);
@@ -214,46 +397,1025 @@ impl AvailabilityChallenger {
         challenge: &AvailabilityChallenge,
         chunk_data: &[u8],
     ) -> Result<Vec<u8>> {
-        let mut proof_input = Vec::new();
-        proof_input.extend_from_slice(&challenge.challenge_nonce);
-        proof_input.extend_from_slice(chunk_data);
-        proof_input.extend_from_slice(&challenge.chain_id);
-        proof_input.extend_from_slice(b"authenticity_proof");
+        Ok(
+            compute_authenticity_proof(&challenge.challenge_nonce, chunk_data, &challenge.chain_id)
+                .to_vec(),
+        )
+    }
+
+    /// Verify responses to many challenges in parallel, returning one result per
+    /// challenge in challenge order. Responses are paired to challenges by challenge
+    /// id and unanswered challenges time out. All deadline checks use one timestamp
+    /// captured up front. Unlike `process_response`, challenges are not resolved and
+    /// scores are not updated.
+    pub fn verify_availability_responses_batch(
+        &self,
+        challenges: &[AvailabilityChallenge],
+        responses: &[AvailabilityResponse],
+    ) -> Result<Vec<AvailabilityResult>> {
+        let current_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| Error::new(Status::GenericFailure, format!("Time error: {}", e)))?
+            .as_secs_f64();
+
+        let responses_by_id: HashMap<String, &AvailabilityResponse> = responses
+            .iter()
+            .map(|response| (hex::encode(&response.challenge_id), response))
+            .collect();
+
+        // napi buffers cannot cross threads, so copy out what verification needs
+        let pending = challenges
+            .iter()
+            .map(|challenge| {
+                let challenge_id = self.compute_challenge_id(challenge)?;
+                Ok(PendingVerification::new(
+                    challenge,
+                    responses_by_id.get(&challenge_id).copied(),
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(pending
+            .into_par_iter()
+            .map(|verification| verification.verify(current_time))
+            .collect())
+    }
+
+    /// Audit `start_chunk..end_chunk` of a chain, answered in segments of
+    /// `AVAILABILITY_SEGMENT_CHUNKS` chunks. Each segment gets its own response window,
+    /// staggered so the prover can stream them one after another.
+    pub fn create_range_challenge(
+        &mut self,
+        chain_id: Buffer,
+        chunk_root: Buffer,
+        total_chunks: u32,
+        start_chunk: u32,
+        end_chunk: u32,
+        challenger_id: Buffer,
+    ) -> Result<RangeAvailabilityChallenge> {
+        if start_chunk >= end_chunk || end_chunk > total_chunks {
+            return Err(Error::new(
+                Status::InvalidArg,
+                format!(
+                    "Invalid chunk range {}..{} for {} chunks",
+                    start_chunk, end_chunk, total_chunks
+                ),
+            ));
+        }
+        if chunk_root.len() != 32 {
+            return Err(Error::new(
+                Status::InvalidArg,
+                "Chunk root must be 32 bytes".to_string(),
+            ));
+        }
+
+        let current_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| Error::new(Status::GenericFailure, format!("Time error: {}", e)))?
+            .as_secs_f64();
+
+        let mut nonce_seed = Vec::new();
+        nonce_seed.extend_from_slice(&chain_id);
+        nonce_seed.extend_from_slice(&start_chunk.to_be_bytes());
+        nonce_seed.extend_from_slice(&end_chunk.to_be_bytes());
+        nonce_seed.extend_from_slice(&challenger_id);
+        nonce_seed.extend_from_slice(&current_time.to_be_bytes());
+        nonce_seed.extend_from_slice(b"range_challenge_nonce");
+
+        let segment_count = (end_chunk - start_chunk).div_ceil(AVAILABILITY_SEGMENT_CHUNKS);
+        let segment_timeout = self.response_timeout_ms as f64 / 1000.0;
+        let challenge = RangeAvailabilityChallenge {
+            chain_id,
+            start_chunk,
+            end_chunk,
+            total_chunks,
+            chunk_root,
+            challenge_nonce: Buffer::from(compute_sha256(&nonce_seed).to_vec()),
+            challenger_id,
+            challenge_time: current_time,
+            chunks_per_segment: AVAILABILITY_SEGMENT_CHUNKS,
+            segment_deadlines: (1..=segment_count)
+                .map(|segment| current_time + segment as f64 * segment_timeout)
+                .collect(),
+        };
+
+        self.range_audits.insert(
+            hex::encode(compute_range_challenge_id_hash(&challenge)),
+            RangeAudit {
+                challenge: challenge.clone(),
+                outstanding: (0..segment_count).collect(),
+            },
+        );
+        Ok(challenge)
+    }
+
+    /// Verify one segment of a streamed range audit. The audit fails as soon as a
+    /// segment is invalid or any outstanding segment is past its deadline, and
+    /// succeeds once every segment has been verified.
+    pub fn process_segment(&mut self, segment: &ChallengeResponseSegment) -> Result<SegmentResult> {
+        let challenge_id = hex::encode(&segment.challenge_id);
+        let audit = self.range_audits.get_mut(&challenge_id).ok_or_else(|| {
+            Error::new(
+                Status::GenericFailure,
+                "Range challenge not found".to_string(),
+            )
+        })?;
+        if !audit.outstanding.contains(&segment.segment_index) {
+            return Err(Error::new(
+                Status::InvalidArg,
+                format!(
+                    "Segment {} is not outstanding for this challenge",
+                    segment.segment_index
+                ),
+            ));
+        }
+
+        let chain_key = hex::encode(&audit.challenge.chain_id);
+        let deadlines = &audit.challenge.segment_deadlines;
+        let overdue = audit
+            .outstanding
+            .iter()
+            .any(|&index| segment.response_time > deadlines[index as usize]);
+
+        let result = if overdue {
+            SegmentResult::Timeout
+        } else if !verify_response_segment(&audit.challenge, segment) {
+            SegmentResult::InvalidData
+        } else {
+            audit.outstanding.remove(&segment.segment_index);
+            if audit.outstanding.is_empty() {
+                SegmentResult::Complete
+            } else {
+                return Ok(SegmentResult::Accepted {
+                    remaining_segments: audit.outstanding.len() as u32,
+                });
+            }
+        };
+
+        self.range_audits.remove(&challenge_id);
+        match result {
+            SegmentResult::Complete => self.scorer.record_success(&chain_key),
+            _ => self.scorer.record_failure(&chain_key),
+        }
+        Ok(result)
+    }
 
-        Ok(compute_sha256(&proof_input).to_vec())
+    /// Get a range audit that is still receiving segments
+    pub fn get_range_challenge(&self, challenge_id: &str) -> Option<&RangeAvailabilityChallenge> {
+        self.range_audits
+            .get(challenge_id)
+            .map(|audit| &audit.challenge)
     }
 
-    /// Clean up expired challenges
-    pub fn cleanup_expired_challenges(&mut self) -> Result<Vec<String>> {
+    /// Segments of a range audit still awaiting verification, in order
+    pub fn outstanding_segments(&self, challenge_id: &str) -> Option<Vec<u32>> {
+        self.range_audits
+            .get(challenge_id)
+            .map(|audit| audit.outstanding.iter().copied().collect())
+    }
+
+    /// Clean up expired challenges, reporting which ones were never answered
+    pub fn cleanup_expired_challenges(&mut self) -> Result<Vec<ExpiredChallenge>> {
         let current_time = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .map_err(|e| Error::new(Status::GenericFailure, format!("Time error: {}", e)))?
             .as_secs_f64();
 
-        let mut expired_challenges = Vec::new();
+        self.cleanup_expired_challenges_at(current_time)
+    }
+
+    /// Clean up challenges whose deadline is before `current_time`
+    pub fn cleanup_expired_challenges_at(
+        &mut self,
+        current_time: f64,
+    ) -> Result<Vec<ExpiredChallenge>> {
+        let mut expired_challenges = Vec::new();
+        let mut unanswered = Vec::new();
+
+        self.active_challenges.retain(|challenge_id, challenge| {
+            if current_time > challenge.deadline {
+                expired_challenges.push(ExpiredChallenge {
+                    challenge_id: challenge_id.clone(),
+                    chain_id: hex::encode(&challenge.chain_id),
+                    outcome: ExpiredChallengeOutcome::ExpiredUnanswered,
+                });
+                unanswered.push((challenge_id.clone(), challenge.clone()));
+                false
+            } else {
+                true
+            }
+        });
+
+        self.resolved_challenges.retain(|challenge_id, challenge| {
+            if current_time > challenge.deadline {
+                expired_challenges.push(ExpiredChallenge {
+                    challenge_id: challenge_id.clone(),
+                    chain_id: hex::encode(&challenge.chain_id),
+                    outcome: ExpiredChallengeOutcome::AlreadyResolved,
+                });
+                false
+            } else {
+                true
+            }
+        });
+
+        // Range audits fail once any segment still outstanding is past its deadline
+        self.range_audits.retain(|challenge_id, audit| {
+            let overdue = audit
+                .outstanding
+                .iter()
+                .any(|&index| current_time > audit.challenge.segment_deadlines[index as usize]);
+            if overdue {
+                expired_challenges.push(ExpiredChallenge {
+                    challenge_id: challenge_id.clone(),
+                    chain_id: hex::encode(&audit.challenge.chain_id),
+                    outcome: ExpiredChallengeOutcome::SegmentOverdue,
+                });
+            }
+            !overdue
+        });
+
+        // Unanswered timeouts are genuine availability failures
+        for expired in &expired_challenges {
+            if expired.outcome != ExpiredChallengeOutcome::AlreadyResolved {
+                self.scorer.record_failure(&expired.chain_id);
+            }
+        }
+
+        for (challenge_id, challenge) in unanswered {
+            self.slice_roots
+                .remove(&(hex::encode(&challenge.chain_id), challenge.chunk_index));
+            self.record_evidence(challenge_id, challenge, None, "unanswered", current_time);
+        }
+        self.slice_roots.retain(|_, (_, learned_at)| {
+            current_time - *learned_at <= AVAILABILITY_SLICE_ROOT_RETENTION_SECONDS
+        });
+        let retention_seconds = self.evidence_retention_seconds;
+        self.evidence
+            .retain(|_, evidence| current_time - evidence.recorded_at <= retention_seconds);
+
+        expired_challenges.sort_by(|a, b| a.challenge_id.cmp(&b.challenge_id));
+        Ok(expired_challenges)
+    }
+
+    /// Get statistics about challenges
+    pub fn get_challenge_stats(&self) -> ChallengeStats {
+        ChallengeStats {
+            active_challenges: self.active_challenges.len() as u32,
+            challenge_probability: self.challenge_probability,
+            response_timeout_ms: self.response_timeout_ms,
+        }
+    }
+}
+
+/// Rolling per-chain response-time samples for availability SLA monitoring
+pub struct AvailabilityLatencyStats {
+    max_samples_per_chain: usize,
+    sla_ms: u32,
+    samples: HashMap<String, VecDeque<u32>>,
+}
+
+impl Default for AvailabilityLatencyStats {
+    fn default() -> Self {
+        Self::new(
+            AVAILABILITY_LATENCY_SAMPLE_WINDOW,
+            AVAILABILITY_RESPONSE_TIME_MS,
+        )
+    }
+}
+
+impl AvailabilityLatencyStats {
+    /// Create tracker keeping up to `max_samples_per_chain` responses per chain
+    pub fn new(max_samples_per_chain: usize, sla_ms: u32) -> Self {
+        Self {
+            max_samples_per_chain: max_samples_per_chain.max(1),
+            sla_ms,
+            samples: HashMap::new(),
+        }
+    }
+
+    /// Record a successful response time for a chain
+    pub fn record(&mut self, chain_id: &str, response_time_ms: u32) {
+        let chain_samples = self.samples.entry(chain_id.to_string()).or_default();
+        chain_samples.push_back(response_time_ms);
+        while chain_samples.len() > self.max_samples_per_chain {
+            chain_samples.pop_front();
+        }
+    }
+
+    /// Get response-time percentiles for a chain
+    pub fn get_chain_percentiles(&self, chain_id: &str) -> Option<AvailabilityLatencyPercentiles> {
+        let chain_samples = self.samples.get(chain_id)?;
+        if chain_samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted: Vec<u32> = chain_samples.iter().copied().collect();
+        sorted.sort_unstable();
+
+        Some(AvailabilityLatencyPercentiles {
+            sample_count: sorted.len() as u32,
+            p50_ms: Self::percentile(&sorted, 50.0),
+            p95_ms: Self::percentile(&sorted, 95.0),
+            p99_ms: Self::percentile(&sorted, 99.0),
+            max_ms: sorted[sorted.len() - 1],
+            sla_ms: self.sla_ms,
+        })
+    }
+
+    /// Chains with recorded samples
+    pub fn tracked_chains(&self) -> Vec<String> {
+        let mut chains: Vec<String> = self.samples.keys().cloned().collect();
+        chains.sort();
+        chains
+    }
+
+    /// Nearest-rank percentile over sorted samples
+    fn percentile(sorted: &[u32], percentile: f64) -> u32 {
+        let rank = ((percentile / 100.0) * sorted.len() as f64).ceil() as usize;
+        sorted[rank.clamp(1, sorted.len()) - 1]
+    }
+}
+
+/// Per-chain availability score from challenge outcomes
+#[derive(Default)]
+pub struct AvailabilityScorer {
+    /// (successes, failures, maintenance responses) per chain
+    outcomes: HashMap<String, (u32, u32, u32)>,
+    /// Most recent outcomes per chain (true for a failure), newest last
+    recent_outcomes: HashMap<String, VecDeque<bool>>,
+    failure_policy: ChallengeFailurePolicy,
+}
+
+impl AvailabilityScorer {
+    /// Create empty scorer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a correct, on-time response
+    pub fn record_success(&mut self, chain_id: &str) {
+        let entry = self.outcomes.entry(chain_id.to_string()).or_default();
+        entry.0 = entry.0.saturating_add(1);
+        self.record_recent(chain_id, false);
+    }
+
+    /// Record a failed challenge (timeout, invalid data or no response)
+    pub fn record_failure(&mut self, chain_id: &str) {
+        let entry = self.outcomes.entry(chain_id.to_string()).or_default();
+        entry.1 = entry.1.saturating_add(1);
+        self.record_recent(chain_id, true);
+    }
+
+    /// Record a maintenance notice: counts against the score at a reduced weight,
+    /// but not as a failure
+    pub fn record_maintenance(&mut self, chain_id: &str) {
+        let entry = self.outcomes.entry(chain_id.to_string()).or_default();
+        entry.2 = entry.2.saturating_add(1);
+        self.record_recent(chain_id, false);
+    }
+
+    /// Push an outcome onto the chain's sliding window
+    fn record_recent(&mut self, chain_id: &str, failed: bool) {
+        let window = self.failure_policy.window.max(1) as usize;
+        let recent = self
+            .recent_outcomes
+            .entry(chain_id.to_string())
+            .or_default();
+        recent.push_back(failed);
+        while recent.len() > window {
+            recent.pop_front();
+        }
+    }
+
+    /// Replace the failure tolerance; recent windows are trimmed to the new size
+    pub fn set_failure_policy(&mut self, policy: ChallengeFailurePolicy) {
+        self.failure_policy = policy;
+        let window = policy.window.max(1) as usize;
+        for recent in self.recent_outcomes.values_mut() {
+            while recent.len() > window {
+                recent.pop_front();
+            }
+        }
+    }
+
+    /// Current failure tolerance
+    pub fn get_failure_policy(&self) -> ChallengeFailurePolicy {
+        self.failure_policy
+    }
+
+    /// Failed challenges within the chain's recent window
+    pub fn get_recent_failure_count(&self, chain_id: &str) -> u32 {
+        self.recent_outcomes.get(chain_id).map_or(0, |recent| {
+            recent.iter().filter(|&&failed| failed).count() as u32
+        })
+    }
+
+    /// Whether recent failures exceed the tolerance, escalating to a slashable
+    /// offense. Isolated failures within the tolerance still lower the score.
+    pub fn is_slashable(&self, chain_id: &str) -> bool {
+        self.get_recent_failure_count(chain_id) > self.failure_policy.tolerated_failures
+    }
+
+    /// Fraction of challenges passed; chains without history score 1.0
+    pub fn get_score(&self, chain_id: &str) -> f64 {
+        match self.outcomes.get(chain_id) {
+            Some(&(successes, failures, maintenance)) if successes + failures + maintenance > 0 => {
+                successes as f64
+                    / (successes as f64
+                        + failures as f64
+                        + maintenance as f64 * MAINTENANCE_SCORE_WEIGHT)
+            }
+            _ => 1.0,
+        }
+    }
+
+    /// Number of failed challenges recorded for a chain
+    pub fn get_failure_count(&self, chain_id: &str) -> u32 {
+        self.outcomes
+            .get(chain_id)
+            .map_or(0, |&(_, failures, _)| failures)
+    }
+}
+
+/// Authenticity proof binding served chunk data to a challenge:
+/// sha256(nonce || chunk_data || chain_id || "authenticity_proof")
+pub fn compute_authenticity_proof(
+    challenge_nonce: &[u8],
+    chunk_data: &[u8],
+    chain_id: &[u8],
+) -> [u8; 32] {
+    let mut proof_input = Vec::new();
+    proof_input.extend_from_slice(challenge_nonce);
+    proof_input.extend_from_slice(chunk_data);
+    proof_input.extend_from_slice(chain_id);
+    proof_input.extend_from_slice(b"authenticity_proof");
+    compute_sha256(&proof_input)
+}
+
+/// Slice a light probe must serve, derived from the challenge nonce
+pub fn select_challenge_slice(challenge_nonce: &[u8]) -> u32 {
+    let seed = compute_sha256(&[challenge_nonce, b"challenge_slice".as_slice()].concat());
+    u32::from_be_bytes([seed[0], seed[1], seed[2], seed[3]])
+        % (CHUNK_SIZE_BYTES / AVAILABILITY_SLICE_BYTES)
+}
+
+/// Check a light probe response: the challenged slice must open to the challenge's
+/// slice root, and the authenticity proof must bind it to the challenge nonce
+fn verify_slice_response(
+    challenge_nonce: &[u8],
+    chain_id: &[u8],
+    slice_root: &[u8],
+    slice_index: u32,
+    slice_data: &[u8],
+    merkle_path: &[u8],
+    authenticity_proof: &[u8],
+) -> bool {
+    slice_index == select_challenge_slice(challenge_nonce)
+        && verify_chunk_slice(slice_data, slice_index, merkle_path, slice_root)
+        && authenticity_proof == compute_authenticity_proof(challenge_nonce, slice_data, chain_id)
+}
+
+/// Raw challenge id: sha256(chain_id || chunk_index || nonce || challenger_id)
+pub fn compute_challenge_id_hash(challenge: &AvailabilityChallenge) -> [u8; 32] {
+    let mut id_input = Vec::new();
+    id_input.extend_from_slice(&challenge.chain_id);
+    id_input.extend_from_slice(&challenge.chunk_index.to_be_bytes());
+    id_input.extend_from_slice(&challenge.challenge_nonce);
+    id_input.extend_from_slice(&challenge.challenger_id);
+    compute_sha256(&id_input)
+}
+
+/// Identifier of a range audit, binding the chain, range, chunk root and nonce
+pub fn compute_range_challenge_id_hash(challenge: &RangeAvailabilityChallenge) -> [u8; 32] {
+    let mut id_input = Vec::new();
+    id_input.extend_from_slice(&challenge.chain_id);
+    id_input.extend_from_slice(&challenge.start_chunk.to_be_bytes());
+    id_input.extend_from_slice(&challenge.end_chunk.to_be_bytes());
+    id_input.extend_from_slice(&challenge.chunk_root);
+    id_input.extend_from_slice(&challenge.challenge_nonce);
+    id_input.extend_from_slice(&challenge.challenger_id);
+    id_input.extend_from_slice(b"range_challenge");
+    compute_sha256(&id_input)
+}
+
+/// Chunks `(first, end)` covered by a segment of a range audit, or None past the
+/// last segment
+pub fn range_segment_bounds(
+    challenge: &RangeAvailabilityChallenge,
+    segment_index: u32,
+) -> Option<(u32, u32)> {
+    if challenge.chunks_per_segment == 0
+        || segment_index as usize >= challenge.segment_deadlines.len()
+    {
+        return None;
+    }
+    let first = challenge
+        .start_chunk
+        .checked_add(segment_index.checked_mul(challenge.chunks_per_segment)?)?;
+    if first >= challenge.end_chunk {
+        return None;
+    }
+    let end = first
+        .saturating_add(challenge.chunks_per_segment)
+        .min(challenge.end_chunk);
+    Some((first, end))
+}
+
+/// Authenticity proof of a segment: binds the hashes of its chunks, in order, to
+/// the challenge nonce so the chunk data itself need not be hashed twice
+pub fn compute_segment_authenticity_proof(
+    challenge_nonce: &[u8],
+    segment_index: u32,
+    chunk_hashes: &[[u8; 32]],
+    chain_id: &[u8],
+) -> [u8; 32] {
+    let mut proof_input = Vec::new();
+    proof_input.extend_from_slice(challenge_nonce);
+    proof_input.extend_from_slice(&segment_index.to_be_bytes());
+    for chunk_hash in chunk_hashes {
+        proof_input.extend_from_slice(chunk_hash);
+    }
+    proof_input.extend_from_slice(chain_id);
+    proof_input.extend_from_slice(b"segment_authenticity_proof");
+    compute_sha256(&proof_input)
+}
+
+/// Check a segment on its own: it must belong to the challenge, cover exactly the
+/// chunks of its position, open every chunk to the chunk root and carry a matching
+/// authenticity proof. The chunk root is the chain's Merkle root, over the blake3
+/// hashes of its padded chunks. Deadlines are the caller's concern.
+pub fn verify_response_segment(
+    challenge: &RangeAvailabilityChallenge,
+    segment: &ChallengeResponseSegment,
+) -> bool {
+    if segment.challenge_id.as_ref() != compute_range_challenge_id_hash(challenge).as_slice()
+        || segment.total_segments as usize != challenge.segment_deadlines.len()
+        || challenge.end_chunk > challenge.total_chunks
+    {
+        return false;
+    }
+    let Some((first, end)) = range_segment_bounds(challenge, segment.segment_index) else {
+        return false;
+    };
+    if segment.chunks.len() != (end - first) as usize {
+        return false;
+    }
+
+    let mut chunk_hashes = Vec::with_capacity(segment.chunks.len());
+    for (expected_index, chunk) in (first..end).zip(&segment.chunks) {
+        if chunk.chunk_index != expected_index
+            || chunk.chunk_data.len() != CHUNK_SIZE_BYTES as usize
+        {
+            return false;
+        }
+        let chunk_hash = compute_blake3(&chunk.chunk_data);
+        if !verify_merkle_inclusion(
+            chunk_hash,
+            expected_index as usize,
+            challenge.total_chunks as usize,
+            &chunk.merkle_path,
+            &challenge.chunk_root,
+        ) {
+            return false;
+        }
+        chunk_hashes.push(chunk_hash);
+    }
+
+    segment.authenticity_proof.as_ref()
+        == compute_segment_authenticity_proof(
+            &challenge.challenge_nonce,
+            segment.segment_index,
+            &chunk_hashes,
+            &challenge.chain_id,
+        )
+        .as_slice()
+}
+
+/// Assemble one segment of the response to a range audit. `chunks` are the segment's
+/// chunks in index order, each padded as read from the chain with its inclusion path
+/// to the chain's Merkle root. Callers stream `0..segment_deadlines.len()` in order.
+pub fn build_range_segment(
+    challenge: &RangeAvailabilityChallenge,
+    segment_index: u32,
+    chunks: Vec<(Buffer, Vec<MerklePathNode>)>,
+) -> Result<ChallengeResponseSegment> {
+    let (first, end) = range_segment_bounds(challenge, segment_index).ok_or_else(|| {
+        Error::new(
+            Status::InvalidArg,
+            format!("Segment {} is outside the challenged range", segment_index),
+        )
+    })?;
+    if chunks.len() != (end - first) as usize {
+        return Err(Error::new(
+            Status::InvalidArg,
+            format!(
+                "Segment {} needs {} chunks, got {}",
+                segment_index,
+                end - first,
+                chunks.len()
+            ),
+        ));
+    }
+
+    let chunk_hashes: Vec<[u8; 32]> = chunks
+        .iter()
+        .map(|(chunk_data, _)| compute_blake3(chunk_data))
+        .collect();
+    let authenticity_proof = compute_segment_authenticity_proof(
+        &challenge.challenge_nonce,
+        segment_index,
+        &chunk_hashes,
+        &challenge.chain_id,
+    );
+    let response_time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| Error::new(Status::GenericFailure, format!("Time error: {}", e)))?
+        .as_secs_f64();
+
+    Ok(ChallengeResponseSegment {
+        challenge_id: Buffer::from(compute_range_challenge_id_hash(challenge).to_vec()),
+        segment_index,
+        total_segments: challenge.segment_deadlines.len() as u32,
+        chunks: (first..end)
+            .zip(chunks)
+            .map(|(chunk_index, (chunk_data, path))| SegmentChunkProof {
+                chunk_index,
+                chunk_data,
+                merkle_path: Buffer::from(encode_merkle_path(&path)),
+            })
+            .collect(),
+        authenticity_proof: Buffer::from(authenticity_proof.to_vec()),
+        response_time,
+    })
+}
+
+/// Determine if a chain is challenged at a block. Deterministic given the block's
+/// beacon, but unpredictable before the beacon is known.
+pub fn is_chain_challenged(
+    chain_id: &[u8],
+    block_height: u64,
+    beacon: Option<&[u8]>,
+    probability: f64,
+) -> bool {
+    let mut challenge_seed = Vec::new();
+    challenge_seed.extend_from_slice(chain_id);
+    challenge_seed.extend_from_slice(&block_height.to_be_bytes());
+    challenge_seed.extend_from_slice(b"availability_challenge");
+    if let Some(beacon) = beacon {
+        challenge_seed.extend_from_slice(beacon);
+    }
+
+    let challenge_hash = compute_sha256(&challenge_seed);
+    let challenge_value = u64::from_be_bytes([
+        challenge_hash[0],
+        challenge_hash[1],
+        challenge_hash[2],
+        challenge_hash[3],
+        challenge_hash[4],
+        challenge_hash[5],
+        challenge_hash[6],
+        challenge_hash[7],
+    ]);
+
+    // Convert probability to threshold
+    let threshold = (probability * u64::MAX as f64) as u64;
+
+    challenge_value < threshold
+}
+
+/// Select the chunk to challenge, mixing in the block's beacon when known
+pub fn select_challenge_chunk(
+    chain_id: &[u8],
+    total_chunks: u32,
+    block_height: u64,
+    beacon: Option<&[u8]>,
+) -> Result<u32> {
+    if total_chunks == 0 {
+        return Err(Error::new(
+            Status::InvalidArg,
+            "Cannot challenge a chain without chunks".to_string(),
+        ));
+    }
+
+    let mut chunk_seed = Vec::new();
+    chunk_seed.extend_from_slice(chain_id);
+    chunk_seed.extend_from_slice(&block_height.to_be_bytes());
+    chunk_seed.extend_from_slice(b"challenge_chunk_selection");
+    if let Some(beacon) = beacon {
+        chunk_seed.extend_from_slice(beacon);
+    }
+
+    let chunk_hash = compute_sha256(&chunk_seed);
+    let chunk_value =
+        u32::from_be_bytes([chunk_hash[0], chunk_hash[1], chunk_hash[2], chunk_hash[3]]);
+
+    Ok(chunk_value % total_chunks)
+}
+
+/// Recheck after the fact that a challenge issued at `block_height` was selected:
+/// its chain must be chosen and its chunk picked under the beacon it carries
+pub fn verify_challenge_selection(
+    challenge: &AvailabilityChallenge,
+    block_height: u64,
+    total_chunks: u32,
+    probability: f64,
+) -> bool {
+    let beacon = challenge.selection_beacon.as_ref().map(|b| b.as_ref());
+    is_chain_challenged(&challenge.chain_id, block_height, beacon, probability)
+        && select_challenge_chunk(&challenge.chain_id, total_chunks, block_height, beacon)
+            .is_ok_and(|chunk_index| chunk_index == challenge.chunk_index)
+}
+
+/// Append `bytes` to `input` with a big-endian u32 length prefix
+fn put_prefixed(input: &mut Vec<u8>, bytes: &[u8]) {
+    input.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+    input.extend_from_slice(bytes);
+}
+
+/// Append an optional field: a zero byte when absent, else a one byte and the
+/// length-prefixed bytes
+fn put_optional(input: &mut Vec<u8>, bytes: Option<&[u8]>) {
+    match bytes {
+        Some(bytes) => {
+            input.push(1);
+            put_prefixed(input, bytes);
+        }
+        None => input.push(0),
+    }
+}
+
+/// Append the fields of a response the prover signs, i.e. all but its key and signature
+fn put_response_fields(input: &mut Vec<u8>, response: &AvailabilityResponse) {
+    put_prefixed(input, &response.challenge_id);
+    put_prefixed(input, &response.chunk_data);
+    input.extend_from_slice(&response.response_time.to_be_bytes());
+    put_prefixed(input, &response.authenticity_proof);
+    input.push(
+        response
+            .maintenance_mode
+            .map_or(0, |enabled| 1 + enabled as u8),
+    );
+    match &response.slice_proof {
+        Some(proof) => {
+            input.push(1);
+            input.extend_from_slice(&proof.slice_index.to_be_bytes());
+            put_prefixed(input, &proof.slice_data);
+            put_prefixed(input, &proof.merkle_path);
+        }
+        None => input.push(0),
+    }
+}
+
+/// Bytes a prover signs for an availability response
+pub fn availability_response_signing_data(response: &AvailabilityResponse) -> Vec<u8> {
+    let mut input = AVAILABILITY_RESPONSE_SIGNING_DOMAIN.to_vec();
+    put_response_fields(&mut input, response);
+    input
+}
+
+/// Attach the prover's key and signature to an availability response
+pub fn sign_availability_response(
+    response: &mut AvailabilityResponse,
+    prover_key: &[u8],
+    signer: &dyn crate::core::signer::Signer,
+) -> crate::core::errors::HashChainResult<()> {
+    let signature = signer.sign(&availability_response_signing_data(response))?;
+    response.prover_key = Some(Buffer::from(prover_key.to_vec()));
+    response.prover_signature = Some(Buffer::from(signature));
+    Ok(())
+}
+
+/// Whether a response carries a valid signature by the key it names
+pub fn verify_availability_response_signature(response: &AvailabilityResponse) -> bool {
+    let (Some(prover_key), Some(signature)) = (&response.prover_key, &response.prover_signature)
+    else {
+        return false;
+    };
+    verify_signature(
+        prover_key,
+        &availability_response_signing_data(response),
+        signature,
+    )
+    .unwrap_or(false)
+}
+
+/// Hash binding a challenge evidence record. Variable-length fields are length
+/// prefixed; a missing response is encoded as a single zero byte.
+pub fn compute_evidence_hash(
+    challenge_id: &str,
+    challenge: &AvailabilityChallenge,
+    response: Option<&AvailabilityResponse>,
+    outcome: &str,
+    recorded_at: f64,
+) -> [u8; 32] {
+    let mut input = Vec::new();
+    input.extend_from_slice(CHALLENGE_EVIDENCE_DOMAIN);
+    put_prefixed(&mut input, challenge_id.as_bytes());
+    put_prefixed(&mut input, &challenge.chain_id);
+    input.extend_from_slice(&challenge.chunk_index.to_be_bytes());
+    put_prefixed(&mut input, &challenge.challenge_nonce);
+    put_prefixed(&mut input, &challenge.challenger_id);
+    input.extend_from_slice(&challenge.challenge_time.to_be_bytes());
+    input.extend_from_slice(&challenge.deadline.to_be_bytes());
+    input.extend_from_slice(&challenge.reward_amount.to_be_bytes());
+    put_optional(&mut input, challenge.slice_root.as_deref());
+    put_optional(&mut input, challenge.selection_beacon.as_deref());
+    match response {
+        Some(response) => {
+            input.push(1);
+            put_response_fields(&mut input, response);
+            put_optional(&mut input, response.prover_key.as_deref());
+            put_optional(&mut input, response.prover_signature.as_deref());
+        }
+        None => input.push(0),
+    }
+    put_prefixed(&mut input, outcome.as_bytes());
+    input.extend_from_slice(&recorded_at.to_be_bytes());
+    compute_sha256(&input)
+}
+
+/// Sign an evidence record's hash as its challenger. `private_key` must belong to
+/// `challenge.challenger_id`.
+pub fn sign_challenge_evidence(
+    evidence: &mut ChallengeEvidence,
+    private_key: &[u8],
+) -> crate::core::errors::HashChainResult<()> {
+    let signature = crate::core::utils::sign_data(private_key, &evidence.evidence_hash)?;
+    evidence.challenger_signature = Some(Buffer::from(signature));
+    Ok(())
+}
+
+/// Check an exported challenge record: the id must match the challenge, the hash
+/// must bind the record, and the outcome must follow from the challenge and response.
+/// Signatures by the challenger and prover are checked when present; with
+/// `require_signed` the challenger's signature, and the prover's on any response,
+/// must be present.
+pub fn verify_challenge_evidence(evidence: &ChallengeEvidence, require_signed: bool) -> bool {
+    let challenge_id = compute_challenge_id_hash(&evidence.challenge);
+    if evidence.challenge_id != hex::encode(challenge_id) {
+        return false;
+    }
+
+    let evidence_hash = compute_evidence_hash(
+        &evidence.challenge_id,
+        &evidence.challenge,
+        evidence.response.as_ref(),
+        &evidence.outcome,
+        evidence.recorded_at,
+    );
+    if evidence.evidence_hash.as_ref() != evidence_hash.as_slice() {
+        return false;
+    }
+
+    let challenger_signed = match &evidence.challenger_signature {
+        Some(signature) => {
+            verify_signature(&evidence.challenge.challenger_id, &evidence_hash, signature)
+                .unwrap_or(false)
+        }
+        None => !require_signed,
+    };
+    if !challenger_signed {
+        return false;
+    }
+    if let Some(response) = &evidence.response {
+        let signed = response.prover_signature.is_some();
+        if (signed || require_signed) && !verify_availability_response_signature(response) {
+            return false;
+        }
+    }
+
+    match &evidence.response {
+        // Only cleanup records unanswered challenges, after their deadline
+        None => {
+            evidence.outcome == "unanswered" && evidence.recorded_at > evidence.challenge.deadline
+        }
+        Some(response) => {
+            // Re-judge the response as `process_response` did, without a clock check
+            response.challenge_id.as_ref() == challenge_id.as_slice()
+                && PendingVerification::new(&evidence.challenge, Some(response))
+                    .verify(f64::INFINITY)
+                    .outcome_label()
+                    == evidence.outcome
+        }
+    }
+}
+
+/// Challenge and response data copied out for parallel batch verification
+struct PendingVerification {
+    challenge_nonce: Vec<u8>,
+    chain_id: Vec<u8>,
+    challenge_time: f64,
+    deadline: f64,
+    reward_amount: f64,
+    slice_root: Option<Vec<u8>>,
+    response: Option<PendingResponse>,
+}
 
-        self.active_challenges.retain(|challenge_id, challenge| {
-            if current_time > challenge.deadline {
-                expired_challenges.push(challenge_id.clone());
-                false
-            } else {
-                true
-            }
-        });
+struct PendingResponse {
+    chunk_data: Vec<u8>,
+    authenticity_proof: Vec<u8>,
+    response_time: f64,
+    maintenance_mode: bool,
+    /// (slice index, slice data, merkle path) for light probes
+    slice_proof: Option<(u32, Vec<u8>, Vec<u8>)>,
+}
 
-        Ok(expired_challenges)
+impl PendingVerification {
+    fn new(challenge: &AvailabilityChallenge, response: Option<&AvailabilityResponse>) -> Self {
+        Self {
+            challenge_nonce: challenge.challenge_nonce.to_vec(),
+            chain_id: challenge.chain_id.to_vec(),
+            challenge_time: challenge.challenge_time,
+            deadline: challenge.deadline,
+            reward_amount: challenge.reward_amount,
+            slice_root: challenge.slice_root.as_ref().map(|root| root.to_vec()),
+            response: response.map(|response| PendingResponse {
+                chunk_data: response.chunk_data.to_vec(),
+                authenticity_proof: response.authenticity_proof.to_vec(),
+                response_time: response.response_time,
+                maintenance_mode: response.maintenance_mode == Some(true),
+                slice_proof: response.slice_proof.as_ref().map(|proof| {
+                    (
+                        proof.slice_index,
+                        proof.slice_data.to_vec(),
+                        proof.merkle_path.to_vec(),
+                    )
+                }),
+            }),
+        }
     }
 
-    /// Get statistics about challenges
-    pub fn get_challenge_stats(&self) -> ChallengeStats {
-        ChallengeStats {
-            active_challenges: self.active_challenges.len() as u32,
-            challenge_probability: self.challenge_probability,
-            response_timeout_ms: self.response_timeout_ms,
+    fn verify(&self, current_time: f64) -> AvailabilityResult {
+        let Some(response) = &self.response else {
+            return AvailabilityResult::Timeout;
+        };
+        if response.response_time > self.deadline {
+            return AvailabilityResult::Timeout;
+        }
+        // A response cannot have been produced after it was received
+        if response.response_time > current_time {
+            return AvailabilityResult::InvalidData;
+        }
+        if response.maintenance_mode {
+            return AvailabilityResult::MaintenanceMode;
+        }
+        if let Some(slice_root) = &self.slice_root {
+            let authentic = response.slice_proof.as_ref().is_some_and(
+                |(slice_index, slice_data, merkle_path)| {
+                    verify_slice_response(
+                        &self.challenge_nonce,
+                        &self.chain_id,
+                        slice_root,
+                        *slice_index,
+                        slice_data,
+                        merkle_path,
+                        &response.authenticity_proof,
+                    )
+                },
+            );
+            if !authentic {
+                return AvailabilityResult::InvalidData;
+            }
+        } else if response.chunk_data.len() != CHUNK_SIZE_BYTES as usize
+            || response.authenticity_proof
+                != compute_authenticity_proof(
+                    &self.challenge_nonce,
+                    &response.chunk_data,
+                    &self.chain_id,
+                )
+        {
+            return AvailabilityResult::InvalidData;
+        }
+
+        AvailabilityResult::Success {
+            response_time_ms: ((response.response_time - self.challenge_time) * 1000.0) as u32,
+            challenger_reward: self.reward_amount,
         }
     }
 }
 
+/// Outcome of a challenge removed by cleanup
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExpiredChallengeOutcome {
+    /// Deadline passed with no response - a failure, slashable once the chain
+    /// exceeds its failure tolerance
+    ExpiredUnanswered,
+    /// A response was already processed before the deadline passed
+    AlreadyResolved,
+    /// A streamed range audit had a segment still outstanding at its deadline
+    SegmentOverdue,
+}
+
+/// Challenge removed by cleanup
+#[derive(Debug, Clone)]
+pub struct ExpiredChallenge {
+    pub challenge_id: String,
+    /// Hex-encoded chain identifier
+    pub chain_id: String,
+    pub outcome: ExpiredChallengeOutcome,
+}
+
 /// Result of processing availability challenge
 #[derive(Debug, Clone)]
 pub enum AvailabilityResult {
@@ -263,6 +1425,45 @@ pub enum AvailabilityResult {
     },
     Timeout,
     InvalidData,
+    /// Prover announced maintenance: temporarily unavailable, not slashable
+    MaintenanceMode,
+}
+
+impl AvailabilityResult {
+    /// Outcome name used in challenge evidence records
+    pub fn outcome_label(&self) -> &'static str {
+        match self {
+            AvailabilityResult::Success { .. } => "success",
+            AvailabilityResult::Timeout => "timeout",
+            AvailabilityResult::InvalidData => "invalid_data",
+            AvailabilityResult::MaintenanceMode => "maintenance_mode",
+        }
+    }
+}
+
+/// Result of processing one segment of a streamed range audit
+#[derive(Debug, Clone, PartialEq)]
+pub enum SegmentResult {
+    /// Segment verified; the audit is waiting for the rest
+    Accepted { remaining_segments: u32 },
+    /// Last outstanding segment verified; the audit succeeded
+    Complete,
+    /// An outstanding segment missed its deadline; the audit failed
+    Timeout,
+    /// Segment failed verification; the audit failed
+    InvalidData,
+}
+
+impl SegmentResult {
+    /// Outcome name reported to callers
+    pub fn outcome_label(&self) -> &'static str {
+        match self {
+            SegmentResult::Accepted { .. } => "accepted",
+            SegmentResult::Complete => "complete",
+            SegmentResult::Timeout => "timeout",
+            SegmentResult::InvalidData => "invalid_data",
+        }
+    }
 }
 
 /// Challenge statistics
@@ -282,15 +1483,21 @@ pub struct ChainStorageStats {
     pub cache_hit_ratio: f64,
 }
 
+/// Path prefix for chains registered with an explicit storage backend
+const BACKEND_PATH_PREFIX: &str = "backend://";
+
 /// Availability prover for responding to challenges
 pub struct AvailabilityProver {
     chain_data: HashMap<String, ChainAvailabilityData>,
+    /// Answer challenges with a maintenance notice instead of chunk data
+    maintenance_mode: bool,
 }
 
-#[derive(Clone)]
 struct ChainAvailabilityData {
     file_path: String,
     total_chunks: u32,
+    /// Opened lazily on first read for file-registered chains
+    backend: Option<Box<dyn StorageBackend>>,
     chunk_cache: HashMap<u32, Vec<u8>>, // Cache recently accessed chunks
 }
 
@@ -305,14 +1512,54 @@ impl AvailabilityProver {
     pub fn new() -> Self {
         AvailabilityProver {
             chain_data: HashMap::new(),
+            maintenance_mode: false,
         }
     }
 
+    /// Enable or disable answering challenges with a maintenance notice
+    pub fn set_maintenance_mode(&mut self, enabled: bool) {
+        self.maintenance_mode = enabled;
+    }
+
     /// Register chain for availability proving
     pub fn register_chain(&mut self, chain_id: String, file_path: String, total_chunks: u32) {
         let chain_data = ChainAvailabilityData {
             file_path,
             total_chunks,
+            backend: None,
+            chunk_cache: HashMap::new(),
+        };
+        self.chain_data.insert(chain_id, chain_data);
+    }
+
+    /// Stop serving availability challenges for a chain
+    pub fn unregister_chain(&mut self, chain_id: &str) -> bool {
+        self.chain_data.remove(chain_id).is_some()
+    }
+
+    /// File-registered chains as (chain_id, file_path, total_chunks), sorted by
+    /// chain id. Backend-registered chains have no path to re-register from.
+    pub fn file_registrations(&self) -> Vec<(String, String, u32)> {
+        let mut registrations: Vec<_> = self
+            .chain_data
+            .iter()
+            .filter(|(_, data)| !data.file_path.starts_with(BACKEND_PATH_PREFIX))
+            .map(|(chain_id, data)| (chain_id.clone(), data.file_path.clone(), data.total_chunks))
+            .collect();
+        registrations.sort();
+        registrations
+    }
+
+    /// Register chain served from an explicit storage backend (e.g. in-memory for tests)
+    pub fn register_chain_with_backend(
+        &mut self,
+        chain_id: String,
+        backend: Box<dyn StorageBackend>,
+    ) {
+        let chain_data = ChainAvailabilityData {
+            file_path: format!("{}{}", BACKEND_PATH_PREFIX, chain_id),
+            total_chunks: backend.total_chunks().min(u32::MAX as u64) as u32,
+            backend: Some(backend),
             chunk_cache: HashMap::new(),
         };
         self.chain_data.insert(chain_id, chain_data);
@@ -325,9 +1572,61 @@ impl AvailabilityProver {
     ) -> Result<AvailabilityResponse> {
         let chain_id = hex::encode(&challenge.chain_id);
 
+        if self.maintenance_mode {
+            let response_time = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_err(|e| Error::new(Status::GenericFailure, format!("Time error: {}", e)))?
+                .as_secs_f64();
+            return Ok(AvailabilityResponse {
+                challenge_id: Buffer::from(self.compute_challenge_id(challenge)?),
+                chunk_data: Buffer::from(Vec::new()),
+                response_time,
+                authenticity_proof: Buffer::from(Vec::new()),
+                maintenance_mode: Some(true),
+                slice_proof: None,
+                prover_key: None,
+                prover_signature: None,
+            });
+        }
+
         // Read chunk data - separate the operations to avoid borrowing conflicts
         let chunk_data = self.read_chunk_for_chain(&chain_id, challenge.chunk_index)?;
 
+        // Light probes serve only the challenged slice and its path to the slice root
+        if challenge.slice_root.is_some() {
+            let slice_index = select_challenge_slice(&challenge.challenge_nonce);
+            let (slice_data, merkle_path) =
+                open_chunk_slice(&chunk_data, slice_index).ok_or_else(|| {
+                    Error::new(
+                        Status::GenericFailure,
+                        format!("Chunk too short for slice {}", slice_index),
+                    )
+                })?;
+            let authenticity_proof = compute_authenticity_proof(
+                &challenge.challenge_nonce,
+                &slice_data,
+                &challenge.chain_id,
+            );
+            let response_time = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_err(|e| Error::new(Status::GenericFailure, format!("Time error: {}", e)))?
+                .as_secs_f64();
+            return Ok(AvailabilityResponse {
+                challenge_id: Buffer::from(self.compute_challenge_id(challenge)?),
+                chunk_data: Buffer::from(Vec::new()),
+                response_time,
+                authenticity_proof: Buffer::from(authenticity_proof.to_vec()),
+                maintenance_mode: None,
+                slice_proof: Some(ChunkSliceProof {
+                    slice_index,
+                    slice_data: Buffer::from(slice_data),
+                    merkle_path: Buffer::from(merkle_path),
+                }),
+                prover_key: None,
+                prover_signature: None,
+            });
+        }
+
         // Generate authenticity proof
         let authenticity_proof = self.generate_authenticity_proof(challenge, &chunk_data)?;
 
@@ -345,6 +1644,10 @@ impl AvailabilityProver {
             chunk_data: Buffer::from(chunk_data),
             response_time,
             authenticity_proof: Buffer::from(authenticity_proof),
+            maintenance_mode: None,
+            slice_proof: None,
+            prover_key: None,
+            prover_signature: None,
         })
     }
 
@@ -426,16 +1729,8 @@ impl AvailabilityProver {
             return Ok(cached_chunk.clone());
         }
 
-        // Get file path for reading
-        let file_path = self
-            .chain_data
-            .get(chain_id)
-            .ok_or_else(|| Error::new(Status::GenericFailure, "Chain not found".to_string()))?
-            .file_path
-            .clone();
-
-        // Read chunk data from file
-        let chunk_data = self.read_chunk_from_file(&file_path, chunk_index)?;
+        // Read chunk data from the chain's storage backend
+        let chunk_data = self.read_chunk_from_backend(chain_id, chunk_index)?;
 
         // Now update cache with mutable borrow
         if let Some(chain_data) = self.chain_data.get_mut(chain_id) {
@@ -457,33 +1752,22 @@ impl AvailabilityProver {
         Ok(chunk_data)
     }
 
-    /// Read chunk directly from file
-    fn read_chunk_from_file(&self, file_path: &str, chunk_index: u32) -> Result<Vec<u8>> {
-        use std::fs::File;
-        use std::io::{Read, Seek, SeekFrom};
-
-        let mut file = File::open(file_path).map_err(|e| {
-            Error::new(
-                Status::GenericFailure,
-                format!("Failed to open file: {}", e),
-            )
-        })?;
-
-        let chunk_offset = chunk_index as u64 * CHUNK_SIZE_BYTES as u64;
-        file.seek(SeekFrom::Start(chunk_offset))
-            .map_err(|e| Error::new(Status::GenericFailure, format!("Failed to seek: {}", e)))?;
-
-        let mut chunk_data = vec![0u8; CHUNK_SIZE_BYTES as usize];
-        let bytes_read = file
-            .read(&mut chunk_data)
-            .map_err(|e| Error::new(Status::GenericFailure, format!("Failed to read: {}", e)))?;
+    /// Read chunk from the chain's backend, opening the data file on first use
+    fn read_chunk_from_backend(&mut self, chain_id: &str, chunk_index: u32) -> Result<Vec<u8>> {
+        let chain_data = self
+            .chain_data
+            .get_mut(chain_id)
+            .ok_or_else(|| Error::new(Status::GenericFailure, "Chain not found".to_string()))?;
 
-        // Handle partial last chunk
-        if bytes_read < CHUNK_SIZE_BYTES as usize {
-            chunk_data.resize(bytes_read, 0);
+        if chain_data.backend.is_none() {
+            chain_data.backend = Some(Box::new(FileBackend::new(chain_data.file_path.clone())?));
         }
 
-        Ok(chunk_data)
+        Ok(chain_data
+            .backend
+            .as_mut()
+            .unwrap()
+            .read_chunk(chunk_index)?)
     }
 
     /// Generate authenticity proof for chunk
@@ -492,13 +1776,10 @@ impl AvailabilityProver {
         challenge: &AvailabilityChallenge,
         chunk_data: &[u8],
     ) -> Result<Vec<u8>> {
-        let mut proof_input = Vec::new();
-        proof_input.extend_from_slice(&challenge.challenge_nonce);
-        proof_input.extend_from_slice(chunk_data);
-        proof_input.extend_from_slice(&challenge.chain_id);
-        proof_input.extend_from_slice(b"authenticity_proof");
-
-        Ok(compute_sha256(&proof_input).to_vec())
+        Ok(
+            compute_authenticity_proof(&challenge.challenge_nonce, chunk_data, &challenge.chain_id)
+                .to_vec(),
+        )
     }
 
     /// Compute challenge ID
@@ -580,6 +1861,8 @@ mod tests {
             challenge_time: 1234567890.0,
             deadline: 1234567890.5,
             reward_amount: AVAILABILITY_REWARD_UNITS as f64,
+            slice_root: None,
+            selection_beacon: None,
         };
 
         // This will fail because /fake/path doesn't exist, but it tests the flow
@@ -587,6 +1870,676 @@ mod tests {
         assert!(result.is_err()); // Expected to fail with fake path
     }
 
+    #[test]
+    fn test_in_memory_challenge_response_flow() {
+        let chain_id = Buffer::from([8u8; 32].to_vec());
+        let chain_key = hex::encode(&chain_id);
+        let data: Vec<u8> = (0..(CHUNK_SIZE_BYTES as usize * 64))
+            .map(|i| (i % 253) as u8)
+            .collect();
+
+        let mut prover = AvailabilityProver::new();
+        prover.register_chain_with_backend(
+            chain_key.clone(),
+            Box::new(crate::core::storage_backend::MemoryBackend::new(
+                data.clone(),
+            )),
+        );
+        assert_eq!(prover.get_total_chunks(&chain_key), Some(64));
+
+        // Find a block height at which this chain is challenged
+        let mut challenger = AvailabilityChallenger::new();
+        let challenger_id = Buffer::from([2u8; 32].to_vec());
+        let challenge = (0..1000u64)
+            .find_map(|height| {
+                challenger
+                    .create_challenge(chain_id.clone(), 64, challenger_id.clone(), height)
+                    .unwrap()
+            })
+            .unwrap();
+
+        let response = prover.respond_to_challenge(&challenge).unwrap();
+        let start = challenge.chunk_index as usize * CHUNK_SIZE_BYTES as usize;
+        assert_eq!(
+            response.chunk_data.as_ref(),
+            &data[start..start + CHUNK_SIZE_BYTES as usize]
+        );
+
+        let result = challenger
+            .process_response(hex::encode(&response.challenge_id), response)
+            .unwrap();
+        assert!(matches!(result, AvailabilityResult::Success { .. }));
+    }
+
+    #[test]
+    fn test_maintenance_response_reduces_score_without_failure() {
+        let chain_id = Buffer::from([9u8; 32].to_vec());
+        let chain_key = hex::encode(&chain_id);
+        let data = vec![7u8; CHUNK_SIZE_BYTES as usize * 16];
+        let mut prover = AvailabilityProver::new();
+        prover.register_chain_with_backend(
+            chain_key.clone(),
+            Box::new(crate::core::storage_backend::MemoryBackend::new(data)),
+        );
+        prover.set_maintenance_mode(true);
+
+        let mut challenger = AvailabilityChallenger::new();
+        let challenger_id = Buffer::from([2u8; 32].to_vec());
+        let challenge = (0..1000u64)
+            .find_map(|height| {
+                challenger
+                    .create_challenge(chain_id.clone(), 16, challenger_id.clone(), height)
+                    .unwrap()
+            })
+            .unwrap();
+
+        let response = prover.respond_to_challenge(&challenge).unwrap();
+        assert_eq!(response.maintenance_mode, Some(true));
+        assert!(response.chunk_data.is_empty());
+
+        let result = challenger
+            .process_response(hex::encode(&response.challenge_id), response)
+            .unwrap();
+        assert!(matches!(result, AvailabilityResult::MaintenanceMode));
+        assert_eq!(challenger.get_scorer().get_failure_count(&chain_key), 0);
+
+        // Reduced, but less than a failure would
+        let mut scorer = AvailabilityScorer::new();
+        scorer.record_success("a");
+        scorer.record_maintenance("a");
+        scorer.record_success("b");
+        scorer.record_failure("b");
+        assert!(challenger.get_scorer().get_score(&chain_key) < 1.0);
+        assert!(scorer.get_score("a") < 1.0);
+        assert!(scorer.get_score("a") > scorer.get_score("b"));
+    }
+
+    #[test]
+    fn test_batch_verification_pairs_responses_to_challenges() {
+        let mut prover = AvailabilityProver::new();
+        let mut challenger = AvailabilityChallenger::new();
+        let challenger_id = Buffer::from([2u8; 32].to_vec());
+
+        let mut challenges = Vec::new();
+        for seed in 20..24u8 {
+            let chain_id = Buffer::from([seed; 32].to_vec());
+            let data = vec![seed; CHUNK_SIZE_BYTES as usize * 8];
+            prover.register_chain_with_backend(
+                hex::encode(&chain_id),
+                Box::new(crate::core::storage_backend::MemoryBackend::new(data)),
+            );
+            let challenge = (0..1000u64)
+                .find_map(|height| {
+                    challenger
+                        .create_challenge(chain_id.clone(), 8, challenger_id.clone(), height)
+                        .unwrap()
+                })
+                .unwrap();
+            challenges.push(challenge);
+        }
+
+        let mut responses: Vec<AvailabilityResponse> = challenges
+            .iter()
+            .map(|challenge| prover.respond_to_challenge(challenge).unwrap())
+            .collect();
+        // Tamper with one response, drop another and answer the rest out of order
+        responses[1].chunk_data = Buffer::from(vec![0u8; CHUNK_SIZE_BYTES as usize]);
+        responses.remove(2);
+        responses.reverse();
+
+        let results = challenger
+            .verify_availability_responses_batch(&challenges, &responses)
+            .unwrap();
+        assert_eq!(results.len(), 4);
+        assert!(matches!(results[0], AvailabilityResult::Success { .. }));
+        assert!(matches!(results[1], AvailabilityResult::InvalidData));
+        assert!(matches!(results[2], AvailabilityResult::Timeout));
+        assert!(matches!(results[3], AvailabilityResult::Success { .. }));
+    }
+
+    #[test]
+    fn test_latency_percentiles() {
+        let mut stats = AvailabilityLatencyStats::new(100, AVAILABILITY_RESPONSE_TIME_MS);
+        assert!(stats.get_chain_percentiles("chain_a").is_none());
+
+        for response_time_ms in 1..=100 {
+            stats.record("chain_a", response_time_ms);
+        }
+
+        let percentiles = stats.get_chain_percentiles("chain_a").unwrap();
+        assert_eq!(percentiles.sample_count, 100);
+        assert_eq!(percentiles.p50_ms, 50);
+        assert_eq!(percentiles.p95_ms, 95);
+        assert_eq!(percentiles.p99_ms, 99);
+        assert_eq!(percentiles.max_ms, 100);
+
+        // Window keeps only the most recent samples
+        stats.record("chain_a", 400);
+        let percentiles = stats.get_chain_percentiles("chain_a").unwrap();
+        assert_eq!(percentiles.sample_count, 100);
+        assert_eq!(percentiles.max_ms, 400);
+        assert_eq!(stats.tracked_chains(), vec!["chain_a".to_string()]);
+    }
+
+    #[test]
+    fn test_cleanup_distinguishes_unanswered_challenges() {
+        let mut challenger = AvailabilityChallenger::new();
+        let challenger_id = Buffer::from([2u8; 32].to_vec());
+
+        // Find two chains selected for challenge at this height
+        let mut challenges = Vec::new();
+        for seed in 0..=255u8 {
+            let chain_id = Buffer::from([seed; 32].to_vec());
+            if let Some(challenge) = challenger
+                .create_challenge(chain_id, 1000, challenger_id.clone(), 100)
+                .unwrap()
+            {
+                challenges.push(challenge);
+            }
+            if challenges.len() == 2 {
+                break;
+            }
+        }
+        assert_eq!(challenges.len(), 2);
+
+        // Answer the first challenge after its deadline
+        let answered = &challenges[0];
+        let answered_id = challenger.compute_challenge_id(answered).unwrap();
+        let response = AvailabilityResponse {
+            challenge_id: Buffer::from(hex::decode(&answered_id).unwrap()),
+            chunk_data: Buffer::from(vec![0u8; CHUNK_SIZE_BYTES as usize]),
+            response_time: answered.deadline + 1.0,
+            authenticity_proof: Buffer::from([0u8; 32].to_vec()),
+            maintenance_mode: None,
+            slice_proof: None,
+            prover_key: None,
+            prover_signature: None,
+        };
+        let result = challenger
+            .process_response(answered_id.clone(), response)
+            .unwrap();
+        assert!(matches!(result, AvailabilityResult::Timeout));
+
+        let unanswered_chain = hex::encode(&challenges[1].chain_id);
+        let failures_before = challenger.get_scorer().get_failure_count(&unanswered_chain);
+
+        let expired = challenger
+            .cleanup_expired_challenges_at(challenges[1].deadline.max(answered.deadline) + 1.0)
+            .unwrap();
+        assert_eq!(expired.len(), 2);
+
+        let resolved = expired
+            .iter()
+            .find(|e| e.challenge_id == answered_id)
+            .unwrap();
+        assert_eq!(resolved.outcome, ExpiredChallengeOutcome::AlreadyResolved);
+
+        let unanswered = expired
+            .iter()
+            .find(|e| e.challenge_id != answered_id)
+            .unwrap();
+        assert_eq!(
+            unanswered.outcome,
+            ExpiredChallengeOutcome::ExpiredUnanswered
+        );
+        assert_eq!(unanswered.chain_id, unanswered_chain);
+        assert_eq!(
+            challenger.get_scorer().get_failure_count(&unanswered_chain),
+            failures_before + 1
+        );
+        assert!(challenger.get_scorer().get_score(&unanswered_chain) < 1.0);
+        assert_eq!(challenger.get_challenge_stats().active_challenges, 0);
+    }
+
+    #[test]
+    fn test_light_probe_after_full_audit() {
+        let chain_id = Buffer::from([12u8; 32].to_vec());
+        let data: Vec<u8> = (0..CHUNK_SIZE_BYTES).map(|i| (i % 249) as u8).collect();
+        let mut prover = AvailabilityProver::new();
+        prover.register_chain_with_backend(
+            hex::encode(&chain_id),
+            Box::new(crate::core::storage_backend::MemoryBackend::new(data)),
+        );
+
+        let mut challenger = AvailabilityChallenger::new();
+        let challenger_id = Buffer::from([2u8; 32].to_vec());
+        let mut heights = 0..1000u64;
+        let mut next_light_challenge = |challenger: &mut AvailabilityChallenger| {
+            heights
+                .find_map(|height| {
+                    challenger
+                        .create_light_challenge(chain_id.clone(), 1, challenger_id.clone(), height)
+                        .unwrap()
+                })
+                .unwrap()
+        };
+
+        // Without a known slice root the probe falls back to a full-chunk challenge
+        let audit = next_light_challenge(&mut challenger);
+        assert!(audit.slice_root.is_none());
+        let response = prover.respond_to_challenge(&audit).unwrap();
+        assert_eq!(response.chunk_data.len(), CHUNK_SIZE_BYTES as usize);
+        let result = challenger
+            .process_response(hex::encode(&response.challenge_id), response)
+            .unwrap();
+        assert!(matches!(result, AvailabilityResult::Success { .. }));
+
+        // The audited chunk can now be probed with a single slice
+        let probe = next_light_challenge(&mut challenger);
+        assert!(probe.slice_root.is_some());
+        let response = prover.respond_to_challenge(&probe).unwrap();
+        assert!(response.chunk_data.is_empty());
+        let slice_proof = response.slice_proof.clone().unwrap();
+        assert_eq!(
+            slice_proof.slice_data.len(),
+            AVAILABILITY_SLICE_BYTES as usize
+        );
+
+        let mut tampered = response.clone();
+        tampered.slice_proof.as_mut().unwrap().slice_data =
+            Buffer::from(vec![0u8; AVAILABILITY_SLICE_BYTES as usize]);
+        let results = challenger
+            .verify_availability_responses_batch(std::slice::from_ref(&probe), &[tampered])
+            .unwrap();
+        assert!(matches!(results[0], AvailabilityResult::InvalidData));
+
+        let result = challenger
+            .process_response(hex::encode(&response.challenge_id), response)
+            .unwrap();
+        assert!(matches!(result, AvailabilityResult::Success { .. }));
+    }
+
+    #[test]
+    fn test_slice_roots_pruned_on_failure_and_expiry() {
+        let chain_id = Buffer::from([14u8; 32].to_vec());
+        let data: Vec<u8> = (0..CHUNK_SIZE_BYTES).map(|i| (i % 241) as u8).collect();
+        let mut prover = AvailabilityProver::new();
+        prover.register_chain_with_backend(
+            hex::encode(&chain_id),
+            Box::new(crate::core::storage_backend::MemoryBackend::new(data)),
+        );
+
+        let mut challenger = AvailabilityChallenger::new();
+        let challenger_id = Buffer::from([2u8; 32].to_vec());
+        let mut heights = 0..1000u64;
+        let mut next_light_challenge = |challenger: &mut AvailabilityChallenger| {
+            heights
+                .find_map(|height| {
+                    challenger
+                        .create_light_challenge(chain_id.clone(), 1, challenger_id.clone(), height)
+                        .unwrap()
+                })
+                .unwrap()
+        };
+        let full_audit = |challenger: &mut AvailabilityChallenger,
+                          prover: &mut AvailabilityProver,
+                          audit: AvailabilityChallenge| {
+            assert!(audit.slice_root.is_none());
+            let response = prover.respond_to_challenge(&audit).unwrap();
+            let result = challenger
+                .process_response(hex::encode(&response.challenge_id), response)
+                .unwrap();
+            assert!(matches!(result, AvailabilityResult::Success { .. }));
+        };
+
+        // A failed light probe drops the root, so the next probe is a full audit
+        let audit = next_light_challenge(&mut challenger);
+        full_audit(&mut challenger, &mut prover, audit);
+        let probe = next_light_challenge(&mut challenger);
+        let mut response = prover.respond_to_challenge(&probe).unwrap();
+        response.slice_proof.as_mut().unwrap().slice_data =
+            Buffer::from(vec![0u8; AVAILABILITY_SLICE_BYTES as usize]);
+        let result = challenger
+            .process_response(hex::encode(&response.challenge_id), response)
+            .unwrap();
+        assert!(matches!(result, AvailabilityResult::InvalidData));
+        let audit = next_light_challenge(&mut challenger);
+
+        // So does a probe that expires unanswered
+        full_audit(&mut challenger, &mut prover, audit);
+        let probe = next_light_challenge(&mut challenger);
+        assert!(probe.slice_root.is_some());
+        challenger
+            .cleanup_expired_challenges_at(probe.deadline + 1.0)
+            .unwrap();
+        let audit = next_light_challenge(&mut challenger);
+
+        // And roots older than the retention window
+        let learned_at = audit.challenge_time;
+        full_audit(&mut challenger, &mut prover, audit);
+        challenger
+            .cleanup_expired_challenges_at(
+                learned_at + AVAILABILITY_SLICE_ROOT_RETENTION_SECONDS + 10.0,
+            )
+            .unwrap();
+        assert!(next_light_challenge(&mut challenger).slice_root.is_none());
+    }
+
+    #[test]
+    fn test_streamed_range_audit() {
+        let chain_id = Buffer::from([13u8; 32].to_vec());
+        let total_chunks = 40u32;
+        let data: Vec<u8> = (0..total_chunks * CHUNK_SIZE_BYTES)
+            .map(|i| ((i / CHUNK_SIZE_BYTES) * 7 + i % 251) as u8)
+            .collect();
+        let chunk_hashes: Vec<[u8; 32]> = data
+            .chunks(CHUNK_SIZE_BYTES as usize)
+            .map(compute_blake3)
+            .collect();
+        let levels = crate::core::utils::compute_merkle_levels(&chunk_hashes);
+        let chunk_root = Buffer::from(levels.last().unwrap()[0].to_vec());
+
+        // Serve segments as the prover does, from the chunks and one shared tree
+        let respond = |challenge: &RangeAvailabilityChallenge, segment_index: u32| {
+            let chunks = range_segment_bounds(challenge, segment_index)
+                .map(|(first, end)| {
+                    (first as usize..end as usize)
+                        .map(|chunk_index| {
+                            let offset = chunk_index * CHUNK_SIZE_BYTES as usize;
+                            (
+                                Buffer::from(
+                                    data[offset..offset + CHUNK_SIZE_BYTES as usize].to_vec(),
+                                ),
+                                crate::core::utils::merkle_path_from_levels(&levels, chunk_index)
+                                    .unwrap(),
+                            )
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+            build_range_segment(challenge, segment_index, chunks)
+        };
+        let mut challenger = AvailabilityChallenger::new();
+        let challenger_id = Buffer::from([2u8; 32].to_vec());
+
+        assert!(challenger
+            .create_range_challenge(
+                chain_id.clone(),
+                chunk_root.clone(),
+                total_chunks,
+                30,
+                41,
+                challenger_id.clone()
+            )
+            .is_err());
+
+        // 3..40 streams as segments of 16, 16 and 5 chunks
+        let challenge = challenger
+            .create_range_challenge(
+                chain_id.clone(),
+                chunk_root.clone(),
+                total_chunks,
+                3,
+                40,
+                challenger_id.clone(),
+            )
+            .unwrap();
+        assert_eq!(challenge.segment_deadlines.len(), 3);
+        let challenge_id = hex::encode(compute_range_challenge_id_hash(&challenge));
+
+        let first = respond(&challenge, 0).unwrap();
+        assert_eq!(first.chunks.len(), 16);
+        assert_eq!(
+            challenger.process_segment(&first).unwrap(),
+            SegmentResult::Accepted {
+                remaining_segments: 2
+            }
+        );
+        assert!(challenger.process_segment(&first).is_err());
+
+        // Tampered data or a chunk served under another index does not verify
+        let last = respond(&challenge, 2).unwrap();
+        assert_eq!(last.chunks.len(), 5);
+        let mut tampered = last.clone();
+        tampered.chunks[1].chunk_data = Buffer::from(vec![0u8; CHUNK_SIZE_BYTES as usize]);
+        assert!(!verify_response_segment(&challenge, &tampered));
+        let mut misplaced = last.clone();
+        misplaced.chunks[1].chunk_index = misplaced.chunks[0].chunk_index;
+        misplaced.chunks[0] = last.chunks[1].clone();
+        assert!(!verify_response_segment(&challenge, &misplaced));
+
+        // Segments may arrive out of order; the audit completes with the last one
+        assert_eq!(
+            challenger.process_segment(&last).unwrap(),
+            SegmentResult::Accepted {
+                remaining_segments: 1
+            }
+        );
+        assert_eq!(
+            challenger.outstanding_segments(&challenge_id),
+            Some(vec![1])
+        );
+        let middle = respond(&challenge, 1).unwrap();
+        assert_eq!(
+            challenger.process_segment(&middle).unwrap(),
+            SegmentResult::Complete
+        );
+        assert!(challenger.outstanding_segments(&challenge_id).is_none());
+        assert!(respond(&challenge, 3).is_err());
+
+        // An outstanding segment past its deadline fails the audit
+        let late = challenger
+            .create_range_challenge(
+                chain_id.clone(),
+                chunk_root.clone(),
+                total_chunks,
+                0,
+                40,
+                challenger_id.clone(),
+            )
+            .unwrap();
+        let mut segment = respond(&late, 1).unwrap();
+        segment.response_time = late.segment_deadlines[0] + 0.001;
+        assert_eq!(
+            challenger.process_segment(&segment).unwrap(),
+            SegmentResult::Timeout
+        );
+
+        let stalled = challenger
+            .create_range_challenge(chain_id, chunk_root, total_chunks, 0, 40, challenger_id)
+            .unwrap();
+        let segment = respond(&stalled, 0).unwrap();
+        assert!(matches!(
+            challenger.process_segment(&segment).unwrap(),
+            SegmentResult::Accepted { .. }
+        ));
+        let expired = challenger
+            .cleanup_expired_challenges_at(stalled.segment_deadlines[1] + 1.0)
+            .unwrap();
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].outcome, ExpiredChallengeOutcome::SegmentOverdue);
+        assert!(challenger
+            .outstanding_segments(&hex::encode(compute_range_challenge_id_hash(&stalled)))
+            .is_none());
+    }
+
+    #[test]
+    fn test_failure_policy_tolerates_transient_failures() {
+        let mut scorer = AvailabilityScorer::new();
+        scorer.set_failure_policy(ChallengeFailurePolicy {
+            tolerated_failures: 1,
+            window: 4,
+        });
+
+        // One failure is tolerated but still lowers the score
+        scorer.record_failure("chain");
+        scorer.record_success("chain");
+        assert!(!scorer.is_slashable("chain"));
+        assert!(scorer.get_score("chain") < 1.0);
+
+        // A second failure within the window escalates
+        scorer.record_maintenance("chain");
+        scorer.record_failure("chain");
+        assert_eq!(scorer.get_recent_failure_count("chain"), 2);
+        assert!(scorer.is_slashable("chain"));
+
+        // Old failures slide out of the window
+        scorer.record_success("chain");
+        assert_eq!(scorer.get_recent_failure_count("chain"), 1);
+        assert!(!scorer.is_slashable("chain"));
+        assert_eq!(scorer.get_failure_count("chain"), 2);
+
+        // Shrinking the window trims recorded outcomes
+        scorer.set_failure_policy(ChallengeFailurePolicy {
+            tolerated_failures: 0,
+            window: 1,
+        });
+        assert!(!scorer.is_slashable("chain"));
+        scorer.record_failure("chain");
+        assert!(scorer.is_slashable("chain"));
+    }
+
+    #[test]
+    fn test_challenge_evidence_export() {
+        let chain_id = Buffer::from([11u8; 32].to_vec());
+        let data = vec![3u8; CHUNK_SIZE_BYTES as usize * 16];
+        let mut prover = AvailabilityProver::new();
+        prover.register_chain_with_backend(
+            hex::encode(&chain_id),
+            Box::new(crate::core::storage_backend::MemoryBackend::new(data)),
+        );
+
+        let mut challenger = AvailabilityChallenger::new();
+        let challenger_id = Buffer::from([2u8; 32].to_vec());
+        let mut heights = 0..1000u64;
+        let mut next_challenge = |challenger: &mut AvailabilityChallenger| {
+            heights
+                .find_map(|height| {
+                    challenger
+                        .create_challenge(chain_id.clone(), 16, challenger_id.clone(), height)
+                        .unwrap()
+                })
+                .unwrap()
+        };
+
+        // Answered challenges are recorded when processed
+        let answered = next_challenge(&mut challenger);
+        let response = prover.respond_to_challenge(&answered).unwrap();
+        let answered_id = hex::encode(&response.challenge_id);
+        challenger
+            .process_response(answered_id.clone(), response)
+            .unwrap();
+        let evidence = challenger.export_challenge_evidence(&answered_id).unwrap();
+        assert_eq!(evidence.outcome, "success");
+        assert!(verify_challenge_evidence(&evidence, false));
+
+        // Claiming a different outcome breaks the hash, and rehashing it still fails
+        let mut disputed = evidence.clone();
+        disputed.outcome = "invalid_data".to_string();
+        assert!(!verify_challenge_evidence(&disputed, false));
+        disputed.evidence_hash = Buffer::from(
+            compute_evidence_hash(
+                &disputed.challenge_id,
+                &disputed.challenge,
+                disputed.response.as_ref(),
+                &disputed.outcome,
+                disputed.recorded_at,
+            )
+            .to_vec(),
+        );
+        assert!(!verify_challenge_evidence(&disputed, false));
+
+        // Unanswered challenges are recorded when cleanup expires them
+        let unanswered = next_challenge(&mut challenger);
+        let unanswered_id = hex::encode(compute_challenge_id_hash(&unanswered));
+        assert!(challenger
+            .export_challenge_evidence(&unanswered_id)
+            .is_err());
+        challenger
+            .cleanup_expired_challenges_at(unanswered.deadline + 1.0)
+            .unwrap();
+        let evidence = challenger
+            .export_challenge_evidence(&unanswered_id)
+            .unwrap();
+        assert_eq!(evidence.outcome, "unanswered");
+        assert!(evidence.response.is_none());
+        assert!(verify_challenge_evidence(&evidence, false));
+
+        // Records are dropped once the retention window passes
+        challenger.set_evidence_retention(60.0);
+        challenger
+            .cleanup_expired_challenges_at(evidence.recorded_at + 61.0)
+            .unwrap();
+        assert!(challenger
+            .export_challenge_evidence(&unanswered_id)
+            .is_err());
+    }
+
+    #[test]
+    fn test_signed_challenge_evidence() {
+        use crate::core::signer::SoftwareSigner;
+        use crate::core::utils::derive_public_key;
+
+        let chain_id = Buffer::from([12u8; 32].to_vec());
+        let mut prover = AvailabilityProver::new();
+        prover.register_chain_with_backend(
+            hex::encode(&chain_id),
+            Box::new(crate::core::storage_backend::MemoryBackend::new(vec![
+                4u8;
+                CHUNK_SIZE_BYTES as usize
+                    * 16
+            ])),
+        );
+        let prover_private_key = [6u8; 32];
+        let prover_key = derive_public_key(&prover_private_key).unwrap();
+        let challenger_private_key = [5u8; 32];
+        let challenger_key = derive_public_key(&challenger_private_key).unwrap();
+
+        let mut challenger = AvailabilityChallenger::new();
+        let challenge = (0..1000u64)
+            .find_map(|height| {
+                challenger
+                    .create_challenge(
+                        chain_id.clone(),
+                        16,
+                        Buffer::from(challenger_key.to_vec()),
+                        height,
+                    )
+                    .unwrap()
+            })
+            .unwrap();
+        let mut response = prover.respond_to_challenge(&challenge).unwrap();
+        sign_availability_response(
+            &mut response,
+            &prover_key,
+            &SoftwareSigner::new(prover_private_key.to_vec()),
+        )
+        .unwrap();
+        assert!(verify_availability_response_signature(&response));
+        let challenge_id = hex::encode(&response.challenge_id);
+        challenger
+            .process_response(challenge_id.clone(), response)
+            .unwrap();
+
+        // Unsigned records pass only when signatures are not required
+        let mut evidence = challenger.export_challenge_evidence(&challenge_id).unwrap();
+        assert!(verify_challenge_evidence(&evidence, false));
+        assert!(!verify_challenge_evidence(&evidence, true));
+
+        sign_challenge_evidence(&mut evidence, &challenger_private_key).unwrap();
+        assert!(verify_challenge_evidence(&evidence, true));
+
+        // A signature by another key, or a tampered prover signature, is rejected
+        let mut forged = evidence.clone();
+        sign_challenge_evidence(&mut forged, &[9u8; 32]).unwrap();
+        assert!(!verify_challenge_evidence(&forged, false));
+        let mut tampered = evidence.clone();
+        let response = tampered.response.as_mut().unwrap();
+        response.response_time += 1.0;
+        tampered.evidence_hash = Buffer::from(
+            compute_evidence_hash(
+                &tampered.challenge_id,
+                &tampered.challenge,
+                tampered.response.as_ref(),
+                &tampered.outcome,
+                tampered.recorded_at,
+            )
+            .to_vec(),
+        );
+        sign_challenge_evidence(&mut tampered, &challenger_private_key).unwrap();
+        assert!(!verify_challenge_evidence(&tampered, false));
+    }
+
     #[test]
     fn test_challenge_stats() {
         let challenger = AvailabilityChallenger::new();
@@ -599,4 +2552,62 @@ mod tests {
         );
         assert_eq!(stats.response_timeout_ms, AVAILABILITY_RESPONSE_TIME_MS);
     }
+
+    #[test]
+    fn test_challenge_selection_depends_on_beacon() {
+        let challenger_id = Buffer::from([2u8; 32].to_vec());
+        let block_height = 300;
+
+        // Chains challenged without a beacon versus with one: the beacon changes the
+        // selection, so it cannot be computed before the block exists
+        let selected = |beacon: Option<[u8; 32]>| -> Vec<u8> {
+            let mut challenger = AvailabilityChallenger::new();
+            if let Some(beacon) = beacon {
+                challenger.set_challenge_beacon(block_height, beacon);
+            }
+            (0..=255u8)
+                .filter(|seed| {
+                    challenger
+                        .create_challenge(
+                            Buffer::from([*seed; 32].to_vec()),
+                            1000,
+                            challenger_id.clone(),
+                            block_height,
+                        )
+                        .unwrap()
+                        .is_some()
+                })
+                .collect()
+        };
+        assert_ne!(selected(None), selected(Some([7u8; 32])));
+        assert_eq!(selected(Some([7u8; 32])), selected(Some([7u8; 32])));
+
+        // A challenge carries its beacon and can be rechecked afterwards
+        let mut challenger = AvailabilityChallenger::new();
+        challenger.set_challenge_beacon(block_height, [7u8; 32]);
+        let seed = selected(Some([7u8; 32]))[0];
+        let challenge = challenger
+            .create_challenge(
+                Buffer::from([seed; 32].to_vec()),
+                1000,
+                challenger_id.clone(),
+                block_height,
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(challenge.selection_beacon.as_deref(), Some(&[7u8; 32][..]));
+        assert!(challenger.verify_challenge_selection(&challenge, block_height, 1000));
+
+        // A swapped beacon or chunk does not verify
+        let mut forged = challenge.clone();
+        forged.chunk_index = (forged.chunk_index + 1) % 1000;
+        assert!(!challenger.verify_challenge_selection(&forged, block_height, 1000));
+        let mut forged = challenge.clone();
+        forged.selection_beacon = Some(Buffer::from([8u8; 32].to_vec()));
+        assert!(!challenger.verify_challenge_selection(&forged, block_height, 1000));
+
+        // The beacon only applies at its own height
+        challenger.set_challenge_beacon(block_height + 1, [7u8; 32]);
+        assert!(challenger.beacon_for(block_height).is_none());
+    }
 }
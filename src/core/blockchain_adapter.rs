@@ -0,0 +1,80 @@
+use std::collections::BTreeMap;
+
+use crate::core::errors::{HashChainError, HashChainResult};
+
+/// Native blockchain access for verification, used by Rust nodes instead of
+/// marshaling every query through the JavaScript callbacks
+pub trait BlockchainAdapter: Send {
+    /// Hash of the block at `block_height`
+    fn get_block_hash(&self, block_height: u64) -> HashChainResult<[u8; 32]>;
+
+    /// Current blockchain height
+    fn get_current_height(&self) -> HashChainResult<u64>;
+
+    /// Whether `block_hash` is the hash of the block at `block_height`
+    fn validate_block_hash(&self, block_height: u64, block_hash: &[u8]) -> HashChainResult<bool> {
+        Ok(self.get_block_hash(block_height)?.as_slice() == block_hash)
+    }
+}
+
+/// In-memory blockchain for tests and deterministic simulations
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryBlockchain {
+    blocks: BTreeMap<u64, [u8; 32]>,
+}
+
+impl InMemoryBlockchain {
+    /// Create an empty chain
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the hash of the block at `block_height`
+    pub fn add_block(&mut self, block_height: u64, block_hash: [u8; 32]) {
+        self.blocks.insert(block_height, block_hash);
+    }
+}
+
+impl BlockchainAdapter for InMemoryBlockchain {
+    fn get_block_hash(&self, block_height: u64) -> HashChainResult<[u8; 32]> {
+        self.blocks.get(&block_height).copied().ok_or_else(|| {
+            HashChainError::InvalidProofParameters {
+                reason: format!("Unknown block height {}", block_height),
+            }
+        })
+    }
+
+    fn get_current_height(&self) -> HashChainResult<u64> {
+        Ok(self.blocks.keys().next_back().copied().unwrap_or(0))
+    }
+
+    fn validate_block_hash(&self, block_height: u64, block_hash: &[u8]) -> HashChainResult<bool> {
+        Ok(self
+            .blocks
+            .get(&block_height)
+            .is_some_and(|hash| hash.as_slice() == block_hash))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_memory_blockchain_adapter() {
+        let mut chain = InMemoryBlockchain::new();
+        assert_eq!(chain.get_current_height().unwrap(), 0);
+
+        chain.add_block(1, [1u8; 32]);
+        chain.add_block(2, [2u8; 32]);
+
+        let adapter: &dyn BlockchainAdapter = &chain;
+        assert_eq!(adapter.get_current_height().unwrap(), 2);
+        assert_eq!(adapter.get_block_hash(1).unwrap(), [1u8; 32]);
+        assert!(adapter.get_block_hash(3).is_err());
+
+        assert!(adapter.validate_block_hash(2, &[2u8; 32]).unwrap());
+        assert!(!adapter.validate_block_hash(2, &[1u8; 32]).unwrap());
+        assert!(!adapter.validate_block_hash(3, &[2u8; 32]).unwrap());
+    }
+}
@@ -0,0 +1,174 @@
+use napi::bindgen_prelude::Buffer;
+
+use crate::core::errors::{HashChainError, HashChainResult};
+
+/// Byte order of the numbers in a binary format
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ByteOrder {
+    Big,
+    Little,
+}
+
+/// Bounds-checked cursor over serialized bytes. Truncated or corrupt input fails
+/// with a serialization error naming `format` instead of panicking.
+pub struct ByteReader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    order: ByteOrder,
+    format: &'static str,
+}
+
+impl<'a> ByteReader<'a> {
+    /// Read `bytes`, a `format` (e.g. "prover state") with numbers in `order`
+    pub fn new(bytes: &'a [u8], order: ByteOrder, format: &'static str) -> Self {
+        Self {
+            bytes,
+            offset: 0,
+            order,
+            format,
+        }
+    }
+
+    /// Bytes not yet read
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() - self.offset
+    }
+
+    /// Next `len` bytes
+    pub fn take(&mut self, len: usize) -> HashChainResult<&'a [u8]> {
+        let end = self
+            .offset
+            .checked_add(len)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or_else(|| {
+                HashChainError::Serialization(format!(
+                    "Truncated {}: needed {} bytes at offset {}, have {}",
+                    self.format,
+                    len,
+                    self.offset,
+                    self.bytes.len()
+                ))
+            })?;
+        let slice = &self.bytes[self.offset..end];
+        self.offset = end;
+        Ok(slice)
+    }
+
+    fn array<const N: usize>(&mut self) -> HashChainResult<[u8; N]> {
+        Ok(self.take(N)?.try_into().unwrap())
+    }
+
+    pub fn u32(&mut self) -> HashChainResult<u32> {
+        let bytes = self.array()?;
+        Ok(match self.order {
+            ByteOrder::Big => u32::from_be_bytes(bytes),
+            ByteOrder::Little => u32::from_le_bytes(bytes),
+        })
+    }
+
+    pub fn u64(&mut self) -> HashChainResult<u64> {
+        let bytes = self.array()?;
+        Ok(match self.order {
+            ByteOrder::Big => u64::from_be_bytes(bytes),
+            ByteOrder::Little => u64::from_le_bytes(bytes),
+        })
+    }
+
+    pub fn f64(&mut self) -> HashChainResult<f64> {
+        let bytes = self.array()?;
+        Ok(match self.order {
+            ByteOrder::Big => f64::from_be_bytes(bytes),
+            ByteOrder::Little => f64::from_le_bytes(bytes),
+        })
+    }
+
+    /// 32-byte hash
+    pub fn hash(&mut self) -> HashChainResult<[u8; 32]> {
+        self.array()
+    }
+
+    /// Next `len` bytes copied into a buffer
+    pub fn buffer(&mut self, len: usize) -> HashChainResult<Buffer> {
+        Ok(Buffer::from(self.take(len)?.to_vec()))
+    }
+
+    /// u32 length-prefixed bytes
+    pub fn bytes(&mut self) -> HashChainResult<&'a [u8]> {
+        let len = self.u32()? as usize;
+        self.take(len)
+    }
+
+    /// u32 length-prefixed UTF-8 string
+    pub fn string(&mut self) -> HashChainResult<String> {
+        String::from_utf8(self.bytes()?.to_vec()).map_err(|e| {
+            HashChainError::Serialization(format!("Invalid UTF-8 in {}: {}", self.format, e))
+        })
+    }
+
+    /// Presence byte of an optional field
+    pub fn flag(&mut self) -> HashChainResult<bool> {
+        match self.take(1)?[0] {
+            0 => Ok(false),
+            1 => Ok(true),
+            other => Err(HashChainError::Serialization(format!(
+                "Invalid presence flag {} in {}",
+                other, self.format
+            ))),
+        }
+    }
+
+    /// u32 element count, bounded by the bytes left so a corrupt count cannot
+    /// trigger a huge allocation. `element_size` is the least each element takes.
+    pub fn count(&mut self, element_size: usize) -> HashChainResult<usize> {
+        let count = self.u32()? as usize;
+        if count > self.remaining() / element_size {
+            return Err(HashChainError::Serialization(format!(
+                "{} claims {} elements of at least {} bytes in {} remaining bytes",
+                self.format,
+                count,
+                element_size,
+                self.remaining()
+            )));
+        }
+        Ok(count)
+    }
+
+    /// Fail unless every byte has been read
+    pub fn finish(&self) -> HashChainResult<()> {
+        if self.remaining() != 0 {
+            return Err(HashChainError::Serialization(format!(
+                "Trailing bytes after {}",
+                self.format
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_byte_reader_bounds_and_order() {
+        let bytes = [0u8, 0, 0, 2, 0xAA, 0xBB, 1, 7];
+
+        let mut big = ByteReader::new(&bytes, ByteOrder::Big, "test data");
+        assert_eq!(big.u32().unwrap(), 2);
+        assert_eq!(big.take(2).unwrap(), &[0xAA, 0xBB]);
+        assert!(big.flag().unwrap());
+        assert!(big.finish().is_err());
+        assert!(matches!(big.flag(), Err(HashChainError::Serialization(_))));
+        assert!(big.u32().is_err());
+
+        let mut little = ByteReader::new(&bytes, ByteOrder::Little, "test data");
+        assert_eq!(little.u32().unwrap(), 2 << 24);
+        assert_eq!(little.remaining(), 4);
+
+        // Counts are bounded by the bytes left
+        let mut counted = ByteReader::new(&bytes, ByteOrder::Big, "test data");
+        assert_eq!(counted.count(2).unwrap(), 2);
+        let mut counted = ByteReader::new(&bytes, ByteOrder::Big, "test data");
+        assert!(counted.count(4).is_err());
+    }
+}
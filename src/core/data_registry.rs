@@ -0,0 +1,88 @@
+use std::collections::BTreeMap;
+
+/// Provers claiming each data hash, in registration order. The same data claimed by
+/// several provers is either legitimate replication or a Sybil double-claiming
+/// rewards; the registry only surfaces it and leaves the policy to the network.
+#[derive(Default)]
+pub struct DataRegistry {
+    /// Prover keys by data hash, first claimant first
+    claims: BTreeMap<Vec<u8>, Vec<Vec<u8>>>,
+}
+
+impl DataRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record that `prover_key` claims the data with `data_hash`. Returns false if
+    /// the prover had already claimed it.
+    pub fn register(&mut self, data_hash: &[u8], prover_key: &[u8]) -> bool {
+        let claimants = self.claims.entry(data_hash.to_vec()).or_default();
+        if claimants.iter().any(|key| key.as_slice() == prover_key) {
+            return false;
+        }
+        claimants.push(prover_key.to_vec());
+        true
+    }
+
+    /// Drop every claim made by `prover_key`, returning how many were dropped
+    pub fn remove_prover(&mut self, prover_key: &[u8]) -> usize {
+        let mut removed = 0;
+        self.claims.retain(|_, claimants| {
+            let before = claimants.len();
+            claimants.retain(|key| key.as_slice() != prover_key);
+            removed += before - claimants.len();
+            !claimants.is_empty()
+        });
+        removed
+    }
+
+    /// Provers claiming `data_hash`, first claimant first
+    pub fn claimants(&self, data_hash: &[u8]) -> &[Vec<u8>] {
+        self.claims
+            .get(data_hash)
+            .map_or(&[], |claimants| claimants)
+    }
+
+    /// Data hashes claimed by more than one prover, ordered by data hash, each with
+    /// its claimants in registration order
+    pub fn duplicates(&self) -> Vec<(Vec<u8>, Vec<Vec<u8>>)> {
+        self.claims
+            .iter()
+            .filter(|(_, claimants)| claimants.len() > 1)
+            .map(|(data_hash, claimants)| (data_hash.clone(), claimants.clone()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duplicate_data_registrations() {
+        let mut registry = DataRegistry::new();
+        assert!(registry.register(&[1u8; 32], &[0xaa; 32]));
+        assert!(registry.register(&[2u8; 32], &[0xaa; 32]));
+        assert!(registry.register(&[1u8; 32], &[0xbb; 32]));
+        assert!(registry.register(&[1u8; 32], &[0xcc; 32]));
+
+        // Re-registering the same claim is not a second claimant
+        assert!(!registry.register(&[1u8; 32], &[0xbb; 32]));
+
+        let duplicates = registry.duplicates();
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates[0].0, vec![1u8; 32]);
+        assert_eq!(
+            duplicates[0].1,
+            vec![vec![0xaa; 32], vec![0xbb; 32], vec![0xcc; 32]]
+        );
+
+        // Removing a prover drops its claims; a single claimant is no duplicate
+        assert_eq!(registry.remove_prover(&[0xaa; 32]), 2);
+        assert!(registry.claimants(&[2u8; 32]).is_empty());
+        assert_eq!(registry.claimants(&[1u8; 32])[0], vec![0xbb; 32]);
+        registry.remove_prover(&[0xcc; 32]);
+        assert!(registry.duplicates().is_empty());
+    }
+}
@@ -0,0 +1,150 @@
+use std::collections::BTreeMap;
+
+use crate::core::types::{
+    NetworkNode, PROVER_DISCOVERY_MIN_INTERVAL_SECONDS, PROVER_DISCOVERY_TTL_SECONDS,
+};
+
+/// Verifier-side cache of discovered provers. Discovery results are deduplicated by
+/// node key and expire after a TTL; refreshes are rate-limited, and a refresh in
+/// flight can be cancelled so its results are discarded.
+pub struct ProverDiscovery {
+    /// Provers by hex node key, with the time each was last discovered
+    provers: BTreeMap<String, (NetworkNode, f64)>,
+    ttl_seconds: f64,
+    min_interval_seconds: f64,
+    last_refresh: Option<f64>,
+    /// Bumped by every refresh start and cancellation; stale refreshes are ignored
+    generation: u64,
+}
+
+impl Default for ProverDiscovery {
+    fn default() -> Self {
+        Self::new(
+            PROVER_DISCOVERY_TTL_SECONDS,
+            PROVER_DISCOVERY_MIN_INTERVAL_SECONDS,
+        )
+    }
+}
+
+impl ProverDiscovery {
+    pub fn new(ttl_seconds: f64, min_interval_seconds: f64) -> Self {
+        Self {
+            provers: BTreeMap::new(),
+            ttl_seconds,
+            min_interval_seconds,
+            last_refresh: None,
+            generation: 0,
+        }
+    }
+
+    /// Whether the rate limit allows calling the discovery source at `now`
+    pub fn can_refresh(&self, now: f64) -> bool {
+        self.last_refresh
+            .is_none_or(|last| now - last >= self.min_interval_seconds)
+    }
+
+    /// Start a refresh, returning the token its results must be completed with
+    pub fn begin_refresh(&mut self, now: f64) -> u64 {
+        self.last_refresh = Some(now);
+        self.generation += 1;
+        self.generation
+    }
+
+    /// Cancel the refresh in flight; its results will be discarded
+    pub fn cancel(&mut self) {
+        self.generation += 1;
+    }
+
+    /// Record a refresh's discovered nodes, keeping only provers and the most recently
+    /// active entry per key. Returns false if the refresh was cancelled or superseded.
+    pub fn complete_refresh(&mut self, token: u64, nodes: Vec<NetworkNode>, now: f64) -> bool {
+        if token != self.generation {
+            return false;
+        }
+        for node in nodes {
+            if !matches!(node.node_type.as_str(), "prover" | "both") {
+                continue;
+            }
+            let key = hex::encode(node.node_key.as_ref());
+            let newer = self
+                .provers
+                .get(&key)
+                .is_none_or(|(cached, _)| node.last_activity >= cached.last_activity);
+            if newer {
+                self.provers.insert(key, (node, now));
+            }
+        }
+        self.prune_expired(now);
+        true
+    }
+
+    /// Drop provers not rediscovered within the TTL
+    pub fn prune_expired(&mut self, now: f64) {
+        let ttl_seconds = self.ttl_seconds;
+        self.provers
+            .retain(|_, (_, discovered_at)| now - *discovered_at < ttl_seconds);
+    }
+
+    /// Provers discovered within the TTL, ordered by node key
+    pub fn cached_provers(&self, now: f64) -> Vec<NetworkNode> {
+        self.provers
+            .values()
+            .filter(|(_, discovered_at)| now - *discovered_at < self.ttl_seconds)
+            .map(|(node, _)| node.clone())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use napi::bindgen_prelude::Buffer;
+
+    fn node(key: u8, node_type: &str, last_activity: f64) -> NetworkNode {
+        NetworkNode {
+            node_key: Buffer::from(vec![key; 32]),
+            node_type: node_type.to_string(),
+            reputation: 1.0,
+            last_activity,
+            position: Buffer::from(vec![0u8; 32]),
+        }
+    }
+
+    #[test]
+    fn test_prover_discovery_cache() {
+        let mut discovery = ProverDiscovery::new(100.0, 10.0);
+        assert!(discovery.can_refresh(0.0));
+
+        // Duplicates keep the most recent activity; verifiers are not provers
+        let token = discovery.begin_refresh(0.0);
+        assert!(!discovery.can_refresh(5.0));
+        assert!(discovery.complete_refresh(
+            token,
+            vec![
+                node(1, "prover", 10.0),
+                node(1, "both", 20.0),
+                node(1, "prover", 15.0),
+                node(2, "verifier", 10.0),
+                node(3, "both", 10.0),
+            ],
+            0.0,
+        ));
+        let cached = discovery.cached_provers(1.0);
+        assert_eq!(cached.len(), 2);
+        assert_eq!(cached[0].last_activity, 20.0);
+
+        // A cancelled refresh's results are discarded
+        assert!(discovery.can_refresh(10.0));
+        let token = discovery.begin_refresh(10.0);
+        discovery.cancel();
+        assert!(!discovery.complete_refresh(token, vec![node(4, "prover", 10.0)], 10.0));
+        assert_eq!(discovery.cached_provers(11.0).len(), 2);
+
+        // Provers not rediscovered within the TTL expire
+        let token = discovery.begin_refresh(60.0);
+        assert!(discovery.complete_refresh(token, vec![node(3, "prover", 60.0)], 60.0));
+        let cached = discovery.cached_provers(120.0);
+        assert_eq!(cached.len(), 1);
+        assert_eq!(cached[0].node_key.as_ref(), &[3u8; 32]);
+    }
+}
@@ -0,0 +1,183 @@
+use crate::core::{
+    errors::{HashChainError, HashChainResult},
+    types::ErasureCodingParams,
+};
+
+/// Reducing polynomial for GF(2^8) arithmetic (x^8 + x^4 + x^3 + x^2 + 1)
+const GF_POLYNOMIAL: u16 = 0x11d;
+
+/// Exponent and logarithm tables for GF(2^8), built at compile time
+struct GaloisTables {
+    exp: [u8; 512],
+    log: [u8; 256],
+}
+
+const GALOIS_TABLES: GaloisTables = build_galois_tables();
+
+const fn build_galois_tables() -> GaloisTables {
+    let mut exp = [0u8; 512];
+    let mut log = [0u8; 256];
+    let mut value: u16 = 1;
+    let mut i = 0;
+    while i < 255 {
+        exp[i] = value as u8;
+        log[value as usize] = i as u8;
+        value <<= 1;
+        if value & 0x100 != 0 {
+            value ^= GF_POLYNOMIAL;
+        }
+        i += 1;
+    }
+    // Duplicate so products can index exp[log a + log b] without a modulo
+    while i < 512 {
+        exp[i] = exp[i - 255];
+        i += 1;
+    }
+    GaloisTables { exp, log }
+}
+
+fn gf_mul(a: u8, b: u8) -> u8 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    GALOIS_TABLES.exp
+        [GALOIS_TABLES.log[a as usize] as usize + GALOIS_TABLES.log[b as usize] as usize]
+}
+
+fn gf_inv(a: u8) -> u8 {
+    GALOIS_TABLES.exp[255 - GALOIS_TABLES.log[a as usize] as usize]
+}
+
+/// Cauchy matrix coefficient for parity shard `parity_index` and data shard `data_index`.
+/// Rows and columns use disjoint field elements, so every coefficient is defined.
+fn cauchy_coefficient(params: &ErasureCodingParams, parity_index: u32, data_index: u32) -> u8 {
+    let x = (params.data_shards + parity_index) as u8;
+    let y = data_index as u8;
+    gf_inv(x ^ y)
+}
+
+/// Check erasure coding parameters fit a Reed-Solomon code over GF(2^8)
+pub fn validate_erasure_params(params: &ErasureCodingParams) -> HashChainResult<()> {
+    if params.data_shards == 0 || params.parity_shards == 0 {
+        return Err(HashChainError::InvalidProofParameters {
+            reason: "Erasure coding needs at least one data and one parity shard".to_string(),
+        });
+    }
+    if params.data_shards + params.parity_shards > 256 {
+        return Err(HashChainError::InvalidProofParameters {
+            reason: format!(
+                "Erasure coding supports at most 256 shards per stripe, got {}",
+                params.data_shards + params.parity_shards
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Chunks per stripe: data shards followed by parity shards
+pub fn stripe_size(params: &ErasureCodingParams) -> u32 {
+    params.data_shards + params.parity_shards
+}
+
+/// Chunk indices of stripe `stripe_index`, data shards first
+pub fn stripe_chunks(params: &ErasureCodingParams, stripe_index: u32) -> Vec<u32> {
+    let start = stripe_index * stripe_size(params);
+    (start..start + stripe_size(params)).collect()
+}
+
+/// Systematic Reed-Solomon parity shards for equally sized data shards
+pub fn compute_parity_shards(
+    params: &ErasureCodingParams,
+    data_shards: &[&[u8]],
+) -> HashChainResult<Vec<Vec<u8>>> {
+    validate_erasure_params(params)?;
+    if data_shards.len() != params.data_shards as usize {
+        return Err(HashChainError::InvalidProofParameters {
+            reason: format!(
+                "Expected {} data shards, got {}",
+                params.data_shards,
+                data_shards.len()
+            ),
+        });
+    }
+    let shard_len = data_shards[0].len();
+    if data_shards.iter().any(|shard| shard.len() != shard_len) {
+        return Err(HashChainError::InvalidProofParameters {
+            reason: "Data shards must all be the same length".to_string(),
+        });
+    }
+
+    Ok((0..params.parity_shards)
+        .map(|parity_index| {
+            let mut parity = vec![0u8; shard_len];
+            for (data_index, shard) in data_shards.iter().enumerate() {
+                let coefficient = cauchy_coefficient(params, parity_index, data_index as u32);
+                for (out, &byte) in parity.iter_mut().zip(shard.iter()) {
+                    *out ^= gf_mul(coefficient, byte);
+                }
+            }
+            parity
+        })
+        .collect())
+}
+
+/// Whether a full stripe of raw coded shards (data then parity) is consistent with
+/// the coding parameters, i.e. the parity shards are exactly those of the data shards
+pub fn verify_coded_stripe(params: &ErasureCodingParams, shards: &[&[u8]]) -> bool {
+    if shards.len() != stripe_size(params) as usize {
+        return false;
+    }
+    let (data_shards, parity_shards) = shards.split_at(params.data_shards as usize);
+    match compute_parity_shards(params, data_shards) {
+        Ok(expected) => expected
+            .iter()
+            .zip(parity_shards)
+            .all(|(expected, actual)| expected.as_slice() == *actual),
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coded_stripe_verification() {
+        let params = ErasureCodingParams {
+            data_shards: 4,
+            parity_shards: 2,
+        };
+        let data: Vec<Vec<u8>> = (0..4u8)
+            .map(|shard| (0..64u8).map(|i| i.wrapping_mul(7) ^ shard).collect())
+            .collect();
+        let data_refs: Vec<&[u8]> = data.iter().map(|shard| shard.as_slice()).collect();
+        let parity = compute_parity_shards(&params, &data_refs).unwrap();
+        assert_eq!(parity.len(), 2);
+
+        let mut stripe = data_refs.clone();
+        stripe.extend(parity.iter().map(|shard| shard.as_slice()));
+        assert!(verify_coded_stripe(&params, &stripe));
+
+        // A data shard reconstructed wrongly (or altered) breaks parity consistency
+        let mut altered = data[1].clone();
+        altered[0] ^= 1;
+        stripe[1] = &altered;
+        assert!(!verify_coded_stripe(&params, &stripe));
+
+        // Incomplete stripes and invalid parameters are rejected
+        assert!(!verify_coded_stripe(&params, &stripe[..5]));
+        assert!(validate_erasure_params(&ErasureCodingParams {
+            data_shards: 200,
+            parity_shards: 57,
+        })
+        .is_err());
+        assert_eq!(stripe_chunks(&params, 2), vec![12, 13, 14, 15, 16, 17]);
+    }
+
+    #[test]
+    fn test_galois_field_inverse() {
+        for a in 1..=255u8 {
+            assert_eq!(gf_mul(a, gf_inv(a)), 1);
+        }
+    }
+}
@@ -1,4 +1,3 @@
-use napi::bindgen_prelude::*;
 use thiserror::Error;
 
 /// Comprehensive error handling for HashChain system
@@ -129,56 +128,172 @@ pub enum HashChainError {
 
     #[error("VDF error: {0}")]
     VDFError(String),
+
+    #[error("VDF not ready: {current} of {required} required iterations computed")]
+    VdfNotReady { current: u64, required: u64 },
+
+    #[error("Challenge deadline {deadline:.3} cannot be met: response ready at {ready_at:.3} at the earliest")]
+    ChallengeExpired { deadline: f64, ready_at: f64 },
 }
 
-/// Convert to NAPI error for JavaScript
-impl From<HashChainError> for napi::Error {
-    fn from(err: HashChainError) -> Self {
-        match err {
+impl HashChainError {
+    /// Whether the error may clear on retry (momentary I/O failure) rather than
+    /// being permanent (missing file, bad index, corrupt data)
+    pub fn is_transient(&self) -> bool {
+        match self {
+            HashChainError::Io(e) => matches!(
+                e.kind(),
+                std::io::ErrorKind::Interrupted
+                    | std::io::ErrorKind::WouldBlock
+                    | std::io::ErrorKind::TimedOut
+                    | std::io::ErrorKind::ConnectionReset
+                    | std::io::ErrorKind::ConnectionAborted
+                    | std::io::ErrorKind::BrokenPipe
+                    | std::io::ErrorKind::Other
+            ),
+            _ => false,
+        }
+    }
+}
+
+/// Stable error codes prefixed to NAPI error messages as `"<CODE>: <message>"`,
+/// letting JavaScript callers branch on the code instead of the message text
+pub mod error_codes {
+    pub const E_IO: &str = "E_IO";
+    pub const E_FILE_NOT_FOUND: &str = "E_FILE_NOT_FOUND";
+    pub const E_INVALID_INPUT: &str = "E_INVALID_INPUT";
+    pub const E_CHUNK_OOB: &str = "E_CHUNK_OOB";
+    pub const E_CHUNK_COUNT: &str = "E_CHUNK_COUNT";
+    pub const E_CHAIN_STATE: &str = "E_CHAIN_STATE";
+    pub const E_CHAIN_TOO_SHORT: &str = "E_CHAIN_TOO_SHORT";
+    pub const E_CHAIN_NOT_FOUND: &str = "E_CHAIN_NOT_FOUND";
+    pub const E_NO_ACTIVE_CHAINS: &str = "E_NO_ACTIVE_CHAINS";
+    pub const E_CORRUPTED: &str = "E_CORRUPTED";
+    pub const E_INTEGRITY_FLAGGED: &str = "E_INTEGRITY_FLAGGED";
+    pub const E_CAPACITY: &str = "E_CAPACITY";
+    pub const E_PERFORMANCE: &str = "E_PERFORMANCE";
+    pub const E_VERIFICATION_FAILED: &str = "E_VERIFICATION_FAILED";
+    pub const E_CONSENSUS: &str = "E_CONSENSUS";
+    pub const E_CRYPTO: &str = "E_CRYPTO";
+    pub const E_UNAUTHORIZED: &str = "E_UNAUTHORIZED";
+    pub const E_MAINTENANCE_MODE: &str = "E_MAINTENANCE_MODE";
+    pub const E_VDF_NOT_READY: &str = "E_VDF_NOT_READY";
+    pub const E_VDF_FAILED: &str = "E_VDF_FAILED";
+    pub const E_CHALLENGE_EXPIRED: &str = "E_CHALLENGE_EXPIRED";
+    pub const E_INTERNAL: &str = "E_INTERNAL";
+}
+
+impl HashChainError {
+    /// Stable error code for this variant (see [`error_codes`])
+    pub fn code(&self) -> &'static str {
+        use error_codes::*;
+        match self {
+            HashChainError::Io(_) => E_IO,
+            HashChainError::FileNotFound { .. } => E_FILE_NOT_FOUND,
+
             HashChainError::InvalidPublicKeySize(_)
+            | HashChainError::InvalidPrivateKeySize(_)
+            | HashChainError::InvalidSignatureSize(_)
             | HashChainError::InvalidBlockHashSize(_)
             | HashChainError::InvalidBlockHeight(_)
-            | HashChainError::ChunkIndexOutOfRange { .. }
-            | HashChainError::TooManyChunks { .. }
-            | HashChainError::TooFewChunks { .. } => {
-                napi::Error::new(napi::Status::InvalidArg, err.to_string())
+            | HashChainError::InvalidProofParameters { .. } => E_INVALID_INPUT,
+
+            HashChainError::ChunkIndexOutOfRange { .. } => E_CHUNK_OOB,
+            HashChainError::TooManyChunks { .. } | HashChainError::TooFewChunks { .. } => {
+                E_CHUNK_COUNT
             }
 
             HashChainError::AlreadyHasData
             | HashChainError::NoDataStreamed
-            | HashChainError::InsufficientChainLength(_)
-            | HashChainError::ChainTooShort { .. } => {
-                napi::Error::new(napi::Status::InvalidArg, err.to_string())
+            | HashChainError::ChainLifecycle { .. }
+            | HashChainError::RetentionPolicy { .. } => E_CHAIN_STATE,
+            HashChainError::InsufficientChainLength(_) | HashChainError::ChainTooShort { .. } => {
+                E_CHAIN_TOO_SHORT
             }
-
-            HashChainError::Io(_) => {
-                napi::Error::new(napi::Status::GenericFailure, err.to_string())
-            }
-
-            HashChainError::FileNotFound { .. } => {
-                napi::Error::new(napi::Status::GenericFailure, err.to_string())
+            HashChainError::ChainNotFound { .. } => E_CHAIN_NOT_FOUND,
+
+            HashChainError::FileFormat(_)
+            | HashChainError::Corruption(_)
+            | HashChainError::Serialization(_) => E_CORRUPTED,
+
+            HashChainError::GroupFull { .. }
+            | HashChainError::RegionFull { .. }
+            | HashChainError::ScaleLimit { .. }
+            | HashChainError::GroupAssignment { .. } => E_CAPACITY,
+            HashChainError::PerformanceTarget { .. } => E_PERFORMANCE,
+
+            HashChainError::VerificationFailed { .. }
+            | HashChainError::AuditFailed { .. }
+            | HashChainError::CompactProof { .. }
+            | HashChainError::MerkleTree(_) => E_VERIFICATION_FAILED,
+            HashChainError::Consensus(_) => E_CONSENSUS,
+
+            HashChainError::CryptographicError(_)
+            | HashChainError::EntropyGenerationFailed { .. }
+            | HashChainError::KeyDerivationFailed { .. } => E_CRYPTO,
+
+            HashChainError::VdfNotReady { .. } => E_VDF_NOT_READY,
+            HashChainError::VDFError(_) | HashChainError::VDFVerificationFailed { .. } => {
+                E_VDF_FAILED
             }
+            HashChainError::ChallengeExpired { .. } => E_CHALLENGE_EXPIRED,
 
-            HashChainError::FileFormat(_) | HashChainError::Corruption(_) => {
-                napi::Error::new(napi::Status::InvalidArg, err.to_string())
-            }
+            HashChainError::HierarchicalProofFailed { .. }
+            | HashChainError::ParallelProcessing { .. }
+            | HashChainError::ChainRegistry { .. }
+            | HashChainError::GlobalState { .. } => E_INTERNAL,
+        }
+    }
 
-            HashChainError::ChainNotFound { .. }
+    /// NAPI status reported to JavaScript for this variant
+    fn napi_status(&self) -> napi::Status {
+        match self {
+            HashChainError::InvalidPublicKeySize(_)
+            | HashChainError::InvalidBlockHashSize(_)
+            | HashChainError::InvalidBlockHeight(_)
+            | HashChainError::ChunkIndexOutOfRange { .. }
+            | HashChainError::TooManyChunks { .. }
+            | HashChainError::TooFewChunks { .. }
+            | HashChainError::AlreadyHasData
+            | HashChainError::NoDataStreamed
+            | HashChainError::InsufficientChainLength(_)
+            | HashChainError::ChainTooShort { .. }
+            | HashChainError::FileFormat(_)
+            | HashChainError::Corruption(_)
+            | HashChainError::ChainNotFound { .. }
             | HashChainError::GroupFull { .. }
-            | HashChainError::RegionFull { .. } => {
-                napi::Error::new(napi::Status::InvalidArg, err.to_string())
-            }
+            | HashChainError::RegionFull { .. }
+            | HashChainError::AuditFailed { .. }
+            | HashChainError::CompactProof { .. }
+            | HashChainError::InvalidProofParameters { .. } => napi::Status::InvalidArg,
 
-            HashChainError::PerformanceTarget { .. } | HashChainError::ScaleLimit { .. } => {
-                napi::Error::new(napi::Status::GenericFailure, err.to_string())
-            }
+            _ => napi::Status::GenericFailure,
+        }
+    }
 
-            HashChainError::AuditFailed { .. } | HashChainError::CompactProof { .. } => {
-                napi::Error::new(napi::Status::InvalidArg, err.to_string())
-            }
+    /// Convert to a coded NAPI error, prefixing the message with `context`
+    pub fn into_napi_error(self, context: impl std::fmt::Display) -> napi::Error {
+        coded_error(
+            self.napi_status(),
+            self.code(),
+            format!("{}: {}", context, self),
+        )
+    }
+}
 
-            _ => napi::Error::new(napi::Status::GenericFailure, err.to_string()),
-        }
+/// Build a NAPI error whose message carries a stable error code
+pub fn coded_error(
+    status: napi::Status,
+    code: &str,
+    message: impl std::fmt::Display,
+) -> napi::Error {
+    napi::Error::new(status, format!("{}: {}", code, message))
+}
+
+/// Convert to NAPI error for JavaScript
+impl From<HashChainError> for napi::Error {
+    fn from(err: HashChainError) -> Self {
+        coded_error(err.napi_status(), err.code(), &err)
     }
 }
 
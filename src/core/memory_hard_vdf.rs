@@ -1,7 +1,10 @@
 use napi::bindgen_prelude::*;
 use std::time::Instant;
 
-use crate::core::{types::*, utils::compute_sha256};
+use crate::core::{
+    types::*,
+    utils::{combine_entropy_sources, compute_sha256},
+};
 
 /// Memory-hard VDF implementation for ASIC resistance
 /// Uses 256MB memory buffer to resist hardware acceleration
@@ -356,19 +359,12 @@ pub fn create_vdf_entropy(
     }
 
     // Combine all entropy sources
-    let mut combined = Vec::new();
-    combined.extend_from_slice(&blockchain_entropy);
-
-    if let Some(ref beacon) = beacon_entropy {
-        combined.extend_from_slice(beacon);
-    } else {
-        combined.extend_from_slice(&[0u8; 32]); // Deterministic fallback
-    }
-
-    combined.extend_from_slice(&local_entropy);
-    combined.extend_from_slice(&timestamp.to_be_bytes());
-
-    let combined_hash = compute_sha256(&combined);
+    let combined_hash = combine_entropy_sources(
+        &blockchain_entropy,
+        beacon_entropy.as_deref(),
+        &local_entropy,
+        timestamp,
+    );
 
     Ok(MultiSourceEntropy {
         blockchain_entropy,
@@ -454,6 +450,43 @@ mod tests {
         assert_eq!(entropy.combined_hash.len(), 32);
     }
 
+    #[test]
+    fn test_vdf_entropy_matches_combined_entropy() {
+        let blockchain_entropy = [1u8; 32];
+        let local_entropy = [3u8; 32];
+        let timestamp = 1234567890.0;
+
+        for beacon in [None, Some([2u8; 32]), Some([0u8; 32])] {
+            let entropy = create_vdf_entropy(
+                Buffer::from(blockchain_entropy.to_vec()),
+                beacon.map(|b| Buffer::from(b.to_vec())),
+                Buffer::from(local_entropy.to_vec()),
+                timestamp,
+            )
+            .unwrap();
+            assert_eq!(
+                entropy.combined_hash.as_ref(),
+                combine_entropy_sources(
+                    &blockchain_entropy,
+                    beacon.as_ref().map(|b| &b[..]),
+                    &local_entropy,
+                    timestamp
+                )
+            );
+        }
+
+        // An absent beacon is distinct from an all-zero beacon
+        assert_ne!(
+            combine_entropy_sources(&blockchain_entropy, None, &local_entropy, timestamp),
+            combine_entropy_sources(
+                &blockchain_entropy,
+                Some(&[0u8; 32]),
+                &local_entropy,
+                timestamp
+            )
+        );
+    }
+
     #[test]
     fn test_block_vdf_functions() {
         let input_state = Buffer::from([4u8; 32].to_vec());
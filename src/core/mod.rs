@@ -1,8 +1,18 @@
 pub mod availability;
+pub mod blockchain_adapter;
+pub mod byte_reader;
+pub mod data_registry;
+pub mod discovery;
+pub mod erasure;
 pub mod errors;
 pub mod file_encoding;
 pub mod logging;
 pub mod memory_hard_vdf;
+pub mod portfolio;
+pub mod prover_state;
+pub mod signature_cache;
+pub mod signer;
+pub mod storage_backend;
 pub mod types;
 pub mod utils;
 pub mod vdf_processor;
@@ -0,0 +1,172 @@
+use crate::core::types::{
+    PORTFOLIO_CHAIN_SET_DOMAIN, PORTFOLIO_LEAF_DOMAIN, PORTFOLIO_SIGNATURE_DOMAIN,
+};
+use crate::core::utils::{
+    compute_blake3, compute_merkle_inclusion_path, compute_merkle_root,
+    compute_merkle_root_from_path, MerklePathNode,
+};
+
+/// A chain covered by a portfolio proof and the commitment it is attested at
+#[derive(Clone, Debug, PartialEq)]
+pub struct PortfolioEntry {
+    pub chain_id: Vec<u8>,
+    pub commitment_hash: [u8; 32],
+}
+
+/// Leaf binding a chain to its current commitment hash
+pub fn compute_portfolio_leaf(chain_id: &[u8], commitment_hash: &[u8; 32]) -> [u8; 32] {
+    compute_blake3(&[PORTFOLIO_LEAF_DOMAIN, chain_id, &commitment_hash[..]].concat())
+}
+
+/// Portfolio leaves in chain id order, the order every portfolio tree is built in
+fn portfolio_leaves(entries: &[PortfolioEntry]) -> Vec<[u8; 32]> {
+    let mut sorted: Vec<&PortfolioEntry> = entries.iter().collect();
+    sorted.sort_by(|a, b| a.chain_id.cmp(&b.chain_id));
+    sorted
+        .into_iter()
+        .map(|entry| compute_portfolio_leaf(&entry.chain_id, &entry.commitment_hash))
+        .collect()
+}
+
+/// Merkle root over a portfolio's leaves
+pub fn compute_portfolio_root(entries: &[PortfolioEntry]) -> [u8; 32] {
+    let leaves = portfolio_leaves(entries);
+    let leaf_refs: Vec<&[u8]> = leaves.iter().map(|leaf| leaf.as_ref()).collect();
+    compute_merkle_root(&leaf_refs)
+}
+
+/// Hash of the set of chain ids a portfolio covers, independent of their order,
+/// so a verifier knowing only the expected chain ids can check the set
+pub fn compute_portfolio_chain_set_hash(chain_ids: &[Vec<u8>]) -> [u8; 32] {
+    let mut sorted: Vec<&Vec<u8>> = chain_ids.iter().collect();
+    sorted.sort();
+    let mut data = PORTFOLIO_CHAIN_SET_DOMAIN.to_vec();
+    data.extend_from_slice(&(sorted.len() as u32).to_be_bytes());
+    for chain_id in sorted {
+        data.extend_from_slice(&(chain_id.len() as u32).to_be_bytes());
+        data.extend_from_slice(chain_id);
+    }
+    compute_blake3(&data)
+}
+
+/// Inclusion path proving `chain_id`'s entry is under the portfolio root
+pub fn compute_portfolio_inclusion_path(
+    entries: &[PortfolioEntry],
+    chain_id: &[u8],
+) -> Option<Vec<MerklePathNode>> {
+    let mut sorted_ids: Vec<&[u8]> = entries.iter().map(|e| e.chain_id.as_slice()).collect();
+    sorted_ids.sort();
+    let index = sorted_ids.iter().position(|&id| id == chain_id)?;
+    compute_merkle_inclusion_path(&portfolio_leaves(entries), index)
+}
+
+/// Verify a chain's commitment is covered by a portfolio root via its inclusion path
+pub fn verify_portfolio_inclusion(
+    portfolio_root: &[u8; 32],
+    chain_id: &[u8],
+    commitment_hash: &[u8; 32],
+    inclusion_path: &[MerklePathNode],
+) -> bool {
+    let leaf = compute_portfolio_leaf(chain_id, commitment_hash);
+    compute_merkle_root_from_path(leaf, inclusion_path) == *portfolio_root
+}
+
+/// Message the prover signs for a portfolio: its key, VDF state, portfolio root, chain
+/// set, count, timestamp and iteration count. Binding the key means a proof cannot be
+/// claimed by another prover.
+pub fn portfolio_signing_message(
+    prover_key: &[u8],
+    vdf_state: &[u8; 32],
+    portfolio_root: &[u8; 32],
+    chain_set_hash: &[u8; 32],
+    chain_count: u32,
+    timestamp: f64,
+    total_iterations: u64,
+) -> Vec<u8> {
+    [
+        PORTFOLIO_SIGNATURE_DOMAIN,
+        prover_key,
+        &vdf_state[..],
+        &portfolio_root[..],
+        &chain_set_hash[..],
+        &chain_count.to_be_bytes(),
+        &timestamp.to_be_bytes(),
+        &total_iterations.to_be_bytes(),
+    ]
+    .concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: u8, commitment: u8) -> PortfolioEntry {
+        PortfolioEntry {
+            chain_id: vec![id; 32],
+            commitment_hash: [commitment; 32],
+        }
+    }
+
+    #[test]
+    fn test_portfolio_inclusion_and_chain_set() {
+        let entries = vec![entry(3, 30), entry(1, 10), entry(2, 20)];
+        let root = compute_portfolio_root(&entries);
+
+        // Root and chain set do not depend on the order chains are listed in
+        let reordered = vec![entry(2, 20), entry(3, 30), entry(1, 10)];
+        assert_eq!(compute_portfolio_root(&reordered), root);
+        let ids: Vec<Vec<u8>> = entries.iter().map(|e| e.chain_id.clone()).collect();
+        let reordered_ids: Vec<Vec<u8>> = reordered.iter().map(|e| e.chain_id.clone()).collect();
+        assert_eq!(
+            compute_portfolio_chain_set_hash(&ids),
+            compute_portfolio_chain_set_hash(&reordered_ids)
+        );
+        assert_ne!(
+            compute_portfolio_chain_set_hash(&ids),
+            compute_portfolio_chain_set_hash(&ids[..2])
+        );
+
+        // Every chain proves inclusion at its own commitment, and only at it
+        for e in &entries {
+            let path = compute_portfolio_inclusion_path(&entries, &e.chain_id).unwrap();
+            assert!(verify_portfolio_inclusion(
+                &root,
+                &e.chain_id,
+                &e.commitment_hash,
+                &path
+            ));
+            assert!(!verify_portfolio_inclusion(
+                &root,
+                &e.chain_id,
+                &[0u8; 32],
+                &path
+            ));
+        }
+        assert!(compute_portfolio_inclusion_path(&entries, &[9u8; 32]).is_none());
+
+        // A changed commitment changes the root
+        let updated = vec![entry(3, 30), entry(1, 11), entry(2, 20)];
+        assert_ne!(compute_portfolio_root(&updated), root);
+    }
+
+    #[test]
+    fn test_portfolio_signature_binds_prover_key() {
+        use crate::core::utils::{derive_public_key, sign_data, verify_signature};
+
+        let private_key = [4u8; 32];
+        let prover_key = derive_public_key(&private_key).unwrap();
+        let other_key = derive_public_key(&[5u8; 32]).unwrap();
+        let root = compute_portfolio_root(&[entry(1, 10)]);
+        let chain_set = compute_portfolio_chain_set_hash(&[vec![1u8; 32]]);
+        let message = |key: &[u8], count: u32| {
+            portfolio_signing_message(key, &[9u8; 32], &root, &chain_set, count, 1.5, 200_000)
+        };
+
+        let signature = sign_data(&private_key, &message(&prover_key, 1)).unwrap();
+        assert!(verify_signature(&prover_key, &message(&prover_key, 1), &signature).unwrap());
+
+        // Another prover cannot claim the proof, and no field can change under it
+        assert!(!verify_signature(&other_key, &message(&other_key, 1), &signature).unwrap());
+        assert!(!verify_signature(&prover_key, &message(&prover_key, 2), &signature).unwrap());
+    }
+}
@@ -0,0 +1,247 @@
+use crate::core::{
+    byte_reader::{ByteOrder, ByteReader},
+    errors::{HashChainError, HashChainResult},
+    vdf_processor::{deserialize_vdf_proof_chain, serialize_vdf_proof_chain, VDFStateSnapshot},
+};
+
+/// Magic and format version prefix of exported prover state
+const PROVER_STATE_MAGIC: &[u8] = b"prover_state_v3";
+
+/// Chain entry of an exported prover state
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChainStateRecord {
+    pub chain_id: String,
+    pub hashchain_file_path: String,
+    pub chain_length: u32,
+    pub last_activity_height: u64,
+    pub current_commitment: Option<Vec<u8>>,
+}
+
+/// Availability registration of an exported prover state
+#[derive(Debug, Clone, PartialEq)]
+pub struct AvailabilityRecord {
+    pub chain_id: String,
+    pub file_path: String,
+    pub total_chunks: u32,
+}
+
+/// Prover state for moving a running prover to another process or host.
+/// Chain data stays on disk; only paths and in-memory progress are exported.
+#[derive(Debug, Clone)]
+pub struct ProverStateSnapshot {
+    pub prover_key: Vec<u8>,
+    pub vdf: VDFStateSnapshot,
+    pub chains: Vec<ChainStateRecord>,
+    pub availability: Vec<AvailabilityRecord>,
+}
+
+impl ProverStateSnapshot {
+    /// Big-endian serialization prefixed with the format magic
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(PROVER_STATE_MAGIC);
+        put_bytes(&mut bytes, &self.prover_key);
+
+        bytes.extend_from_slice(&self.vdf.vdf_state);
+        bytes.extend_from_slice(&self.vdf.total_iterations.to_be_bytes());
+        put_bytes(&mut bytes, &self.vdf.memory_buffer);
+        bytes.extend_from_slice(&self.vdf.last_block_height.to_be_bytes());
+        bytes.extend_from_slice(&self.vdf.last_block_hash);
+        put_bytes(
+            &mut bytes,
+            &serialize_vdf_proof_chain(&self.vdf.shared_proofs),
+        );
+        put_bytes(
+            &mut bytes,
+            &serialize_vdf_proof_chain(std::slice::from_ref(&self.vdf.checkpoint_proof)),
+        );
+
+        bytes.extend_from_slice(&(self.chains.len() as u32).to_be_bytes());
+        for chain in &self.chains {
+            put_bytes(&mut bytes, chain.chain_id.as_bytes());
+            put_bytes(&mut bytes, chain.hashchain_file_path.as_bytes());
+            bytes.extend_from_slice(&chain.chain_length.to_be_bytes());
+            bytes.extend_from_slice(&chain.last_activity_height.to_be_bytes());
+            match &chain.current_commitment {
+                Some(commitment) => {
+                    bytes.push(1);
+                    put_bytes(&mut bytes, commitment);
+                }
+                None => bytes.push(0),
+            }
+        }
+
+        bytes.extend_from_slice(&(self.availability.len() as u32).to_be_bytes());
+        for record in &self.availability {
+            put_bytes(&mut bytes, record.chain_id.as_bytes());
+            put_bytes(&mut bytes, record.file_path.as_bytes());
+            bytes.extend_from_slice(&record.total_chunks.to_be_bytes());
+        }
+
+        bytes
+    }
+
+    /// Parse state produced by `to_bytes`
+    pub fn from_bytes(bytes: &[u8]) -> HashChainResult<Self> {
+        let mut reader = ByteReader::new(bytes, ByteOrder::Big, "prover state");
+        if reader.take(PROVER_STATE_MAGIC.len())? != PROVER_STATE_MAGIC {
+            return Err(HashChainError::Serialization(
+                "Unrecognized prover state format".to_string(),
+            ));
+        }
+        let prover_key = reader.bytes()?.to_vec();
+
+        let vdf_state = reader.hash()?;
+        let total_iterations = reader.u64()?;
+        let memory_buffer = reader.bytes()?.to_vec();
+        let last_block_height = reader.u64()?;
+        let last_block_hash = reader.hash()?;
+        let shared_proofs = deserialize_vdf_proof_chain(reader.bytes()?)?;
+        let checkpoint_proof =
+            match <[_; 1]>::try_from(deserialize_vdf_proof_chain(reader.bytes()?)?) {
+                Ok([proof]) => proof,
+                Err(_) => {
+                    return Err(HashChainError::Serialization(
+                        "Prover state must carry exactly one VDF checkpoint proof".to_string(),
+                    ))
+                }
+            };
+
+        let chain_count = reader.u32()?;
+        let mut chains = Vec::new();
+        for _ in 0..chain_count {
+            let chain_id = reader.string()?;
+            let hashchain_file_path = reader.string()?;
+            let chain_length = reader.u32()?;
+            let last_activity_height = reader.u64()?;
+            let current_commitment = match reader.take(1)?[0] {
+                0 => None,
+                1 => Some(reader.bytes()?.to_vec()),
+                flag => {
+                    return Err(HashChainError::Serialization(format!(
+                        "Invalid commitment flag {} for chain {}",
+                        flag, chain_id
+                    )))
+                }
+            };
+            chains.push(ChainStateRecord {
+                chain_id,
+                hashchain_file_path,
+                chain_length,
+                last_activity_height,
+                current_commitment,
+            });
+        }
+
+        let availability_count = reader.u32()?;
+        let mut availability = Vec::new();
+        for _ in 0..availability_count {
+            availability.push(AvailabilityRecord {
+                chain_id: reader.string()?,
+                file_path: reader.string()?,
+                total_chunks: reader.u32()?,
+            });
+        }
+
+        reader.finish()?;
+
+        Ok(Self {
+            prover_key,
+            vdf: VDFStateSnapshot {
+                vdf_state,
+                total_iterations,
+                memory_buffer,
+                last_block_height,
+                last_block_hash,
+                shared_proofs,
+                checkpoint_proof,
+            },
+            chains,
+            availability,
+        })
+    }
+}
+
+/// Append a u32 length prefix followed by `data`
+fn put_bytes(bytes: &mut Vec<u8>, data: &[u8]) {
+    bytes.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    bytes.extend_from_slice(data);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::vdf_processor::SharedVDFProof;
+
+    #[test]
+    fn test_prover_state_roundtrip() {
+        let snapshot = ProverStateSnapshot {
+            prover_key: vec![1u8; 32],
+            vdf: VDFStateSnapshot {
+                vdf_state: [2u8; 32],
+                total_iterations: 12_345,
+                memory_buffer: vec![3u8; 1024],
+                last_block_height: 99,
+                last_block_hash: [10u8; 32],
+                shared_proofs: vec![SharedVDFProof {
+                    vdf_state: [4u8; 32],
+                    total_iterations: 12_000,
+                    timestamp: 1_700_000_000.0,
+                    signature: vec![5u8; 32],
+                    proof_chain_hash: [6u8; 32],
+                }],
+                checkpoint_proof: SharedVDFProof {
+                    vdf_state: [2u8; 32],
+                    total_iterations: 12_345,
+                    timestamp: 1_700_000_100.0,
+                    signature: vec![8u8; 64],
+                    proof_chain_hash: [9u8; 32],
+                },
+            },
+            chains: vec![
+                ChainStateRecord {
+                    chain_id: "aa".to_string(),
+                    hashchain_file_path: "/data/aa.hashchain".to_string(),
+                    chain_length: 7,
+                    last_activity_height: 100,
+                    current_commitment: Some(vec![7u8; 32]),
+                },
+                ChainStateRecord {
+                    chain_id: "bb".to_string(),
+                    hashchain_file_path: "/data/bb.hashchain".to_string(),
+                    chain_length: 0,
+                    last_activity_height: 0,
+                    current_commitment: None,
+                },
+            ],
+            availability: vec![AvailabilityRecord {
+                chain_id: "aa".to_string(),
+                file_path: "/data/aa.data".to_string(),
+                total_chunks: 64,
+            }],
+        };
+
+        let bytes = snapshot.to_bytes();
+        let restored = ProverStateSnapshot::from_bytes(&bytes).unwrap();
+        assert_eq!(restored.prover_key, snapshot.prover_key);
+        assert_eq!(restored.vdf.vdf_state, snapshot.vdf.vdf_state);
+        assert_eq!(restored.vdf.total_iterations, snapshot.vdf.total_iterations);
+        assert_eq!(restored.vdf.memory_buffer, snapshot.vdf.memory_buffer);
+        assert_eq!(restored.vdf.last_block_height, 99);
+        assert_eq!(restored.vdf.last_block_hash, [10u8; 32]);
+        assert_eq!(restored.vdf.shared_proofs.len(), 1);
+        assert_eq!(
+            restored.vdf.checkpoint_proof.signed_data(),
+            snapshot.vdf.checkpoint_proof.signed_data()
+        );
+        assert_eq!(restored.chains, snapshot.chains);
+        assert_eq!(restored.availability, snapshot.availability);
+
+        // Truncated, trailing and foreign data are rejected
+        assert!(ProverStateSnapshot::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        let mut extended = bytes.clone();
+        extended.push(0);
+        assert!(ProverStateSnapshot::from_bytes(&extended).is_err());
+        assert!(ProverStateSnapshot::from_bytes(b"not prover state").is_err());
+    }
+}
@@ -0,0 +1,95 @@
+use crate::core::errors::{HashChainError, HashChainResult};
+use crate::core::types::SIGNER_PROBE_DOMAIN;
+use crate::core::utils::{sign_data, verify_signature};
+
+/// Source of the prover's Ed25519 signatures. Implementations decide where the
+/// private key lives, so it can stay inside a hardware security module.
+pub trait Signer: Send + Sync {
+    /// Sign `data`, returning a 64-byte Ed25519 signature
+    fn sign(&self, data: &[u8]) -> HashChainResult<Vec<u8>>;
+}
+
+/// Signer holding a raw 32-byte private key in process memory
+pub struct SoftwareSigner {
+    private_key: Vec<u8>,
+}
+
+impl SoftwareSigner {
+    pub fn new(private_key: Vec<u8>) -> Self {
+        Self { private_key }
+    }
+}
+
+impl Signer for SoftwareSigner {
+    fn sign(&self, data: &[u8]) -> HashChainResult<Vec<u8>> {
+        sign_data(&self.private_key, data)
+    }
+}
+
+/// Signer delegating every signature to a callback, e.g. one calling into an HSM
+pub struct CallbackSigner<F> {
+    callback: F,
+}
+
+impl<F> CallbackSigner<F>
+where
+    F: Fn(&[u8]) -> HashChainResult<Vec<u8>> + Send + Sync,
+{
+    pub fn new(callback: F) -> Self {
+        Self { callback }
+    }
+}
+
+impl<F> Signer for CallbackSigner<F>
+where
+    F: Fn(&[u8]) -> HashChainResult<Vec<u8>> + Send + Sync,
+{
+    fn sign(&self, data: &[u8]) -> HashChainResult<Vec<u8>> {
+        (self.callback)(data)
+    }
+}
+
+/// Check a signer produces valid signatures for `public_key` by signing a probe,
+/// so a misconfigured key is caught before any proof is signed with it
+pub fn check_signer_key(signer: &dyn Signer, public_key: &[u8]) -> HashChainResult<()> {
+    let probe = [SIGNER_PROBE_DOMAIN, public_key].concat();
+    let signature = signer.sign(&probe)?;
+    if signature.len() != 64 || !verify_signature(public_key, &probe, &signature)? {
+        return Err(HashChainError::CryptographicError(
+            "Signer does not produce signatures for the prover key".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::utils::derive_public_key;
+
+    #[test]
+    fn test_signer_key_check() {
+        let private_key = [5u8; 32];
+        let public_key = derive_public_key(&private_key).unwrap();
+        let software = SoftwareSigner::new(private_key.to_vec());
+        assert!(check_signer_key(&software, &public_key).is_ok());
+
+        // A callback signer is checked the same way as the key it fronts
+        let callback = CallbackSigner::new(move |data: &[u8]| sign_data(&private_key, data));
+        assert!(check_signer_key(&callback, &public_key).is_ok());
+        assert_eq!(
+            callback.sign(b"data").unwrap(),
+            software.sign(b"data").unwrap()
+        );
+
+        // A signer for another key, or one that fails, is rejected
+        let other = SoftwareSigner::new([6u8; 32].to_vec());
+        assert!(check_signer_key(&other, &public_key).is_err());
+        let failing = CallbackSigner::new(|_: &[u8]| {
+            Err(HashChainError::CryptographicError(
+                "HSM offline".to_string(),
+            ))
+        });
+        assert!(check_signer_key(&failing, &public_key).is_err());
+    }
+}
@@ -0,0 +1,162 @@
+use memmap2::Mmap;
+use std::fs::File;
+
+use crate::core::{
+    errors::{HashChainError, HashChainResult},
+    types::*,
+    utils::compute_sha256,
+};
+
+/// Raw chunk storage used by chain storage and availability proving.
+/// Chunks are `CHUNK_SIZE_BYTES` long; the last chunk may be shorter.
+pub trait StorageBackend: Send {
+    /// Size of the stored data in bytes
+    fn file_size(&self) -> u64;
+
+    /// Read the raw bytes of a chunk
+    fn read_chunk(&mut self, chunk_index: u32) -> HashChainResult<Vec<u8>>;
+
+    /// SHA256 of the entire stored data
+    fn compute_hash(&mut self) -> HashChainResult<[u8; 32]>;
+
+    /// Release any open handles (no-op for backends without any)
+    fn release(&mut self) {}
+
+    /// Whether the backend currently holds an open handle
+    fn is_open(&self) -> bool {
+        false
+    }
+
+    /// Number of chunks in the stored data
+    fn total_chunks(&self) -> u64 {
+        self.file_size().div_ceil(CHUNK_SIZE_BYTES as u64)
+    }
+}
+
+/// Byte range of a chunk within data of `file_size` bytes
+fn chunk_range(chunk_index: u32, file_size: u64) -> HashChainResult<(usize, usize)> {
+    let chunk_start = chunk_index as u64 * CHUNK_SIZE_BYTES as u64;
+    if chunk_start >= file_size {
+        return Err(HashChainError::ChunkIndexOutOfRange {
+            index: chunk_index,
+            max: file_size.div_ceil(CHUNK_SIZE_BYTES as u64),
+        });
+    }
+    let chunk_end = std::cmp::min(chunk_start + CHUNK_SIZE_BYTES as u64, file_size);
+    Ok((chunk_start as usize, chunk_end as usize))
+}
+
+/// File-backed storage using lazily initialized memory mapping
+pub struct FileBackend {
+    path: String,
+    file_size: u64,
+    mmap: Option<Mmap>,
+}
+
+impl FileBackend {
+    /// Open backend for an existing file
+    pub fn new(path: String) -> HashChainResult<Self> {
+        let metadata = std::fs::metadata(&path)
+            .map_err(|_| HashChainError::FileNotFound { path: path.clone() })?;
+
+        Ok(Self {
+            path,
+            file_size: metadata.len(),
+            mmap: None,
+        })
+    }
+
+    /// Initialize memory-mapped access if needed
+    fn mmap(&mut self) -> HashChainResult<&Mmap> {
+        if self.mmap.is_none() {
+            let file = File::open(&self.path).map_err(HashChainError::Io)?;
+            let mmap = unsafe { Mmap::map(&file) }.map_err(HashChainError::Io)?;
+            log::debug!("Initialized memory-mapped access for {}", self.path);
+            self.mmap = Some(mmap);
+        }
+        Ok(self.mmap.as_ref().unwrap())
+    }
+}
+
+impl StorageBackend for FileBackend {
+    fn file_size(&self) -> u64 {
+        self.file_size
+    }
+
+    fn read_chunk(&mut self, chunk_index: u32) -> HashChainResult<Vec<u8>> {
+        let (start, end) = chunk_range(chunk_index, self.file_size)?;
+        Ok(self.mmap()?[start..end].to_vec())
+    }
+
+    fn compute_hash(&mut self) -> HashChainResult<[u8; 32]> {
+        Ok(compute_sha256(self.mmap()?))
+    }
+
+    fn release(&mut self) {
+        if self.mmap.take().is_some() {
+            // Dropping the mapping releases the file handle (Windows compatibility)
+            log::debug!("Explicitly closing memory-mapped file: {}", self.path);
+        }
+    }
+
+    fn is_open(&self) -> bool {
+        self.mmap.is_some()
+    }
+}
+
+/// In-memory storage for tests and deterministic simulations
+pub struct MemoryBackend {
+    data: Vec<u8>,
+}
+
+impl MemoryBackend {
+    /// Create backend holding `data`
+    pub fn new(data: Vec<u8>) -> Self {
+        Self { data }
+    }
+}
+
+impl StorageBackend for MemoryBackend {
+    fn file_size(&self) -> u64 {
+        self.data.len() as u64
+    }
+
+    fn read_chunk(&mut self, chunk_index: u32) -> HashChainResult<Vec<u8>> {
+        let (start, end) = chunk_range(chunk_index, self.file_size())?;
+        Ok(self.data[start..end].to_vec())
+    }
+
+    fn compute_hash(&mut self) -> HashChainResult<[u8; 32]> {
+        Ok(compute_sha256(&self.data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_backend_chunks() {
+        let chunk_size = CHUNK_SIZE_BYTES as usize;
+        let data: Vec<u8> = (0..(2 * chunk_size + 10))
+            .map(|i| (i % 251) as u8)
+            .collect();
+        let mut backend = MemoryBackend::new(data.clone());
+
+        assert_eq!(backend.file_size(), data.len() as u64);
+        assert_eq!(backend.total_chunks(), 3);
+        assert_eq!(
+            backend.read_chunk(1).unwrap(),
+            data[chunk_size..2 * chunk_size]
+        );
+
+        // Last chunk is short, past the end is out of range
+        assert_eq!(backend.read_chunk(2).unwrap().len(), 10);
+        assert!(matches!(
+            backend.read_chunk(3),
+            Err(HashChainError::ChunkIndexOutOfRange { .. })
+        ));
+
+        assert_eq!(backend.compute_hash().unwrap(), compute_sha256(&data));
+    }
+}
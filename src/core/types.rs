@@ -1,8 +1,8 @@
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
 
-// Production dependencies (currently unused but may be needed for future merkle implementations)
-// use rs_merkle::{algorithms::Sha256 as MerkleSha256, MerkleProof, MerkleTree as RsMerkleTree};
+use crate::core::byte_reader::{ByteOrder, ByteReader};
+use crate::core::errors::{HashChainError, HashChainResult};
 
 // Enhanced Network Consensus Constants
 pub const BLOCK_TIME_SECONDS: u32 = 52; // Blockchain average block time
@@ -10,11 +10,15 @@ pub const PROOF_WINDOW_MINUTES: u32 = 4; // Extended proof window
 pub const PROOF_WINDOW_BLOCKS: u32 = 5; // (PROOF_WINDOW_MINUTES * 60) / BLOCK_TIME_SECONDS
 pub const CHUNK_SIZE_BYTES: u32 = 4096; // 4KB chunks
 pub const CHUNKS_PER_BLOCK: u32 = 16; // Enhanced to 16 chunks per block for anti-erasure coding resistance
+pub const MAX_CHUNKS_PER_BLOCK: u32 = 256; // Upper bound for a deployment's configured chunks per block
 pub const HASH_SIZE: usize = 32; // SHA256 output size
+pub const MERKLE_PATH_NODE_SIZE: usize = HASH_SIZE + 1; // Encoded Merkle path node: sibling hash and side byte
 
 // Enhanced Security Constants
 pub const MIN_FILE_SIZE: u64 = (CHUNKS_PER_BLOCK * CHUNK_SIZE_BYTES) as u64; // Minimum 16 chunks (64KB)
 pub const MEMORY_HARD_VDF_MEMORY: usize = 256 * 1024 * 1024; // 256MB memory requirement
+pub const CHALLENGE_ACCESS_PROOF_ITERATIONS: u32 = 50_000; // Memory-hard VDF iterations in a challenge response's access proof
+pub const CHALLENGE_ACCESS_PROOF_MEMORY_KB: u32 = (MEMORY_HARD_VDF_MEMORY / 2 / 1024) as u32; // 128MB for the access proof VDF
 pub const MEMORY_HARD_ITERATIONS: u32 = 28_000_000; // Tuned for ~16 second compute to match block intervals
 pub const CHUNK_SELECTION_VERSION: u32 = 2; // Updated version with enhanced security
 
@@ -22,13 +26,48 @@ pub const CHUNK_SELECTION_VERSION: u32 = 2; // Updated version with enhanced sec
 pub const CHUNK_SELECTION_SEED_SIZE: usize = 16; // Increased entropy
 pub const CHUNK_SELECTION_MAX_ATTEMPTS: u32 = 32; // More attempts for unique chunks
 pub const ENTROPY_SOURCES_COUNT: u32 = 3; // Multi-source entropy
+pub const BEACON_ENTROPY_REQUIRED: bool = false; // Whether commitments must carry verified beacon entropy
 
 // File Format Consensus Constants
 pub const HASHCHAIN_MAGIC: &[u8] = b"HCH2"; // Updated magic for v2
-pub const HASHCHAIN_FORMAT_VERSION: u32 = 2; // Enhanced format version
+pub const HASHCHAIN_FORMAT_VERSION: u32 = 3; // Enhanced format version with header checksum
+pub const HASHCHAIN_CHECKSUM_FORMAT_VERSION: u32 = 3; // First format version whose header must carry a checksum
 pub const HASHCHAIN_HEADER_SIZE: usize = 512; // Expanded header for new fields
 pub const HASHCHAIN_MAX_CHUNKS: u64 = 1048576; // Max chunks per file (4TB max)
 pub const HASHCHAIN_MIN_CHUNKS: u64 = 1; // Minimum 1 chunk (4KB)
+pub const CHAIN_INDEX_FILE_NAME: &str = "index.json"; // chain_id -> files index in each output directory
+pub const CHAIN_INDEX_VERSION: u32 = 2; // Index layout version (mismatching indexes are rebuilt)
+pub const STORAGE_COMMITMENT_MAGIC: &[u8] = b"PSC1"; // Serialized StorageCommitment prefix
+pub const STORAGE_COMMITMENT_FORMAT_VERSION: u32 = 1; // Serialized StorageCommitment layout version
+
+// Hash Domain Separation Tags (prefixed to hashed or signed data; bump the version when a layout changes)
+pub const COMMITMENT_HASH_DOMAIN: &[u8] = b"commitment_hash_v1"; // Storage commitment hash
+pub const PHYSICAL_ACCESS_COMMITMENT_DOMAIN: &[u8] = b"physical_access_commitment_v1"; // Hashchain log commitment hash
+pub const ENHANCED_PHYSICAL_ACCESS_DOMAIN: &[u8] = b"enhanced_physical_access_v3"; // Enhanced commitment hash
+pub const ENHANCED_ENCODING_DOMAIN: &[u8] = b"enhanced_encoding_v3"; // Prover-specific encoding parameters
+pub const ENHANCED_OWNERSHIP_DOMAIN: &[u8] = b"enhanced_ownership_v3"; // Data ownership commitment
+pub const ENHANCED_VDF_INPUT_DOMAIN: &[u8] = b"enhanced_vdf_input_v3"; // VDF input for enhanced commitments
+pub const GEOGRAPHIC_DISTRIBUTION_DOMAIN: &[u8] = b"geographic_distribution_proof_v3"; // Network latency distribution proof
+pub const VDF_BLOCK_SIGNATURE_DOMAIN: &[u8] = b"vdf_block_signature_v1"; // Continuous VDF per-block signature
+pub const VDF_BLOCK_RANGE_LEAF_DOMAIN: &[u8] = b"vdf_block_range_leaf_v1"; // Leaf of a signed block range
+pub const VDF_BLOCK_RANGE_SIGNATURE_DOMAIN: &[u8] = b"vdf_block_range_signature_v1"; // Block range signature
+pub const VDF_BLOCK_SIGNING_DOMAIN: &[u8] = b"vdf_block_ed25519_v1"; // Ed25519-signed block data
+pub const SHARED_VDF_PROOF_DOMAIN: &[u8] = b"shared_vdf_proof_v2"; // Ed25519-signed shared VDF proof data (millisecond timestamps)
+pub const VDF_PROOF_CHAIN_DOMAIN: &[u8] = b"vdf_proof_chain_v1"; // Link between consecutive shared VDF proofs
+pub const COMBINED_ENTROPY_DOMAIN: &[u8] = b"combined_entropy_v1"; // Reproducible combined entropy hash
+pub const MULTI_SOURCE_ENTROPY_DOMAIN: &[u8] = b"multi_source_entropy_v1"; // Fresh multi-source entropy seed
+pub const MERKLE_NODE_DOMAIN: &[u8] = b"merkle_node_v1"; // Merkle tree internal node
+pub const STORAGE_CHALLENGE_DOMAIN: &[u8] = b"storage_challenge_v1"; // Verifier-signed storage challenge
+pub const SIGNER_PROBE_DOMAIN: &[u8] = b"signer_probe_v1"; // Probe signed to check a signer holds the prover key
+pub const PORTFOLIO_LEAF_DOMAIN: &[u8] = b"portfolio_leaf_v1"; // Chain and commitment leaf of a portfolio proof
+pub const PORTFOLIO_CHAIN_SET_DOMAIN: &[u8] = b"portfolio_chain_set_v1"; // Set of chains a portfolio covers
+pub const PORTFOLIO_SIGNATURE_DOMAIN: &[u8] = b"portfolio_signature_v2"; // Prover signature over a portfolio
+pub const CHALLENGE_EVIDENCE_DOMAIN: &[u8] = b"challenge_evidence_v1"; // Exported availability challenge record
+pub const AVAILABILITY_RESPONSE_SIGNING_DOMAIN: &[u8] = b"availability_response_v1"; // Prover-signed availability response
+pub const CHALLENGE_LATENCY_BINDING_DOMAIN: &[u8] = b"challenge_latency_binding_v1"; // Access proof input bound to measured latency
+pub const CHUNK_SLICE_LEAF_DOMAIN: &[u8] = b"chunk_slice_leaf_v1"; // Leaf of a chunk's slice Merkle tree
+pub const HIERARCHICAL_POSITION_DOMAIN: &[u8] = b"hierarchical_position_v1"; // Ultra-compact proof position claim
+pub const HASHCHAIN_HEADER_CHECKSUM_DOMAIN: &[u8] = b"hashchain_header_checksum_v1"; // Checksum over .hashchain header fields
 
 // Hierarchical Temporal Proof Parameters (Enhanced)
 pub const GLOBAL_ROOT_ITERATIONS: u32 = 20000; // Increased security
@@ -41,11 +80,49 @@ pub const GROUPS_PER_REGION: u32 = 10; // Standard region size
 pub const AVAILABILITY_CHALLENGES_PER_BLOCK: u32 = 10;
 pub const AVAILABILITY_RESPONSE_TIME_MS: u32 = 500; // 500ms response deadline
 pub const AVAILABILITY_CHALLENGE_PROBABILITY: f64 = 0.1; // 10% of chains challenged per block
+pub const AVAILABILITY_LATENCY_SAMPLE_WINDOW: usize = 1000; // Responses kept per chain for SLA tracking
+pub const AVAILABILITY_EVIDENCE_RETENTION_SECONDS: f64 = 604800.0; // Completed challenges kept for disputes (1 week)
+pub const CHALLENGE_FAILURE_TOLERANCE: u32 = 2; // Failed challenges tolerated per window before slashing
+pub const CHALLENGE_FAILURE_WINDOW: u32 = 10; // Recent challenge outcomes tracked per chain
+pub const CHUNK_READ_MAX_ATTEMPTS: u32 = 3; // Reads attempted before a challenge response fails
+pub const MAINTENANCE_SCORE_WEIGHT: f64 = 0.25; // Maintenance responses count as a quarter of a failure
+pub const CHUNK_READ_RETRY_BACKOFF_MS: u64 = 10; // Initial backoff, doubled per retry
+pub const SUB_CHUNK_CHALLENGE_BYTES: u32 = 32; // Bytes revealed per sub-chunk challenge
+pub const AVAILABILITY_SLICE_BYTES: u32 = 64; // Bytes served per light availability probe
+pub const AVAILABILITY_SLICE_ROOT_RETENTION_SECONDS: f64 = 86400.0; // Slice roots kept for light probes after a full audit (1 day)
+pub const AVAILABILITY_SEGMENT_CHUNKS: u32 = 16; // Chunks per segment of a streamed range audit response
+pub const MAX_CHUNK_BATCH: u32 = 1024; // Chunks returned per batched chunk read (4MB)
+pub const SELECTION_BIAS_BINS: u32 = 16; // Chunk index ranges in the selection uniformity test
+pub const SELECTION_BIAS_MIN_EXPECTED_PER_BIN: f64 = 5.0; // Expected selections per bin for a valid chi-square test
+pub const SELECTION_BIAS_Z_THRESHOLD: f64 = 3.09; // Normal score flagged as biased (p < 0.001)
+
+// Continuous VDF Reproduction Challenge Constants
+pub const CONTINUOUS_VDF_MEMORY_KB: u32 = 256; // 256KB memory for continuous VDF
+pub const MIN_CONTINUOUS_VDF_ITERATIONS: u32 = 1000; // Minimum iterations before proofs are accepted
+pub const DEVELOPMENT_MIN_VDF_ITERATIONS: u32 = 10; // Minimum iterations accepted by development validators
+pub const MIN_VDF_ITERATIONS_PER_BLOCK: u32 = 1000; // Sequential VDF progress required between block commitments
+pub const VDF_REPRODUCTION_MAX_SEGMENT: u32 = 10_000; // Bound on verifier recomputation
+pub const VDF_SEED_REPLAY_MAX_ITERATIONS: u32 = 1_000_000; // Longest replay from the prover key seed a verifier performs
+pub const JS_SIGNER_TIMEOUT_SECONDS: u64 = 30; // Longest the VDF thread waits on a JavaScript signing callback
+pub const VDF_CHECKPOINT_SNAPSHOTS: usize = 8; // VDF memory snapshots a prover keeps at recent shared proofs
+pub const VDF_SIGNATURE_CACHE_SIZE: usize = 10_000; // Recent (prover, VDF signature) bindings a verifier remembers
+pub const MAX_VDF_ITERATIONS_PER_SECOND: f64 = 500_000.0; // Fastest plausible VDF hardware
+pub const VDF_ELAPSED_TIME_TOLERANCE: f64 = 1.5; // Factor by which claimed VDF compute time may exceed wall-clock time
+pub const VDF_ELAPSED_TIME_SLACK_SECONDS: f64 = 5.0; // Clock skew allowed between chain start and proof timestamps
+pub const DEFAULT_VDF_TARGET_ITERATIONS_PER_SECOND: u32 = 1000; // Local VDF pacing, not a consensus rule
+pub const MIN_VDF_TARGET_ITERATIONS_PER_SECOND: u32 = 100; // Slowest pacing that still clears per-block progress
+pub const VDF_CALIBRATION_MS: u64 = 200; // Length of the startup VDF speed benchmark
+pub const VDF_CALIBRATION_HEADROOM: f64 = 0.8; // Fraction of the benchmarked rate used as the target pace
 
 // Network Latency Proof Constants (Anti-outsourcing)
 pub const NETWORK_LATENCY_SAMPLES: u32 = 5;
 pub const NETWORK_LATENCY_MAX_MS: u32 = 100; // Maximum acceptable latency
 pub const NETWORK_LATENCY_VARIANCE_MAX: f64 = 0.3; // Maximum variance in latency
+pub const CHALLENGE_LATENCY_TOLERANCE: f64 = 1.5; // Response network time may exceed the bound latency by 50%
+pub const CHALLENGE_LATENCY_SLACK_MS: f64 = 20.0; // Fixed allowance for jitter on top of the tolerance
+pub const CHALLENGE_ACCESS_PROOF_TIME_TOLERANCE: f64 = 3.0; // Claimed access proof time may exceed the verifier's recomputation 3x
+pub const PROVER_DISCOVERY_TTL_SECONDS: f64 = 300.0; // Discovered provers are cached this long
+pub const PROVER_DISCOVERY_MIN_INTERVAL_SECONDS: f64 = 30.0; // Minimum time between discovery callback calls
 
 // Economic Constants (Generic Token Units)
 pub const CHECKPOINT_BOND_UNITS: u64 = 1000; // Bond amount in base token units
@@ -58,11 +135,16 @@ pub const MAX_CHAINS_PER_INSTANCE: u32 = 100000; // Support up to 100K chains
 pub const GLOBAL_STATE_UPDATE_INTERVAL: u32 = 5; // Every 5 blocks for enhanced security
 pub const REMOVAL_DELAY_BLOCKS: u32 = 20; // Delay before chain removal
 pub const INACTIVE_CHAIN_TIMEOUT_BLOCKS: u32 = 2070; // ~30 days in blocks
+pub const CHAIN_HEALTH_LAG_BLOCKS: u32 = 5; // Commitment lag at which a chain is flagged unhealthy
+pub const CHAIN_HEALTH_IDLE_BLOCKS: u32 = 1035; // Inactivity at which a chain is flagged, half the removal timeout
 pub const STATE_CLEANUP_INTERVAL: u32 = 69; // Periodic cleanup
+pub const VDF_QUEUE_COMPLETED_RETENTION: u32 = 100; // Completed VDF queue commitments kept for consumers
+pub const VDF_QUEUE_COMPLETED_MAX_AGE_SECONDS: f64 = 3600.0; // Age after which completed commitments are dropped
 
 // Performance Targets (Enhanced)
 pub const BLOCK_PROCESSING_TARGET_MS: u32 = 40000; // 40 seconds for enhanced processing
 pub const PER_CHAIN_PROCESSING_TARGET_MS: u32 = 5; // <5ms per chain with enhanced security
+pub const DEFAULT_BLOCK_PROCESSING_THREADS: u32 = 4; // Worker threads for per-chain block processing
 
 // Callback Interface Types
 
@@ -180,6 +262,25 @@ pub struct AvailabilityChallenge {
     pub deadline: f64,
     /// Reward amount for successful challenge
     pub reward_amount: f64,
+    /// Slice Merkle root of the challenged chunk, learned from an earlier full-chunk
+    /// response. When set this is a light probe answered with one authenticated slice.
+    pub slice_root: Option<Buffer>,
+    /// Block beacon mixed into the challenge decision and chunk selection, so they
+    /// cannot be predicted before the block exists but can be rechecked afterwards
+    pub selection_beacon: Option<Buffer>,
+}
+
+/// Slice of a chunk served for a light availability probe
+#[napi(object)]
+#[derive(Clone)]
+pub struct ChunkSliceProof {
+    /// Index of the slice within the chunk
+    pub slice_index: u32,
+    /// Slice bytes
+    pub slice_data: Buffer,
+    /// Path from the slice leaf up to the chunk's slice root, encoded by
+    /// `encode_merkle_path`
+    pub merkle_path: Buffer,
 }
 
 /// Response to availability challenge
@@ -194,6 +295,128 @@ pub struct AvailabilityResponse {
     pub response_time: f64,
     /// Proof of chunk authenticity
     pub authenticity_proof: Buffer,
+    /// Set when the prover is in maintenance and did not serve the chunk
+    pub maintenance_mode: Option<bool>,
+    /// Served slice for light probes (chunk data is omitted)
+    pub slice_proof: Option<ChunkSliceProof>,
+    /// Public key of the prover that signed the response
+    pub prover_key: Option<Buffer>,
+    /// Prover's Ed25519 signature over the response fields above
+    pub prover_signature: Option<Buffer>,
+}
+
+/// Availability audit of a contiguous chunk range, answered as a stream of
+/// independently verifiable segments rather than one response holding every chunk
+#[napi(object)]
+#[derive(Clone)]
+pub struct RangeAvailabilityChallenge {
+    /// Chain being audited
+    pub chain_id: Buffer,
+    /// First chunk of the audited range
+    pub start_chunk: u32,
+    /// Chunk after the last audited chunk
+    pub end_chunk: u32,
+    /// Chunks in the chain's data file
+    pub total_chunks: u32,
+    /// Merkle root over the blake3 hashes of every padded chunk (the header's `merkle_root`)
+    pub chunk_root: Buffer,
+    /// Challenge nonce
+    pub challenge_nonce: Buffer,
+    /// Challenger identifier
+    pub challenger_id: Buffer,
+    /// Challenge timestamp
+    pub challenge_time: f64,
+    /// Chunks served per segment; the last segment may be shorter
+    pub chunks_per_segment: u32,
+    /// Deadline of each segment, in segment order
+    pub segment_deadlines: Vec<f64>,
+}
+
+/// Chunk served in a range audit segment with its path to the chunk root
+#[napi(object)]
+#[derive(Clone)]
+pub struct SegmentChunkProof {
+    /// Index of the chunk in the data file
+    pub chunk_index: u32,
+    /// Decoded chunk bytes, padded to the chunk size
+    pub chunk_data: Buffer,
+    /// Path from the chunk hash up to the chunk root, encoded by `encode_merkle_path`
+    pub merkle_path: Buffer,
+}
+
+/// One segment of a streamed range audit response, verifiable on its own
+#[napi(object)]
+#[derive(Clone)]
+pub struct ChallengeResponseSegment {
+    /// Range challenge being responded to
+    pub challenge_id: Buffer,
+    /// Position of this segment in the response
+    pub segment_index: u32,
+    /// Segments in the whole response
+    pub total_segments: u32,
+    /// Chunks covered by this segment, in index order
+    pub chunks: Vec<SegmentChunkProof>,
+    /// Binds the segment's chunk hashes to the challenge nonce
+    pub authenticity_proof: Buffer,
+    /// Response timestamp
+    pub response_time: f64,
+}
+
+/// Record of a completed availability challenge, exported for slashing disputes
+#[napi(object)]
+#[derive(Clone)]
+pub struct ChallengeEvidence {
+    /// Hex challenge id, recomputable from the challenge
+    pub challenge_id: String,
+    pub challenge: AvailabilityChallenge,
+    /// Response processed for the challenge; absent if it expired unanswered
+    pub response: Option<AvailabilityResponse>,
+    /// "success", "timeout", "invalid_data", "maintenance_mode" or "unanswered"
+    pub outcome: String,
+    /// When the outcome was recorded
+    pub recorded_at: f64,
+    /// Hash binding the id, challenge, response, outcome and timestamp
+    pub evidence_hash: Buffer,
+    /// Challenger's Ed25519 signature over the evidence hash, by the key in
+    /// `challenge.challenger_id`
+    pub challenger_signature: Option<Buffer>,
+}
+
+/// Tolerance for failed availability challenges before a chain becomes slashable
+#[napi(object)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ChallengeFailurePolicy {
+    /// Failures allowed within the window; one more makes the chain slashable
+    pub tolerated_failures: u32,
+    /// Number of most recent challenge outcomes considered
+    pub window: u32,
+}
+
+impl Default for ChallengeFailurePolicy {
+    fn default() -> Self {
+        Self {
+            tolerated_failures: CHALLENGE_FAILURE_TOLERANCE,
+            window: CHALLENGE_FAILURE_WINDOW,
+        }
+    }
+}
+
+/// Availability response-time percentiles for a chain
+#[napi(object)]
+#[derive(Clone)]
+pub struct AvailabilityLatencyPercentiles {
+    /// Number of responses in the sample window
+    pub sample_count: u32,
+    /// Median response time
+    pub p50_ms: u32,
+    /// 95th percentile response time
+    pub p95_ms: u32,
+    /// 99th percentile response time
+    pub p99_ms: u32,
+    /// Slowest response in the sample window
+    pub max_ms: u32,
+    /// Response-time SLA the samples are measured against
+    pub sla_ms: u32,
 }
 
 /// Network latency proof for anti-outsourcing
@@ -326,6 +549,50 @@ pub struct EnhancedCheckpoint {
     pub submitter_id: Buffer,
 }
 
+/// Chain leaf of a hierarchical proof tree
+#[napi(object)]
+#[derive(Clone)]
+pub struct ChainProofNode {
+    /// Chain identifier
+    pub chain_id: Buffer,
+    /// Chain commitment included in its group proof
+    pub commitment: Buffer,
+}
+
+/// Group node of a hierarchical proof tree
+#[napi(object)]
+#[derive(Clone)]
+pub struct GroupProofNode {
+    /// Group identifier
+    pub group_id: String,
+    /// Group proof
+    pub proof: Buffer,
+    /// Chains in the group, ordered by chain id
+    pub chains: Vec<ChainProofNode>,
+}
+
+/// Region node of a hierarchical proof tree
+#[napi(object)]
+#[derive(Clone)]
+pub struct RegionProofNode {
+    /// Region identifier
+    pub region_id: String,
+    /// Regional proof
+    pub proof: Buffer,
+    /// Groups in the region, ordered by group id
+    pub groups: Vec<GroupProofNode>,
+}
+
+/// Hierarchical proof as a nested global → regions → groups → chains tree
+#[napi(object)]
+#[derive(Clone)]
+pub struct ProofTree {
+    /// Global root proof
+    pub global_root_proof: Buffer,
+    /// Regions ordered by region id
+    pub regions: Vec<RegionProofNode>,
+}
+
 /// File encoding information for prover-specific storage
 #[napi(object)]
 #[derive(Clone)]
@@ -449,6 +716,21 @@ pub struct HashChainHeader {
     pub initial_block_hash: Buffer,
     /// SHA256 of header fields (32 bytes)
     pub header_checksum: Buffer,
+    /// Erasure coding layout, when the stored data is a legitimately erasure-coded dataset
+    pub erasure_coding: Option<ErasureCodingParams>,
+    /// Prover-specific encoding of the data file, when recorded at creation
+    pub file_encoding: Option<FileEncodingInfo>,
+}
+
+/// Reed-Solomon layout of erasure-coded chain data: consecutive stripes of
+/// `data_shards` data chunks followed by `parity_shards` parity chunks
+#[napi(object)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ErasureCodingParams {
+    /// Data chunks per stripe
+    pub data_shards: u32,
+    /// Parity chunks per stripe
+    pub parity_shards: u32,
 }
 
 #[napi(object)]
@@ -570,6 +852,8 @@ pub struct LightweightHashChain {
     pub availability_score: f64,
     /// Last network latency score
     pub latency_score: f64,
+    /// Last block height with chain activity (registration or answered challenge)
+    pub last_activity_height: u64,
 }
 
 impl LightweightHashChain {
@@ -729,8 +1013,22 @@ pub struct CompactProof {
     pub regional_proof: Buffer,
     /// Global proof reference (32 bytes)
     pub global_proof_reference: Buffer,
-    /// Merkle path to group (up to 512 bytes)
-    pub merkle_path: Vec<Buffer>,
+    /// Merkle path to group, encoded by `encode_merkle_path` (up to 528 bytes)
+    pub merkle_path: Buffer,
+    /// Group the chain belongs to
+    pub group_id: String,
+    /// Merkle path from the group proof to the region, encoded by `encode_merkle_path`
+    /// (up to 165 bytes)
+    pub region_path: Buffer,
+    /// Region the group belongs to
+    pub region_id: String,
+    /// Merkle path from the regional proof to the global root, encoded by
+    /// `encode_merkle_path` (up to 528 bytes)
+    pub global_path: Buffer,
+    /// Global root proof the global proof chained from (32 bytes)
+    pub previous_global_proof: Buffer,
+    /// Number of regions under the global proof
+    pub region_count: u32,
     /// Enhanced proof metadata (128 bytes)
     pub metadata: ProofMetadata,
 }
@@ -749,8 +1047,9 @@ pub struct FullProof {
     pub regional_proofs: Vec<Buffer>,
     /// Global hierarchical proof (32 bytes)
     pub global_proof: Buffer,
-    /// Complete merkle paths for verification (up to 16 KB)
-    pub merkle_paths: Vec<Vec<Buffer>>,
+    /// Complete merkle paths for verification, each encoded by `encode_merkle_path`
+    /// (up to 16 KB)
+    pub merkle_paths: Vec<Buffer>,
     /// Chunk verification data with enhanced security (up to 8 KB)
     pub chunk_verification: ChunkVerificationData,
     /// Full metadata and statistics (up to 4 KB)
@@ -816,23 +1115,44 @@ pub struct ChunkVerificationData {
 }
 
 /// Enhanced full proof metadata
+/// Only `generation_time_ms` and `memory_usage_mb` are consensus-relevant and are
+/// validated against the proof's VDF; all other fields are advisory only.
 #[napi(object)]
 #[derive(Clone)]
 pub struct FullProofMetadata {
-    /// Detailed system statistics
+    /// Detailed system statistics (advisory)
     pub system_stats: String, // JSON string
-    /// Performance metrics including VDF
+    /// Performance metrics including VDF (advisory)
     pub performance_metrics: String, // JSON string
-    /// Verification instructions
+    /// Verification instructions (advisory)
     pub verification_guide: String,
-    /// Proof generation time
+    /// Proof generation time (consensus)
     pub generation_time_ms: f64,
-    /// Memory usage during generation
+    /// Memory usage during generation (consensus)
     pub memory_usage_mb: f64,
-    /// Enhanced security features used
+    /// Enhanced security features used (advisory)
     pub security_features: Vec<String>,
 }
 
+/// Consensus-relevant subset of FullProofMetadata
+#[derive(Clone, Debug)]
+pub struct ConsensusProofMetadata {
+    /// Proof generation time in milliseconds
+    pub generation_time_ms: f64,
+    /// Memory usage during generation in MB
+    pub memory_usage_mb: f64,
+}
+
+impl FullProofMetadata {
+    /// Extract the fields verifiers are allowed to rely on
+    pub fn consensus_fields(&self) -> ConsensusProofMetadata {
+        ConsensusProofMetadata {
+            generation_time_ms: self.generation_time_ms,
+            memory_usage_mb: self.memory_usage_mb,
+        }
+    }
+}
+
 /// Enhanced hierarchical position
 #[napi(object)]
 #[derive(Clone)]
@@ -875,6 +1195,202 @@ pub struct StorageCommitment {
     pub entropy: MultiSourceEntropy,
     /// Commitment hash
     pub commitment_hash: Buffer,
+    /// Merkle root over the hashes of all chunks of the data; challenge responses
+    /// must prove their chunks against it
+    pub merkle_root: Option<Buffer>,
+    /// Number of chunks (Merkle leaves) under `merkle_root`
+    pub total_chunks: Option<f64>,
+}
+
+impl StorageCommitment {
+    /// Binary encoding: magic and format version, then the fields in declaration
+    /// order. Hashes are 32 bytes, numbers little-endian, lists count-prefixed and
+    /// optional fields prefixed with a presence byte.
+    pub fn serialize(&self) -> Result<Buffer> {
+        fn put_hash(data: &mut Vec<u8>, hash: &Buffer, field: &str) -> Result<()> {
+            if hash.len() != HASH_SIZE {
+                return Err(HashChainError::Serialization(format!(
+                    "Commitment {} must be {} bytes, got {}",
+                    field,
+                    HASH_SIZE,
+                    hash.len()
+                ))
+                .into());
+            }
+            data.extend_from_slice(hash);
+            Ok(())
+        }
+
+        let mut data = Vec::new();
+        data.extend_from_slice(STORAGE_COMMITMENT_MAGIC);
+        data.extend_from_slice(&STORAGE_COMMITMENT_FORMAT_VERSION.to_le_bytes());
+
+        put_hash(&mut data, &self.prover_key, "prover key")?;
+        put_hash(&mut data, &self.data_hash, "data hash")?;
+        data.extend_from_slice(&self.block_height.to_le_bytes());
+        put_hash(&mut data, &self.block_hash, "block hash")?;
+
+        data.extend_from_slice(&(self.selected_chunks.len() as u32).to_le_bytes());
+        for chunk in &self.selected_chunks {
+            data.extend_from_slice(&chunk.to_le_bytes());
+        }
+        data.extend_from_slice(&(self.chunk_hashes.len() as u32).to_le_bytes());
+        for chunk_hash in &self.chunk_hashes {
+            put_hash(&mut data, chunk_hash, "chunk hash")?;
+        }
+
+        // VDF proof
+        let vdf_proof = &self.vdf_proof;
+        put_hash(&mut data, &vdf_proof.input_state, "VDF input state")?;
+        put_hash(&mut data, &vdf_proof.output_state, "VDF output state")?;
+        data.extend_from_slice(&vdf_proof.iterations.to_le_bytes());
+        data.extend_from_slice(&(vdf_proof.memory_access_samples.len() as u32).to_le_bytes());
+        for sample in &vdf_proof.memory_access_samples {
+            data.extend_from_slice(&sample.iteration.to_le_bytes());
+            data.extend_from_slice(&sample.read_address.to_le_bytes());
+            data.extend_from_slice(&sample.write_address.to_le_bytes());
+            put_hash(
+                &mut data,
+                &sample.memory_content_hash,
+                "memory content hash",
+            )?;
+        }
+        data.extend_from_slice(&vdf_proof.computation_time_ms.to_le_bytes());
+        data.extend_from_slice(&vdf_proof.memory_usage_bytes.to_le_bytes());
+
+        // Entropy
+        let entropy = &self.entropy;
+        put_hash(&mut data, &entropy.blockchain_entropy, "blockchain entropy")?;
+        data.push(entropy.beacon_entropy.is_some() as u8);
+        if let Some(beacon_entropy) = &entropy.beacon_entropy {
+            put_hash(&mut data, beacon_entropy, "beacon entropy")?;
+        }
+        put_hash(&mut data, &entropy.local_entropy, "local entropy")?;
+        data.extend_from_slice(&entropy.timestamp.to_le_bytes());
+        put_hash(&mut data, &entropy.combined_hash, "combined entropy hash")?;
+
+        put_hash(&mut data, &self.commitment_hash, "commitment hash")?;
+        data.push(self.merkle_root.is_some() as u8);
+        if let Some(merkle_root) = &self.merkle_root {
+            put_hash(&mut data, merkle_root, "Merkle root")?;
+        }
+        data.push(self.total_chunks.is_some() as u8);
+        if let Some(total_chunks) = self.total_chunks {
+            data.extend_from_slice(&total_chunks.to_le_bytes());
+        }
+
+        Ok(Buffer::from(data))
+    }
+
+    /// Parse a commitment produced by `serialize`
+    pub fn deserialize(data: Buffer) -> Result<Self> {
+        let mut reader = ByteReader::new(data.as_ref(), ByteOrder::Little, "commitment");
+
+        if reader.take(STORAGE_COMMITMENT_MAGIC.len())? != STORAGE_COMMITMENT_MAGIC {
+            return Err(HashChainError::Serialization(
+                "Not a serialized storage commitment".to_string(),
+            )
+            .into());
+        }
+        let version = reader.u32()?;
+        if version != STORAGE_COMMITMENT_FORMAT_VERSION {
+            return Err(HashChainError::Serialization(format!(
+                "Unsupported commitment format version {}",
+                version
+            ))
+            .into());
+        }
+
+        let prover_key = reader.buffer(HASH_SIZE)?;
+        let data_hash = reader.buffer(HASH_SIZE)?;
+        let block_height = reader.u32()?;
+        let block_hash = reader.buffer(HASH_SIZE)?;
+
+        let selected_count = reader.count(4)?;
+        let selected_chunks = (0..selected_count)
+            .map(|_| reader.u32())
+            .collect::<HashChainResult<Vec<_>>>()?;
+        let chunk_hash_count = reader.count(HASH_SIZE)?;
+        let chunk_hashes = (0..chunk_hash_count)
+            .map(|_| reader.buffer(HASH_SIZE))
+            .collect::<HashChainResult<Vec<_>>>()?;
+
+        let input_state = reader.buffer(HASH_SIZE)?;
+        let output_state = reader.buffer(HASH_SIZE)?;
+        let iterations = reader.u32()?;
+        let sample_count = reader.count(4 + 8 + 8 + HASH_SIZE)?;
+        let memory_access_samples = (0..sample_count)
+            .map(|_| {
+                Ok(MemoryAccessSample {
+                    iteration: reader.u32()?,
+                    read_address: reader.f64()?,
+                    write_address: reader.f64()?,
+                    memory_content_hash: reader.buffer(HASH_SIZE)?,
+                })
+            })
+            .collect::<HashChainResult<Vec<_>>>()?;
+        let vdf_proof = MemoryHardVDFProof {
+            input_state,
+            output_state,
+            iterations,
+            memory_access_samples,
+            computation_time_ms: reader.f64()?,
+            memory_usage_bytes: reader.f64()?,
+        };
+
+        let entropy = MultiSourceEntropy {
+            blockchain_entropy: reader.buffer(HASH_SIZE)?,
+            beacon_entropy: if reader.flag()? {
+                Some(reader.buffer(HASH_SIZE)?)
+            } else {
+                None
+            },
+            local_entropy: reader.buffer(HASH_SIZE)?,
+            timestamp: reader.f64()?,
+            combined_hash: reader.buffer(HASH_SIZE)?,
+        };
+
+        let commitment_hash = reader.buffer(HASH_SIZE)?;
+        let merkle_root = if reader.flag()? {
+            Some(reader.buffer(HASH_SIZE)?)
+        } else {
+            None
+        };
+        let total_chunks = if reader.flag()? {
+            Some(reader.f64()?)
+        } else {
+            None
+        };
+
+        reader.finish()?;
+
+        Ok(Self {
+            prover_key,
+            data_hash,
+            block_height,
+            block_hash,
+            selected_chunks,
+            chunk_hashes,
+            vdf_proof,
+            entropy,
+            commitment_hash,
+            merkle_root,
+            total_chunks,
+        })
+    }
+}
+
+/// Consensus values a deployment may override, e.g. fewer chunks per block on a
+/// private network with small files. Provers and verifiers must use the same values.
+#[napi(object)]
+#[derive(Clone)]
+pub struct ConsensusParams {
+    /// Chunks selected and proven per block (1 to `MAX_CHUNKS_PER_BLOCK`)
+    pub chunks_per_block: u32,
+    /// Minimum continuous VDF iterations before commitments are generated or accepted
+    pub min_vdf_iterations: u32,
+    /// Chunk size in bytes; must equal the hashchain format's `CHUNK_SIZE_BYTES`
+    pub chunk_size_bytes: u32,
 }
 
 /// Challenge issued to prover for data availability
@@ -895,6 +1411,55 @@ pub struct StorageChallenge {
     pub timestamp: f64,
     /// Response deadline
     pub deadline: f64,
+    /// Byte offset to reveal within each challenged chunk (sub-chunk challenge)
+    pub byte_offsets: Option<Vec<u32>>,
+    /// Public key of the issuing verifier
+    pub verifier_key: Option<Buffer>,
+    /// Verifier's Ed25519 signature over the challenge
+    pub verifier_signature: Option<Buffer>,
+    /// Coding parameters when the challenge targets a whole stripe of raw coded shards
+    pub erasure_coding: Option<ErasureCodingParams>,
+}
+
+/// Integrity check outcome for one chain
+#[napi(object)]
+#[derive(Clone)]
+pub struct ChainIntegrityReport {
+    /// Chain identifier (hex)
+    pub chain_id: String,
+    /// Whether the chain passed
+    pub valid: bool,
+    /// Why the chain failed, when it did
+    pub error: Option<String>,
+}
+
+/// Prover maintenance window state
+#[napi(object)]
+#[derive(Clone)]
+pub struct MaintenanceStatus {
+    /// Whether challenges are currently answered with a maintenance notice
+    pub enabled: bool,
+    /// Seconds since the current maintenance window started (0 when not in maintenance)
+    pub current_duration_seconds: f64,
+    /// Seconds spent in maintenance across all windows, including the current one
+    pub total_duration_seconds: f64,
+}
+
+/// Bytes revealed at a challenged offset, with the rest of the chunk binding
+/// them to the committed chunk hash
+#[napi(object)]
+#[derive(Clone)]
+pub struct SubChunkProof {
+    /// Challenged chunk index
+    pub chunk_index: u32,
+    /// Offset of the revealed bytes within the chunk
+    pub byte_offset: u32,
+    /// Bytes at the challenged offset
+    pub revealed_bytes: Buffer,
+    /// Chunk bytes before the revealed range
+    pub prefix: Buffer,
+    /// Chunk bytes after the revealed range
+    pub suffix: Buffer,
 }
 
 /// Proof response to storage challenge
@@ -905,12 +1470,121 @@ pub struct ChallengeResponse {
     pub challenge_id: Buffer,
     /// Actual chunk data
     pub chunk_data: Vec<Buffer>,
-    /// Merkle proofs for chunks
+    /// Merkle proof per challenged chunk: its path from the chunk hash up to the
+    /// chain's Merkle root, encoded by `encode_merkle_path`
     pub merkle_proofs: Vec<Buffer>,
     /// Response timestamp
     pub timestamp: f64,
     /// VDF proof of timely access
     pub access_proof: MemoryHardVDFProof,
+    /// Revealed byte ranges for sub-chunk challenges (chunk data is omitted)
+    pub sub_chunk_proofs: Option<Vec<SubChunkProof>>,
+    /// Round-trip latency to the challenger measured before responding, bound into
+    /// the access proof input
+    pub measured_latency_ms: Option<f64>,
+}
+
+/// Prover's continuous VDF state at the time it signed a block
+#[napi(object)]
+#[derive(Clone)]
+pub struct VDFSigningState {
+    /// VDF state the signature was made against
+    pub vdf_state: Buffer,
+    /// Total VDF iterations at signing
+    pub total_iterations: f64,
+}
+
+/// Challenge asking a prover to reproduce an intermediate continuous VDF state
+#[napi(object)]
+#[derive(Clone)]
+pub struct VDFReproductionChallenge {
+    /// Target prover
+    pub prover_key: Buffer,
+    /// Iteration index whose state must be revealed
+    pub iteration_index: f64,
+    /// Number of iterations the verifier recomputes from that state
+    pub segment_length: u32,
+    /// Challenge timestamp
+    pub timestamp: f64,
+}
+
+/// Prover response to a VDF reproduction challenge
+#[napi(object)]
+#[derive(Clone)]
+pub struct VDFReproductionResponse {
+    /// Iteration index being revealed
+    pub iteration_index: f64,
+    /// Number of iterations in the revealed segment
+    pub segment_length: u32,
+    /// VDF state at iteration_index
+    pub start_state: Buffer,
+    /// VDF memory buffer at iteration_index
+    pub memory_snapshot: Buffer,
+    /// VDF state after segment_length further iterations
+    pub end_state: Buffer,
+}
+
+/// Continuous VDF state and memory revealed at an iteration, from which a verifier
+/// can resume the VDF
+#[napi(object)]
+#[derive(Clone)]
+pub struct VDFCheckpoint {
+    /// Iteration the checkpoint was taken at
+    pub iteration: f64,
+    /// VDF state at the iteration
+    pub state: Buffer,
+    /// VDF memory buffer at the iteration
+    pub memory_snapshot: Buffer,
+}
+
+/// Forecast of when the prover's VDF reaches a required iteration count
+#[napi(object)]
+#[derive(Clone)]
+pub struct VDFIterationsForecast {
+    /// Current total VDF iterations
+    pub current_iterations: f64,
+    /// Iterations still missing (0 when ready)
+    pub shortfall: f64,
+    /// Rate the estimate is based on
+    pub iterations_per_second: f64,
+    /// Seconds until the required iterations are reached; absent if the VDF is not advancing
+    pub estimated_seconds: Option<f64>,
+}
+
+/// Attestation that a prover holds all of its active chains: one Merkle root over
+/// the chains' current commitment hashes, signed once by the prover key. The size
+/// does not depend on the number of chains.
+#[napi(object)]
+#[derive(Clone)]
+pub struct PortfolioProof {
+    /// Prover identification
+    pub prover_key: Buffer,
+    /// Merkle root over (chain id, commitment hash) leaves in chain id order
+    pub portfolio_root: Buffer,
+    /// Hash of the set of chain ids covered
+    pub chain_set_hash: Buffer,
+    /// Number of chains covered
+    pub chain_count: u32,
+    /// VDF state the portfolio was signed against
+    pub vdf_state: Buffer,
+    /// Total VDF iterations at signing
+    pub total_iterations: f64,
+    /// Ed25519 signature by `prover_key` over every other field
+    pub signature: Buffer,
+    /// Signing timestamp
+    pub timestamp: f64,
+}
+
+/// Proof that one chain's commitment is covered by a portfolio root
+#[napi(object)]
+#[derive(Clone)]
+pub struct PortfolioInclusionProof {
+    /// Hex chain id
+    pub chain_id: String,
+    /// Commitment hash the chain is attested at
+    pub commitment_hash: Buffer,
+    /// Path from the leaf up to the root, encoded by `encode_merkle_path`
+    pub merkle_path: Buffer,
 }
 
 /// Compact proof for efficient verification
@@ -919,6 +1593,8 @@ pub struct ChallengeResponse {
 pub struct CompactStorageProof {
     /// Prover identification
     pub prover_key: Buffer,
+    /// Hash of the committed data file; with the prover key it identifies the chain
+    pub data_hash: Buffer,
     /// Data commitment hash
     pub commitment_hash: Buffer,
     /// Block height reference
@@ -953,6 +1629,44 @@ pub struct FullStorageProof {
     pub metadata: ProofMetadata,
 }
 
+/// Outcome of incremental full proof verification
+#[napi(object)]
+#[derive(Clone)]
+pub struct VerificationResult {
+    /// Whether the proof verified
+    pub valid: bool,
+    /// Merkle root covered by this result (pass as the previous root next block)
+    pub merkle_root: Buffer,
+    /// Whether the Merkle tree was re-verified rather than skipped as unchanged
+    pub tree_reverified: bool,
+    /// Reason the proof was rejected
+    pub failure_reason: Option<String>,
+    /// Validation strictness the proof was checked under
+    pub strictness: String,
+}
+
+/// Uniformity test of a chain's chunk selections across its history
+#[napi(object)]
+#[derive(Clone)]
+pub struct BiasReport {
+    /// Chain analyzed (hex)
+    pub chain_id: String,
+    /// Chunk selections tested
+    pub sample_count: u32,
+    /// Chunk index ranges the selections are counted in
+    pub bin_count: u32,
+    /// Chi-square statistic of the bin counts against a uniform distribution
+    pub chi_square: f64,
+    /// Degrees of freedom of the test (bins - 1)
+    pub degrees_of_freedom: u32,
+    /// Chi-square statistic normalized to a standard normal score
+    pub z_score: f64,
+    /// Whether there are enough selections for the test to be meaningful
+    pub sufficient_samples: bool,
+    /// Whether the selections are significantly non-uniform
+    pub biased: bool,
+}
+
 /// Network node information
 #[napi(object)]
 #[derive(Clone)]
@@ -984,3 +1698,236 @@ pub struct NetworkStats {
     /// Challenge success rate
     pub challenge_success_rate: f64,
 }
+
+/// Data hash claimed by more than one prover
+#[napi(object)]
+#[derive(Clone)]
+pub struct DuplicateDataRegistration {
+    /// Claimed data hash
+    pub data_hash: Buffer,
+    /// Claiming prover keys in registration order, first claimant first
+    pub prover_keys: Vec<Buffer>,
+}
+
+/// Health of one chain group in the hierarchy
+#[napi(object)]
+#[derive(Clone)]
+pub struct GroupHealth {
+    /// Group identifier
+    pub group_id: String,
+    /// Region the group belongs to, if assigned
+    pub region_id: Option<String>,
+    /// Chains in the group
+    pub chain_count: u32,
+    /// Maximum chains in the group
+    pub max_chains: u32,
+    /// Last block at which the group was updated
+    pub last_update_block: f64,
+    /// Time of the last group proof computation in milliseconds, if one was computed
+    pub last_proof_time_ms: Option<f64>,
+    /// Whether the group has a current group proof
+    pub has_proof: bool,
+    /// Hex ids of chains lagging behind the current block or idle too long
+    pub unhealthy_chains: Vec<String>,
+}
+
+/// Health of one region in the hierarchy, aggregated over its groups
+#[napi(object)]
+#[derive(Clone)]
+pub struct RegionHealth {
+    /// Region identifier
+    pub region_id: String,
+    /// Groups in the region
+    pub group_count: u32,
+    /// Maximum groups in the region
+    pub max_groups: u32,
+    /// Chains across all groups in the region
+    pub chain_count: u32,
+    /// Last block at which the region was updated
+    pub last_update_block: f64,
+    /// Time of the last regional proof computation in milliseconds, if one was computed
+    pub last_proof_time_ms: Option<f64>,
+    /// Whether the region has a current regional proof
+    pub has_proof: bool,
+    /// Groups containing at least one unhealthy chain
+    pub lagging_groups: Vec<String>,
+    /// Hex ids of unhealthy chains across the region
+    pub unhealthy_chains: Vec<String>,
+}
+
+/// Per-chain block processing time measured against the block processing target
+#[napi(object)]
+#[derive(Clone)]
+pub struct BlockProcessingStats {
+    /// Block height processed
+    pub block_height: f64,
+    /// Chains whose commitments were updated
+    pub chains_processed: u32,
+    /// Worker threads used
+    pub worker_threads: u32,
+    /// Actual processing time in milliseconds
+    pub elapsed_ms: f64,
+    /// Block processing target in milliseconds
+    pub target_ms: f64,
+    /// Average processing time per updated chain in milliseconds
+    pub per_chain_ms: f64,
+    /// Whether processing finished within the target
+    pub within_target: bool,
+}
+
+/// Disk consumed by one chain's files
+#[napi(object)]
+#[derive(Clone)]
+pub struct ChainDiskUsage {
+    /// Hex chain id
+    pub chain_id: String,
+    /// Size of the `.data` file in bytes
+    pub data_file_bytes: f64,
+    /// Size of the `.hashchain` file in bytes (0 if not yet written)
+    pub hashchain_file_bytes: f64,
+    /// Both files together
+    pub total_bytes: f64,
+}
+
+/// Disk use and free space of one output directory holding chain files
+#[napi(object)]
+#[derive(Clone)]
+pub struct DirectoryDiskUsage {
+    /// Output directory path
+    pub path: String,
+    /// Chain index and leftover `_original.data` staging files in bytes
+    pub sidecar_bytes: f64,
+    /// Bytes available on the directory's filesystem, if it could be determined
+    pub free_bytes: Option<f64>,
+}
+
+/// Disk usage across every active chain, for capacity planning
+#[napi(object)]
+#[derive(Clone)]
+pub struct DiskUsageReport {
+    /// Per-chain usage, ordered by chain id
+    pub chains: Vec<ChainDiskUsage>,
+    /// Output directories of those chains, ordered by path
+    pub directories: Vec<DirectoryDiskUsage>,
+    /// All `.data` files in bytes
+    pub data_file_bytes: f64,
+    /// All `.hashchain` files in bytes
+    pub hashchain_file_bytes: f64,
+    /// All sidecar files in bytes
+    pub sidecar_bytes: f64,
+    /// Everything above together
+    pub total_bytes: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commitment() -> StorageCommitment {
+        StorageCommitment {
+            prover_key: Buffer::from(vec![1u8; 32]),
+            data_hash: Buffer::from(vec![2u8; 32]),
+            block_height: 4242,
+            block_hash: Buffer::from(vec![3u8; 32]),
+            selected_chunks: vec![5, 17, 900],
+            chunk_hashes: vec![
+                Buffer::from(vec![4u8; 32]),
+                Buffer::from(vec![5u8; 32]),
+                Buffer::from(vec![6u8; 32]),
+            ],
+            vdf_proof: MemoryHardVDFProof {
+                input_state: Buffer::from(vec![7u8; 32]),
+                output_state: Buffer::from(vec![8u8; 32]),
+                iterations: 123_456,
+                memory_access_samples: vec![MemoryAccessSample {
+                    iteration: 9,
+                    read_address: 1543.0,
+                    write_address: 2741.0,
+                    memory_content_hash: Buffer::from(vec![9u8; 32]),
+                }],
+                computation_time_ms: 12.5,
+                memory_usage_bytes: 256.0 * 1024.0,
+            },
+            entropy: MultiSourceEntropy {
+                blockchain_entropy: Buffer::from(vec![10u8; 32]),
+                beacon_entropy: Some(Buffer::from(vec![11u8; 32])),
+                local_entropy: Buffer::from(vec![12u8; 32]),
+                // Not exactly representable in decimal JSON round trips
+                timestamp: 1_760_000_000.123_456_7,
+                combined_hash: Buffer::from(vec![13u8; 32]),
+            },
+            commitment_hash: Buffer::from(vec![14u8; 32]),
+            merkle_root: Some(Buffer::from(vec![15u8; 32])),
+            total_chunks: Some(1024.0),
+        }
+    }
+
+    fn encoded(commitment: &StorageCommitment) -> Vec<u8> {
+        commitment.serialize().unwrap().to_vec()
+    }
+
+    #[test]
+    fn test_storage_commitment_round_trip() {
+        let original = commitment();
+        let bytes = encoded(&original);
+        assert_eq!(&bytes[..4], STORAGE_COMMITMENT_MAGIC);
+
+        let decoded = StorageCommitment::deserialize(Buffer::from(bytes.clone())).unwrap();
+        assert_eq!(encoded(&decoded), bytes);
+        assert_eq!(decoded.block_height, original.block_height);
+        assert_eq!(decoded.selected_chunks, original.selected_chunks);
+        assert_eq!(
+            decoded.entropy.timestamp.to_bits(),
+            original.entropy.timestamp.to_bits()
+        );
+        assert_eq!(
+            decoded.vdf_proof.memory_access_samples[0].write_address,
+            2741.0
+        );
+
+        // Optional fields survive being absent
+        let mut sparse = commitment();
+        sparse.entropy.beacon_entropy = None;
+        sparse.merkle_root = None;
+        sparse.total_chunks = None;
+        sparse.vdf_proof.memory_access_samples.clear();
+        let decoded = StorageCommitment::deserialize(sparse.serialize().unwrap()).unwrap();
+        assert!(decoded.entropy.beacon_entropy.is_none());
+        assert!(decoded.merkle_root.is_none());
+        assert!(decoded.total_chunks.is_none());
+        assert_eq!(encoded(&decoded), encoded(&sparse));
+
+        // Hashes of the wrong size are refused rather than written ambiguously
+        let mut malformed = commitment();
+        malformed.chunk_hashes[1] = Buffer::from(vec![0u8; 31]);
+        assert!(malformed.serialize().is_err());
+    }
+
+    #[test]
+    fn test_storage_commitment_rejects_malformed_bytes() {
+        let bytes = encoded(&commitment());
+
+        // Every truncation fails cleanly instead of panicking
+        for len in 0..bytes.len() {
+            assert!(StorageCommitment::deserialize(Buffer::from(bytes[..len].to_vec())).is_err());
+        }
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(StorageCommitment::deserialize(Buffer::from(trailing)).is_err());
+
+        let mut wrong_magic = bytes.clone();
+        wrong_magic[0] ^= 0xff;
+        assert!(StorageCommitment::deserialize(Buffer::from(wrong_magic)).is_err());
+
+        let mut future_version = bytes.clone();
+        future_version[4] = 2;
+        assert!(StorageCommitment::deserialize(Buffer::from(future_version)).is_err());
+
+        // A huge selected chunk count is rejected before allocating
+        let count_offset = 4 + 4 + 32 + 32 + 4 + 32;
+        let mut huge_count = bytes;
+        huge_count[count_offset..count_offset + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(StorageCommitment::deserialize(Buffer::from(huge_count)).is_err());
+    }
+}
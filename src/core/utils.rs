@@ -42,6 +42,23 @@ pub fn compute_crc32(data: &[u8]) -> u32 {
     CRC.checksum(data)
 }
 
+/// SHA256 of a file's contents, read in 64KB blocks so large files are never held in memory
+pub fn compute_file_sha256(file_path: &str) -> HashChainResult<[u8; 32]> {
+    use std::io::Read;
+
+    let mut file = File::open(file_path)?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(hasher.finalize().into())
+}
+
 /// Memory-mapped file checksum (solves Windows file access issues)
 pub fn compute_file_checksum_mmap(file_path: &str) -> HashChainResult<u32> {
     let file = File::open(file_path)?;
@@ -85,6 +102,23 @@ pub fn validate_block_hash(block_hash: &Buffer) -> HashChainResult<()> {
     Ok(())
 }
 
+/// Validate a block hash and convert it to a fixed-size array
+pub fn block_hash_to_array(block_hash: &[u8]) -> HashChainResult<[u8; 32]> {
+    block_hash
+        .try_into()
+        .map_err(|_| HashChainError::InvalidBlockHashSize(block_hash.len()))
+}
+
+/// Validate a fixed-size hash argument such as a commitment hash
+pub fn validate_hash_buffer(buffer: &Buffer, name: &str) -> HashChainResult<()> {
+    if buffer.len() != HASH_SIZE {
+        return Err(HashChainError::InvalidProofParameters {
+            reason: format!("{} must be {} bytes, got {}", name, HASH_SIZE, buffer.len()),
+        });
+    }
+    Ok(())
+}
+
 pub fn validate_block_height(block_height: f64) -> HashChainResult<u64> {
     if block_height < 0.0 {
         return Err(HashChainError::InvalidBlockHeight(block_height));
@@ -159,6 +193,27 @@ pub fn compute_hierarchical_position(
     compute_sha256(&data)
 }
 
+/// Hash an ultra-compact proof carries for its claimed hierarchical position, binding
+/// the level, index, level size and parent to the chain
+pub fn compute_ultra_compact_position_hash(
+    chain_hash: &[u8],
+    position: &HierarchicalPosition,
+) -> [u8; 32] {
+    let mut data = HIERARCHICAL_POSITION_DOMAIN.to_vec();
+    data.extend_from_slice(chain_hash);
+    data.extend_from_slice(&position.level.to_be_bytes());
+    data.extend_from_slice(&position.position.to_be_bytes());
+    data.extend_from_slice(&position.total_at_level.to_be_bytes());
+    match position.parent_position {
+        Some(parent) => {
+            data.push(1);
+            data.extend_from_slice(&parent.to_be_bytes());
+        }
+        None => data.push(0),
+    }
+    compute_sha256(&data)
+}
+
 /// Chunk validation utilities
 pub fn validate_chunk_index(chunk_idx: u32, total_chunks: u64) -> HashChainResult<()> {
     if chunk_idx >= total_chunks as u32 {
@@ -186,6 +241,25 @@ pub fn validate_chunk_count(count: u64) -> HashChainResult<()> {
     Ok(())
 }
 
+/// Bytes available to unprivileged users on the filesystem holding `path`, or None
+/// where it cannot be determined
+#[cfg(unix)]
+pub fn available_disk_space(path: &str) -> Option<u64> {
+    let c_path = std::ffi::CString::new(path).ok()?;
+    let mut stats: libc::statvfs = unsafe { std::mem::zeroed() };
+    if unsafe { libc::statvfs(c_path.as_ptr(), &mut stats) } != 0 {
+        return None;
+    }
+    #[allow(clippy::unnecessary_cast)] // Field widths differ between platforms
+    Some(stats.f_bavail as u64 * stats.f_frsize as u64)
+}
+
+/// Free space is only reported on unix
+#[cfg(not(unix))]
+pub fn available_disk_space(_path: &str) -> Option<u64> {
+    None
+}
+
 /// File path utilities
 pub fn derive_data_file_path(hashchain_file_path: &str) -> String {
     hashchain_file_path.replace(".hashchain", ".data")
@@ -197,25 +271,45 @@ pub fn create_output_file_paths(output_dir: &str, data_hash_hex: &str) -> (Strin
     (data_file_path, hashchain_file_path)
 }
 
-/// Merkle tree utilities for hierarchical system
-pub fn compute_merkle_root(hashes: &[&[u8]]) -> [u8; 32] {
-    if hashes.is_empty() {
-        return [0u8; 32];
-    }
+/// Parent of two Merkle nodes, domain-separated from every other hash construction
+pub fn compute_merkle_parent(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    compute_sha256(&[MERKLE_NODE_DOMAIN, &left[..], &right[..]].concat())
+}
 
-    // Convert to the format expected by rs_merkle
-    let leaves: Vec<[u8; 32]> = hashes
+/// Next Merkle tree level: pairs are hashed, an odd last node is promoted
+fn merkle_next_level(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => compute_merkle_parent(left, right),
+            _ => pair[0],
+        })
+        .collect()
+}
+
+/// Leaves from hashes, taking the first 32 bytes of each
+fn merkle_leaves(hashes: &[&[u8]]) -> Vec<[u8; 32]> {
+    hashes
         .iter()
         .map(|&hash| {
             let mut leaf = [0u8; 32];
             leaf.copy_from_slice(&hash[..32]);
             leaf
         })
-        .collect();
+        .collect()
+}
 
-    use rs_merkle::{algorithms::Sha256 as MerkleSha256, MerkleTree as RsMerkleTree};
-    let tree = RsMerkleTree::<MerkleSha256>::from_leaves(&leaves);
-    tree.root().unwrap_or([0u8; 32])
+/// Merkle tree utilities for hierarchical system
+pub fn compute_merkle_root(hashes: &[&[u8]]) -> [u8; 32] {
+    if hashes.is_empty() {
+        return [0u8; 32];
+    }
+
+    let mut current_level = merkle_leaves(hashes);
+    while current_level.len() > 1 {
+        current_level = merkle_next_level(&current_level);
+    }
+    current_level[0]
 }
 
 /// Compute full Merkle tree with all intermediate nodes for production proofs
@@ -224,48 +318,154 @@ pub fn compute_full_merkle_tree(hashes: &[&[u8]]) -> ([u8; 32], Vec<[u8; 32]>) {
         return ([0u8; 32], Vec::new());
     }
 
-    // Convert to the format expected by rs_merkle
-    let leaves: Vec<[u8; 32]> = hashes
-        .iter()
-        .map(|&hash| {
-            let mut leaf = [0u8; 32];
-            leaf.copy_from_slice(&hash[..32]);
-            leaf
-        })
-        .collect();
+    // Build tree bottom-up, collecting all intermediate nodes level by level
+    let mut all_nodes = Vec::new();
+    let mut current_level = merkle_leaves(hashes);
+    while current_level.len() > 1 {
+        current_level = merkle_next_level(&current_level);
+        all_nodes.extend_from_slice(&current_level);
+    }
 
-    use rs_merkle::{algorithms::Sha256 as MerkleSha256, MerkleTree as RsMerkleTree};
-    let tree = RsMerkleTree::<MerkleSha256>::from_leaves(&leaves);
+    (current_level[0], all_nodes)
+}
 
-    let root = tree.root().unwrap_or([0u8; 32]);
+/// Sibling hash on a Merkle inclusion path
+#[derive(Clone, Debug, PartialEq)]
+pub struct MerklePathNode {
+    /// Sibling hash at this level
+    pub sibling: [u8; 32],
+    /// Whether the sibling is the left operand
+    pub is_left: bool,
+}
 
-    // Generate all intermediate nodes level by level
-    let mut all_nodes = Vec::new();
-    let mut current_level = leaves;
+/// Every level of the Merkle tree over `leaves`, leaves first and the root level
+/// last, with the same shape as `compute_merkle_root`. Build once to derive many paths.
+pub fn compute_merkle_levels(leaves: &[[u8; 32]]) -> Vec<Vec<[u8; 32]>> {
+    let mut levels = vec![leaves.to_vec()];
+    while levels.last().is_some_and(|level| level.len() > 1) {
+        let next = merkle_next_level(levels.last().unwrap());
+        levels.push(next);
+    }
+    levels
+}
 
-    // Build tree bottom-up, collecting all intermediate nodes
-    while current_level.len() > 1 {
-        let mut next_level = Vec::new();
-
-        // Process pairs of nodes
-        for chunk in current_level.chunks(2) {
-            if chunk.len() == 2 {
-                // Pair of nodes - compute parent
-                let parent_data = [&chunk[0][..], &chunk[1][..]].concat();
-                let parent = compute_sha256(&parent_data);
-                all_nodes.push(parent);
-                next_level.push(parent);
-            } else {
-                // Odd node - promote to next level
-                all_nodes.push(chunk[0]);
-                next_level.push(chunk[0]);
-            }
+/// Inclusion path for leaf `index` from tree levels built by `compute_merkle_levels`
+pub fn merkle_path_from_levels(
+    levels: &[Vec<[u8; 32]>],
+    index: usize,
+) -> Option<Vec<MerklePathNode>> {
+    if index >= levels.first()?.len() {
+        return None;
+    }
+
+    let mut path = Vec::new();
+    let mut position = index;
+    for level in &levels[..levels.len() - 1] {
+        let sibling_position = position ^ 1;
+        if sibling_position < level.len() {
+            path.push(MerklePathNode {
+                sibling: level[sibling_position],
+                is_left: sibling_position < position,
+            });
+        }
+        position /= 2;
+    }
+    Some(path)
+}
+
+/// Compute the inclusion path for the leaf at `index`, using the same tree shape
+/// as `compute_merkle_root` (domain-separated SHA256 pairs, odd node promoted)
+pub fn compute_merkle_inclusion_path(
+    leaves: &[[u8; 32]],
+    index: usize,
+) -> Option<Vec<MerklePathNode>> {
+    if index >= leaves.len() {
+        return None;
+    }
+    merkle_path_from_levels(&compute_merkle_levels(leaves), index)
+}
+
+/// Recompute a Merkle root from a leaf and its inclusion path
+pub fn compute_merkle_root_from_path(leaf: [u8; 32], path: &[MerklePathNode]) -> [u8; 32] {
+    path.iter().fold(leaf, |current, node| {
+        if node.is_left {
+            compute_merkle_parent(&node.sibling, &current)
+        } else {
+            compute_merkle_parent(&current, &node.sibling)
+        }
+    })
+}
+
+/// Sibling sides on the inclusion path of leaf `index` in a tree of `leaf_count`
+/// leaves (true when the sibling is the left operand). Levels where the node is
+/// promoted have no sibling and no entry, as in `compute_merkle_inclusion_path`.
+pub fn merkle_path_directions(leaf_count: usize, index: usize) -> Option<Vec<bool>> {
+    if index >= leaf_count {
+        return None;
+    }
+
+    let mut directions = Vec::new();
+    let mut level_len = leaf_count;
+    let mut position = index;
+    while level_len > 1 {
+        let sibling_position = position ^ 1;
+        if sibling_position < level_len {
+            directions.push(sibling_position < position);
         }
+        level_len = level_len.div_ceil(2);
+        position /= 2;
+    }
+    Some(directions)
+}
+
+/// Serialize an inclusion path for the wire: per node, the sibling hash followed by
+/// a side byte (1 when the sibling is the left operand). Every Merkle path in the
+/// crate's proofs uses this encoding.
+pub fn encode_merkle_path(path: &[MerklePathNode]) -> Vec<u8> {
+    path.iter()
+        .flat_map(|node| node.sibling.into_iter().chain([node.is_left as u8]))
+        .collect()
+}
 
-        current_level = next_level;
+/// Decode a path produced by `encode_merkle_path`; None if it is malformed
+pub fn decode_merkle_path(encoded: &[u8]) -> Option<Vec<MerklePathNode>> {
+    if !encoded.len().is_multiple_of(MERKLE_PATH_NODE_SIZE) {
+        return None;
     }
+    encoded
+        .chunks(MERKLE_PATH_NODE_SIZE)
+        .map(|node| match node {
+            [sibling @ .., side] if *side <= 1 => Some(MerklePathNode {
+                sibling: sibling.try_into().ok()?,
+                is_left: *side == 1,
+            }),
+            _ => None,
+        })
+        .collect()
+}
 
-    (root, all_nodes)
+/// Check that `leaf` is leaf `index` of the `leaf_count`-leaf tree under `root`,
+/// given its path from `encode_merkle_path`. The path's sides must be those of the
+/// index, which ties the proof to that position, so one chunk cannot answer for another.
+pub fn verify_merkle_inclusion(
+    leaf: [u8; 32],
+    index: usize,
+    leaf_count: usize,
+    encoded_path: &[u8],
+    root: &[u8],
+) -> bool {
+    let (Some(directions), Some(path)) = (
+        merkle_path_directions(leaf_count, index),
+        decode_merkle_path(encoded_path),
+    ) else {
+        return false;
+    };
+    path.len() == directions.len()
+        && path
+            .iter()
+            .zip(directions)
+            .all(|(node, is_left)| node.is_left == is_left)
+        && compute_merkle_root_from_path(leaf, &path).as_slice() == root
 }
 
 /// Scale monitoring utilities
@@ -435,6 +635,31 @@ pub fn compute_memory_hard_vdf(
     Ok((output, computation_time, memory_usage))
 }
 
+/// Estimated seconds for `compute_memory_hard_vdf` at `hashes_per_second`: one hash
+/// per 32 bytes of memory initialised plus one per iteration
+pub fn estimate_memory_hard_vdf_seconds(
+    iterations: u32,
+    memory_kb: u32,
+    hashes_per_second: f64,
+) -> f64 {
+    let hashes = memory_kb as f64 * 1024.0 / 32.0 + iterations as f64;
+    hashes / hashes_per_second
+}
+
+/// Fail fast on a challenge that cannot be answered in time: its deadline has passed,
+/// or work estimated at `estimated_seconds` from `now` would finish after it
+pub fn check_challenge_deadline(
+    deadline: f64,
+    now: f64,
+    estimated_seconds: Option<f64>,
+) -> HashChainResult<()> {
+    let ready_at = now + estimated_seconds.unwrap_or(0.0);
+    if now > deadline || ready_at > deadline {
+        return Err(HashChainError::ChallengeExpired { deadline, ready_at });
+    }
+    Ok(())
+}
+
 /// HMAC-based key derivation
 pub fn derive_key(master_key: &[u8], context: &[u8], info: &str) -> [u8; 32] {
     type HmacSha256 = Hmac<Sha256>;
@@ -487,6 +712,70 @@ pub fn verify_signature(public_key: &[u8], data: &[u8], signature: &[u8]) -> Has
     Ok(public_key.verify(data, &signature).is_ok())
 }
 
+/// Ed25519 public key belonging to a 32-byte private key
+pub fn derive_public_key(private_key: &[u8]) -> HashChainResult<[u8; 32]> {
+    if private_key.len() != 32 {
+        return Err(HashChainError::InvalidPrivateKeySize(private_key.len()));
+    }
+
+    let secret_key = SecretKey::from_bytes(private_key)
+        .map_err(|e| HashChainError::CryptographicError(format!("Invalid private key: {}", e)))?;
+    Ok(PublicKey::from(&secret_key).to_bytes())
+}
+
+/// Data a verifier signs to authorize a storage challenge: every field except
+/// the signature itself
+pub fn storage_challenge_signing_data(challenge: &StorageChallenge) -> Vec<u8> {
+    let mut data = Vec::new();
+    data.extend_from_slice(STORAGE_CHALLENGE_DOMAIN);
+    data.extend_from_slice(&challenge.challenge_id);
+    data.extend_from_slice(&challenge.prover_key);
+    data.extend_from_slice(&challenge.commitment_hash);
+    data.extend_from_slice(&(challenge.challenged_chunks.len() as u32).to_be_bytes());
+    for chunk in &challenge.challenged_chunks {
+        data.extend_from_slice(&chunk.to_be_bytes());
+    }
+    data.extend_from_slice(&challenge.nonce);
+    data.extend_from_slice(&challenge.timestamp.to_be_bytes());
+    data.extend_from_slice(&challenge.deadline.to_be_bytes());
+    match &challenge.byte_offsets {
+        Some(offsets) => {
+            data.push(1);
+            for offset in offsets {
+                data.extend_from_slice(&offset.to_be_bytes());
+            }
+        }
+        None => data.push(0),
+    }
+    match &challenge.erasure_coding {
+        Some(params) => {
+            data.push(1);
+            data.extend_from_slice(&params.data_shards.to_be_bytes());
+            data.extend_from_slice(&params.parity_shards.to_be_bytes());
+        }
+        None => data.push(0),
+    }
+    if let Some(verifier_key) = &challenge.verifier_key {
+        data.extend_from_slice(verifier_key);
+    }
+    data
+}
+
+/// Whether a storage challenge carries a valid signature from its stated verifier key
+pub fn verify_storage_challenge_signature(challenge: &StorageChallenge) -> bool {
+    let (Some(verifier_key), Some(signature)) =
+        (&challenge.verifier_key, &challenge.verifier_signature)
+    else {
+        return false;
+    };
+    verify_signature(
+        verifier_key,
+        &storage_challenge_signing_data(challenge),
+        signature,
+    )
+    .unwrap_or(false)
+}
+
 /// Generate cryptographically secure random entropy
 pub fn generate_secure_entropy(additional_data: &[u8]) -> [u8; 32] {
     let mut entropy_sources = Vec::new();
@@ -520,11 +809,13 @@ pub struct CommitmentParams<'a> {
     pub chunk_hashes: &'a [Vec<u8>],
     pub vdf_output: &'a [u8],
     pub entropy_hash: &'a [u8],
+    /// Merkle root over all chunk hashes and its leaf count, when the commitment carries them
+    pub merkle_root: Option<(&'a [u8], u64)>,
 }
 
 /// Compute commitment hash from parameters struct
 pub fn compute_commitment_hash(params: &CommitmentParams) -> [u8; 32] {
-    let mut commitment_data = Vec::new();
+    let mut commitment_data = COMMITMENT_HASH_DOMAIN.to_vec();
 
     // Prover identity
     commitment_data.extend_from_slice(params.prover_key);
@@ -552,6 +843,12 @@ pub fn compute_commitment_hash(params: &CommitmentParams) -> [u8; 32] {
     // Entropy contribution
     commitment_data.extend_from_slice(params.entropy_hash);
 
+    // Whole-data Merkle root challenges are proven against
+    if let Some((merkle_root, total_chunks)) = params.merkle_root {
+        commitment_data.extend_from_slice(merkle_root);
+        commitment_data.extend_from_slice(&total_chunks.to_be_bytes());
+    }
+
     // Use Blake3 for final commitment (faster than SHA2)
     compute_blake3(&commitment_data)
 }
@@ -589,6 +886,273 @@ pub fn select_chunks_deterministic(entropy: &[u8], total_chunks: f64, num_chunks
     selected
 }
 
+/// Deterministically select up to `num_chunks` chunks from a commitment's selected
+/// chunks, so every challenged chunk is one the prover committed to
+pub fn select_chunks_from_committed(
+    entropy: &[u8],
+    committed_chunks: &[u32],
+    num_chunks: u32,
+) -> Vec<u32> {
+    let mut committed = committed_chunks.to_vec();
+    committed.sort_unstable();
+    committed.dedup();
+
+    let count = num_chunks.min(committed.len() as u32);
+    let mut selected: Vec<u32> =
+        select_chunks_deterministic(entropy, committed.len() as f64, count)
+            .into_iter()
+            .map(|position| committed[position as usize])
+            .collect();
+    selected.sort_unstable();
+    selected
+}
+
+/// Deterministically select a byte offset within each challenged chunk, leaving
+/// room for `SUB_CHUNK_CHALLENGE_BYTES` revealed bytes in a full chunk
+pub fn select_sub_chunk_offsets(entropy: &[u8], chunk_indices: &[u32]) -> Vec<u32> {
+    let offset_range = CHUNK_SIZE_BYTES - SUB_CHUNK_CHALLENGE_BYTES + 1;
+    chunk_indices
+        .iter()
+        .map(|chunk_index| {
+            let mut seed = Vec::new();
+            seed.extend_from_slice(entropy);
+            seed.extend_from_slice(&chunk_index.to_be_bytes());
+            seed.extend_from_slice(b"sub_chunk_offset");
+            let hash = compute_sha256(&seed);
+            u32::from_be_bytes([hash[0], hash[1], hash[2], hash[3]]) % offset_range
+        })
+        .collect()
+}
+
+/// Split a chunk into the bytes before, at and after a sub-chunk challenge offset.
+/// Ranges past the end of a short final chunk are clipped.
+pub fn split_sub_chunk(chunk: &[u8], byte_offset: u32) -> (&[u8], &[u8], &[u8]) {
+    let start = (byte_offset as usize).min(chunk.len());
+    let end = (start + SUB_CHUNK_CHALLENGE_BYTES as usize).min(chunk.len());
+    (&chunk[..start], &chunk[start..end], &chunk[end..])
+}
+
+/// Verify revealed sub-chunk bytes sit at `byte_offset` of a chunk whose BLAKE3
+/// hash is `chunk_hash`, recomputing the hash over prefix, revealed bytes and suffix
+pub fn verify_sub_chunk_opening(
+    prefix: &[u8],
+    revealed: &[u8],
+    suffix: &[u8],
+    byte_offset: u32,
+    chunk_hash: &[u8],
+) -> bool {
+    // The revealed range is only shorter than requested when it hits the chunk end
+    let offset_matches = prefix.len() == byte_offset as usize
+        || (prefix.len() < byte_offset as usize && revealed.is_empty());
+    let length_matches = revealed.len() == SUB_CHUNK_CHALLENGE_BYTES as usize || suffix.is_empty();
+    if !offset_matches || !length_matches {
+        return false;
+    }
+
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(prefix);
+    hasher.update(revealed);
+    hasher.update(suffix);
+    hasher.finalize().as_bytes().as_slice() == chunk_hash
+}
+
+/// Check a storage challenge response against the prover's `commitment`.
+/// Each returned chunk must hash to the commitment's chunk hash at the position of
+/// that chunk in its selected chunks. Chunks the commitment did not select need a
+/// committed Merkle root instead. When the commitment has a root, every challenged
+/// chunk's hash is also rebuilt up to it with its Merkle proof. Sub-chunk responses
+/// need each revealed byte range to rebuild the committed hash of its chunk.
+pub fn verify_challenge_response_data(
+    response: &ChallengeResponse,
+    original_challenge: &StorageChallenge,
+    commitment: &StorageCommitment,
+) -> bool {
+    if response.challenge_id.len() != original_challenge.challenge_id.len()
+        || response.access_proof.iterations == 0
+    {
+        return false;
+    }
+
+    let challenged_chunks = &original_challenge.challenged_chunks;
+    let committed_hash = |chunk_index: u32| {
+        commitment
+            .selected_chunks
+            .iter()
+            .position(|&chunk| chunk == chunk_index)
+            .and_then(|position| commitment.chunk_hashes.get(position))
+    };
+    let committed_tree = commitment
+        .merkle_root
+        .as_ref()
+        .zip(commitment.total_chunks)
+        .map(|(root, total_chunks)| (root.as_ref(), total_chunks as usize));
+    if committed_tree.is_some() && response.merkle_proofs.len() != challenged_chunks.len() {
+        return false;
+    }
+    let proves_inclusion = |position: usize, chunk_index: u32, chunk_hash: [u8; 32]| {
+        committed_tree.is_none_or(|(root, total_chunks)| {
+            verify_merkle_inclusion(
+                chunk_hash,
+                chunk_index as usize,
+                total_chunks,
+                &response.merkle_proofs[position],
+                root,
+            )
+        })
+    };
+
+    let Some(byte_offsets) = &original_challenge.byte_offsets else {
+        return response.chunk_data.len() == challenged_chunks.len()
+            && challenged_chunks
+                .iter()
+                .zip(&response.chunk_data)
+                .enumerate()
+                .all(|(position, (&chunk_index, chunk))| {
+                    let chunk_hash = compute_blake3(chunk);
+                    let matches_commitment = match committed_hash(chunk_index) {
+                        Some(committed) => committed.as_ref() == chunk_hash.as_slice(),
+                        None => committed_tree.is_some(),
+                    };
+                    matches_commitment && proves_inclusion(position, chunk_index, chunk_hash)
+                });
+    };
+    let Some(proofs) = &response.sub_chunk_proofs else {
+        return false;
+    };
+    if proofs.len() != challenged_chunks.len() || byte_offsets.len() != proofs.len() {
+        return false;
+    }
+
+    challenged_chunks
+        .iter()
+        .zip(byte_offsets)
+        .zip(proofs)
+        .enumerate()
+        .all(|(position, ((&chunk_index, &byte_offset), proof))| {
+            proof.chunk_index == chunk_index
+                && proof.byte_offset == byte_offset
+                && committed_hash(chunk_index).is_some_and(|chunk_hash| {
+                    chunk_hash.len() == HASH_SIZE
+                        && verify_sub_chunk_opening(
+                            &proof.prefix,
+                            &proof.revealed_bytes,
+                            &proof.suffix,
+                            byte_offset,
+                            chunk_hash,
+                        )
+                        && proves_inclusion(
+                            position,
+                            chunk_index,
+                            chunk_hash.as_ref().try_into().unwrap(),
+                        )
+                })
+        })
+}
+
+/// Leaves of a chunk's slice Merkle tree, one per `AVAILABILITY_SLICE_BYTES` slice
+fn chunk_slice_leaves(chunk: &[u8]) -> Vec<[u8; 32]> {
+    chunk
+        .chunks(AVAILABILITY_SLICE_BYTES as usize)
+        .map(|slice| compute_sha256(&[CHUNK_SLICE_LEAF_DOMAIN, slice].concat()))
+        .collect()
+}
+
+/// Merkle root over a chunk's slices, letting a single slice be proven against it
+pub fn compute_chunk_slice_root(chunk: &[u8]) -> [u8; 32] {
+    let leaves = chunk_slice_leaves(chunk);
+    let leaf_refs: Vec<&[u8]> = leaves.iter().map(|leaf| leaf.as_slice()).collect();
+    compute_merkle_root(&leaf_refs)
+}
+
+/// Open one slice of a chunk: its bytes and its path up to the slice root, encoded
+/// by `encode_merkle_path`
+pub fn open_chunk_slice(chunk: &[u8], slice_index: u32) -> Option<(Vec<u8>, Vec<u8>)> {
+    let leaves = chunk_slice_leaves(chunk);
+    let path = compute_merkle_inclusion_path(&leaves, slice_index as usize)?;
+    let slice = chunk
+        .chunks(AVAILABILITY_SLICE_BYTES as usize)
+        .nth(slice_index as usize)?;
+    Some((slice.to_vec(), encode_merkle_path(&path)))
+}
+
+/// Verify a served slice sits at `slice_index` of a full chunk with `slice_root`,
+/// given its path from `open_chunk_slice`
+pub fn verify_chunk_slice(
+    slice: &[u8],
+    slice_index: u32,
+    encoded_path: &[u8],
+    slice_root: &[u8],
+) -> bool {
+    let slice_count = CHUNK_SIZE_BYTES / AVAILABILITY_SLICE_BYTES;
+    if slice.len() != AVAILABILITY_SLICE_BYTES as usize {
+        return false;
+    }
+    let leaf = compute_sha256(&[CHUNK_SLICE_LEAF_DOMAIN, slice].concat());
+    verify_merkle_inclusion(
+        leaf,
+        slice_index as usize,
+        slice_count as usize,
+        encoded_path,
+        slice_root,
+    )
+}
+
+/// Hash summarizing commitments truncated from a chain's log, extending any
+/// earlier checkpoint so the summary always reaches back to genesis
+pub fn compute_log_checkpoint_hash(
+    previous_checkpoint: Option<&[u8]>,
+    truncated_commitments: &[&[u8]],
+) -> [u8; 32] {
+    let mut data = Vec::new();
+    data.extend_from_slice(b"hashchain_log_checkpoint");
+    data.extend_from_slice(previous_checkpoint.unwrap_or(&[0u8; 32]));
+    for commitment_hash in truncated_commitments {
+        data.extend_from_slice(commitment_hash);
+    }
+    compute_sha256(&data)
+}
+
+/// Checksum over the canonical serialization of every header field except the
+/// checksum itself. Buffers are length prefixed and numbers big-endian.
+pub fn compute_header_checksum(header: &HashChainHeader) -> [u8; 32] {
+    fn put(data: &mut Vec<u8>, bytes: &[u8]) {
+        data.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+        data.extend_from_slice(bytes);
+    }
+
+    let mut data = Vec::new();
+    data.extend_from_slice(HASHCHAIN_HEADER_CHECKSUM_DOMAIN);
+    put(&mut data, &header.magic);
+    data.extend_from_slice(&header.format_version.to_be_bytes());
+    put(&mut data, &header.data_file_hash);
+    put(&mut data, &header.merkle_root);
+    data.extend_from_slice(&header.total_chunks.to_be_bytes());
+    data.extend_from_slice(&header.chunk_size.to_be_bytes());
+    put(&mut data, &header.data_file_path_hash);
+    put(&mut data, &header.anchored_commitment);
+    data.extend_from_slice(&header.chain_length.to_be_bytes());
+    put(&mut data, &header.public_key);
+    data.extend_from_slice(&header.initial_block_height.to_be_bytes());
+    put(&mut data, &header.initial_block_hash);
+    match &header.erasure_coding {
+        Some(params) => {
+            data.push(1);
+            data.extend_from_slice(&params.data_shards.to_be_bytes());
+            data.extend_from_slice(&params.parity_shards.to_be_bytes());
+        }
+        None => data.push(0),
+    }
+    // Appended only when present so headers written without it keep their checksum
+    if let Some(info) = &header.file_encoding {
+        put(&mut data, &info.original_hash);
+        put(&mut data, &info.encoded_hash);
+        put(&mut data, &info.prover_key);
+        data.extend_from_slice(&info.encoding_version.to_be_bytes());
+        put(&mut data, &info.encoding_params);
+    }
+    compute_sha256(&data)
+}
+
 /// Verify chunk selection algorithm
 pub fn verify_chunk_selection(entropy: &[u8], total_chunks: u32, selected_chunks: &[u32]) -> bool {
     let num_chunks = selected_chunks.len() as u32;
@@ -607,21 +1171,68 @@ pub fn verify_chunk_selection(entropy: &[u8], total_chunks: u32, selected_chunks
     true
 }
 
-/// Generate multi-source entropy for enhanced randomness
+/// Append beacon entropy behind a presence flag, so an absent beacon never hashes
+/// the same as a beacon that happens to be all zeros
+fn append_beacon_entropy(data: &mut Vec<u8>, beacon_entropy: Option<&[u8]>) {
+    match beacon_entropy {
+        Some(beacon) => {
+            data.push(1);
+            data.extend_from_slice(beacon);
+        }
+        None => data.push(0),
+    }
+}
+
+/// Reproducible combined hash of entropy sources, as carried in
+/// `MultiSourceEntropy.combined_hash` by every code path that builds one
+pub fn combine_entropy_sources(
+    blockchain_entropy: &[u8],
+    beacon_entropy: Option<&[u8]>,
+    local_entropy: &[u8],
+    timestamp: f64,
+) -> [u8; 32] {
+    let mut combined = COMBINED_ENTROPY_DOMAIN.to_vec();
+    combined.extend_from_slice(blockchain_entropy);
+    append_beacon_entropy(&mut combined, beacon_entropy);
+    combined.extend_from_slice(local_entropy);
+    combined.extend_from_slice(&timestamp.to_be_bytes());
+    compute_sha256(&combined)
+}
+
+/// Entropy for a prover's commitment at the block `entropy_seed` identifies: block
+/// entropy derived from the seed, fresh local entropy, and the reproducible combined
+/// hash verifiers recompute with `combine_entropy_sources`
+pub fn commitment_entropy(prover_key: &[u8], entropy_seed: &[u8]) -> MultiSourceEntropy {
+    let blockchain_entropy = generate_deterministic_bytes(entropy_seed, 32);
+    let local_entropy = generate_secure_entropy(prover_key);
+    let timestamp = get_current_timestamp();
+    let combined_hash =
+        combine_entropy_sources(&blockchain_entropy, None, &local_entropy, timestamp);
+
+    MultiSourceEntropy {
+        blockchain_entropy: Buffer::from(blockchain_entropy),
+        beacon_entropy: None,
+        local_entropy: Buffer::from(local_entropy.to_vec()),
+        timestamp,
+        combined_hash: Buffer::from(combined_hash.to_vec()),
+    }
+}
+
+/// Generate fresh multi-source entropy for enhanced randomness. Mixes in system
+/// randomness, so the result is a seed and cannot be recomputed by verifiers; use
+/// `combine_entropy_sources` for reproducible combined hashes.
 pub fn generate_multi_source_entropy(
     blockchain_entropy: &[u8],
     beacon_entropy: Option<&[u8]>,
     prover_entropy: &[u8],
 ) -> [u8; 32] {
-    let mut entropy_data = Vec::new();
+    let mut entropy_data = MULTI_SOURCE_ENTROPY_DOMAIN.to_vec();
 
     // Blockchain randomness
     entropy_data.extend_from_slice(blockchain_entropy);
 
     // External beacon (if available)
-    if let Some(beacon) = beacon_entropy {
-        entropy_data.extend_from_slice(beacon);
-    }
+    append_beacon_entropy(&mut entropy_data, beacon_entropy);
 
     // Prover-specific entropy
     entropy_data.extend_from_slice(prover_entropy);
@@ -712,6 +1323,46 @@ impl ContinuousVDF {
         (self.current_state, self.total_iterations)
     }
 
+    /// Restore a VDF from a captured state, iteration count and memory buffer
+    pub fn from_snapshot(
+        state: [u8; 32],
+        total_iterations: u64,
+        memory_buffer: Vec<u8>,
+    ) -> HashChainResult<Self> {
+        if memory_buffer.len() <= 64 {
+            return Err(HashChainError::VDFError(format!(
+                "VDF memory snapshot too small: {} bytes",
+                memory_buffer.len()
+            )));
+        }
+
+        Ok(Self {
+            current_state: state,
+            total_iterations,
+            last_block_height: 0,
+            last_block_hash: [0u8; 32],
+            memory_size: memory_buffer.len(),
+            memory_buffer,
+            start_time: std::time::Instant::now(),
+        })
+    }
+
+    /// Get a copy of the VDF memory buffer
+    pub fn memory_snapshot(&self) -> Vec<u8> {
+        self.memory_buffer.clone()
+    }
+
+    /// Height and hash of the last block signed
+    pub fn last_block(&self) -> (u64, [u8; 32]) {
+        (self.last_block_height, self.last_block_hash)
+    }
+
+    /// Restore the last signed block, e.g. when resuming from a snapshot
+    pub fn set_last_block(&mut self, block_height: u64, block_hash: [u8; 32]) {
+        self.last_block_height = block_height;
+        self.last_block_hash = block_hash;
+    }
+
     /// Sign a block against the current VDF state
     pub fn sign_block(
         &mut self,
@@ -727,15 +1378,12 @@ impl ContinuousVDF {
         }
 
         // Create block signature using VDF state
-        let signature_data = [
-            &self.current_state[..],
-            &block_height.to_be_bytes(),
-            &block_hash[..],
-            &self.total_iterations.to_be_bytes(),
-        ]
-        .concat();
-
-        let signature = compute_blake3(&signature_data);
+        let signature = compute_vdf_block_signature(
+            &self.current_state,
+            block_height,
+            &block_hash,
+            self.total_iterations,
+        );
 
         // Update last block info
         self.last_block_height = block_height;
@@ -744,6 +1392,35 @@ impl ContinuousVDF {
         Ok(signature)
     }
 
+    /// Sign a contiguous range of blocks, committed to by the Merkle root of
+    /// their block leaves, against the current VDF state
+    pub fn sign_block_range(
+        &mut self,
+        start_height: u64,
+        end_height: u64,
+        range_root: [u8; 32],
+        required_iterations: u64,
+    ) -> HashChainResult<[u8; 32]> {
+        if self.total_iterations < required_iterations {
+            return Err(HashChainError::VDFError(format!(
+                "Insufficient VDF iterations: {} < {}",
+                self.total_iterations, required_iterations
+            )));
+        }
+
+        let signature = compute_block_range_signature(
+            &self.current_state,
+            start_height,
+            end_height,
+            &range_root,
+            self.total_iterations,
+        );
+
+        self.last_block_height = end_height;
+
+        Ok(signature)
+    }
+
     /// Verify a block signature
     pub fn verify_block_signature(
         &self,
@@ -756,22 +1433,182 @@ impl ContinuousVDF {
             return false;
         }
 
-        let signature_data = [
-            &self.current_state[..],
+        let expected_signature = compute_vdf_block_signature(
+            &self.current_state,
+            block_height,
+            &block_hash,
+            self.total_iterations,
+        );
+        signature == expected_signature
+    }
+}
+
+/// Continuous VDF block signature over VDF state, block height, block hash and
+/// iteration count. Binding the block hash keeps a signature from being reused
+/// for any other block.
+pub fn compute_vdf_block_signature(
+    vdf_state: &[u8; 32],
+    block_height: u64,
+    block_hash: &[u8; 32],
+    total_iterations: u64,
+) -> [u8; 32] {
+    compute_blake3(
+        &[
+            VDF_BLOCK_SIGNATURE_DOMAIN,
+            &vdf_state[..],
             &block_height.to_be_bytes(),
             &block_hash[..],
-            &self.total_iterations.to_be_bytes(),
+            &total_iterations.to_be_bytes(),
         ]
-        .concat();
+        .concat(),
+    )
+}
 
-        let expected_signature = compute_blake3(&signature_data);
-        signature == expected_signature
+/// Leaf committing to a single block inside a signed block range
+pub fn compute_block_range_leaf(block_height: u64, block_hash: &[u8; 32]) -> [u8; 32] {
+    compute_blake3(
+        &[
+            VDF_BLOCK_RANGE_LEAF_DOMAIN,
+            &block_height.to_be_bytes(),
+            &block_hash[..],
+        ]
+        .concat(),
+    )
+}
+
+/// Range signature over VDF state, range bounds, range root and iteration count
+pub fn compute_block_range_signature(
+    vdf_state: &[u8; 32],
+    start_height: u64,
+    end_height: u64,
+    range_root: &[u8; 32],
+    total_iterations: u64,
+) -> [u8; 32] {
+    compute_blake3(
+        &[
+            VDF_BLOCK_RANGE_SIGNATURE_DOMAIN,
+            &vdf_state[..],
+            &start_height.to_be_bytes(),
+            &end_height.to_be_bytes(),
+            &range_root[..],
+            &total_iterations.to_be_bytes(),
+        ]
+        .concat(),
+    )
+}
+
+/// Continuous VDF segment revealed in answer to a reproduction challenge
+#[derive(Clone, Debug)]
+pub struct VDFSegmentReproduction {
+    /// Iteration index the segment starts at
+    pub iteration_index: u64,
+    /// Number of iterations in the segment
+    pub segment_length: u64,
+    /// VDF state at iteration_index
+    pub start_state: [u8; 32],
+    /// VDF memory buffer at iteration_index
+    pub memory_snapshot: Vec<u8>,
+    /// VDF state after segment_length further iterations
+    pub end_state: [u8; 32],
+}
+
+/// Resume a continuous VDF from its checkpoint at `iteration_index` and capture
+/// the segment of `segment_length` iterations starting there
+pub fn reproduce_continuous_vdf_segment(
+    start_state: [u8; 32],
+    iteration_index: u64,
+    memory_snapshot: Vec<u8>,
+    segment_length: u64,
+) -> HashChainResult<VDFSegmentReproduction> {
+    if segment_length == 0 || segment_length > VDF_REPRODUCTION_MAX_SEGMENT as u64 {
+        return Err(HashChainError::InvalidProofParameters {
+            reason: format!(
+                "Segment length must be between 1 and {}, got {}",
+                VDF_REPRODUCTION_MAX_SEGMENT, segment_length
+            ),
+        });
+    }
+
+    let mut vdf = ContinuousVDF::from_snapshot(start_state, iteration_index, memory_snapshot)?;
+    let memory_snapshot = vdf.memory_snapshot();
+    for _ in 0..segment_length {
+        vdf.iterate();
+    }
+
+    let (end_state, _) = vdf.get_state();
+
+    Ok(VDFSegmentReproduction {
+        iteration_index,
+        segment_length,
+        start_state,
+        memory_snapshot,
+        end_state,
+    })
+}
+
+/// State of a continuous VDF after `iterations` iterations from its initial state
+pub fn replay_continuous_vdf_state(
+    initial_state: [u8; 32],
+    memory_kb: u32,
+    iterations: u64,
+) -> [u8; 32] {
+    let mut vdf = ContinuousVDF::new(initial_state, memory_kb);
+    for _ in 0..iterations {
+        vdf.iterate();
+    }
+    vdf.get_state().0
+}
+
+/// State and memory of a continuous VDF after `iterations` iterations from its
+/// initial state, enough to resume the VDF from that point
+pub fn replay_continuous_vdf_checkpoint(
+    initial_state: [u8; 32],
+    memory_kb: u32,
+    iterations: u64,
+) -> ([u8; 32], Vec<u8>) {
+    let mut vdf = ContinuousVDF::new(initial_state, memory_kb);
+    for _ in 0..iterations {
+        vdf.iterate();
     }
+    (vdf.get_state().0, vdf.memory_snapshot())
+}
+
+/// Spot-check a revealed continuous VDF segment by recomputing it from the
+/// revealed state and memory
+pub fn verify_continuous_vdf_segment(
+    reproduction: &VDFSegmentReproduction,
+    expected_memory_kb: u32,
+) -> bool {
+    if reproduction.segment_length == 0
+        || reproduction.segment_length > VDF_REPRODUCTION_MAX_SEGMENT as u64
+    {
+        return false;
+    }
+
+    if reproduction.memory_snapshot.len() != expected_memory_kb as usize * 1024 {
+        return false;
+    }
+
+    let mut vdf = match ContinuousVDF::from_snapshot(
+        reproduction.start_state,
+        reproduction.iteration_index,
+        reproduction.memory_snapshot.clone(),
+    ) {
+        Ok(vdf) => vdf,
+        Err(_) => return false,
+    };
+
+    for _ in 0..reproduction.segment_length {
+        vdf.iterate();
+    }
+
+    let (end_state, _) = vdf.get_state();
+    end_state == reproduction.end_state
 }
 
 /// Sign block data using Ed25519 signature
 pub fn sign_block(
-    private_key: &[u8],
+    signer: &dyn crate::core::signer::Signer,
     block_height: u64,
     block_hash: &[u8],
     vdf_state: &[u8],
@@ -779,6 +1616,7 @@ pub fn sign_block(
 ) -> HashChainResult<Vec<u8>> {
     // Create block data to sign
     let block_data = [
+        VDF_BLOCK_SIGNING_DOMAIN,
         &block_height.to_be_bytes(),
         block_hash,
         vdf_state,
@@ -787,7 +1625,7 @@ pub fn sign_block(
     .concat();
 
     // Sign the block data
-    sign_data(private_key, &block_data)
+    signer.sign(&block_data)
 }
 
 /// Verify block signature
@@ -801,6 +1639,7 @@ pub fn verify_block_signature(
 ) -> HashChainResult<bool> {
     // Recreate block data
     let block_data = [
+        VDF_BLOCK_SIGNING_DOMAIN,
         &block_height.to_be_bytes(),
         block_hash,
         vdf_state,
@@ -831,6 +1670,514 @@ mod tests {
         assert_eq!(chain_id.len(), 32);
     }
 
+    #[test]
+    fn test_challenge_response_data_verification() {
+        let chunks: Vec<Vec<u8>> = (0..4u8).map(|i| vec![i; 64]).collect();
+        let leaves: Vec<[u8; 32]> = chunks.iter().map(|chunk| compute_blake3(chunk)).collect();
+        let leaf_refs: Vec<&[u8]> = leaves.iter().map(|leaf| leaf.as_slice()).collect();
+
+        // Chunks 1 and 2 are selected; chunk 3 is only covered by the Merkle root
+        let mut commitment = crate::consensus::commitments::tests::storage_commitment(1, 2, 10);
+        commitment.selected_chunks = vec![1, 2];
+        commitment.chunk_hashes = vec![
+            Buffer::from(leaves[1].to_vec()),
+            Buffer::from(leaves[2].to_vec()),
+        ];
+        commitment.merkle_root = Some(Buffer::from(compute_merkle_root(&leaf_refs).to_vec()));
+        commitment.total_chunks = Some(4.0);
+
+        let challenge = StorageChallenge {
+            challenge_id: Buffer::from(vec![9u8; 32]),
+            prover_key: commitment.prover_key.clone(),
+            commitment_hash: commitment.commitment_hash.clone(),
+            challenged_chunks: vec![1, 3],
+            nonce: Buffer::from(vec![0u8; 32]),
+            timestamp: 0.0,
+            deadline: 0.0,
+            byte_offsets: None,
+            verifier_key: None,
+            verifier_signature: None,
+            erasure_coding: None,
+        };
+        let path = |index: usize| {
+            Buffer::from(encode_merkle_path(
+                &compute_merkle_inclusion_path(&leaves, index).unwrap(),
+            ))
+        };
+        let response = ChallengeResponse {
+            challenge_id: challenge.challenge_id.clone(),
+            chunk_data: vec![
+                Buffer::from(chunks[1].clone()),
+                Buffer::from(chunks[3].clone()),
+            ],
+            merkle_proofs: vec![path(1), path(3)],
+            timestamp: 0.0,
+            access_proof: commitment.vdf_proof.clone(),
+            sub_chunk_proofs: None,
+            measured_latency_ms: None,
+        };
+        assert!(verify_challenge_response_data(
+            &response,
+            &challenge,
+            &commitment
+        ));
+
+        // Data that does not hash to the committed chunk hash is rejected
+        let mut mismatched = response.clone();
+        mismatched.chunk_data[0] = Buffer::from(vec![7u8; 64]);
+        assert!(!verify_challenge_response_data(
+            &mismatched,
+            &challenge,
+            &commitment
+        ));
+
+        // A valid path for another chunk index does not prove inclusion
+        let mut wrong_index = response.clone();
+        wrong_index.merkle_proofs[1] = path(2);
+        assert!(!verify_challenge_response_data(
+            &wrong_index,
+            &challenge,
+            &commitment
+        ));
+    }
+
+    #[test]
+    fn test_continuous_vdf_segment_reproduction() {
+        let initial_state = [7u8; 32];
+        let (checkpoint_state, checkpoint_memory) =
+            replay_continuous_vdf_checkpoint(initial_state, 4, 50);
+        let reproduction =
+            reproduce_continuous_vdf_segment(checkpoint_state, 50, checkpoint_memory.clone(), 20)
+                .unwrap();
+
+        // Segment must land on the same state as an uninterrupted run
+        let mut vdf = ContinuousVDF::new(initial_state, 4);
+        for _ in 0..70 {
+            vdf.iterate();
+        }
+        assert_eq!(vdf.get_state().0, reproduction.end_state);
+        assert!(verify_continuous_vdf_segment(&reproduction, 4));
+
+        // Replaying to an iteration count reaches the state the running VDF had there
+        assert_eq!(
+            replay_continuous_vdf_state(initial_state, 4, 50),
+            reproduction.start_state
+        );
+        assert_eq!(
+            replay_continuous_vdf_state(initial_state, 4, 70),
+            reproduction.end_state
+        );
+
+        // The segment reveals the checkpoint it resumed from
+        assert_eq!(checkpoint_state, reproduction.start_state);
+        assert_eq!(checkpoint_memory, reproduction.memory_snapshot);
+
+        // Segments longer than a verifier recomputes are refused
+        assert!(reproduce_continuous_vdf_segment(
+            checkpoint_state,
+            50,
+            checkpoint_memory,
+            VDF_REPRODUCTION_MAX_SEGMENT as u64 + 1
+        )
+        .is_err());
+
+        // A fabricated intermediate state must not reproduce the segment
+        let mut forged = reproduction.clone();
+        forged.start_state[0] ^= 1;
+        assert!(!verify_continuous_vdf_segment(&forged, 4));
+
+        // Claiming a different iteration index changes the recomputation
+        let mut shifted = reproduction.clone();
+        shifted.iteration_index += 1;
+        assert!(!verify_continuous_vdf_segment(&shifted, 4));
+    }
+
+    #[test]
+    fn test_select_chunks_from_committed() {
+        let committed = [3, 17, 17, 42, 99, 150];
+        let selected = select_chunks_from_committed(&[5u8; 32], &committed, 4);
+        assert_eq!(selected.len(), 4);
+        assert!(selected.iter().all(|chunk| committed.contains(chunk)));
+        assert!(selected.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(
+            selected,
+            select_chunks_from_committed(&[5u8; 32], &committed, 4)
+        );
+
+        // Never more than the distinct committed chunks
+        assert_eq!(
+            select_chunks_from_committed(&[5u8; 32], &[8, 8, 9], 4),
+            vec![8, 9]
+        );
+        assert!(select_chunks_from_committed(&[5u8; 32], &[], 4).is_empty());
+    }
+
+    #[test]
+    fn test_chunk_slice_opening() {
+        let chunk: Vec<u8> = (0..CHUNK_SIZE_BYTES).map(|i| (i % 241) as u8).collect();
+        let slice_root = compute_chunk_slice_root(&chunk);
+
+        for slice_index in [0, 1, 37, CHUNK_SIZE_BYTES / AVAILABILITY_SLICE_BYTES - 1] {
+            let (slice, path) = open_chunk_slice(&chunk, slice_index).unwrap();
+            assert!(verify_chunk_slice(&slice, slice_index, &path, &slice_root));
+
+            // The slice must be the one at the claimed index
+            assert!(!verify_chunk_slice(
+                &slice,
+                slice_index ^ 1,
+                &path,
+                &slice_root
+            ));
+        }
+
+        let (mut slice, path) = open_chunk_slice(&chunk, 5).unwrap();
+        slice[0] ^= 1;
+        assert!(!verify_chunk_slice(&slice, 5, &path, &slice_root));
+        assert!(open_chunk_slice(&chunk, CHUNK_SIZE_BYTES / AVAILABILITY_SLICE_BYTES).is_none());
+    }
+
+    #[test]
+    fn test_sub_chunk_opening() {
+        let chunk: Vec<u8> = (0..CHUNK_SIZE_BYTES).map(|i| (i % 251) as u8).collect();
+        let chunk_hash = compute_blake3(&chunk);
+
+        let offsets = select_sub_chunk_offsets(&[9u8; 32], &[1, 2, 3]);
+        assert_eq!(offsets, select_sub_chunk_offsets(&[9u8; 32], &[1, 2, 3]));
+        assert!(offsets
+            .iter()
+            .all(|&offset| offset + SUB_CHUNK_CHALLENGE_BYTES <= CHUNK_SIZE_BYTES));
+
+        let (prefix, revealed, suffix) = split_sub_chunk(&chunk, offsets[0]);
+        assert_eq!(revealed.len(), SUB_CHUNK_CHALLENGE_BYTES as usize);
+        assert!(verify_sub_chunk_opening(
+            prefix,
+            revealed,
+            suffix,
+            offsets[0],
+            &chunk_hash
+        ));
+
+        // Tampered bytes or a shifted offset are rejected
+        let mut tampered = revealed.to_vec();
+        tampered[0] ^= 1;
+        assert!(!verify_sub_chunk_opening(
+            prefix,
+            &tampered,
+            suffix,
+            offsets[0],
+            &chunk_hash
+        ));
+        assert!(!verify_sub_chunk_opening(
+            prefix,
+            revealed,
+            suffix,
+            offsets[0] + 1,
+            &chunk_hash
+        ));
+
+        // A short final chunk reveals only the bytes it has
+        let short_chunk = &chunk[..100];
+        let short_hash = compute_blake3(short_chunk);
+        let (prefix, revealed, suffix) = split_sub_chunk(short_chunk, 90);
+        assert_eq!(revealed.len(), 10);
+        assert!(verify_sub_chunk_opening(
+            prefix,
+            revealed,
+            suffix,
+            90,
+            &short_hash
+        ));
+    }
+
+    #[test]
+    fn test_storage_challenge_signature() {
+        let verifier_private_key = [7u8; 32];
+        let mut challenge = StorageChallenge {
+            challenge_id: Buffer::from(vec![1u8; 32]),
+            prover_key: Buffer::from(vec![2u8; 32]),
+            commitment_hash: Buffer::from(vec![3u8; 32]),
+            challenged_chunks: vec![1, 5, 9],
+            nonce: Buffer::from(vec![4u8; 12]),
+            timestamp: 1_700_000_000.0,
+            deadline: 1_700_000_030.0,
+            byte_offsets: None,
+            verifier_key: Some(Buffer::from(
+                derive_public_key(&verifier_private_key).unwrap().to_vec(),
+            )),
+            verifier_signature: None,
+            erasure_coding: None,
+        };
+        assert!(!verify_storage_challenge_signature(&challenge));
+
+        let signature = sign_data(
+            &verifier_private_key,
+            &storage_challenge_signing_data(&challenge),
+        )
+        .unwrap();
+        challenge.verifier_signature = Some(Buffer::from(signature));
+        assert!(verify_storage_challenge_signature(&challenge));
+
+        // Any altered field invalidates the signature
+        challenge.challenged_chunks[0] = 2;
+        assert!(!verify_storage_challenge_signature(&challenge));
+    }
+
+    #[test]
+    fn test_log_checkpoint_hash_chains_across_compactions() {
+        let commitments = [[1u8; 32], [2u8; 32], [3u8; 32]];
+
+        // Compacting in two steps extends the first checkpoint instead of replacing it
+        let first = compute_log_checkpoint_hash(None, &[&commitments[0]]);
+        let second = compute_log_checkpoint_hash(Some(&first), &[&commitments[1]]);
+        assert_ne!(first, second);
+        assert_eq!(
+            second,
+            compute_log_checkpoint_hash(Some(&first), &[&commitments[1]])
+        );
+        assert_ne!(
+            second,
+            compute_log_checkpoint_hash(None, &[&commitments[1]])
+        );
+    }
+
+    #[test]
+    fn test_vdf_block_signature_depends_on_block_hash() {
+        let mut vdf = ContinuousVDF::new([3u8; 32], 64);
+        for _ in 0..10 {
+            vdf.iterate();
+        }
+
+        let signature = vdf.sign_block(5, [1u8; 32], 10).unwrap();
+        let other_signature = vdf.sign_block(5, [2u8; 32], 10).unwrap();
+        assert_ne!(signature, other_signature);
+
+        // A signature only verifies for the block hash it was produced for
+        assert!(vdf.verify_block_signature(5, [1u8; 32], signature, 10));
+        assert!(!vdf.verify_block_signature(5, [2u8; 32], signature, 10));
+
+        let (state, iterations) = vdf.get_state();
+        assert_eq!(
+            compute_vdf_block_signature(&state, 5, &[1u8; 32], iterations),
+            signature
+        );
+    }
+
+    #[test]
+    fn test_hash_domains_separate_constructions() {
+        let domains = [
+            COMMITMENT_HASH_DOMAIN,
+            PHYSICAL_ACCESS_COMMITMENT_DOMAIN,
+            ENHANCED_PHYSICAL_ACCESS_DOMAIN,
+            ENHANCED_ENCODING_DOMAIN,
+            ENHANCED_OWNERSHIP_DOMAIN,
+            ENHANCED_VDF_INPUT_DOMAIN,
+            GEOGRAPHIC_DISTRIBUTION_DOMAIN,
+            VDF_BLOCK_SIGNATURE_DOMAIN,
+            VDF_BLOCK_RANGE_LEAF_DOMAIN,
+            VDF_BLOCK_RANGE_SIGNATURE_DOMAIN,
+            VDF_BLOCK_SIGNING_DOMAIN,
+            SHARED_VDF_PROOF_DOMAIN,
+            VDF_PROOF_CHAIN_DOMAIN,
+            COMBINED_ENTROPY_DOMAIN,
+            MULTI_SOURCE_ENTROPY_DOMAIN,
+            MERKLE_NODE_DOMAIN,
+            STORAGE_CHALLENGE_DOMAIN,
+        ];
+
+        // No tag is a prefix of another, so tagged inputs from different
+        // constructions can never be the same byte string
+        for (i, a) in domains.iter().enumerate() {
+            for (j, b) in domains.iter().enumerate() {
+                if i != j {
+                    assert!(!b.starts_with(a), "{:?} prefixes {:?}", a, b);
+                }
+            }
+        }
+
+        let input = [7u8; 64];
+        let hashes: std::collections::HashSet<[u8; 32]> = domains
+            .iter()
+            .map(|domain| compute_blake3(&[*domain, &input[..]].concat()))
+            .collect();
+        assert_eq!(hashes.len(), domains.len());
+
+        // Same 32-byte halves hash differently as a Merkle node and as a VDF range leaf
+        let left = [1u8; 32];
+        let right = [2u8; 32];
+        assert_ne!(
+            compute_merkle_parent(&left, &right),
+            compute_sha256(&[&left[..], &right[..]].concat())
+        );
+        assert_ne!(
+            compute_merkle_root(&[&left, &right]),
+            compute_block_range_leaf(0, &right)
+        );
+
+        // Inclusion paths still agree with the domain-separated root
+        let leaves = [[1u8; 32], [2u8; 32], [3u8; 32]];
+        let leaf_refs: Vec<&[u8]> = leaves.iter().map(|leaf| &leaf[..]).collect();
+        let root = compute_merkle_root(&leaf_refs);
+        for (index, leaf) in leaves.iter().enumerate() {
+            let path = compute_merkle_inclusion_path(&leaves, index).unwrap();
+            assert_eq!(compute_merkle_root_from_path(*leaf, &path), root);
+        }
+        assert_eq!(compute_full_merkle_tree(&leaf_refs).0, root);
+    }
+
+    #[test]
+    fn test_challenge_deadline() {
+        let now = get_current_timestamp();
+
+        // Already past: rejected before any estimate is considered
+        let expired = check_challenge_deadline(now - 1.0, now, None);
+        assert!(matches!(
+            expired,
+            Err(HashChainError::ChallengeExpired { ready_at, .. }) if ready_at == now
+        ));
+        assert!(check_challenge_deadline(now - 1.0, now, Some(0.0)).is_err());
+
+        // Still open, but the work would finish too late
+        assert!(check_challenge_deadline(now + 30.0, now, Some(10.0)).is_ok());
+        assert!(check_challenge_deadline(now + 30.0, now, Some(45.0)).is_err());
+        assert!(check_challenge_deadline(now + 30.0, now, None).is_ok());
+
+        // 1 MiB initialised (32768 hashes) plus 32768 iterations at 65536 hashes/sec
+        assert_eq!(
+            estimate_memory_hard_vdf_seconds(32_768, 1024, 65_536.0),
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_encoded_merkle_paths() {
+        // Odd leaf counts promote the last node at some levels, leaving it no sibling
+        for leaf_count in [1usize, 2, 3, 5, 6, 7, 9] {
+            let leaves: Vec<[u8; 32]> = (0..leaf_count)
+                .map(|i| compute_blake3(&(i as u32).to_be_bytes()))
+                .collect();
+            let leaf_refs: Vec<&[u8]> = leaves.iter().map(|leaf| &leaf[..]).collect();
+            let (root, _) = compute_full_merkle_tree(&leaf_refs);
+            let levels = compute_merkle_levels(&leaves);
+            assert_eq!(levels.last().unwrap(), &vec![root]);
+
+            for (index, leaf) in leaves.iter().enumerate() {
+                let path = compute_merkle_inclusion_path(&leaves, index).unwrap();
+                assert_eq!(
+                    merkle_path_from_levels(&levels, index).as_ref(),
+                    Some(&path)
+                );
+                let directions: Vec<bool> = path.iter().map(|node| node.is_left).collect();
+                assert_eq!(merkle_path_directions(leaf_count, index), Some(directions));
+
+                let siblings = encode_merkle_path(&path);
+                assert_eq!(decode_merkle_path(&siblings), Some(path.clone()));
+                assert!(verify_merkle_inclusion(
+                    *leaf, index, leaf_count, &siblings, &root
+                ));
+
+                // A different leaf, position or sibling does not verify
+                let other = compute_blake3(b"other chunk");
+                assert!(!verify_merkle_inclusion(
+                    other, index, leaf_count, &siblings, &root
+                ));
+                for other_index in (0..leaf_count).filter(|&i| i != index) {
+                    assert!(!verify_merkle_inclusion(
+                        *leaf,
+                        other_index,
+                        leaf_count,
+                        &siblings,
+                        &root
+                    ));
+                }
+                if !siblings.is_empty() {
+                    let mut tampered = siblings.clone();
+                    tampered[0] ^= 1;
+                    assert!(!verify_merkle_inclusion(
+                        *leaf, index, leaf_count, &tampered, &root
+                    ));
+                    let mut flipped = siblings.clone();
+                    flipped[HASH_SIZE] ^= 1;
+                    assert!(!verify_merkle_inclusion(
+                        *leaf, index, leaf_count, &flipped, &root
+                    ));
+                    assert!(!verify_merkle_inclusion(
+                        *leaf,
+                        index,
+                        leaf_count,
+                        &siblings[MERKLE_PATH_NODE_SIZE..],
+                        &root
+                    ));
+                }
+            }
+            assert!(!verify_merkle_inclusion(
+                leaves[0],
+                leaf_count,
+                leaf_count,
+                &[],
+                &root
+            ));
+        }
+    }
+
+    #[test]
+    fn test_commitment_hash_binds_merkle_root() {
+        let chunk_hashes = vec![vec![3u8; 32]];
+        let params = CommitmentParams {
+            prover_key: &[1u8; 32],
+            data_hash: &[2u8; 32],
+            block_height: 7,
+            block_hash: &[4u8; 32],
+            selected_chunks: &[0],
+            chunk_hashes: &chunk_hashes,
+            vdf_output: &[5u8; 32],
+            entropy_hash: &[6u8; 32],
+            merkle_root: Some((&[7u8; 32], 16)),
+        };
+        let hash = compute_commitment_hash(&params);
+
+        // The root and its leaf count cannot be swapped after the fact
+        let other_root = CommitmentParams {
+            merkle_root: Some((&[8u8; 32], 16)),
+            ..params
+        };
+        let other_count = CommitmentParams {
+            merkle_root: Some((&[7u8; 32], 17)),
+            ..params
+        };
+        let without_root = CommitmentParams {
+            merkle_root: None,
+            ..params
+        };
+        assert_ne!(compute_commitment_hash(&other_root), hash);
+        assert_ne!(compute_commitment_hash(&other_count), hash);
+        assert_ne!(compute_commitment_hash(&without_root), hash);
+    }
+
+    #[test]
+    fn test_available_disk_space() {
+        let dir = std::env::temp_dir();
+        let free = available_disk_space(dir.to_str().unwrap());
+        if cfg!(unix) {
+            assert!(free.is_some_and(|bytes| bytes > 0));
+        }
+        assert!(available_disk_space("/nonexistent/output/dir").is_none());
+    }
+
+    #[test]
+    fn test_compute_file_sha256() {
+        let path = std::env::temp_dir().join(format!("file_sha256_{}.bin", std::process::id()));
+        let data: Vec<u8> = (0..200_000u32).map(|i| (i % 251) as u8).collect();
+        std::fs::write(&path, &data).unwrap();
+
+        let path_str = path.to_str().unwrap();
+        assert_eq!(
+            compute_file_sha256(path_str).unwrap(),
+            compute_sha256(&data)
+        );
+        std::fs::remove_file(&path).unwrap();
+        assert!(compute_file_sha256(path_str).is_err());
+    }
+
     #[test]
     fn test_performance_timer() {
         let timer = PerformanceTimer::new("test");
@@ -838,4 +2185,129 @@ mod tests {
         let elapsed = timer.elapsed_ms();
         assert!(elapsed >= 10);
     }
+
+    #[test]
+    fn test_header_checksum_covers_fields() {
+        let header = HashChainHeader {
+            magic: Buffer::from(HASHCHAIN_MAGIC.to_vec()),
+            format_version: HASHCHAIN_FORMAT_VERSION,
+            data_file_hash: Buffer::from(vec![1u8; 32]),
+            merkle_root: Buffer::from(vec![2u8; 32]),
+            total_chunks: 64.0,
+            chunk_size: CHUNK_SIZE_BYTES,
+            data_file_path_hash: Buffer::from(vec![3u8; 32]),
+            anchored_commitment: Buffer::from(vec![4u8; 32]),
+            chain_length: 7,
+            public_key: Buffer::from(vec![5u8; 32]),
+            initial_block_height: 100.0,
+            initial_block_hash: Buffer::from(vec![6u8; 32]),
+            header_checksum: Buffer::from(vec![0u8; 32]),
+            erasure_coding: None,
+            file_encoding: None,
+        };
+        let checksum = compute_header_checksum(&header);
+
+        // The stored checksum itself is not covered
+        let mut with_checksum = header.clone();
+        with_checksum.header_checksum = Buffer::from(checksum.to_vec());
+        assert_eq!(compute_header_checksum(&with_checksum), checksum);
+
+        let mut tampered = header.clone();
+        tampered.chain_length += 1;
+        assert_ne!(compute_header_checksum(&tampered), checksum);
+        let mut tampered = header.clone();
+        tampered.erasure_coding = Some(ErasureCodingParams {
+            data_shards: 4,
+            parity_shards: 2,
+        });
+        assert_ne!(compute_header_checksum(&tampered), checksum);
+    }
+
+    #[test]
+    fn test_ultra_compact_position_hash_binds_every_field() {
+        let chain_hash = [7u8; 32];
+        let position = HierarchicalPosition {
+            level: 0,
+            position: 1_500,
+            total_at_level: 25_000,
+            parent_position: Some(1),
+            security_score: None,
+        };
+        let hash = compute_ultra_compact_position_hash(&chain_hash, &position);
+        assert_eq!(
+            hash,
+            compute_ultra_compact_position_hash(&chain_hash, &position.clone())
+        );
+
+        // The security score is advisory and not part of the claim
+        let mut scored = position.clone();
+        scored.security_score = Some(0.9);
+        assert_eq!(
+            compute_ultra_compact_position_hash(&chain_hash, &scored),
+            hash
+        );
+
+        assert_ne!(
+            compute_ultra_compact_position_hash(&[8u8; 32], &position),
+            hash
+        );
+        let mut changed = position.clone();
+        changed.level = 1;
+        assert_ne!(
+            compute_ultra_compact_position_hash(&chain_hash, &changed),
+            hash
+        );
+        let mut changed = position.clone();
+        changed.position = 1_501;
+        assert_ne!(
+            compute_ultra_compact_position_hash(&chain_hash, &changed),
+            hash
+        );
+        let mut changed = position.clone();
+        changed.total_at_level = 25_001;
+        assert_ne!(
+            compute_ultra_compact_position_hash(&chain_hash, &changed),
+            hash
+        );
+        let mut changed = position.clone();
+        changed.parent_position = None;
+        assert_ne!(
+            compute_ultra_compact_position_hash(&chain_hash, &changed),
+            hash
+        );
+    }
+
+    #[test]
+    fn test_commitment_entropy_is_reproducible() {
+        let entropy = commitment_entropy(&[3u8; 32], &[4u8; 32]);
+        assert_eq!(
+            entropy.blockchain_entropy.as_ref(),
+            generate_deterministic_bytes(&[4u8; 32], 32).as_slice()
+        );
+        assert!(entropy.beacon_entropy.is_none());
+        assert_eq!(
+            entropy.combined_hash.as_ref(),
+            combine_entropy_sources(
+                &entropy.blockchain_entropy,
+                None,
+                &entropy.local_entropy,
+                entropy.timestamp
+            )
+            .as_slice()
+        );
+
+        // The VDF entropy path combines the same sources identically
+        assert_eq!(
+            entropy.combined_hash.as_ref(),
+            crate::core::memory_hard_vdf::create_vdf_entropy(
+                entropy.blockchain_entropy.clone(),
+                None,
+                entropy.local_entropy.clone(),
+                entropy.timestamp
+            )
+            .unwrap()
+            .combined_hash
+            .as_ref()
+        );
+    }
 }
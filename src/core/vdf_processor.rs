@@ -1,9 +1,22 @@
-use crate::core::errors::HashChainResult;
-use crate::core::utils::{compute_blake3, sign_data, ContinuousVDF};
-use log::{debug, info, trace};
-use std::sync::{Arc, Mutex};
+use crate::core::byte_reader::{ByteOrder, ByteReader};
+use crate::core::errors::{HashChainError, HashChainResult};
+use crate::core::signer::{Signer, SoftwareSigner};
+use crate::core::types::{
+    MAX_VDF_ITERATIONS_PER_SECOND, MIN_VDF_TARGET_ITERATIONS_PER_SECOND, SHARED_VDF_PROOF_DOMAIN,
+    VDF_CALIBRATION_HEADROOM, VDF_CALIBRATION_MS, VDF_CHECKPOINT_SNAPSHOTS, VDF_PROOF_CHAIN_DOMAIN,
+    VDF_REPRODUCTION_MAX_SEGMENT, VDF_SEED_REPLAY_MAX_ITERATIONS,
+};
+use crate::core::utils::{
+    compute_blake3, compute_block_range_leaf, compute_block_range_signature,
+    compute_merkle_inclusion_path, compute_merkle_root_from_path, replay_continuous_vdf_checkpoint,
+    reproduce_continuous_vdf_segment, verify_signature, ContinuousVDF, MerklePathNode,
+    VDFSegmentReproduction,
+};
+use log::{debug, info, trace, warn};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 /// Shared VDF proof that demonstrates the VDF is running continuously
 /// and hasn't been manipulated across chains
@@ -13,7 +26,7 @@ pub struct SharedVDFProof {
     pub vdf_state: [u8; 32],
     /// Total iterations at proof time
     pub total_iterations: u64,
-    /// Timestamp when proof was generated
+    /// Timestamp when proof was generated, in whole milliseconds
     pub timestamp: f64,
     /// Signature of the proof using the prover's private key
     pub signature: Vec<u8>,
@@ -21,13 +34,348 @@ pub struct SharedVDFProof {
     pub proof_chain_hash: [u8; 32],
 }
 
+/// Single VDF signature covering a contiguous range of blocks
+#[derive(Clone, Debug)]
+pub struct VDFRangeSignature {
+    /// First block height in the range
+    pub start_height: u64,
+    /// Last block height in the range (inclusive)
+    pub end_height: u64,
+    /// Merkle root over the block leaves of the range
+    pub range_root: [u8; 32],
+    /// VDF state the range was signed against
+    pub vdf_state: [u8; 32],
+    /// Total iterations at signing time
+    pub total_iterations: u64,
+    /// Range signature
+    pub signature: [u8; 32],
+}
+
+/// Serialized size of a shared VDF proof without its signature
+const SHARED_VDF_PROOF_FIXED_SIZE: usize = 32 + 8 + 8 + 32 + 4;
+
+impl SharedVDFProof {
+    /// Chain hash the next proof must carry
+    pub fn next_chain_hash(&self) -> [u8; 32] {
+        compute_blake3(
+            &[
+                VDF_PROOF_CHAIN_DOMAIN,
+                &self.proof_chain_hash[..],
+                &self.vdf_state[..],
+                &self.total_iterations.to_be_bytes(),
+            ]
+            .concat(),
+        )
+    }
+
+    /// Data covered by the prover's signature. The timestamp is signed as integer
+    /// milliseconds, so a decimal round trip of the f64 does not break the signature.
+    pub fn signed_data(&self) -> Vec<u8> {
+        [
+            SHARED_VDF_PROOF_DOMAIN,
+            &self.vdf_state[..],
+            &self.total_iterations.to_be_bytes(),
+            &timestamp_millis(self.timestamp).to_be_bytes(),
+            &self.proof_chain_hash[..],
+        ]
+        .concat()
+    }
+}
+
+/// Timestamp in seconds as whole milliseconds
+fn timestamp_millis(timestamp: f64) -> u64 {
+    (timestamp * 1000.0).round() as u64
+}
+
+/// Serialize a shared VDF proof chain:
+/// count (u32) then per proof state | iterations | timestamp | chain hash | sig len (u32) | sig
+pub fn serialize_vdf_proof_chain(proofs: &[SharedVDFProof]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(&(proofs.len() as u32).to_be_bytes());
+    for proof in proofs {
+        bytes.extend_from_slice(&proof.vdf_state);
+        bytes.extend_from_slice(&proof.total_iterations.to_be_bytes());
+        bytes.extend_from_slice(&proof.timestamp.to_be_bytes());
+        bytes.extend_from_slice(&proof.proof_chain_hash);
+        bytes.extend_from_slice(&(proof.signature.len() as u32).to_be_bytes());
+        bytes.extend_from_slice(&proof.signature);
+    }
+    bytes
+}
+
+/// Parse a shared VDF proof chain produced by `serialize_vdf_proof_chain`
+pub fn deserialize_vdf_proof_chain(bytes: &[u8]) -> HashChainResult<Vec<SharedVDFProof>> {
+    let mut reader = ByteReader::new(bytes, ByteOrder::Big, "VDF proof chain");
+
+    // Every proof needs at least its fixed-size fields
+    let count = reader.count(SHARED_VDF_PROOF_FIXED_SIZE)?;
+    let mut proofs = Vec::with_capacity(count);
+    for _ in 0..count {
+        proofs.push(SharedVDFProof {
+            vdf_state: reader.hash()?,
+            total_iterations: reader.u64()?,
+            timestamp: reader.f64()?,
+            proof_chain_hash: reader.hash()?,
+            signature: reader.bytes()?.to_vec(),
+        });
+    }
+
+    reader.finish()?;
+    Ok(proofs)
+}
+
+/// Chain hash the next shared proof after `proofs` must carry
+fn next_proof_chain_hash(proofs: &[SharedVDFProof]) -> [u8; 32] {
+    match proofs.last() {
+        Some(last) => last.next_chain_hash(),
+        None => compute_blake3(b"genesis_vdf_proof"),
+    }
+}
+
+/// Sign a shared VDF proof over an exact VDF state
+fn sign_shared_proof(
+    signer: &dyn Signer,
+    vdf_state: [u8; 32],
+    total_iterations: u64,
+    proof_chain_hash: [u8; 32],
+) -> HashChainResult<SharedVDFProof> {
+    let timestamp = timestamp_millis(crate::core::utils::get_current_timestamp()) as f64 / 1000.0;
+    let mut proof = SharedVDFProof {
+        vdf_state,
+        total_iterations,
+        timestamp,
+        signature: Vec::new(),
+        proof_chain_hash,
+    };
+    proof.signature = signer.sign(&proof.signed_data())?;
+    Ok(proof)
+}
+
+/// Verify a shared VDF proof chain independently of the prover's processor:
+/// each proof must chain to the previous proof's state, iterations must not go
+/// backwards, and every signature must be valid for the prover key
+pub fn verify_vdf_proof_chain(
+    proofs: &[SharedVDFProof],
+    prover_public_key: &[u8],
+) -> HashChainResult<bool> {
+    if proofs.is_empty() {
+        return Ok(true); // Empty chain is valid
+    }
+
+    for (i, proof) in proofs.iter().enumerate() {
+        // Verify proof chain hash
+        if i > 0 {
+            let previous = &proofs[i - 1];
+            if proof.proof_chain_hash != previous.next_chain_hash() {
+                debug!("❌ Shared VDF proof chain broken at index {}", i);
+                return Ok(false);
+            }
+            if proof.total_iterations < previous.total_iterations {
+                debug!("❌ Shared VDF iterations went backwards at index {}", i);
+                return Ok(false);
+            }
+        }
+
+        // Verify signature
+        if !verify_signature(prover_public_key, &proof.signed_data(), &proof.signature)? {
+            debug!("❌ Invalid signature in shared VDF proof at index {}", i);
+            return Ok(false);
+        }
+    }
+
+    debug!(
+        "✅ Shared VDF proof chain verified successfully ({} proofs)",
+        proofs.len()
+    );
+    Ok(true)
+}
+
+/// Block signature and the exact VDF state it was made against, captured
+/// under a single lock acquisition
+#[derive(Clone, Debug)]
+pub struct VDFBlockSnapshot {
+    /// Block signature over the captured state
+    pub signature: [u8; 32],
+    /// VDF state the signature was made against
+    pub vdf_state: [u8; 32],
+    /// Total iterations at signing time
+    pub total_iterations: u64,
+    /// Time the VDF lock was held, in microseconds
+    pub lock_hold_micros: u64,
+}
+
+/// Everything needed to resume a VDF in another process without breaking
+/// its iteration or shared proof chain
+#[derive(Clone, Debug)]
+pub struct VDFStateSnapshot {
+    /// Current VDF state
+    pub vdf_state: [u8; 32],
+    /// Total iterations performed
+    pub total_iterations: u64,
+    /// Memory buffer the next iterations read from
+    pub memory_buffer: Vec<u8>,
+    /// Height of the last block signed
+    pub last_block_height: u64,
+    /// Hash of the last block signed
+    pub last_block_hash: [u8; 32],
+    /// Shared proofs generated so far
+    pub shared_proofs: Vec<SharedVDFProof>,
+    /// Prover signature over exactly `vdf_state` at `total_iterations`, chained to
+    /// `shared_proofs`, so the imported state cannot be forged
+    pub checkpoint_proof: SharedVDFProof,
+}
+
+/// JSON form of a `VDFStateSnapshot`, with byte fields hex encoded and the proofs
+/// in their `serialize_vdf_proof_chain` form
+#[derive(serde::Serialize, serde::Deserialize)]
+struct VDFStateSnapshotJson {
+    vdf_state: String,
+    total_iterations: u64,
+    memory_buffer: String,
+    last_block_height: u64,
+    last_block_hash: String,
+    shared_proofs: String,
+    checkpoint_proof: String,
+}
+
+impl VDFStateSnapshot {
+    /// Serialize as JSON, e.g. for Node to persist across a restart
+    pub fn to_json(&self) -> String {
+        let json = VDFStateSnapshotJson {
+            vdf_state: hex::encode(self.vdf_state),
+            total_iterations: self.total_iterations,
+            memory_buffer: hex::encode(&self.memory_buffer),
+            last_block_height: self.last_block_height,
+            last_block_hash: hex::encode(self.last_block_hash),
+            shared_proofs: hex::encode(serialize_vdf_proof_chain(&self.shared_proofs)),
+            checkpoint_proof: hex::encode(serialize_vdf_proof_chain(std::slice::from_ref(
+                &self.checkpoint_proof,
+            ))),
+        };
+        serde_json::to_string(&json).expect("VDF snapshot JSON has only string and integer fields")
+    }
+
+    /// Parse a snapshot produced by `to_json`. The snapshot is only decoded here;
+    /// `VDFProcessor::restore_state` checks it before resuming.
+    pub fn from_json(json: &str) -> HashChainResult<Self> {
+        let json: VDFStateSnapshotJson = serde_json::from_str(json)
+            .map_err(|e| HashChainError::Serialization(format!("Invalid VDF snapshot: {}", e)))?;
+        let decode = |value: &str, field: &str| {
+            hex::decode(value).map_err(|e| {
+                HashChainError::Serialization(format!("Invalid VDF snapshot {}: {}", field, e))
+            })
+        };
+        let decode32 = |value: &str, field: &str| -> HashChainResult<[u8; 32]> {
+            decode(value, field)?.try_into().map_err(|_| {
+                HashChainError::Serialization(format!(
+                    "Invalid VDF snapshot {}: expected 32 bytes",
+                    field
+                ))
+            })
+        };
+
+        let checkpoint_proof = match <[_; 1]>::try_from(deserialize_vdf_proof_chain(&decode(
+            &json.checkpoint_proof,
+            "checkpoint proof",
+        )?)?) {
+            Ok([proof]) => proof,
+            Err(_) => {
+                return Err(HashChainError::Serialization(
+                    "VDF snapshot must carry exactly one checkpoint proof".to_string(),
+                ))
+            }
+        };
+        Ok(Self {
+            vdf_state: decode32(&json.vdf_state, "state")?,
+            total_iterations: json.total_iterations,
+            memory_buffer: decode(&json.memory_buffer, "memory buffer")?,
+            last_block_height: json.last_block_height,
+            last_block_hash: decode32(&json.last_block_hash, "last block hash")?,
+            shared_proofs: deserialize_vdf_proof_chain(&decode(
+                &json.shared_proofs,
+                "shared proofs",
+            )?)?,
+            checkpoint_proof,
+        })
+    }
+}
+
+/// VDF state and memory kept at a shared proof, so checkpoints near published
+/// proofs are replayed from here instead of from the initial state
+#[derive(Clone, Debug)]
+struct CheckpointSnapshot {
+    iteration: u64,
+    state: [u8; 32],
+    memory: Vec<u8>,
+}
+
+/// Keep `snapshot`, dropping the oldest beyond `VDF_CHECKPOINT_SNAPSHOTS`
+fn push_checkpoint_snapshot(
+    snapshots: &Mutex<Vec<CheckpointSnapshot>>,
+    snapshot: CheckpointSnapshot,
+) {
+    let mut snapshots = snapshots.lock().unwrap();
+    snapshots.push(snapshot);
+    if snapshots.len() > VDF_CHECKPOINT_SNAPSHOTS {
+        let excess = snapshots.len() - VDF_CHECKPOINT_SNAPSHOTS;
+        snapshots.drain(0..excess);
+    }
+}
+
+/// VDF state and memory at `iteration`, replayed from the latest snapshot at or
+/// before it, or from the initial state when none is. Replays longer than
+/// `VDF_SEED_REPLAY_MAX_ITERATIONS` are refused.
+fn checkpoint_from_snapshots(
+    initial_state: [u8; 32],
+    memory_kb: u32,
+    snapshots: &[CheckpointSnapshot],
+    iteration: u64,
+) -> HashChainResult<([u8; 32], Vec<u8>)> {
+    let base = snapshots
+        .iter()
+        .filter(|snapshot| snapshot.iteration <= iteration)
+        .max_by_key(|snapshot| snapshot.iteration);
+    let base_iteration = base.map_or(0, |snapshot| snapshot.iteration);
+    if iteration - base_iteration > VDF_SEED_REPLAY_MAX_ITERATIONS as u64 {
+        return Err(HashChainError::VDFError(format!(
+            "Checkpoint iteration {} is {} iterations past the nearest stored snapshot, limit is {}",
+            iteration,
+            iteration - base_iteration,
+            VDF_SEED_REPLAY_MAX_ITERATIONS
+        )));
+    }
+
+    let Some(base) = base else {
+        return Ok(replay_continuous_vdf_checkpoint(
+            initial_state,
+            memory_kb,
+            iteration,
+        ));
+    };
+    let mut vdf = ContinuousVDF::from_snapshot(base.state, base.iteration, base.memory.clone())
+        .map_err(|e| HashChainError::VDFError(format!("Invalid stored VDF snapshot: {}", e)))?;
+    for _ in base.iteration..iteration {
+        vdf.iterate();
+    }
+    Ok((vdf.get_state().0, vdf.memory_snapshot()))
+}
+
 /// VDF processor that runs in the background with shared proof generation
 pub struct VDFProcessor {
     vdf: Arc<Mutex<ContinuousVDF>>,
-    target_iterations_per_second: u64,
+    initial_state: [u8; 32],
+    memory_kb: u32,
+    // Read by the background thread on every iteration so changes apply while running
+    target_iterations_per_second: Arc<AtomicU64>,
+    // Benchmarked iterations per second (f64 bits); zero until `calibrate` runs
+    calibrated_iterations_per_second: AtomicU64,
+    // Signalled with the `vdf` lock after every iteration and when stopping
+    progress: Arc<Condvar>,
     running: Arc<Mutex<bool>>,
-    prover_private_key: Vec<u8>,
+    signer: Arc<dyn Signer>,
     shared_proofs: Arc<Mutex<Vec<SharedVDFProof>>>,
+    // Memory at the most recent shared proofs, to serve checkpoints without a full replay
+    checkpoint_snapshots: Arc<Mutex<Vec<CheckpointSnapshot>>>,
     last_proof_time: Arc<Mutex<f64>>,
     proof_interval_seconds: f64,
 }
@@ -38,25 +386,135 @@ impl VDFProcessor {
         memory_kb: u32,
         target_iterations_per_second: u64,
         prover_private_key: Vec<u8>,
+    ) -> Self {
+        Self::with_signer(
+            initial_state,
+            memory_kb,
+            target_iterations_per_second,
+            Arc::new(SoftwareSigner::new(prover_private_key)),
+        )
+    }
+
+    /// Create a processor whose shared VDF proofs are signed by `signer`
+    pub fn with_signer(
+        initial_state: [u8; 32],
+        memory_kb: u32,
+        target_iterations_per_second: u64,
+        signer: Arc<dyn Signer>,
     ) -> Self {
         Self {
             vdf: Arc::new(Mutex::new(ContinuousVDF::new(initial_state, memory_kb))),
-            target_iterations_per_second,
+            initial_state,
+            memory_kb,
+            target_iterations_per_second: Arc::new(AtomicU64::new(target_iterations_per_second)),
+            calibrated_iterations_per_second: AtomicU64::new(0),
+            progress: Arc::new(Condvar::new()),
             running: Arc::new(Mutex::new(false)),
-            prover_private_key,
+            signer,
             shared_proofs: Arc::new(Mutex::new(Vec::new())),
+            checkpoint_snapshots: Arc::new(Mutex::new(Vec::new())),
             last_proof_time: Arc::new(Mutex::new(0.0)),
             proof_interval_seconds: 10.0, // Generate shared proof every 10 seconds
         }
     }
 
-    /// Start the VDF processor in a background thread
+    /// Current target pace in iterations per second
+    pub fn get_target_rate(&self) -> u64 {
+        self.target_iterations_per_second.load(Ordering::Relaxed)
+    }
+
+    /// Change the target pace, taking effect on the next iteration if running.
+    /// Consensus minimum iteration checks are unaffected.
+    pub fn set_target_rate(&self, iterations_per_second: u64) -> Result<(), String> {
+        let max_rate = MAX_VDF_ITERATIONS_PER_SECOND as u64;
+        if iterations_per_second < MIN_VDF_TARGET_ITERATIONS_PER_SECOND as u64
+            || iterations_per_second > max_rate
+        {
+            return Err(format!(
+                "VDF target rate must be between {} and {} iterations/sec, got {}",
+                MIN_VDF_TARGET_ITERATIONS_PER_SECOND, max_rate, iterations_per_second
+            ));
+        }
+
+        self.target_iterations_per_second
+            .store(iterations_per_second, Ordering::Relaxed);
+        info!(
+            "VDF target rate set to {} iterations/sec",
+            iterations_per_second
+        );
+        Ok(())
+    }
+
+    /// Benchmark this machine's VDF speed for `VDF_CALIBRATION_MS` on a scratch VDF
+    /// and lower the target pace to `VDF_CALIBRATION_HEADROOM` of it when the current
+    /// target is not achievable. Faster machines keep their pace: iteration counts are
+    /// bounded by what verifiers replay. The VDF itself is not advanced. Returns the
+    /// new target rate.
+    pub fn calibrate(&self) -> u64 {
+        let mut scratch = ContinuousVDF::new(self.initial_state, self.memory_kb);
+        let budget = Duration::from_millis(VDF_CALIBRATION_MS);
+        let started = Instant::now();
+        let mut iterations = 0u64;
+        while started.elapsed() < budget {
+            scratch.iterate();
+            iterations += 1;
+        }
+        let measured_rate = iterations as f64 / started.elapsed().as_secs_f64();
+        self.calibrated_iterations_per_second
+            .store(measured_rate.to_bits(), Ordering::Relaxed);
+
+        let max_rate = MAX_VDF_ITERATIONS_PER_SECOND as u64;
+        let min_rate = MIN_VDF_TARGET_ITERATIONS_PER_SECOND as u64;
+        let achievable_rate = (measured_rate * VDF_CALIBRATION_HEADROOM) as u64;
+        let target_rate = achievable_rate
+            .min(self.get_target_rate())
+            .clamp(min_rate, max_rate);
+        if (measured_rate as u64) < min_rate {
+            warn!(
+                "VDF benchmark reached only {:.0} iterations/sec, below the minimum target of {}",
+                measured_rate, min_rate
+            );
+        }
+        self.target_iterations_per_second
+            .store(target_rate, Ordering::Relaxed);
+        info!(
+            "VDF calibrated at {:.0} iterations/sec, target set to {} iterations/sec",
+            measured_rate, target_rate
+        );
+        target_rate
+    }
+
+    /// Benchmarked iterations per second, if `calibrate` has run
+    pub fn get_calibrated_rate(&self) -> Option<f64> {
+        let bits = self
+            .calibrated_iterations_per_second
+            .load(Ordering::Relaxed);
+        (bits != 0).then(|| f64::from_bits(bits))
+    }
+
+    /// Block until the VDF has computed `required` total iterations. Returns false
+    /// if the processor is not running or stops before reaching them.
+    pub fn wait_for_iterations(&self, required: u64) -> bool {
+        let vdf = self.vdf.lock().unwrap();
+        let vdf = self
+            .progress
+            .wait_while(vdf, |vdf| vdf.get_state().1 < required && self.is_running())
+            .unwrap();
+        vdf.get_state().1 >= required
+    }
+
+    /// Start the VDF processor in a background thread; does nothing if already running
     pub fn start(&self) {
+        if self.is_running() {
+            return;
+        }
         let vdf = self.vdf.clone();
+        let progress = self.progress.clone();
         let running = self.running.clone();
-        let target_iterations = self.target_iterations_per_second;
-        let prover_private_key = self.prover_private_key.clone();
+        let target_rate = self.target_iterations_per_second.clone();
+        let signer = self.signer.clone();
         let shared_proofs = self.shared_proofs.clone();
+        let checkpoint_snapshots = self.checkpoint_snapshots.clone();
         let last_proof_time = self.last_proof_time.clone();
         let proof_interval = self.proof_interval_seconds;
 
@@ -64,15 +522,16 @@ impl VDFProcessor {
 
         thread::spawn(move || {
             let mut last_iteration_time = std::time::Instant::now();
-            let target_interval = Duration::from_secs_f64(1.0 / target_iterations as f64);
             let mut iteration_count = 0u64;
 
             info!(
                 "🚀 VDF Processor started - target: {} iterations/sec",
-                target_iterations
+                target_rate.load(Ordering::Relaxed)
             );
 
             while *running.lock().unwrap() {
+                let target_iterations = target_rate.load(Ordering::Relaxed).max(1);
+                let target_interval = Duration::from_secs_f64(1.0 / target_iterations as f64);
                 let now = std::time::Instant::now();
                 let elapsed = now.duration_since(last_iteration_time);
 
@@ -110,6 +569,7 @@ impl VDFProcessor {
 
                         state
                     };
+                    progress.notify_all();
 
                     iteration_count += 1;
                     last_iteration_time = now;
@@ -122,9 +582,17 @@ impl VDFProcessor {
                     };
 
                     if should_generate_proof {
-                        if let Ok(proof) =
-                            Self::generate_shared_proof(&vdf, &prover_private_key, &shared_proofs)
+                        if let Ok((proof, memory)) =
+                            Self::generate_shared_proof(&vdf, signer.as_ref(), &shared_proofs)
                         {
+                            push_checkpoint_snapshot(
+                                &checkpoint_snapshots,
+                                CheckpointSnapshot {
+                                    iteration: proof.total_iterations,
+                                    state: proof.vdf_state,
+                                    memory,
+                                },
+                            );
                             let mut proofs = shared_proofs.lock().unwrap();
                             proofs.push(proof.clone());
 
@@ -156,61 +624,36 @@ impl VDFProcessor {
         });
     }
 
-    /// Generate a shared VDF proof that demonstrates continuous operation
+    /// Generate a shared VDF proof that demonstrates continuous operation,
+    /// along with the VDF memory at the proven state
     fn generate_shared_proof(
         vdf: &Arc<Mutex<ContinuousVDF>>,
-        prover_private_key: &[u8],
+        signer: &dyn Signer,
         existing_proofs: &Arc<Mutex<Vec<SharedVDFProof>>>,
-    ) -> HashChainResult<SharedVDFProof> {
-        let (vdf_state, total_iterations) = {
+    ) -> HashChainResult<(SharedVDFProof, Vec<u8>)> {
+        let ((vdf_state, total_iterations), memory) = {
             let vdf_guard = vdf.lock().unwrap();
-            vdf_guard.get_state()
+            (vdf_guard.get_state(), vdf_guard.memory_snapshot())
         };
 
-        let timestamp = crate::core::utils::get_current_timestamp();
-
         // Create proof chain hash from previous proofs
-        let proof_chain_hash = {
-            let proofs = existing_proofs.lock().unwrap();
-            if proofs.is_empty() {
-                compute_blake3(b"genesis_vdf_proof")
-            } else {
-                let last_proof = &proofs[proofs.len() - 1];
-                compute_blake3(
-                    &[
-                        &last_proof.proof_chain_hash[..],
-                        &last_proof.vdf_state[..],
-                        &last_proof.total_iterations.to_be_bytes(),
-                    ]
-                    .concat(),
-                )
-            }
-        };
-
-        // Create proof data to sign
-        let proof_data = [
-            &vdf_state[..],
-            &total_iterations.to_be_bytes(),
-            &timestamp.to_be_bytes(),
-            &proof_chain_hash[..],
-        ]
-        .concat();
-
-        // Sign the proof
-        let signature = sign_data(prover_private_key, &proof_data)?;
+        let proof_chain_hash = next_proof_chain_hash(&existing_proofs.lock().unwrap());
 
-        Ok(SharedVDFProof {
-            vdf_state,
-            total_iterations,
-            timestamp,
-            signature,
-            proof_chain_hash,
-        })
+        let proof = sign_shared_proof(signer, vdf_state, total_iterations, proof_chain_hash)?;
+        Ok((proof, memory))
     }
 
     /// Stop the VDF processor
     pub fn stop(&self) {
         *self.running.lock().unwrap() = false;
+        // Taking the VDF lock orders this after any waiter's check, so none misses it
+        drop(self.vdf.lock().unwrap());
+        self.progress.notify_all();
+    }
+
+    /// Whether the background thread is running
+    pub fn is_running(&self) -> bool {
+        *self.running.lock().unwrap()
     }
 
     /// Get current VDF state and iteration count
@@ -218,6 +661,138 @@ impl VDFProcessor {
         self.vdf.lock().unwrap().get_state()
     }
 
+    /// Total iterations computed so far
+    pub fn get_iteration_count(&self) -> u64 {
+        self.get_state().1
+    }
+
+    /// Fail with `VdfNotReady` unless at least `required` iterations have been computed
+    pub fn ensure_iterations(&self, required: u64) -> HashChainResult<()> {
+        let current = self.get_iteration_count();
+        if current < required {
+            return Err(HashChainError::VdfNotReady { current, required });
+        }
+        Ok(())
+    }
+
+    /// Capture the VDF state, memory and shared proofs for migration, signing a
+    /// checkpoint over the captured state
+    pub fn capture_state(&self) -> HashChainResult<VDFStateSnapshot> {
+        let (vdf_state, total_iterations, memory_buffer, (last_block_height, last_block_hash)) = {
+            let vdf_guard = self.vdf.lock().unwrap();
+            let (vdf_state, total_iterations) = vdf_guard.get_state();
+            (
+                vdf_state,
+                total_iterations,
+                vdf_guard.memory_snapshot(),
+                vdf_guard.last_block(),
+            )
+        };
+        let shared_proofs = self.shared_proofs.lock().unwrap().clone();
+        let checkpoint_proof = sign_shared_proof(
+            self.signer.as_ref(),
+            vdf_state,
+            total_iterations,
+            next_proof_chain_hash(&shared_proofs),
+        )?;
+        Ok(VDFStateSnapshot {
+            vdf_state,
+            total_iterations,
+            memory_buffer,
+            last_block_height,
+            last_block_hash,
+            shared_proofs,
+            checkpoint_proof,
+        })
+    }
+
+    /// Check an imported VDF state against a checkpoint proof: the proof must be
+    /// for exactly the claimed state and iteration count and be signed by the
+    /// trusted prover key, so an import cannot fast-forward the VDF
+    pub fn verify_imported_state(
+        claimed_state: [u8; 32],
+        claimed_iterations: u64,
+        checkpoint_proof: &SharedVDFProof,
+        prover_public_key: &[u8],
+    ) -> HashChainResult<bool> {
+        if checkpoint_proof.vdf_state != claimed_state
+            || checkpoint_proof.total_iterations != claimed_iterations
+        {
+            return Ok(false);
+        }
+        verify_signature(
+            prover_public_key,
+            &checkpoint_proof.signed_data(),
+            &checkpoint_proof.signature,
+        )
+    }
+
+    /// Replace the VDF with a captured one, continuing from its exact state.
+    /// The snapshot must use this processor's memory size, its shared proofs
+    /// must not be ahead of its iteration count, and its proof chain and
+    /// checkpoint must be signed by `prover_public_key`.
+    pub fn restore_state(
+        &self,
+        snapshot: VDFStateSnapshot,
+        prover_public_key: &[u8],
+    ) -> HashChainResult<()> {
+        let expected_memory = self.memory_kb as usize * 1024;
+        if snapshot.memory_buffer.len() != expected_memory {
+            return Err(HashChainError::VDFError(format!(
+                "VDF memory snapshot is {} bytes, expected {}",
+                snapshot.memory_buffer.len(),
+                expected_memory
+            )));
+        }
+        if let Some(last_proof) = snapshot.shared_proofs.last() {
+            if last_proof.total_iterations > snapshot.total_iterations {
+                return Err(HashChainError::VDFError(format!(
+                    "Shared proof at {} iterations is ahead of VDF state at {}",
+                    last_proof.total_iterations, snapshot.total_iterations
+                )));
+            }
+        }
+        if !verify_vdf_proof_chain(&snapshot.shared_proofs, prover_public_key)?
+            || snapshot.checkpoint_proof.proof_chain_hash
+                != next_proof_chain_hash(&snapshot.shared_proofs)
+        {
+            return Err(HashChainError::VDFError(
+                "Imported shared VDF proofs are not signed by the prover key".to_string(),
+            ));
+        }
+        if !Self::verify_imported_state(
+            snapshot.vdf_state,
+            snapshot.total_iterations,
+            &snapshot.checkpoint_proof,
+            prover_public_key,
+        )? {
+            return Err(HashChainError::VDFError(format!(
+                "Imported VDF state at {} iterations has no valid checkpoint proof",
+                snapshot.total_iterations
+            )));
+        }
+
+        *self.checkpoint_snapshots.lock().unwrap() = vec![CheckpointSnapshot {
+            iteration: snapshot.total_iterations,
+            state: snapshot.vdf_state,
+            memory: snapshot.memory_buffer.clone(),
+        }];
+        let mut restored = ContinuousVDF::from_snapshot(
+            snapshot.vdf_state,
+            snapshot.total_iterations,
+            snapshot.memory_buffer,
+        )?;
+        restored.set_last_block(snapshot.last_block_height, snapshot.last_block_hash);
+        *self.vdf.lock().unwrap() = restored;
+        *self.shared_proofs.lock().unwrap() = snapshot.shared_proofs;
+
+        info!(
+            "VDF state restored at {} iterations",
+            snapshot.total_iterations
+        );
+        Ok(())
+    }
+
     /// Sign a block against the current VDF state
     pub fn sign_block(
         &self,
@@ -236,6 +811,63 @@ impl VDFProcessor {
         }
     }
 
+    /// Sign a block and capture the matching VDF state in one short critical section.
+    /// Callers do chunk reads, Merkle and hashing work after this returns, so the
+    /// shared VDF is locked only for one 32-byte signature computation instead of
+    /// two separate acquisitions (sign, then get_state) that could also observe
+    /// different states while the VDF advances in between.
+    pub fn snapshot_for_block(
+        &self,
+        block_height: u64,
+        block_hash: [u8; 32],
+        required_iterations: u64,
+    ) -> Result<VDFBlockSnapshot, String> {
+        let lock_start = std::time::Instant::now();
+        let (signature, vdf_state, total_iterations) = {
+            let mut vdf_guard = self.vdf.lock().unwrap();
+            let signature = vdf_guard
+                .sign_block(block_height, block_hash, required_iterations)
+                .map_err(|e| format!("VDF signing failed: {:?}", e))?;
+            let (vdf_state, total_iterations) = vdf_guard.get_state();
+            (signature, vdf_state, total_iterations)
+        };
+        let lock_hold_micros = lock_start.elapsed().as_micros() as u64;
+
+        Ok(VDFBlockSnapshot {
+            signature,
+            vdf_state,
+            total_iterations,
+            lock_hold_micros,
+        })
+    }
+
+    /// Sign every block in `start_height..=end_height` with a single VDF signature.
+    /// `block_hashes` must hold one hash per height in the range, in order.
+    pub fn sign_block_range(
+        &self,
+        start_height: u64,
+        end_height: u64,
+        block_hashes: &[[u8; 32]],
+        required_iterations: u64,
+    ) -> Result<VDFRangeSignature, String> {
+        let range_root = compute_block_range_root(start_height, end_height, block_hashes)?;
+
+        let mut vdf_guard = self.vdf.lock().unwrap();
+        let signature = vdf_guard
+            .sign_block_range(start_height, end_height, range_root, required_iterations)
+            .map_err(|e| format!("VDF range signing failed: {:?}", e))?;
+        let (vdf_state, total_iterations) = vdf_guard.get_state();
+
+        Ok(VDFRangeSignature {
+            start_height,
+            end_height,
+            range_root,
+            vdf_state,
+            total_iterations,
+            signature,
+        })
+    }
+
     /// Verify a block signature
     pub fn verify_block_signature(
         &self,
@@ -252,6 +884,69 @@ impl VDFProcessor {
         )
     }
 
+    /// Reproduce the VDF segment starting at `iteration_index` for a reproduction challenge.
+    /// The segment resumes from the checkpoint at `iteration_index` (see `checkpoint_at`),
+    /// so the running VDF is not locked and replays are bounded by
+    /// `VDF_SEED_REPLAY_MAX_ITERATIONS` plus `VDF_REPRODUCTION_MAX_SEGMENT`.
+    pub fn respond_to_reproduction_challenge(
+        &self,
+        iteration_index: u64,
+        segment_length: u64,
+    ) -> HashChainResult<VDFSegmentReproduction> {
+        if segment_length == 0 || segment_length > VDF_REPRODUCTION_MAX_SEGMENT as u64 {
+            return Err(HashChainError::InvalidProofParameters {
+                reason: format!(
+                    "Segment length must be between 1 and {}, got {}",
+                    VDF_REPRODUCTION_MAX_SEGMENT, segment_length
+                ),
+            });
+        }
+
+        let (_, total_iterations) = self.get_state();
+        let end_iteration = iteration_index.saturating_add(segment_length);
+        if end_iteration > total_iterations {
+            return Err(HashChainError::VdfNotReady {
+                current: total_iterations,
+                required: end_iteration,
+            });
+        }
+
+        let (start_state, memory_snapshot) = self.checkpoint_at(iteration_index)?;
+        reproduce_continuous_vdf_segment(
+            start_state,
+            iteration_index,
+            memory_snapshot,
+            segment_length,
+        )
+    }
+
+    /// VDF state and memory at `iteration`, for a verifier to resume the VDF from.
+    /// Replayed from the memory stored at the latest shared proof before it (or the
+    /// initial state) so the running VDF is not locked; replays longer than
+    /// `VDF_SEED_REPLAY_MAX_ITERATIONS` are refused.
+    pub fn checkpoint_at(&self, iteration: u64) -> HashChainResult<([u8; 32], Vec<u8>)> {
+        let (_, total_iterations) = self.get_state();
+        if iteration > total_iterations {
+            return Err(HashChainError::VdfNotReady {
+                current: total_iterations,
+                required: iteration,
+            });
+        }
+
+        // Copy out only the snapshot the replay starts from
+        let base: Vec<CheckpointSnapshot> = self
+            .checkpoint_snapshots
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|snapshot| snapshot.iteration <= iteration)
+            .max_by_key(|snapshot| snapshot.iteration)
+            .cloned()
+            .into_iter()
+            .collect();
+        checkpoint_from_snapshots(self.initial_state, self.memory_kb, &base, iteration)
+    }
+
     /// Get the latest shared VDF proof
     pub fn get_latest_shared_proof(&self) -> Option<SharedVDFProof> {
         let proofs = self.shared_proofs.lock().unwrap();
@@ -266,62 +961,63 @@ impl VDFProcessor {
     /// Verify the integrity of the shared VDF proof chain
     pub fn verify_shared_proof_chain(&self, prover_public_key: &[u8]) -> bool {
         let proofs = self.shared_proofs.lock().unwrap();
+        verify_vdf_proof_chain(&proofs, prover_public_key).unwrap_or(false)
+    }
 
-        if proofs.is_empty() {
-            return true; // Empty chain is valid
-        }
-
-        let mut expected_chain_hash = compute_blake3(b"genesis_vdf_proof");
+    /// Forecast when `required` total iterations will be reached, so callers can
+    /// schedule signing before `sign_block` fails with insufficient iterations.
+    /// Uses the measured rate, falling back to the target rate before one is observed.
+    pub fn iterations_until_ready(&self, required: u64) -> IterationsForecast {
+        let stats = self.get_performance_stats();
+        let shortfall = required.saturating_sub(stats.total_iterations);
+        let iterations_per_second = self.effective_rate(&stats);
 
-        for (i, proof) in proofs.iter().enumerate() {
-            // Verify proof chain hash
-            if i > 0 && proof.proof_chain_hash != expected_chain_hash {
-                debug!("❌ Shared VDF proof chain broken at index {}", i);
-                return false;
-            }
+        let estimated_seconds = if shortfall == 0 {
+            Some(0.0)
+        } else if iterations_per_second > 0.0 {
+            Some(shortfall as f64 / iterations_per_second)
+        } else {
+            None
+        };
 
-            // Verify signature
-            let proof_data = [
-                &proof.vdf_state[..],
-                &proof.total_iterations.to_be_bytes(),
-                &proof.timestamp.to_be_bytes(),
-                &proof.proof_chain_hash[..],
-            ]
-            .concat();
-
-            if let Ok(valid) = crate::core::utils::verify_signature(
-                prover_public_key,
-                &proof_data,
-                &proof.signature,
-            ) {
-                if !valid {
-                    debug!("❌ Invalid signature in shared VDF proof at index {}", i);
-                    return false;
-                }
-            } else {
-                debug!(
-                    "❌ Failed to verify signature in shared VDF proof at index {}",
-                    i
-                );
-                return false;
-            }
+        IterationsForecast {
+            current_iterations: stats.total_iterations,
+            required_iterations: required,
+            shortfall,
+            iterations_per_second,
+            estimated_seconds,
+        }
+    }
 
-            // Update expected chain hash for next iteration
-            expected_chain_hash = compute_blake3(
-                &[
-                    &proof.proof_chain_hash[..],
-                    &proof.vdf_state[..],
-                    &proof.total_iterations.to_be_bytes(),
-                ]
-                .concat(),
+    /// Whether the current VDF rate produces `min_iterations_per_block` iterations within
+    /// one `block_time_seconds` interval. Logs a warning when it does not, so operators
+    /// learn their hardware is too slow before blocks start failing.
+    pub fn is_viable_for_block_time(
+        &self,
+        block_time_seconds: f64,
+        min_iterations_per_block: u64,
+    ) -> bool {
+        let iterations_per_second = self.effective_rate(&self.get_performance_stats());
+        let achievable = iterations_per_second * block_time_seconds.max(0.0);
+        let viable = achievable >= min_iterations_per_block as f64;
+        if !viable {
+            warn!(
+                "VDF rate {:.0} it/s yields {:.0} iterations per {:.0}s block, below the required {}",
+                iterations_per_second, achievable, block_time_seconds, min_iterations_per_block
             );
         }
+        viable
+    }
 
-        debug!(
-            "✅ Shared VDF proof chain verified successfully ({} proofs)",
-            proofs.len()
-        );
-        true
+    /// Measured rate, falling back to the target rate before one is observed; zero when stopped
+    fn effective_rate(&self, stats: &VDFPerformanceStats) -> f64 {
+        if !self.is_running() {
+            0.0
+        } else if stats.actual_iterations_per_second > 0.0 {
+            stats.actual_iterations_per_second
+        } else {
+            stats.target_iterations_per_second as f64
+        }
     }
 
     /// Get VDF performance statistics
@@ -342,19 +1038,106 @@ impl VDFProcessor {
         VDFPerformanceStats {
             total_iterations,
             elapsed_seconds,
-            target_iterations_per_second: self.target_iterations_per_second,
+            target_iterations_per_second: self.get_target_rate(),
+            calibrated_iterations_per_second: self.get_calibrated_rate(),
             actual_iterations_per_second,
             shared_proofs_count: self.shared_proofs.lock().unwrap().len(),
         }
     }
 }
 
+/// Compute the Merkle root over the block leaves of a range
+pub fn compute_block_range_root(
+    start_height: u64,
+    end_height: u64,
+    block_hashes: &[[u8; 32]],
+) -> Result<[u8; 32], String> {
+    if end_height < start_height {
+        return Err(format!(
+            "Invalid block range: {} > {}",
+            start_height, end_height
+        ));
+    }
+
+    let expected_count = end_height - start_height + 1;
+    if block_hashes.len() as u64 != expected_count {
+        return Err(format!(
+            "Block hash count mismatch: expected {}, got {}",
+            expected_count,
+            block_hashes.len()
+        ));
+    }
+
+    let leaves = block_range_leaves(start_height, block_hashes);
+    let leaf_refs: Vec<&[u8]> = leaves.iter().map(|leaf| leaf.as_ref()).collect();
+    Ok(crate::core::utils::compute_merkle_root(&leaf_refs))
+}
+
+/// Inclusion path proving that `block_height` is covered by a block range
+pub fn compute_block_range_inclusion_path(
+    start_height: u64,
+    block_hashes: &[[u8; 32]],
+    block_height: u64,
+) -> Option<Vec<MerklePathNode>> {
+    let index = block_height.checked_sub(start_height)? as usize;
+    compute_merkle_inclusion_path(&block_range_leaves(start_height, block_hashes), index)
+}
+
+/// Verify that a block is covered by a range signature via its inclusion path
+pub fn verify_block_in_range_signature(
+    range: &VDFRangeSignature,
+    block_height: u64,
+    block_hash: &[u8; 32],
+    inclusion_path: &[MerklePathNode],
+) -> bool {
+    if block_height < range.start_height || block_height > range.end_height {
+        return false;
+    }
+
+    let expected_signature = compute_block_range_signature(
+        &range.vdf_state,
+        range.start_height,
+        range.end_height,
+        &range.range_root,
+        range.total_iterations,
+    );
+    if expected_signature != range.signature {
+        return false;
+    }
+
+    let leaf = compute_block_range_leaf(block_height, block_hash);
+    compute_merkle_root_from_path(leaf, inclusion_path) == range.range_root
+}
+
+fn block_range_leaves(start_height: u64, block_hashes: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    block_hashes
+        .iter()
+        .enumerate()
+        .map(|(offset, hash)| compute_block_range_leaf(start_height + offset as u64, hash))
+        .collect()
+}
+
+/// Forecast of when the VDF reaches a required iteration count
+#[derive(Debug, Clone)]
+pub struct IterationsForecast {
+    pub current_iterations: u64,
+    pub required_iterations: u64,
+    /// Iterations still missing (0 when ready)
+    pub shortfall: u64,
+    /// Rate the estimate is based on
+    pub iterations_per_second: f64,
+    /// Seconds until `required_iterations` is reached; None if the VDF is not advancing
+    pub estimated_seconds: Option<f64>,
+}
+
 /// VDF performance statistics
 #[derive(Debug, Clone)]
 pub struct VDFPerformanceStats {
     pub total_iterations: u64,
     pub elapsed_seconds: f64,
     pub target_iterations_per_second: u64,
+    /// Benchmarked rate from `VDFProcessor::calibrate`, if it has run
+    pub calibrated_iterations_per_second: Option<f64>,
     pub actual_iterations_per_second: f64,
     pub shared_proofs_count: usize,
 }
@@ -362,6 +1145,286 @@ pub struct VDFPerformanceStats {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::utils::{compute_vdf_block_signature, sign_data};
+
+    #[test]
+    fn test_target_rate_validation() {
+        let processor = VDFProcessor::new([3u8; 32], 256, 1000, vec![1u8; 32]);
+        assert_eq!(processor.get_target_rate(), 1000);
+
+        assert!(processor.set_target_rate(5000).is_ok());
+        assert_eq!(processor.get_target_rate(), 5000);
+
+        // Out-of-range rates are rejected and leave the current rate unchanged
+        assert!(processor.set_target_rate(0).is_err());
+        assert!(processor
+            .set_target_rate(MAX_VDF_ITERATIONS_PER_SECOND as u64 + 1)
+            .is_err());
+        assert_eq!(processor.get_target_rate(), 5000);
+    }
+
+    #[test]
+    fn test_calibration_and_wait_for_iterations() {
+        let processor = VDFProcessor::new([7u8; 32], 256, 1000, vec![1u8; 32]);
+        assert_eq!(processor.get_calibrated_rate(), None);
+
+        let target = processor.calibrate();
+        let measured = processor.get_calibrated_rate().unwrap();
+        assert!(measured > 0.0);
+        assert_eq!(processor.get_target_rate(), target);
+        assert!(target >= MIN_VDF_TARGET_ITERATIONS_PER_SECOND as u64);
+        assert!(target <= 1000);
+        assert!(target as f64 <= measured.max(MIN_VDF_TARGET_ITERATIONS_PER_SECOND as f64));
+        // The benchmark runs on a scratch VDF
+        assert_eq!(processor.get_iteration_count(), 0);
+        assert_eq!(
+            processor
+                .get_performance_stats()
+                .calibrated_iterations_per_second,
+            Some(measured)
+        );
+
+        // Not running: returns at once instead of waiting forever
+        assert!(!processor.wait_for_iterations(10));
+
+        processor.start();
+        processor.start(); // A second start does not spawn another thread
+        assert!(processor.wait_for_iterations(50));
+        assert!(processor.get_iteration_count() >= 50);
+
+        // Stopping wakes a waiter that can no longer be satisfied
+        thread::scope(|scope| {
+            let waiter = scope.spawn(|| processor.wait_for_iterations(u64::MAX));
+            thread::sleep(Duration::from_millis(50));
+            processor.stop();
+            assert!(!waiter.join().unwrap());
+        });
+    }
+
+    #[test]
+    fn test_iterations_forecast() {
+        let processor = VDFProcessor::new([4u8; 32], 256, 1000, vec![1u8; 32]);
+        for _ in 0..50 {
+            processor.vdf.lock().unwrap().iterate();
+        }
+
+        let ready = processor.iterations_until_ready(30);
+        assert_eq!(ready.current_iterations, 50);
+        assert_eq!(ready.shortfall, 0);
+        assert_eq!(ready.estimated_seconds, Some(0.0));
+
+        // A stopped VDF never reaches the target
+        let stalled = processor.iterations_until_ready(1050);
+        assert_eq!(stalled.shortfall, 1000);
+        assert_eq!(stalled.estimated_seconds, None);
+
+        processor.start();
+        let running = processor.iterations_until_ready(u64::MAX);
+        processor.stop();
+        assert!(running.iterations_per_second > 0.0);
+        assert!(running.estimated_seconds.unwrap() > 0.0);
+    }
+
+    #[test]
+    fn test_block_time_viability() {
+        let processor = VDFProcessor::new([6u8; 32], 256, 1000, vec![1u8; 32]);
+
+        // A stopped VDF cannot keep up with any requirement
+        assert!(!processor.is_viable_for_block_time(52.0, 1));
+        assert!(processor.is_viable_for_block_time(52.0, 0));
+
+        processor.start();
+        let rate = processor.effective_rate(&processor.get_performance_stats());
+        let viable = processor.is_viable_for_block_time(52.0, 1);
+        let not_viable = processor.is_viable_for_block_time(52.0, u64::MAX);
+        processor.stop();
+        assert!(rate > 0.0);
+        assert!(viable);
+        assert!(!not_viable);
+    }
+
+    #[test]
+    fn test_ensure_iterations_reports_not_ready() {
+        use crate::core::errors::error_codes;
+
+        let processor = VDFProcessor::new([8u8; 32], 4, 1000, vec![1u8; 32]);
+        let error = processor.ensure_iterations(1000).unwrap_err();
+        assert!(matches!(
+            error,
+            HashChainError::VdfNotReady {
+                current: 0,
+                required: 1000
+            }
+        ));
+        assert_eq!(error.code(), error_codes::E_VDF_NOT_READY);
+
+        for _ in 0..5 {
+            processor.vdf.lock().unwrap().iterate();
+        }
+        assert!(matches!(
+            processor.ensure_iterations(6),
+            Err(HashChainError::VdfNotReady {
+                current: 5,
+                required: 6
+            })
+        ));
+        assert!(processor.ensure_iterations(5).is_ok());
+    }
+
+    #[test]
+    fn test_restored_state_continues_vdf() {
+        let public_key = crate::core::utils::derive_public_key(&[1u8; 32]).unwrap();
+        let source = VDFProcessor::new([5u8; 32], 256, 1000, vec![1u8; 32]);
+        for _ in 0..50 {
+            source.vdf.lock().unwrap().iterate();
+        }
+        let snapshot = source.capture_state().unwrap();
+
+        let target = VDFProcessor::new([6u8; 32], 256, 1000, vec![1u8; 32]);
+        target.restore_state(snapshot.clone(), &public_key).unwrap();
+        assert_eq!(target.get_state(), source.get_state());
+
+        // Both VDFs must produce identical states from here on
+        for _ in 0..20 {
+            let expected = source.vdf.lock().unwrap().iterate();
+            assert_eq!(target.vdf.lock().unwrap().iterate(), expected);
+        }
+        assert_eq!(target.get_state().1, 70);
+
+        // A snapshot from a VDF with a different memory size is rejected
+        let other = VDFProcessor::new([5u8; 32], 128, 1000, vec![1u8; 32]);
+        assert!(other.restore_state(snapshot.clone(), &public_key).is_err());
+
+        // A fast-forwarded iteration count no longer matches the checkpoint proof
+        let mut forged = snapshot.clone();
+        forged.total_iterations += 1_000_000;
+        assert!(!VDFProcessor::verify_imported_state(
+            forged.vdf_state,
+            forged.total_iterations,
+            &forged.checkpoint_proof,
+            &public_key,
+        )
+        .unwrap());
+        assert!(target.restore_state(forged.clone(), &public_key).is_err());
+
+        // Re-signing the forged state needs the prover key
+        let forger = VDFProcessor::new([5u8; 32], 256, 1000, vec![2u8; 32]);
+        for _ in 0..10 {
+            forger.vdf.lock().unwrap().iterate();
+        }
+        forged.checkpoint_proof = forger.capture_state().unwrap().checkpoint_proof;
+        forged.checkpoint_proof.total_iterations = forged.total_iterations;
+        assert!(target.restore_state(forged, &public_key).is_err());
+        assert!(target.restore_state(snapshot, &[9u8; 32]).is_err());
+    }
+
+    #[test]
+    fn test_vdf_snapshot_round_trip() {
+        let public_key = crate::core::utils::derive_public_key(&[1u8; 32]).unwrap();
+        let source = VDFProcessor::new([5u8; 32], 256, 1000, vec![1u8; 32]);
+        for _ in 0..40 {
+            source.vdf.lock().unwrap().iterate();
+        }
+        source.vdf.lock().unwrap().set_last_block(12, [4u8; 32]);
+        let json = source.capture_state().unwrap().to_json();
+
+        // The JSON snapshot resumes the VDF and its last signed block exactly
+        let target = VDFProcessor::new([5u8; 32], 256, 1000, vec![1u8; 32]);
+        target
+            .restore_state(VDFStateSnapshot::from_json(&json).unwrap(), &public_key)
+            .unwrap();
+        assert_eq!(target.get_state(), source.get_state());
+        assert_eq!(target.vdf.lock().unwrap().last_block(), (12, [4u8; 32]));
+        for _ in 0..10 {
+            let expected = source.vdf.lock().unwrap().iterate();
+            assert_eq!(target.vdf.lock().unwrap().iterate(), expected);
+        }
+
+        // A tampered state no longer matches the signed checkpoint
+        let mut tampered: serde_json::Value = serde_json::from_str(&json).unwrap();
+        tampered["vdf_state"] = hex::encode([0u8; 32]).into();
+        let tampered = VDFStateSnapshot::from_json(&tampered.to_string()).unwrap();
+        assert!(target.restore_state(tampered, &public_key).is_err());
+
+        // Malformed hex and a wrong key are rejected
+        let mut malformed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        malformed["last_block_hash"] = "zz".into();
+        assert!(VDFStateSnapshot::from_json(&malformed.to_string()).is_err());
+        assert!(VDFStateSnapshot::from_json("not a snapshot").is_err());
+        let snapshot = VDFStateSnapshot::from_json(&json).unwrap();
+        assert!(target.restore_state(snapshot, &[9u8; 32]).is_err());
+    }
+
+    #[test]
+    fn test_block_snapshot_is_consistent() {
+        let processor = VDFProcessor::new([7u8; 32], 256, 1000, vec![1u8; 32]);
+        processor.start();
+        thread::sleep(Duration::from_millis(50));
+
+        let block_hash = [9u8; 32];
+        let snapshot = processor.snapshot_for_block(42, block_hash, 1).unwrap();
+        processor.stop();
+
+        // Signature must be over exactly the captured state
+        let expected_signature = compute_vdf_block_signature(
+            &snapshot.vdf_state,
+            42,
+            &block_hash,
+            snapshot.total_iterations,
+        );
+        assert_eq!(snapshot.signature, expected_signature);
+        assert!(snapshot.total_iterations >= 1);
+
+        // Insufficient iterations is reported without a snapshot
+        assert!(processor
+            .snapshot_for_block(43, block_hash, u64::MAX)
+            .is_err());
+    }
+
+    #[test]
+    fn test_standalone_vdf_proof_chain_verification() {
+        use ed25519_dalek::{PublicKey, SecretKey};
+        let private_key = [3u8; 32];
+        let public_key = PublicKey::from(&SecretKey::from_bytes(&private_key).unwrap())
+            .to_bytes()
+            .to_vec();
+
+        // Build a three-proof chain the way the processor does
+        let mut proofs: Vec<SharedVDFProof> = Vec::new();
+        for i in 0..3u64 {
+            let proof_chain_hash = match proofs.last() {
+                Some(last) => last.next_chain_hash(),
+                None => compute_blake3(b"genesis_vdf_proof"),
+            };
+            let mut proof = SharedVDFProof {
+                vdf_state: compute_blake3(&i.to_be_bytes()),
+                total_iterations: 1000 * (i + 1),
+                timestamp: 1_700_000_000.0 + i as f64,
+                signature: Vec::new(),
+                proof_chain_hash,
+            };
+            proof.signature = sign_data(&private_key, &proof.signed_data()).unwrap();
+            proofs.push(proof);
+        }
+
+        // Round-trips through the serialized form
+        let serialized = serialize_vdf_proof_chain(&proofs);
+        let parsed = deserialize_vdf_proof_chain(&serialized).unwrap();
+        assert_eq!(parsed.len(), 3);
+        assert!(verify_vdf_proof_chain(&parsed, &public_key).unwrap());
+
+        // Wrong prover key
+        let other_key = PublicKey::from(&SecretKey::from_bytes(&[4u8; 32]).unwrap()).to_bytes();
+        assert!(!verify_vdf_proof_chain(&parsed, &other_key).unwrap());
+
+        // Broken linkage
+        let mut broken = parsed.clone();
+        broken[2].proof_chain_hash = [0u8; 32];
+        assert!(!verify_vdf_proof_chain(&broken, &public_key).unwrap());
+
+        // Truncated buffer
+        assert!(deserialize_vdf_proof_chain(&serialized[..serialized.len() - 1]).is_err());
+    }
 
     #[test]
     fn test_vdf_processor_with_shared_proofs() {
@@ -404,4 +1467,213 @@ mod tests {
         // Stop processor
         processor.stop();
     }
+
+    #[test]
+    fn test_block_range_signature_inclusion() {
+        let processor = VDFProcessor::new([2u8; 32], 4, 1000, vec![1u8; 32]);
+        let block_hashes: Vec<[u8; 32]> = (0..5u8).map(|i| [i; 32]).collect();
+
+        let range = processor
+            .sign_block_range(100, 104, &block_hashes, 0)
+            .unwrap();
+
+        // Every block in the range is provably covered
+        for (offset, hash) in block_hashes.iter().enumerate() {
+            let height = 100 + offset as u64;
+            let path = compute_block_range_inclusion_path(100, &block_hashes, height).unwrap();
+            assert!(verify_block_in_range_signature(&range, height, hash, &path));
+        }
+
+        // A block hash that was not signed is rejected
+        let path = compute_block_range_inclusion_path(100, &block_hashes, 102).unwrap();
+        assert!(!verify_block_in_range_signature(
+            &range, 102, &[9u8; 32], &path
+        ));
+
+        // Heights outside the range are rejected
+        assert!(!verify_block_in_range_signature(
+            &range,
+            105,
+            &block_hashes[4],
+            &path
+        ));
+
+        // Mismatched hash count is an error
+        assert!(processor
+            .sign_block_range(100, 104, &block_hashes[..4], 0)
+            .is_err());
+    }
+
+    #[test]
+    fn test_checkpoint_validates_against_published_proof() {
+        use crate::consensus::NetworkConsensusValidator;
+        use crate::core::types::{VDFCheckpoint, CONTINUOUS_VDF_MEMORY_KB};
+        use ed25519_dalek::{PublicKey, SecretKey};
+        use napi::bindgen_prelude::Buffer;
+
+        let private_key = [3u8; 32];
+        let public_key = PublicKey::from(&SecretKey::from_bytes(&private_key).unwrap()).to_bytes();
+        let signer = SoftwareSigner::new(private_key.to_vec());
+        let initial_state = [5u8; 32];
+
+        // The prover publishes a signed proof at iteration 500
+        let mut vdf = ContinuousVDF::new(initial_state, CONTINUOUS_VDF_MEMORY_KB);
+        for _ in 0..500 {
+            vdf.iterate();
+        }
+        let (state, iterations) = vdf.get_state();
+        let published = sign_shared_proof(&signer, state, iterations, [0u8; 32]).unwrap();
+
+        let checkpoint_at = |iteration: u64| {
+            let (state, memory) =
+                checkpoint_from_snapshots(initial_state, CONTINUOUS_VDF_MEMORY_KB, &[], iteration)
+                    .unwrap();
+            VDFCheckpoint {
+                iteration: iteration as f64,
+                state: Buffer::from(state.to_vec()),
+                memory_snapshot: Buffer::from(memory),
+            }
+        };
+        let validator = NetworkConsensusValidator::new_development();
+        let checkpoint = checkpoint_at(400);
+        assert!(validator
+            .validate_vdf_checkpoint(&public_key, &published, &checkpoint)
+            .is_ok());
+
+        // Another prover's key does not vouch for the published state
+        let other_key = PublicKey::from(&SecretKey::from_bytes(&[4u8; 32]).unwrap()).to_bytes();
+        assert!(validator
+            .validate_vdf_checkpoint(&other_key, &published, &checkpoint)
+            .is_err());
+
+        // An end state the prover never signed is rejected
+        let mut unsigned = published.clone();
+        unsigned.vdf_state = [9u8; 32];
+        assert!(validator
+            .validate_vdf_checkpoint(&public_key, &unsigned, &checkpoint)
+            .is_err());
+
+        // A checkpoint that does not recompute to the published state is rejected
+        let mut forged = checkpoint_at(400);
+        forged.state = Buffer::from(vec![1u8; 32]);
+        assert!(validator
+            .validate_vdf_checkpoint(&public_key, &published, &forged)
+            .is_err());
+        let shifted = VDFCheckpoint {
+            iteration: 399.0,
+            ..checkpoint_at(400)
+        };
+        assert!(validator
+            .validate_vdf_checkpoint(&public_key, &published, &shifted)
+            .is_err());
+
+        // The checkpoint must precede the published proof
+        assert!(validator
+            .validate_vdf_checkpoint(&public_key, &published, &checkpoint_at(500))
+            .is_err());
+    }
+
+    #[test]
+    fn test_reproduction_served_from_checkpoints() {
+        use crate::consensus::NetworkConsensusValidator;
+        use crate::core::errors::error_codes;
+        use crate::core::types::{VDFCheckpoint, CONTINUOUS_VDF_MEMORY_KB};
+        use napi::bindgen_prelude::Buffer;
+
+        let private_key = [3u8; 32];
+        let public_key = crate::core::utils::derive_public_key(&private_key).unwrap();
+        let signer = SoftwareSigner::new(private_key.to_vec());
+        let processor = VDFProcessor::new(
+            [5u8; 32],
+            CONTINUOUS_VDF_MEMORY_KB,
+            1000,
+            private_key.to_vec(),
+        );
+        for _ in 0..300 {
+            processor.vdf.lock().unwrap().iterate();
+        }
+        let (state, iterations) = processor.get_state();
+        let published = sign_shared_proof(&signer, state, iterations, [0u8; 32]).unwrap();
+
+        // The segment ends at the published state and recomputes from its checkpoint
+        let reproduction = processor
+            .respond_to_reproduction_challenge(250, 50)
+            .unwrap();
+        assert_eq!(reproduction.end_state, published.vdf_state);
+        let checkpoint = VDFCheckpoint {
+            iteration: 250.0,
+            state: Buffer::from(reproduction.start_state.to_vec()),
+            memory_snapshot: Buffer::from(reproduction.memory_snapshot),
+        };
+        assert!(NetworkConsensusValidator::new_development()
+            .validate_vdf_checkpoint(&public_key, &published, &checkpoint)
+            .is_ok());
+
+        // Segments past the VDF's progress are not ready yet
+        let not_ready = processor
+            .respond_to_reproduction_challenge(260, 50)
+            .unwrap_err();
+        assert!(matches!(
+            not_ready,
+            HashChainError::VdfNotReady {
+                current: 300,
+                required: 310
+            }
+        ));
+        assert_eq!(not_ready.code(), error_codes::E_VDF_NOT_READY);
+
+        // Segments longer than a verifier recomputes are refused
+        assert!(processor
+            .respond_to_reproduction_challenge(0, VDF_REPRODUCTION_MAX_SEGMENT as u64 + 1)
+            .is_err());
+        assert!(processor.respond_to_reproduction_challenge(250, 0).is_err());
+
+        // Replays beyond the bound fail rather than report the VDF as not ready
+        let too_far =
+            checkpoint_from_snapshots([5u8; 32], 4, &[], VDF_SEED_REPLAY_MAX_ITERATIONS as u64 + 1)
+                .unwrap_err();
+        assert_eq!(too_far.code(), error_codes::E_VDF_FAILED);
+    }
+
+    #[test]
+    fn test_checkpoint_replay_from_stored_snapshots() {
+        let initial_state = [6u8; 32];
+        let (state, memory) = replay_continuous_vdf_checkpoint(initial_state, 4, 300);
+        let snapshots = vec![CheckpointSnapshot {
+            iteration: 300,
+            state,
+            memory,
+        }];
+
+        // Resuming from the stored snapshot matches a replay from the initial state
+        assert_eq!(
+            checkpoint_from_snapshots(initial_state, 4, &snapshots, 350).unwrap(),
+            replay_continuous_vdf_checkpoint(initial_state, 4, 350)
+        );
+        assert_eq!(
+            checkpoint_from_snapshots(initial_state, 4, &snapshots, 200).unwrap(),
+            replay_continuous_vdf_checkpoint(initial_state, 4, 200)
+        );
+
+        // Replays past the limit are refused instead of blocking the prover
+        let limit = VDF_SEED_REPLAY_MAX_ITERATIONS as u64;
+        assert!(checkpoint_from_snapshots(initial_state, 4, &[], limit + 1).is_err());
+        assert!(checkpoint_from_snapshots(initial_state, 4, &snapshots, 300 + limit + 1).is_err());
+
+        // Only the most recent snapshots are kept
+        let stored = Mutex::new(Vec::new());
+        for i in 0..VDF_CHECKPOINT_SNAPSHOTS as u64 + 2 {
+            push_checkpoint_snapshot(
+                &stored,
+                CheckpointSnapshot {
+                    iteration: i,
+                    state: [0u8; 32],
+                    memory: Vec::new(),
+                },
+            );
+        }
+        let stored = stored.into_inner().unwrap();
+        assert_eq!(stored.len(), VDF_CHECKPOINT_SNAPSHOTS);
+        assert_eq!(stored[0].iteration, 2);
+    }
 }
@@ -1,10 +1,14 @@
-use crate::core::errors::HashChainResult;
+use crate::core::errors::{HashChainError, HashChainResult};
 use napi::bindgen_prelude::*;
+use rayon::prelude::*;
 use serde_json;
 use std::collections::HashMap;
 
 use crate::core::types::*;
-use crate::hierarchy::{GroupManager, RegionManager};
+use crate::hierarchy::{
+    ChainInclusionProof, GroupManager, HierarchicalGlobalProof, HierarchicalProofResult,
+    RegionManager,
+};
 
 /// Global state for all chains in the system
 #[derive(Clone)]
@@ -94,6 +98,17 @@ pub struct RetentionPolicy {
     pub days: u32,
 }
 
+/// Thread pool bounded to `threads` workers for per-chain block processing
+fn build_block_processing_pool(threads: u32) -> HashChainResult<rayon::ThreadPool> {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(threads as usize)
+        .thread_name(|index| format!("block-processing-{}", index))
+        .build()
+        .map_err(|e| HashChainError::ParallelProcessing {
+            reason: format!("Failed to build block processing pool: {}", e),
+        })
+}
+
 /// Enhanced manager with hierarchical proof support for 100,000+ chains
 pub struct HierarchicalGlobalChainManager {
     pub hierarchy_levels: u32,
@@ -102,6 +117,19 @@ pub struct HierarchicalGlobalChainManager {
     pub region_manager: RegionManager,
     pub chain_registry: HashMap<Vec<u8>, LightweightHashChain>,
     pub active_chains: u32,
+    pub current_block_height: u64,
+    /// Global root of the previous checkpoint
+    pub previous_global_proof: Buffer,
+    /// Running hash over all checkpoint roots
+    pub cumulative_work: Buffer,
+    /// Hierarchical proof behind the latest checkpoint
+    pub last_hierarchical_proof: Option<HierarchicalProofResult>,
+    /// Worker thread bound for per-chain block processing
+    pub block_processing_threads: u32,
+    /// Work-stealing pool for per-chain block processing, built on first use
+    block_processing_pool: Option<rayon::ThreadPool>,
+    /// Timing of the most recent block
+    pub last_block_processing: Option<BlockProcessingStats>,
 }
 
 impl HierarchicalGlobalChainManager {
@@ -113,7 +141,27 @@ impl HierarchicalGlobalChainManager {
             region_manager: RegionManager::new(),
             chain_registry: HashMap::new(),
             active_chains: 0,
+            current_block_height: 0,
+            previous_global_proof: Buffer::from([0u8; 32].to_vec()),
+            cumulative_work: Buffer::from([0u8; 32].to_vec()),
+            last_hierarchical_proof: None,
+            block_processing_threads: DEFAULT_BLOCK_PROCESSING_THREADS,
+            block_processing_pool: None,
+            last_block_processing: None,
+        }
+    }
+
+    /// Bound the worker threads used for per-chain block processing
+    pub fn set_block_processing_threads(&mut self, threads: u32) -> HashChainResult<()> {
+        if threads == 0 {
+            return Err(HashChainError::ParallelProcessing {
+                reason: "Block processing needs at least one thread".to_string(),
+            });
         }
+
+        self.block_processing_pool = Some(build_block_processing_pool(threads)?);
+        self.block_processing_threads = threads;
+        Ok(())
     }
 
     pub fn add_chain(
@@ -123,8 +171,7 @@ impl HierarchicalGlobalChainManager {
         _retention_policy: Option<String>,
         _metadata: Option<HashMap<String, String>>,
     ) -> HashChainResult<HashMap<String, serde_json::Value>> {
-        // Create a basic chain ID
-        let chain_id = public_key.iter().take(16).cloned().collect::<Vec<u8>>();
+        let chain_id = Self::chain_id_for_key(&public_key);
 
         let chain = LightweightHashChain {
             chain_id: chain_id.clone(),
@@ -138,6 +185,7 @@ impl HierarchicalGlobalChainManager {
             file_encoding: None,
             availability_score: 1.0,
             latency_score: 1.0,
+            last_activity_height: self.current_block_height,
         };
 
         self.chain_registry.insert(chain_id.clone(), chain);
@@ -195,26 +243,46 @@ impl HierarchicalGlobalChainManager {
         block_hash: Buffer,
         block_height: u64,
     ) -> HashChainResult<()> {
-        // Process block for all active chains
-        let mut chains_to_update = Vec::new();
+        let start_time = std::time::Instant::now();
+        self.current_block_height = block_height;
 
         // Collect chains that need updates
-        for (chain_id, chain) in &self.chain_registry {
-            if (chain.chain_length as u64) < block_height {
-                chains_to_update.push(chain_id.clone());
-            }
+        let chains_to_update: Vec<ChainId> = self
+            .chain_registry
+            .iter()
+            .filter(|(_, chain)| (chain.chain_length as u64) < block_height)
+            .map(|(chain_id, _)| chain_id.clone())
+            .collect();
+
+        // Generate new commitments in parallel on the bounded pool
+        let block_hash_bytes = block_hash.to_vec();
+        if self.block_processing_pool.is_none() {
+            self.block_processing_pool =
+                Some(build_block_processing_pool(self.block_processing_threads)?);
         }
+        let commitments: Vec<(ChainId, [u8; 32])> =
+            self.block_processing_pool.as_ref().unwrap().install(|| {
+                chains_to_update
+                    .into_par_iter()
+                    .map(|chain_id| {
+                        let commitment_data = [
+                            &chain_id[..],
+                            &block_hash_bytes[..],
+                            &block_height.to_be_bytes(),
+                        ]
+                        .concat();
+                        let commitment_hash = crate::core::utils::compute_sha256(&commitment_data);
+                        (chain_id, commitment_hash)
+                    })
+                    .collect()
+            });
 
-        // Update each chain with new block
-        for chain_id in chains_to_update {
+        // Apply commitments to shared state
+        let chains_processed = commitments.len() as u32;
+        for (chain_id, commitment_hash) in commitments {
             if let Some(chain) = self.chain_registry.get_mut(&chain_id) {
                 // Update chain state for new block
                 chain.chain_length = block_height as u32;
-
-                // Generate new commitment for this block
-                let commitment_data =
-                    [&chain_id[..], &block_hash[..], &block_height.to_be_bytes()].concat();
-                let commitment_hash = crate::core::utils::compute_sha256(&commitment_data);
                 chain.current_commitment = Some(Buffer::from(commitment_hash.to_vec()));
 
                 // Update group and region managers
@@ -229,9 +297,277 @@ impl HierarchicalGlobalChainManager {
             }
         }
 
+        let elapsed_ms = start_time.elapsed().as_secs_f64() * 1000.0;
+        let stats = BlockProcessingStats {
+            block_height: block_height as f64,
+            chains_processed,
+            worker_threads: self.block_processing_threads,
+            elapsed_ms,
+            target_ms: BLOCK_PROCESSING_TARGET_MS as f64,
+            per_chain_ms: if chains_processed > 0 {
+                elapsed_ms / chains_processed as f64
+            } else {
+                0.0
+            },
+            within_target: elapsed_ms <= BLOCK_PROCESSING_TARGET_MS as f64,
+        };
+        if stats.within_target {
+            log::debug!(
+                "Block {} processed {} chains in {:.1}ms (target {}ms)",
+                block_height,
+                chains_processed,
+                elapsed_ms,
+                BLOCK_PROCESSING_TARGET_MS
+            );
+        } else {
+            log::warn!(
+                "Block {} processing missed target: {} chains in {:.1}ms (target {}ms)",
+                block_height,
+                chains_processed,
+                elapsed_ms,
+                BLOCK_PROCESSING_TARGET_MS
+            );
+        }
+        self.last_block_processing = Some(stats);
+
         Ok(())
     }
 
+    /// Chain id under which a public key's chain is registered: the first 16 bytes
+    /// of the key
+    pub fn chain_id_for_key(public_key: &[u8]) -> Vec<u8> {
+        public_key.iter().take(16).cloned().collect()
+    }
+
+    /// Group and region the chain is placed in, if it is registered
+    pub fn chain_placement(&self, chain_id: &[u8]) -> Option<(GroupId, RegionId)> {
+        let group_id = self.group_manager.chain_to_group.get(chain_id)?;
+        let region_id = self.region_manager.group_to_region.get(group_id)?;
+        Some((group_id.clone(), region_id.clone()))
+    }
+
+    /// Health of a group: its size, proof freshness and the chains flagged unhealthy
+    pub fn get_group_health(&self, group_id: &str) -> HashChainResult<GroupHealth> {
+        let group = self.group_manager.groups.get(group_id).ok_or_else(|| {
+            HashChainError::GroupAssignment {
+                reason: format!("Group {} not found", group_id),
+            }
+        })?;
+
+        let mut unhealthy_chains: Vec<String> = group
+            .chain_ids
+            .iter()
+            .filter(|chain_id| !self.is_chain_healthy(chain_id))
+            .map(hex::encode)
+            .collect();
+        unhealthy_chains.sort();
+
+        Ok(GroupHealth {
+            group_id: group.group_id.clone(),
+            region_id: self.region_manager.group_to_region.get(group_id).cloned(),
+            chain_count: group.chain_count(),
+            max_chains: group.max_chains,
+            last_update_block: group.last_update_block as f64,
+            last_proof_time_ms: group.performance_stats.get("last_proof_time_ms").copied(),
+            has_proof: group.last_group_proof.is_some(),
+            unhealthy_chains,
+        })
+    }
+
+    /// Health of a region, aggregating the health of its groups
+    pub fn get_region_health(&self, region_id: &str) -> HashChainResult<RegionHealth> {
+        let region = self.region_manager.regions.get(region_id).ok_or_else(|| {
+            HashChainError::GroupAssignment {
+                reason: format!("Region {} not found", region_id),
+            }
+        })?;
+
+        let mut chain_count = 0;
+        let mut lagging_groups = Vec::new();
+        let mut unhealthy_chains = Vec::new();
+        for group_id in &region.group_ids {
+            let group_health = self.get_group_health(group_id)?;
+            chain_count += group_health.chain_count;
+            if !group_health.unhealthy_chains.is_empty() {
+                lagging_groups.push(group_id.clone());
+                unhealthy_chains.extend(group_health.unhealthy_chains);
+            }
+        }
+        lagging_groups.sort();
+        unhealthy_chains.sort();
+
+        Ok(RegionHealth {
+            region_id: region.region_id.clone(),
+            group_count: region.group_count(),
+            max_groups: region.max_groups,
+            chain_count,
+            last_update_block: region.last_update_block as f64,
+            last_proof_time_ms: region.performance_stats.get("last_proof_time_ms").copied(),
+            has_proof: region.last_regional_proof.is_some(),
+            lagging_groups,
+            unhealthy_chains,
+        })
+    }
+
+    /// A chain is healthy while its commitment keeps up with the current block and it
+    /// has been active within `CHAIN_HEALTH_IDLE_BLOCKS`
+    fn is_chain_healthy(&self, chain_id: &[u8]) -> bool {
+        let Some(chain) = self.chain_registry.get(chain_id) else {
+            return false;
+        };
+        let commitment_lag = self
+            .current_block_height
+            .saturating_sub(chain.chain_length as u64);
+        let idle = self
+            .current_block_height
+            .saturating_sub(chain.last_activity_height);
+        chain.current_commitment.is_some()
+            && commitment_lag <= CHAIN_HEALTH_LAG_BLOCKS as u64
+            && idle <= CHAIN_HEALTH_IDLE_BLOCKS as u64
+    }
+
+    /// Record activity for a chain at the current block height; unknown chains are ignored
+    pub fn record_chain_activity(&mut self, chain_id: &[u8]) {
+        if let Some(chain) = self.chain_registry.get_mut(chain_id) {
+            chain.last_activity_height = self.current_block_height;
+        }
+    }
+
+    /// Remove chains with no activity for more than `INACTIVE_CHAIN_TIMEOUT_BLOCKS`
+    pub fn cleanup_inactive_chains(
+        &mut self,
+        current_height: u64,
+    ) -> HashChainResult<Vec<ChainId>> {
+        let mut inactive: Vec<ChainId> = self
+            .chain_registry
+            .iter()
+            .filter(|(_, chain)| {
+                current_height.saturating_sub(chain.last_activity_height)
+                    > INACTIVE_CHAIN_TIMEOUT_BLOCKS as u64
+            })
+            .map(|(chain_id, _)| chain_id.clone())
+            .collect();
+        inactive.sort();
+
+        for chain_id in &inactive {
+            self.remove_chain(chain_id.clone(), Some("inactive".to_string()), false)?;
+        }
+        Ok(inactive)
+    }
+
+    /// Inclusion proof for a chain in the global root of the latest checkpoint
+    pub fn generate_inclusion_proof(
+        &self,
+        chain_id: &[u8],
+    ) -> HashChainResult<ChainInclusionProof> {
+        self.last_hierarchical_proof
+            .as_ref()
+            .ok_or_else(|| HashChainError::HierarchicalProofFailed {
+                reason: "No checkpoint has been created yet".to_string(),
+            })?
+            .inclusion_proof(chain_id)
+    }
+
+    /// Compact proof for a chain in the global root of the latest checkpoint, ending at
+    /// the last commitment of `proof_window`
+    pub fn generate_compact_proof(
+        &self,
+        chain_id: &[u8],
+        chain_length: f64,
+        proof_window: Vec<PhysicalAccessCommitment>,
+    ) -> HashChainResult<CompactProof> {
+        let metadata = ProofMetadata {
+            timestamp: crate::core::utils::get_current_timestamp(),
+            total_chains: self.chain_registry.len() as u32,
+            version: 2,
+            proof_type: "compact".to_string(),
+            vdf_metadata: None,
+            availability_challenges: 0,
+        };
+        self.last_hierarchical_proof
+            .as_ref()
+            .ok_or_else(|| HashChainError::HierarchicalProofFailed {
+                reason: "No checkpoint has been created yet".to_string(),
+            })?
+            .compact_proof(chain_id, chain_length, proof_window, metadata)
+    }
+
+    /// Build the global state checkpoint for `block_height` from current chain commitments
+    pub fn create_checkpoint(
+        &mut self,
+        block_hash: &Buffer,
+        block_height: u64,
+        submitter_id: &Buffer,
+    ) -> HashChainResult<EnhancedCheckpoint> {
+        let commitments: HashMap<ChainId, Buffer> = self
+            .chain_registry
+            .iter()
+            .filter_map(|(chain_id, chain)| {
+                chain
+                    .current_commitment
+                    .clone()
+                    .map(|commitment| (chain_id.clone(), commitment))
+            })
+            .collect();
+
+        let proof = HierarchicalGlobalProof::new(self.chains_per_group, GROUPS_PER_REGION)
+            .compute_hierarchical_proof(block_hash, &commitments, &self.previous_global_proof)?;
+        let global_root = proof.global_root_proof.clone();
+
+        let cumulative_work = crate::core::utils::compute_sha256(
+            &[&self.cumulative_work[..], &global_root[..]].concat(),
+        );
+
+        let mut regional_proofs: Vec<(RegionId, Buffer)> =
+            proof.regional_proofs.clone().into_iter().collect();
+        regional_proofs.sort_by(|a, b| a.0.cmp(&b.0));
+        let security_proofs = regional_proofs
+            .into_iter()
+            .map(|(_, proof)| proof)
+            .collect();
+
+        let chain_count = commitments.len() as u32;
+        let checkpoint_hash = crate::core::utils::compute_sha256(
+            &[
+                &block_height.to_be_bytes()[..],
+                &global_root[..],
+                &chain_count.to_be_bytes()[..],
+                &cumulative_work[..],
+                &submitter_id[..],
+            ]
+            .concat(),
+        );
+
+        let bond_info = BondInfo {
+            bond_id: Buffer::from(
+                crate::core::utils::compute_sha256(
+                    &[b"checkpoint_bond", &checkpoint_hash[..]].concat(),
+                )
+                .to_vec(),
+            ),
+            amount: CHECKPOINT_BOND_UNITS as f64,
+            holder_id: submitter_id.clone(),
+            creation_height: block_height as f64,
+            release_height: (block_height + PROOF_WINDOW_BLOCKS as u64) as f64,
+            bond_type: "checkpoint".to_string(),
+        };
+
+        self.previous_global_proof = global_root.clone();
+        self.cumulative_work = Buffer::from(cumulative_work.to_vec());
+        self.last_hierarchical_proof = Some(proof);
+
+        Ok(EnhancedCheckpoint {
+            checkpoint_hash: Buffer::from(checkpoint_hash.to_vec()),
+            block_height: block_height as f64,
+            global_root,
+            chain_count,
+            cumulative_work: self.cumulative_work.clone(),
+            bond_info,
+            security_proofs,
+            submitter_id: submitter_id.clone(),
+        })
+    }
+
     pub fn get_statistics(&self) -> HashMap<String, f64> {
         let mut stats = HashMap::new();
         stats.insert("active_chains".to_string(), self.active_chains as f64);
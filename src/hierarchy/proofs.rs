@@ -1,11 +1,15 @@
 use log::{debug, info};
 use napi::bindgen_prelude::*;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 
 use crate::core::{
     errors::{HashChainError, HashChainResult},
     types::*,
-    utils::{compute_merkle_root, compute_sha256, get_current_timestamp, PerformanceTimer},
+    utils::{
+        compute_merkle_inclusion_path, compute_merkle_root, compute_merkle_root_from_path,
+        compute_sha256, decode_merkle_path, encode_merkle_path, get_current_timestamp,
+        MerklePathNode, PerformanceTimer,
+    },
 };
 
 /// Result of hierarchical proof computation
@@ -17,12 +21,77 @@ pub struct HierarchicalProofResult {
     pub group_proofs: HashMap<GroupId, Buffer>,
     /// Regional proofs by region_id
     pub regional_proofs: HashMap<RegionId, Buffer>,
+    /// Chains and their commitments by group_id
+    pub group_members: HashMap<GroupId, Vec<(ChainId, Buffer)>>,
+    /// Group ids by region_id
+    pub region_members: HashMap<RegionId, Vec<GroupId>>,
+    /// Block hash the proof was computed for
+    pub block_hash: Buffer,
+    /// Global root proof the computation chained from
+    pub previous_global_proof: Buffer,
     /// Performance statistics
     pub stats: HashMap<String, f64>,
     /// When computed
     pub computed_at: f64,
 }
 
+impl HierarchicalProofResult {
+    /// Nested global → regions → groups → chains view of the proof, with every
+    /// level ordered by id
+    pub fn as_tree(&self) -> ProofTree {
+        let zero_proof = || Buffer::from([0u8; 32].to_vec());
+
+        let group_node = |group_id: &GroupId| {
+            let mut chains: Vec<&(ChainId, Buffer)> = self
+                .group_members
+                .get(group_id)
+                .map(|members| members.iter().collect())
+                .unwrap_or_default();
+            chains.sort_by(|a, b| a.0.cmp(&b.0));
+
+            GroupProofNode {
+                group_id: group_id.clone(),
+                proof: self
+                    .group_proofs
+                    .get(group_id)
+                    .cloned()
+                    .unwrap_or_else(zero_proof),
+                chains: chains
+                    .into_iter()
+                    .map(|(chain_id, commitment)| ChainProofNode {
+                        chain_id: Buffer::from(chain_id.clone()),
+                        commitment: commitment.clone(),
+                    })
+                    .collect(),
+            }
+        };
+
+        let mut region_ids: Vec<&RegionId> = self.region_members.keys().collect();
+        region_ids.sort();
+        let regions = region_ids
+            .into_iter()
+            .map(|region_id| {
+                let mut group_ids: Vec<&GroupId> = self.region_members[region_id].iter().collect();
+                group_ids.sort();
+                RegionProofNode {
+                    region_id: region_id.clone(),
+                    proof: self
+                        .regional_proofs
+                        .get(region_id)
+                        .cloned()
+                        .unwrap_or_else(zero_proof),
+                    groups: group_ids.into_iter().map(group_node).collect(),
+                }
+            })
+            .collect();
+
+        ProofTree {
+            global_root_proof: self.global_root_proof.clone(),
+            regions,
+        }
+    }
+}
+
 /// Hierarchical proof computation engine
 pub struct HierarchicalGlobalProof {
     /// Maximum chains per group
@@ -140,10 +209,24 @@ impl HierarchicalGlobalProof {
         // Log comprehensive performance report
         self.log_performance_report(&stats);
 
+        let region_members = group_regions
+            .into_iter()
+            .map(|(region_id, groups)| {
+                (
+                    region_id,
+                    groups.into_iter().map(|(group_id, _)| group_id).collect(),
+                )
+            })
+            .collect();
+
         Ok(HierarchicalProofResult {
             global_root_proof,
             group_proofs,
             regional_proofs,
+            group_members: chain_groups,
+            region_members,
+            block_hash: block_hash.clone(),
+            previous_global_proof: previous_global_proof.clone(),
             stats,
             computed_at: get_current_timestamp(),
         })
@@ -158,7 +241,8 @@ impl HierarchicalGlobalProof {
         let mut current_group_index = 0;
         let mut current_group_size = 0;
 
-        for (chain_id, commitment) in chain_commitments {
+        // Assign in chain id order so every node builds the same groups
+        for (chain_id, commitment) in sorted_by_id(chain_commitments) {
             let group_id = format!("group_{:06}", current_group_index);
 
             let group_chains = groups.entry(group_id).or_default();
@@ -226,23 +310,9 @@ impl HierarchicalGlobalProof {
         // Build merkle tree
         let group_merkle = compute_merkle_root(&commitments);
 
-        // Compute group proof with GROUP_ITERATIONS
-        let mut state = {
-            let mut data = Vec::new();
-            data.extend_from_slice(block_hash);
-            data.extend_from_slice(&group_merkle);
-            data.extend_from_slice(group_id.as_bytes());
-            compute_sha256(&data)
-        };
-
-        for i in 0..GROUP_ITERATIONS {
-            let mut iteration_data = Vec::new();
-            iteration_data.extend_from_slice(&state);
-            iteration_data.extend_from_slice(&i.to_be_bytes());
-            state = compute_sha256(&iteration_data);
-        }
-
-        Ok(Buffer::from(state.to_vec()))
+        Ok(Buffer::from(
+            group_proof_from_merkle(block_hash, &group_merkle, group_id).to_vec(),
+        ))
     }
 
     /// Organize groups into regions
@@ -254,7 +324,8 @@ impl HierarchicalGlobalProof {
         let mut current_region_index = 0;
         let mut current_region_size = 0;
 
-        for (group_id, proof) in group_proofs {
+        // Assign in group id order so every node builds the same regions
+        for (group_id, proof) in sorted_by_id(group_proofs) {
             let region_id = format!("region_{:03}", current_region_index);
 
             let region_groups = regions.entry(region_id).or_default();
@@ -322,23 +393,9 @@ impl HierarchicalGlobalProof {
         // Build merkle tree
         let region_merkle = compute_merkle_root(&group_proofs);
 
-        // Compute regional proof with REGIONAL_ITERATIONS
-        let mut state = {
-            let mut data = Vec::new();
-            data.extend_from_slice(block_hash);
-            data.extend_from_slice(&region_merkle);
-            data.extend_from_slice(region_id.as_bytes());
-            compute_sha256(&data)
-        };
-
-        for i in 0..REGIONAL_ITERATIONS {
-            let mut iteration_data = Vec::new();
-            iteration_data.extend_from_slice(&state);
-            iteration_data.extend_from_slice(&i.to_be_bytes());
-            state = compute_sha256(&iteration_data);
-        }
-
-        Ok(Buffer::from(state.to_vec()))
+        Ok(Buffer::from(
+            regional_proof_from_merkle(block_hash, &region_merkle, region_id).to_vec(),
+        ))
     }
 
     /// Compute final global root proof (Level 3)
@@ -352,33 +409,25 @@ impl HierarchicalGlobalProof {
             return Ok(Buffer::from([0u8; 32].to_vec()));
         }
 
-        // Collect all regional proofs
-        let all_regional_proofs: Vec<&[u8]> = regional_proofs
-            .values()
-            .map(|proof| proof.as_ref())
+        // Collect all regional proofs, ordered by region id so inclusion paths
+        // can be reproduced
+        let all_regional_proofs: Vec<&[u8]> = sorted_by_id(regional_proofs)
+            .into_iter()
+            .map(|(_, proof)| proof.as_ref())
             .collect();
 
         // Build merkle tree of regional proofs
         let global_merkle = compute_merkle_root(&all_regional_proofs);
 
-        // Compute global root proof with GLOBAL_ROOT_ITERATIONS
-        let mut state = {
-            let mut data = Vec::new();
-            data.extend_from_slice(block_hash);
-            data.extend_from_slice(&global_merkle);
-            data.extend_from_slice(previous_global_proof);
-            data.extend_from_slice(&(regional_proofs.len() as u32).to_be_bytes());
-            compute_sha256(&data)
-        };
-
-        for i in 0..GLOBAL_ROOT_ITERATIONS {
-            let mut iteration_data = Vec::new();
-            iteration_data.extend_from_slice(&state);
-            iteration_data.extend_from_slice(&i.to_be_bytes());
-            state = compute_sha256(&iteration_data);
-        }
-
-        Ok(Buffer::from(state.to_vec()))
+        Ok(Buffer::from(
+            global_root_from_merkle(
+                block_hash,
+                &global_merkle,
+                previous_global_proof,
+                regional_proofs.len() as u32,
+            )
+            .to_vec(),
+        ))
     }
 
     /// Log comprehensive performance report
@@ -419,6 +468,373 @@ impl HierarchicalGlobalProof {
     }
 }
 
+/// Entries of an id-keyed map ordered by id
+fn sorted_by_id<K: Ord, V>(map: &HashMap<K, V>) -> Vec<(&K, &V)> {
+    let mut entries: Vec<(&K, &V)> = map.iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    entries
+}
+
+/// Apply `iterations` rounds of SHA256 chaining to `state`
+pub fn iterate_sha256(mut state: [u8; 32], iterations: u32) -> [u8; 32] {
+    for i in 0..iterations {
+        let mut iteration_data = Vec::new();
+        iteration_data.extend_from_slice(&state);
+        iteration_data.extend_from_slice(&i.to_be_bytes());
+        state = compute_sha256(&iteration_data);
+    }
+    state
+}
+
+/// Group proof (Level 1) from the Merkle root of the group's chain commitments
+fn group_proof_from_merkle(block_hash: &[u8], group_merkle: &[u8], group_id: &str) -> [u8; 32] {
+    let seed = compute_sha256(&[block_hash, group_merkle, group_id.as_bytes()].concat());
+    iterate_sha256(seed, GROUP_ITERATIONS)
+}
+
+/// Regional proof (Level 2) from the Merkle root of the region's group proofs
+fn regional_proof_from_merkle(
+    block_hash: &[u8],
+    region_merkle: &[u8],
+    region_id: &str,
+) -> [u8; 32] {
+    let seed = compute_sha256(&[block_hash, region_merkle, region_id.as_bytes()].concat());
+    iterate_sha256(seed, REGIONAL_ITERATIONS)
+}
+
+/// Global root proof (Level 3) from the Merkle root of all regional proofs
+fn global_root_from_merkle(
+    block_hash: &[u8],
+    global_merkle: &[u8],
+    previous_global_proof: &[u8],
+    region_count: u32,
+) -> [u8; 32] {
+    let seed = compute_sha256(
+        &[
+            block_hash,
+            global_merkle,
+            previous_global_proof,
+            &region_count.to_be_bytes(),
+        ]
+        .concat(),
+    );
+    iterate_sha256(seed, GLOBAL_ROOT_ITERATIONS)
+}
+
+/// Merkle paths proving one chain's commitment is part of a global root:
+/// commitment → group proof → regional proof → global root
+#[derive(Clone, Debug)]
+pub struct ChainInclusionProof {
+    pub chain_id: ChainId,
+    pub commitment: [u8; 32],
+    pub block_hash: Vec<u8>,
+    pub previous_global_proof: Vec<u8>,
+    pub group_id: GroupId,
+    /// Path from the commitment to the group's commitment Merkle root
+    pub group_path: Vec<MerklePathNode>,
+    pub region_id: RegionId,
+    /// Path from the group proof to the region's Merkle root
+    pub region_path: Vec<MerklePathNode>,
+    /// Path from the regional proof to the global Merkle root
+    pub global_path: Vec<MerklePathNode>,
+    /// Number of regions in the global root
+    pub region_count: u32,
+}
+
+/// Convert proof buffers to Merkle leaves (first 32 bytes, as `compute_merkle_root` does)
+fn merkle_leaves<'a>(hashes: impl Iterator<Item = &'a Buffer>) -> HashChainResult<Vec<[u8; 32]>> {
+    hashes
+        .map(|hash| {
+            hash.get(..32)
+                .and_then(|leaf| leaf.try_into().ok())
+                .ok_or_else(|| HashChainError::MerkleTree(format!("Leaf of {} bytes", hash.len())))
+        })
+        .collect()
+}
+
+impl HierarchicalProofResult {
+    /// Build the inclusion proof for one chain
+    pub fn inclusion_proof(&self, chain_id: &[u8]) -> HashChainResult<ChainInclusionProof> {
+        let not_found = || HashChainError::ChainNotFound {
+            chain_id: hex::encode(chain_id),
+        };
+
+        // Level 1: chain commitment within its group
+        let (group_id, members) = self
+            .group_members
+            .iter()
+            .find(|(_, members)| members.iter().any(|(id, _)| id.as_slice() == chain_id))
+            .ok_or_else(not_found)?;
+        let chain_index = members
+            .iter()
+            .position(|(id, _)| id.as_slice() == chain_id)
+            .ok_or_else(not_found)?;
+        let group_leaves = merkle_leaves(members.iter().map(|(_, commitment)| commitment))?;
+        let group_path =
+            compute_merkle_inclusion_path(&group_leaves, chain_index).ok_or_else(not_found)?;
+
+        // Level 2: group proof within its region
+        let missing = |what: String| HashChainError::HierarchicalProofFailed {
+            reason: format!("Missing {}", what),
+        };
+        let (region_id, group_ids) = self
+            .region_members
+            .iter()
+            .find(|(_, group_ids)| group_ids.contains(group_id))
+            .ok_or_else(|| missing(format!("region for {}", group_id)))?;
+        let group_index = group_ids.iter().position(|id| id == group_id).unwrap();
+        let region_leaves = merkle_leaves(
+            group_ids
+                .iter()
+                .map(|id| {
+                    self.group_proofs
+                        .get(id)
+                        .ok_or_else(|| missing(format!("proof for {}", id)))
+                })
+                .collect::<HashChainResult<Vec<_>>>()?
+                .into_iter(),
+        )?;
+        let region_path = compute_merkle_inclusion_path(&region_leaves, group_index)
+            .ok_or_else(|| missing(format!("path for {}", group_id)))?;
+
+        // Level 3: regional proof within the global root
+        let regions = sorted_by_id(&self.regional_proofs);
+        let region_index = regions
+            .iter()
+            .position(|(id, _)| *id == region_id)
+            .ok_or_else(|| missing(format!("proof for {}", region_id)))?;
+        let global_leaves = merkle_leaves(regions.iter().map(|(_, proof)| *proof))?;
+        let global_path = compute_merkle_inclusion_path(&global_leaves, region_index)
+            .ok_or_else(|| missing(format!("path for {}", region_id)))?;
+
+        Ok(ChainInclusionProof {
+            chain_id: chain_id.to_vec(),
+            commitment: group_leaves[chain_index],
+            block_hash: self.block_hash.to_vec(),
+            previous_global_proof: self.previous_global_proof.to_vec(),
+            group_id: group_id.clone(),
+            group_path,
+            region_id: region_id.clone(),
+            region_path,
+            global_path,
+            region_count: regions.len() as u32,
+        })
+    }
+
+    /// Compact proof for one chain: `proof_window` with every hierarchy field filled
+    /// from the chain's inclusion proof, so `verify_compact_proof_hierarchy` can walk
+    /// the window's last commitment up to this result's global root
+    pub fn compact_proof(
+        &self,
+        chain_id: &[u8],
+        chain_length: f64,
+        proof_window: Vec<PhysicalAccessCommitment>,
+        metadata: ProofMetadata,
+    ) -> HashChainResult<CompactProof> {
+        let inclusion = self.inclusion_proof(chain_id)?;
+        let ends_at_commitment = proof_window.last().is_some_and(|latest| {
+            latest.commitment_hash.as_ref() == inclusion.commitment.as_slice()
+                && latest.block_hash.as_ref() == self.block_hash.as_ref()
+        });
+        if !ends_at_commitment {
+            return Err(HashChainError::CompactProof {
+                reason: "Proof window does not end at the chain's commitment in this proof"
+                    .to_string(),
+            });
+        }
+
+        Ok(CompactProof {
+            chain_hash: Buffer::from(chain_id.to_vec()),
+            chain_length,
+            proof_window,
+            group_proof: self.group_proofs[&inclusion.group_id].clone(),
+            regional_proof: self.regional_proofs[&inclusion.region_id].clone(),
+            global_proof_reference: self.global_root_proof.clone(),
+            merkle_path: Buffer::from(encode_merkle_path(&inclusion.group_path)),
+            group_id: inclusion.group_id,
+            region_path: Buffer::from(encode_merkle_path(&inclusion.region_path)),
+            region_id: inclusion.region_id,
+            global_path: Buffer::from(encode_merkle_path(&inclusion.global_path)),
+            previous_global_proof: self.previous_global_proof.clone(),
+            region_count: inclusion.region_count,
+            metadata,
+        })
+    }
+}
+
+/// Verify a chain inclusion proof by recomputing every level up to `global_root`
+pub fn verify_inclusion_proof(proof: &ChainInclusionProof, global_root: &[u8]) -> bool {
+    let group_merkle = compute_merkle_root_from_path(proof.commitment, &proof.group_path);
+    let group_proof = group_proof_from_merkle(&proof.block_hash, &group_merkle, &proof.group_id);
+
+    let region_merkle = compute_merkle_root_from_path(group_proof, &proof.region_path);
+    let regional_proof =
+        regional_proof_from_merkle(&proof.block_hash, &region_merkle, &proof.region_id);
+
+    let global_merkle = compute_merkle_root_from_path(regional_proof, &proof.global_path);
+    let computed_root = global_root_from_merkle(
+        &proof.block_hash,
+        &global_merkle,
+        &proof.previous_global_proof,
+        proof.region_count,
+    );
+
+    computed_root.as_slice() == global_root
+}
+
+/// Verify a compact proof's hierarchy fields connect its chain to `expected_global`:
+/// `merkle_path` leads from the proof window's last commitment to `group_proof`,
+/// `region_path` from the group proof to `regional_proof`, and `global_path` from
+/// the regional proof to the global root, each level recomputed at the last
+/// commitment's block hash
+pub fn verify_compact_proof_hierarchy(proof: &CompactProof, expected_global: &[u8]) -> bool {
+    let Some(latest) = proof.proof_window.last() else {
+        return false;
+    };
+    let Ok(commitment) = <[u8; 32]>::try_from(latest.commitment_hash.as_ref()) else {
+        return false;
+    };
+    let (Some(group_path), Some(region_path), Some(global_path)) = (
+        decode_merkle_path(&proof.merkle_path),
+        decode_merkle_path(&proof.region_path),
+        decode_merkle_path(&proof.global_path),
+    ) else {
+        return false;
+    };
+    if proof.global_proof_reference.as_ref() != expected_global {
+        return false;
+    }
+
+    let group_merkle = compute_merkle_root_from_path(commitment, &group_path);
+    let group_proof = group_proof_from_merkle(&latest.block_hash, &group_merkle, &proof.group_id);
+    if group_proof.as_slice() != proof.group_proof.as_ref() {
+        debug!("Compact proof path does not lead to its group proof");
+        return false;
+    }
+
+    let region_merkle = compute_merkle_root_from_path(group_proof, &region_path);
+    let regional_proof =
+        regional_proof_from_merkle(&latest.block_hash, &region_merkle, &proof.region_id);
+    if regional_proof.as_slice() != proof.regional_proof.as_ref() {
+        debug!("Compact proof group does not lead to its regional proof");
+        return false;
+    }
+
+    let global_merkle = compute_merkle_root_from_path(regional_proof, &global_path);
+    let global_root = global_root_from_merkle(
+        &latest.block_hash,
+        &global_merkle,
+        &proof.previous_global_proof,
+        proof.region_count,
+    );
+    global_root.as_slice() == expected_global
+}
+
+/// Where two hierarchical proofs for the same block disagree. Every list is sorted,
+/// so two nodes comparing the same pair of results report the same divergence.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RootComparison {
+    /// Whether the global roots are equal
+    pub roots_match: bool,
+    /// Whether both were computed from the same block hash and previous global proof;
+    /// if not, every level differs and the lists below say nothing about the chains
+    pub same_inputs: bool,
+    /// Regions whose regional proofs differ or that exist in only one result
+    pub divergent_regions: Vec<RegionId>,
+    /// Groups, with their region, whose proofs differ or that exist in only one result
+    pub divergent_groups: Vec<(RegionId, GroupId)>,
+    /// Chains whose commitment or group placement differs, or that exist in only one
+    /// result
+    pub divergent_chains: Vec<ChainId>,
+}
+
+impl RootComparison {
+    /// Divergent groups as `region/group` paths for logs
+    pub fn divergent_paths(&self) -> Vec<String> {
+        self.divergent_groups
+            .iter()
+            .map(|(region_id, group_id)| format!("{}/{}", region_id, group_id))
+            .collect()
+    }
+}
+
+/// Compare two hierarchical proofs computed for the same block, pinpointing the
+/// regions, groups and chains where they diverge
+pub fn compare_global_roots(
+    a: &HierarchicalProofResult,
+    b: &HierarchicalProofResult,
+) -> RootComparison {
+    // Region of each group, taken from either result
+    let group_regions: HashMap<&GroupId, &RegionId> = b
+        .region_members
+        .iter()
+        .chain(&a.region_members)
+        .flat_map(|(region_id, group_ids)| group_ids.iter().map(move |id| (id, region_id)))
+        .collect();
+
+    let divergent_regions = differing_ids(
+        &proof_bytes(&a.regional_proofs),
+        &proof_bytes(&b.regional_proofs),
+    );
+
+    let mut divergent_groups: Vec<(RegionId, GroupId)> =
+        differing_ids(&proof_bytes(&a.group_proofs), &proof_bytes(&b.group_proofs))
+            .into_iter()
+            .map(|group_id| {
+                let region_id = group_regions
+                    .get(&group_id)
+                    .map(|region_id| (*region_id).clone())
+                    .unwrap_or_default();
+                (region_id, group_id)
+            })
+            .collect();
+    divergent_groups.sort();
+
+    // Chain placements and commitments, keyed by chain id
+    let placements = |result: &HierarchicalProofResult| {
+        result
+            .group_members
+            .iter()
+            .flat_map(|(group_id, members)| {
+                members.iter().map(move |(chain_id, commitment)| {
+                    (chain_id.clone(), (group_id.clone(), commitment.to_vec()))
+                })
+            })
+            .collect::<HashMap<ChainId, (GroupId, Vec<u8>)>>()
+    };
+
+    RootComparison {
+        roots_match: a.global_root_proof.as_ref() == b.global_root_proof.as_ref(),
+        same_inputs: a.block_hash.as_ref() == b.block_hash.as_ref()
+            && a.previous_global_proof.as_ref() == b.previous_global_proof.as_ref(),
+        divergent_regions,
+        divergent_groups,
+        divergent_chains: differing_ids(&placements(a), &placements(b)),
+    }
+}
+
+/// Proof bytes by id, for comparing proofs across results
+fn proof_bytes<K: Clone + Eq + std::hash::Hash>(proofs: &HashMap<K, Buffer>) -> HashMap<K, &[u8]> {
+    proofs
+        .iter()
+        .map(|(id, proof)| (id.clone(), proof.as_ref()))
+        .collect()
+}
+
+/// Ids, sorted, whose values differ between the maps or that only one map has
+fn differing_ids<K: Ord + Clone + std::hash::Hash, V: PartialEq>(
+    a: &HashMap<K, V>,
+    b: &HashMap<K, V>,
+) -> Vec<K> {
+    a.keys()
+        .chain(b.keys())
+        .filter(|id| a.get(*id) != b.get(*id))
+        .cloned()
+        .collect::<BTreeSet<K>>()
+        .into_iter()
+        .collect()
+}
+
 impl Default for HierarchicalGlobalProof {
     fn default() -> Self {
         Self::new(CHAINS_PER_GROUP, GROUPS_PER_REGION)
@@ -461,6 +877,199 @@ mod tests {
         assert_eq!(groups.get("group_000002").unwrap().len(), 1);
     }
 
+    #[test]
+    fn test_proof_tree_structure() {
+        let proof_engine = HierarchicalGlobalProof::new(2, 2);
+        let block_hash = Buffer::from([1u8; 32].to_vec());
+        let mut chain_commitments = HashMap::new();
+        for i in 0..5 {
+            chain_commitments.insert(vec![i as u8; 32], Buffer::from(vec![i as u8 + 100; 32]));
+        }
+
+        let result = proof_engine
+            .compute_hierarchical_proof(
+                &block_hash,
+                &chain_commitments,
+                &Buffer::from([0u8; 32].to_vec()),
+            )
+            .unwrap();
+        let tree = result.as_tree();
+
+        // 5 chains → 3 groups → 2 regions
+        assert_eq!(
+            tree.global_root_proof.as_ref(),
+            result.global_root_proof.as_ref()
+        );
+        assert_eq!(tree.regions.len(), 2);
+        assert!(tree.regions[0].region_id < tree.regions[1].region_id);
+
+        let mut chain_count = 0;
+        for region in &tree.regions {
+            assert_eq!(
+                region.proof.as_ref(),
+                result.regional_proofs[&region.region_id].as_ref()
+            );
+            for group in &region.groups {
+                assert_eq!(
+                    group.proof.as_ref(),
+                    result.group_proofs[&group.group_id].as_ref()
+                );
+                for chain in &group.chains {
+                    assert_eq!(
+                        chain.commitment.as_ref(),
+                        chain_commitments[&chain.chain_id.to_vec()].as_ref()
+                    );
+                    chain_count += 1;
+                }
+            }
+        }
+        assert_eq!(chain_count, 5);
+    }
+
+    #[test]
+    fn test_chain_inclusion_proof() {
+        // Odd group and region sizes exercise promoted Merkle nodes
+        let proof_engine = HierarchicalGlobalProof::new(3, 2);
+        let block_hash = Buffer::from([1u8; 32].to_vec());
+        let mut chain_commitments = HashMap::new();
+        for i in 0..7 {
+            chain_commitments.insert(vec![i as u8; 32], Buffer::from(vec![i as u8 + 100; 32]));
+        }
+
+        let result = proof_engine
+            .compute_hierarchical_proof(
+                &block_hash,
+                &chain_commitments,
+                &Buffer::from([9u8; 32].to_vec()),
+            )
+            .unwrap();
+
+        for chain_id in chain_commitments.keys() {
+            let proof = result.inclusion_proof(chain_id).unwrap();
+            assert!(verify_inclusion_proof(&proof, &result.global_root_proof));
+        }
+
+        // Tampered commitments and foreign roots fail, unknown chains have no proof
+        let mut proof = result.inclusion_proof(&[3u8; 32]).unwrap();
+        assert!(!verify_inclusion_proof(&proof, &[0u8; 32]));
+        proof.commitment = [0u8; 32];
+        assert!(!verify_inclusion_proof(&proof, &result.global_root_proof));
+        assert!(result.inclusion_proof(&[42u8; 32]).is_err());
+    }
+
+    #[test]
+    fn test_compare_global_roots() {
+        let proof_engine = HierarchicalGlobalProof::new(3, 2);
+        let block_hash = Buffer::from([1u8; 32].to_vec());
+        let previous = Buffer::from([9u8; 32].to_vec());
+        let mut chain_commitments = HashMap::new();
+        for i in 0..7 {
+            chain_commitments.insert(vec![i as u8; 32], Buffer::from(vec![i as u8 + 100; 32]));
+        }
+        let compute = |commitments: &HashMap<ChainId, Buffer>| {
+            proof_engine
+                .compute_hierarchical_proof(&block_hash, commitments, &previous)
+                .unwrap()
+        };
+        let honest = compute(&chain_commitments);
+
+        let comparison = compare_global_roots(&honest, &compute(&chain_commitments));
+        assert!(comparison.roots_match && comparison.same_inputs);
+        assert!(comparison.divergent_regions.is_empty());
+        assert!(comparison.divergent_groups.is_empty());
+        assert!(comparison.divergent_chains.is_empty());
+
+        // One chain's commitment differs: its group and region are pinpointed
+        let mut forked_commitments = chain_commitments.clone();
+        forked_commitments.insert(vec![4u8; 32], Buffer::from(vec![0u8; 32]));
+        let forked = compute(&forked_commitments);
+        let inclusion = honest.inclusion_proof(&[4u8; 32]).unwrap();
+
+        let comparison = compare_global_roots(&honest, &forked);
+        assert!(!comparison.roots_match && comparison.same_inputs);
+        assert_eq!(
+            comparison.divergent_regions,
+            vec![inclusion.region_id.clone()]
+        );
+        assert_eq!(
+            comparison.divergent_paths(),
+            vec![format!("{}/{}", inclusion.region_id, inclusion.group_id)]
+        );
+        assert_eq!(comparison.divergent_chains, vec![vec![4u8; 32]]);
+        assert_eq!(compare_global_roots(&forked, &honest), comparison);
+    }
+
+    #[test]
+    fn test_compact_proof_hierarchy() {
+        let proof_engine = HierarchicalGlobalProof::new(3, 2);
+        let block_hash = Buffer::from([1u8; 32].to_vec());
+        let mut chain_commitments = HashMap::new();
+        for i in 0..7 {
+            chain_commitments.insert(vec![i as u8; 32], Buffer::from(vec![i as u8 + 100; 32]));
+        }
+        let result = proof_engine
+            .compute_hierarchical_proof(
+                &block_hash,
+                &chain_commitments,
+                &Buffer::from([9u8; 32].to_vec()),
+            )
+            .unwrap();
+        let inclusion = result.inclusion_proof(&[4u8; 32]).unwrap();
+
+        let latest = PhysicalAccessCommitment {
+            block_height: 10.0,
+            previous_commitment: Buffer::from([0u8; 32].to_vec()),
+            block_hash: block_hash.clone(),
+            selected_chunks: Vec::new(),
+            chunk_hashes: Vec::new(),
+            commitment_hash: Buffer::from(inclusion.commitment.to_vec()),
+        };
+        let metadata = ProofMetadata {
+            timestamp: 0.0,
+            total_chains: 7,
+            version: 2,
+            proof_type: "compact".to_string(),
+            vdf_metadata: None,
+            availability_challenges: 0,
+        };
+        let mut proof = result
+            .compact_proof(&[4u8; 32], 10.0, vec![latest.clone()], metadata.clone())
+            .unwrap();
+        assert_eq!(proof.group_id, inclusion.group_id);
+        assert_eq!(proof.region_id, inclusion.region_id);
+        assert_eq!(proof.region_count, inclusion.region_count);
+        assert_eq!(proof.previous_global_proof.as_ref(), &[9u8; 32]);
+        assert!(verify_compact_proof_hierarchy(
+            &proof,
+            &result.global_root_proof
+        ));
+        assert!(!verify_compact_proof_hierarchy(&proof, &[0u8; 32]));
+
+        // A group proof the path does not lead to is rejected, as is a malformed path
+        let group_proof = proof.group_proof.clone();
+        proof.group_proof = Buffer::from([0u8; 32].to_vec());
+        assert!(!verify_compact_proof_hierarchy(
+            &proof,
+            &result.global_root_proof
+        ));
+        proof.group_proof = group_proof;
+        let mut malformed = proof.merkle_path.to_vec();
+        malformed.pop();
+        proof.merkle_path = Buffer::from(malformed);
+        assert!(!verify_compact_proof_hierarchy(
+            &proof,
+            &result.global_root_proof
+        ));
+
+        // The builder refuses a window that does not end at the chain's commitment
+        assert!(result
+            .compact_proof(&[3u8; 32], 10.0, vec![latest], metadata.clone())
+            .is_err());
+        assert!(result
+            .compact_proof(&[4u8; 32], 10.0, Vec::new(), metadata)
+            .is_err());
+    }
+
     #[test]
     fn test_compute_group_proof() {
         let proof_engine = HierarchicalGlobalProof::new(1000, 10);
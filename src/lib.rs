@@ -1,5 +1,9 @@
 use log::info;
 use napi::bindgen_prelude::*;
+use napi::threadsafe_function::{
+    ErrorStrategy, ThreadSafeCallContext, ThreadsafeFunction, ThreadsafeFunctionCallMode,
+};
+use napi::{JsBuffer, JsUnknown, Ref};
 use napi_derive::napi;
 
 // Core modules
@@ -16,8 +20,14 @@ pub use hierarchy::*;
 
 // NAPI bindings for the new prover/verifier interface
 use crate::chain::hashchain::IndividualHashChain;
-use crate::core::utils::{compute_blake3, sign_block, validate_block_hash, validate_public_key};
-use crate::core::vdf_processor::VDFProcessor;
+use crate::chain::index::{open_prover_chains, ChainIndex, ChainIndexEntry};
+use crate::core::blockchain_adapter::BlockchainAdapter;
+use crate::core::prover_state::{AvailabilityRecord, ChainStateRecord, ProverStateSnapshot};
+use crate::core::utils::{
+    block_hash_to_array, compute_blake3, sign_block, validate_block_hash, validate_hash_buffer,
+    validate_public_key,
+};
+use crate::core::vdf_processor::{VDFProcessor, VDFStateSnapshot};
 
 // ====================================================================
 // PROVER CALLBACK INTERFACES
@@ -108,7 +118,7 @@ pub struct AvailabilityChallengeCallbacks {
 pub struct BlockchainDataCallbacks {
     /// Validate data chunk count against blockchain
     pub validate_chunk_count: JsFunction,
-    /// Get registered data file metadata
+    /// Get registered data file metadata: (dataHashHex) => { totalChunks }
     pub get_data_file_metadata: JsFunction,
     /// Verify data file registration
     pub verify_data_registration: JsFunction,
@@ -185,6 +195,13 @@ pub struct VerifierEconomicCallbacks {
     pub penalize_failure: JsFunction,
 }
 
+/// Randomness beacon operations for verifiers
+#[napi(object)]
+pub struct VerifierBeaconCallbacks {
+    /// Verify beacon randomness for a round: (randomness, round) => boolean
+    pub verify_randomness: JsFunction,
+}
+
 /// Combined verifier callbacks
 #[napi(object)]
 pub struct VerifierCallbacks {
@@ -202,6 +219,8 @@ pub struct VerifierCallbacks {
     pub availability_challenge: AvailabilityChallengeCallbacks,
     /// Blockchain data validation
     pub blockchain_data: BlockchainDataCallbacks,
+    /// Randomness beacon verification
+    pub beacon: Option<VerifierBeaconCallbacks>,
 }
 
 // ====================================================================
@@ -213,54 +232,277 @@ pub struct VerifierCallbacks {
 #[napi]
 pub struct ProofOfStorageProver {
     prover_key: Buffer,
-    prover_private_key: Buffer,
+    // Produces the prover's signatures; the private key may live outside the process
+    signer: std::sync::Arc<dyn crate::core::signer::Signer>,
     callbacks: ProverCallbacks,
-    active_chains: std::collections::HashMap<String, IndividualHashChain>,
+    // Ordered by chain_id so iteration and tie-breaking are deterministic
+    active_chains: std::collections::BTreeMap<String, IndividualHashChain>,
     availability_prover: crate::core::availability::AvailabilityProver,
     vdf_processor: VDFProcessor,
     total_blocks_processed: u32,
     last_processing_time_ms: f64,
+    max_chains: u32,
+    // Block height and VDF iterations of the latest accepted commitment
+    last_commitment_vdf: Option<(u32, u64)>,
+    // Chains that failed an integrity check; no commitments until the flag is cleared
+    corrupt_chains: std::collections::BTreeSet<String>,
+    // Verifier keys whose signed storage challenges are answered
+    authorized_verifiers: std::collections::BTreeSet<Vec<u8>>,
+    // Whether unsigned or unauthorized storage challenges are rejected
+    require_signed_challenges: bool,
+    // Start of the current maintenance window, if challenges are paused
+    maintenance_started_at: Option<f64>,
+    // Seconds spent in maintenance windows that have already ended
+    completed_maintenance_seconds: f64,
+    // Consensus values commitments are generated with, shared with the network's verifiers
+    network_params: crate::consensus::NetworkParameters,
+}
+
+/// Select the chain to prove: the one with the most blocks. Equal lengths are broken
+/// by the lowest chain id so the same state always proves the same chain.
+fn select_primary_chain(
+    chains: &mut std::collections::BTreeMap<String, IndividualHashChain>,
+) -> Option<(&String, &mut IndividualHashChain)> {
+    chains.iter_mut().max_by(|(a_id, a), (b_id, b)| {
+        a.chain_length
+            .cmp(&b.chain_length)
+            .then_with(|| b_id.cmp(a_id))
+    })
+}
+
+/// Portfolio entries for every chain at its current commitment. Chains that have not
+/// committed yet are attested at an all-zero commitment hash.
+fn portfolio_entries(
+    chains: &std::collections::BTreeMap<String, IndividualHashChain>,
+) -> Vec<crate::core::portfolio::PortfolioEntry> {
+    chains
+        .values()
+        .map(|chain| crate::core::portfolio::PortfolioEntry {
+            chain_id: chain.chain_id.clone(),
+            commitment_hash: chain
+                .current_commitment
+                .as_ref()
+                .and_then(|commitment| <[u8; 32]>::try_from(commitment.as_ref()).ok())
+                .unwrap_or([0u8; 32]),
+        })
+        .collect()
+}
+
+/// Read a NetworkNode returned by a JS callback
+fn network_node_from_js(node: &Object) -> Result<NetworkNode> {
+    Ok(NetworkNode {
+        node_key: node.get_named_property::<Buffer>("nodeKey")?,
+        node_type: node.get_named_property::<String>("nodeType")?,
+        reputation: node.get_named_property::<f64>("reputation")?,
+        last_activity: node.get_named_property::<f64>("lastActivity")?,
+        position: node.get_named_property::<Buffer>("position")?,
+    })
+}
+
+/// Refuse to prove over a chain whose data failed an integrity check
+fn check_integrity_flag(
+    corrupt_chains: &std::collections::BTreeSet<String>,
+    chain_id: &str,
+) -> Result<()> {
+    if corrupt_chains.contains(chain_id) {
+        return Err(coded_error(
+            Status::GenericFailure,
+            error_codes::E_INTEGRITY_FLAGGED,
+            format!(
+                "Chain {} failed an integrity check; repair it and call clearIntegrityFlag before proving",
+                chain_id
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Check `max_chains` is a valid chain limit for a prover with `active_chains` chains:
+/// between 1 and `MAX_CHAINS_PER_INSTANCE`, and not below the chains already active
+fn validate_max_chains(max_chains: u32, active_chains: usize) -> Result<()> {
+    if max_chains == 0 || max_chains > MAX_CHAINS_PER_INSTANCE {
+        return Err(coded_error(
+            Status::InvalidArg,
+            error_codes::E_CAPACITY,
+            format!(
+                "Max chains must be between 1 and {}, got {}",
+                MAX_CHAINS_PER_INSTANCE, max_chains
+            ),
+        ));
+    }
+
+    if (max_chains as usize) < active_chains {
+        return Err(coded_error(
+            Status::InvalidArg,
+            error_codes::E_CAPACITY,
+            format!(
+                "Max chains {} is below current active chain count {}",
+                max_chains, active_chains
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Network parameters for a prover or verifier: the compiled-in values with any
+/// deployment overrides applied
+fn network_parameters_from(
+    consensus_params: Option<ConsensusParams>,
+) -> Result<crate::consensus::NetworkParameters> {
+    let parameters = crate::consensus::NetworkParameters::current();
+    match consensus_params {
+        Some(params) => parameters
+            .with_consensus_params(&params)
+            .map_err(|e| coded_error(Status::InvalidArg, error_codes::E_CONSENSUS, e)),
+        None => Ok(parameters),
+    }
+}
+
+/// Signer calling a JavaScript `(data: Buffer) => Buffer` callback, e.g. one fronting
+/// a hardware security module. On the JavaScript thread the callback is called
+/// directly; the VDF thread calls it through a threadsafe function and waits up to
+/// `JS_SIGNER_TIMEOUT_SECONDS` for the signature.
+struct JsCallbackSigner {
+    js_thread: std::thread::ThreadId,
+    env: napi::sys::napi_env,
+    callback: std::mem::ManuallyDrop<Ref<()>>,
+    threadsafe: ThreadsafeFunction<Vec<u8>, ErrorStrategy::Fatal>,
+}
+
+// SAFETY: `env` and `callback` are only used on `js_thread`; other threads sign
+// through the threadsafe function
+unsafe impl Send for JsCallbackSigner {}
+unsafe impl Sync for JsCallbackSigner {}
+
+impl JsCallbackSigner {
+    fn new(env: &Env, callback: JsFunction) -> Result<Self> {
+        let mut threadsafe = callback
+            .create_threadsafe_function(0, |ctx: ThreadSafeCallContext<Vec<u8>>| {
+                Ok(vec![Buffer::from(ctx.value)])
+            })?;
+        // A pending background signature must not keep the process alive
+        threadsafe.unref(env)?;
+        Ok(Self {
+            js_thread: std::thread::current().id(),
+            env: env.raw(),
+            callback: std::mem::ManuallyDrop::new(env.create_reference(callback)?),
+            threadsafe,
+        })
+    }
+
+    fn sign_on_js_thread(&self, data: &[u8]) -> Result<Vec<u8>> {
+        let env = unsafe { Env::from_raw(self.env) };
+        let callback: JsFunction = env.get_reference_value(&self.callback)?;
+        let data = env.create_buffer_with_data(data.to_vec())?.into_raw();
+        signature_from_js(callback.call(None, &[data])?)
+    }
+
+    fn sign_from_background(&self, data: &[u8]) -> Result<Vec<u8>> {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let status = self.threadsafe.call_with_return_value(
+            data.to_vec(),
+            ThreadsafeFunctionCallMode::NonBlocking,
+            move |signature: JsUnknown| {
+                let _ = sender.send(signature_from_js(signature));
+                Ok(())
+            },
+        );
+        if status != Status::Ok {
+            return Err(Error::new(
+                status,
+                "Signing callback is unavailable".to_string(),
+            ));
+        }
+        receiver
+            .recv_timeout(std::time::Duration::from_secs(JS_SIGNER_TIMEOUT_SECONDS))
+            .unwrap_or_else(|_| Err(Error::from_reason("Signing callback timed out")))
+    }
+}
+
+/// Signature bytes returned by a JavaScript signing callback
+fn signature_from_js(signature: JsUnknown) -> Result<Vec<u8>> {
+    if !signature.is_buffer()? {
+        return Err(Error::from_reason("Signing callback must return a Buffer"));
+    }
+    let signature = unsafe { signature.cast::<JsBuffer>() }.into_value()?;
+    Ok(signature.to_vec())
+}
+
+impl crate::core::signer::Signer for JsCallbackSigner {
+    fn sign(&self, data: &[u8]) -> HashChainResult<Vec<u8>> {
+        let signature = if std::thread::current().id() == self.js_thread {
+            self.sign_on_js_thread(data)
+        } else {
+            self.sign_from_background(data)
+        };
+        signature.map_err(|e| {
+            HashChainError::CryptographicError(format!("Signing callback failed: {}", e))
+        })
+    }
+}
+
+impl Drop for JsCallbackSigner {
+    fn drop(&mut self) {
+        // The callback reference can only be released on the JavaScript thread
+        if std::thread::current().id() == self.js_thread {
+            let _ = self.callback.unref(unsafe { Env::from_raw(self.env) });
+        }
+    }
 }
 
 #[napi]
 impl ProofOfStorageProver {
-    /// Create new prover instance
+    /// Create new prover instance. `consensusParams` overrides the compiled-in chunks
+    /// per block and minimum VDF iterations; verifiers must be built with the same values.
     #[napi(constructor)]
     pub fn new(
         prover_key: Buffer,
         prover_private_key: Buffer,
         callbacks: ProverCallbacks,
+        consensus_params: Option<ConsensusParams>,
     ) -> Result<Self> {
         validate_public_key(&prover_key)?;
+        let network_params = network_parameters_from(consensus_params)?;
 
         if prover_private_key.len() != 32 {
-            return Err(Error::new(
+            return Err(coded_error(
                 Status::InvalidArg,
+                error_codes::E_INVALID_INPUT,
                 "Private key must be 32 bytes",
             ));
         }
 
-        // Initialize VDF processor with target of 1000 iterations per second
-        let vdf_processor = VDFProcessor::new(
-            compute_blake3(&prover_key),
-            256,  // 256KB memory
-            1000, // Target iterations per second
-            prover_private_key.to_vec(),
-        );
+        let mut prover = Self::with_signer(
+            prover_key,
+            std::sync::Arc::new(crate::core::signer::SoftwareSigner::new(
+                prover_private_key.to_vec(),
+            )),
+            callbacks,
+        )?;
+        prover.network_params = network_params;
+        Ok(prover)
+    }
 
-        // Start VDF processor
-        vdf_processor.start();
+    /// Create a prover whose signatures come from `sign`, a `(data) => signature`
+    /// callback, e.g. one delegating to a hardware security module, so the private key
+    /// never has to be in process memory. The callback must return the 64-byte Ed25519
+    /// signature of `data` for `proverKey`. It is also called from the VDF thread,
+    /// where an exception it throws is fatal; return an empty Buffer to refuse instead.
+    #[napi(factory)]
+    pub fn from_signing_callback(
+        env: Env,
+        prover_key: Buffer,
+        sign: JsFunction,
+        callbacks: ProverCallbacks,
+        consensus_params: Option<ConsensusParams>,
+    ) -> Result<Self> {
+        validate_public_key(&prover_key)?;
+        let network_params = network_parameters_from(consensus_params)?;
+        let signer = JsCallbackSigner::new(&env, sign)?;
 
-        Ok(Self {
-            prover_key: prover_key.clone(),
-            prover_private_key,
-            callbacks,
-            active_chains: std::collections::HashMap::new(),
-            availability_prover: crate::core::availability::AvailabilityProver::new(),
-            vdf_processor,
-            total_blocks_processed: 0,
-            last_processing_time_ms: 0.0,
-        })
+        let mut prover = Self::with_signer(prover_key, std::sync::Arc::new(signer), callbacks)?;
+        prover.network_params = network_params;
+        Ok(prover)
     }
 
     /// Store data and generate initial commitment with real implementation
@@ -274,183 +516,64 @@ impl ProofOfStorageProver {
 
         // Validate input data
         if data.is_empty() {
-            return Err(Error::new(Status::InvalidArg, "Data cannot be empty"));
-        }
-
-        let file_size = data.len() as u64;
-        if file_size < MIN_FILE_SIZE {
-            return Err(Error::new(
+            return Err(coded_error(
                 Status::InvalidArg,
-                format!(
-                    "File too small: {} bytes (minimum: {} bytes)",
-                    file_size, MIN_FILE_SIZE
-                ),
+                error_codes::E_INVALID_INPUT,
+                "Data cannot be empty",
             ));
         }
-
-        // CRITICAL: Start VDF immediately when first chain is created
-        if self.active_chains.is_empty() {
-            info!("🚀 Starting VDF processor for first chain - Network Consensus Requirement");
-            eprintln!("🚀 [RUST DEBUG] Starting VDF processor for first chain - Network Consensus Requirement");
-            self.vdf_processor.start();
-
-            // Wait for VDF to reach minimum iterations required by network consensus
-            info!("⏳ Waiting for VDF to reach minimum 1000 iterations...");
-            loop {
-                let (_, iterations) = self.vdf_processor.get_state();
-                if iterations >= 1000 {
-                    info!(
-                        "✅ VDF reached {} iterations - Network Consensus Met",
-                        iterations
-                    );
-                    break;
-                }
-                std::thread::sleep(std::time::Duration::from_millis(100));
-            }
-        }
+        self.check_new_chain(data.len() as u64)?;
+        self.ensure_vdf_started();
 
         // Create and store the chain
         let chain = IndividualHashChain::new_from_stream(
             self.prover_key.clone(),
             data.clone(),
-            output_directory,
+            output_directory.clone(),
             0, // Genesis block
             Buffer::from([0u8; 32].to_vec()),
         )
-        .map_err(|e| {
-            Error::new(
-                Status::GenericFailure,
-                format!("Failed to create hash chain: {:?}", e),
-            )
-        })?;
-
-        let chain_id = hex::encode(chain.get_chain_id());
-        let total_chunks = chain.get_total_chunks();
-
-        // Register chain for availability proving
-        if let Some(storage) = &chain.storage {
-            self.availability_prover.register_chain(
-                chain_id.clone(),
-                storage.data_file_path.clone(),
-                total_chunks as u32,
-            );
-        }
-
-        // Generate real multi-source entropy
-        let blockchain_entropy =
-            Buffer::from(crate::core::utils::generate_deterministic_bytes(&data, 32));
-        let local_entropy =
-            Buffer::from(crate::core::utils::generate_secure_entropy(&self.prover_key).to_vec());
-        let combined_entropy = crate::core::utils::generate_multi_source_entropy(
-            &blockchain_entropy,
-            None,
-            &local_entropy,
-        );
+        .map_err(|e| e.into_napi_error("Failed to create hash chain"))?;
 
-        let entropy = MultiSourceEntropy {
-            blockchain_entropy,
-            beacon_entropy: None,
-            local_entropy,
-            timestamp: crate::core::utils::get_current_timestamp(),
-            combined_hash: Buffer::from(combined_entropy.to_vec()),
-        };
+        self.commit_new_chain(chain, output_directory, &data, start_time)
+    }
 
-        // Select chunks using deterministic algorithm with 16 chunks
-        let selected_chunks = crate::core::utils::select_chunks_deterministic(
-            &combined_entropy,
-            total_chunks as f64,
-            CHUNKS_PER_BLOCK,
-        );
+    /// Store a data file already on disk, like `storeData` but streamed from the file
+    /// so it is never held in memory as a whole; suitable for files beyond Node's
+    /// buffer size limit
+    #[napi]
+    pub fn store_data_from_file(
+        &mut self,
+        input_path: String,
+        output_directory: String,
+    ) -> Result<StorageCommitment> {
+        let start_time = std::time::Instant::now();
 
-        // Read actual chunk data and compute real hashes
-        let mut chunk_hashes = Vec::new();
-        let mut chain_mut = chain;
-        for &chunk_idx in &selected_chunks {
-            let chunk_data = chain_mut.read_chunk(chunk_idx).map_err(|e| {
-                Error::new(
-                    Status::GenericFailure,
-                    format!("Failed to read chunk {}: {:?}", chunk_idx, e),
-                )
-            })?;
-            let chunk_hash = crate::core::utils::compute_blake3(&chunk_data);
-            chunk_hashes.push(Buffer::from(chunk_hash.to_vec()));
+        let metadata = std::fs::metadata(&input_path).map_err(|e| {
+            HashChainError::Io(e).into_napi_error(format!("Cannot read data file {}", input_path))
+        })?;
+        if !metadata.is_file() {
+            return Err(coded_error(
+                Status::InvalidArg,
+                error_codes::E_INVALID_INPUT,
+                format!("{} is not a file", input_path),
+            ));
         }
+        self.check_new_chain(metadata.len())?;
+        self.ensure_vdf_started();
 
-        // NETWORK CONSENSUS REQUIREMENT: Get VDF signature for this block
-        let block_height = 0u64;
-        let block_hash = [0u8; 32]; // Genesis block hash
-        let required_iterations = 1000; // Minimum 1000 iterations (~1 second)
-
-        let vdf_signature = self
-            .vdf_processor
-            .sign_block(block_height, block_hash, required_iterations)
-            .map_err(|e| {
-                Error::new(
-                    Status::GenericFailure,
-                    format!("VDF signature required by network consensus: {}", e),
-                )
-            })?;
-
-        // Get current VDF state for proof
-        let (vdf_state, total_iterations) = self.vdf_processor.get_state();
-
-        // Create VDF proof with continuous VDF signature (NETWORK CONSENSUS STANDARD)
-        let vdf_proof = MemoryHardVDFProof {
-            input_state: Buffer::from(vdf_state.to_vec()),
-            output_state: Buffer::from(vdf_signature.to_vec()), // VDF signature as output
-            iterations: total_iterations as u32,
-            memory_access_samples: Vec::new(), // Not needed for continuous VDF
-            computation_time_ms: 0.0, // Continuous VDF doesn't have discrete computation time
-            memory_usage_bytes: 256.0 * 1024.0, // 256KB constant memory
-        };
-
-        // Compute real commitment hash
-        let data_hash = chain_mut
-            .storage
-            .as_mut()
-            .unwrap()
-            .compute_file_hash()
-            .map_err(|e| {
-                Error::new(
-                    Status::GenericFailure,
-                    format!("Failed to compute data hash: {:?}", e),
-                )
-            })?;
-
-        let commitment_hash =
-            crate::core::utils::compute_commitment_hash(&crate::core::utils::CommitmentParams {
-                prover_key: &self.prover_key,
-                data_hash: &data_hash,
-                block_height: 0,
-                block_hash: &[0u8; 32],
-                selected_chunks: &selected_chunks,
-                chunk_hashes: &chunk_hashes.iter().map(|h| h.to_vec()).collect::<Vec<_>>(),
-                vdf_output: &vdf_signature, // Use VDF signature in commitment
-                entropy_hash: &combined_entropy,
-            });
-
-        let commitment = StorageCommitment {
-            prover_key: self.prover_key.clone(),
-            data_hash: Buffer::from(data_hash.to_vec()),
-            block_height: 0,
-            block_hash: Buffer::from([0u8; 32].to_vec()),
-            selected_chunks,
-            chunk_hashes,
-            vdf_proof,
-            entropy,
-            commitment_hash: Buffer::from(commitment_hash.to_vec()),
-        };
-
-        // Store the chain
-        self.active_chains.insert(chain_id, chain_mut);
-
-        // Update performance metrics
-        let elapsed_ms = start_time.elapsed().as_millis() as f64;
-        self.last_processing_time_ms = elapsed_ms;
-        self.total_blocks_processed += 1;
+        let chain = IndividualHashChain::new_from_file(
+            self.prover_key.clone(),
+            &input_path,
+            output_directory.clone(),
+            0, // Genesis block
+            Buffer::from([0u8; 32].to_vec()),
+        )
+        .map_err(|e| e.into_napi_error("Failed to create hash chain"))?;
 
-        info!("✅ Block created with VDF signature - Network Consensus Validated");
-        Ok(commitment)
+        // The chain id already binds the file contents, so it seeds the entropy
+        let entropy_seed = chain.get_chain_id().to_vec();
+        self.commit_new_chain(chain, output_directory, &entropy_seed, start_time)
     }
 
     /// Submit a block for VDF-based signing
@@ -460,6 +583,9 @@ impl ProofOfStorageProver {
         block_height: Option<u32>,
         block_hash: Option<Buffer>,
     ) -> Result<String> {
+        if let Some(block_hash) = &block_hash {
+            validate_block_hash(block_hash)?;
+        }
         let block_height = block_height.unwrap_or(0);
         let block_hash = block_hash.unwrap_or_else(|| {
             // Generate deterministic block hash
@@ -472,99 +598,64 @@ impl ProofOfStorageProver {
 
         // Must have at least one active chain
         if self.active_chains.is_empty() {
-            return Err(Error::new(
+            return Err(coded_error(
                 Status::GenericFailure,
+                error_codes::E_NO_ACTIVE_CHAINS,
                 "No active chains available for commitment generation",
             ));
         }
 
         // Select primary chain for commitment generation
-        let (_chain_id, chain) = self
-            .active_chains
-            .iter_mut()
-            .max_by_key(|(_, chain)| chain.chain_length)
-            .unwrap();
+        let (chain_id, chain) = select_primary_chain(&mut self.active_chains).unwrap();
+        check_integrity_flag(&self.corrupt_chains, chain_id)?;
 
         // Generate entropy and select chunks
-        let blockchain_entropy = Buffer::from(crate::core::utils::generate_deterministic_bytes(
-            &block_hash,
-            32,
-        ));
-        let local_entropy =
-            Buffer::from(crate::core::utils::generate_secure_entropy(&self.prover_key).to_vec());
-        let combined_entropy = crate::core::utils::generate_multi_source_entropy(
-            &blockchain_entropy,
-            None,
-            &local_entropy,
-        );
-
-        let entropy = MultiSourceEntropy {
-            blockchain_entropy,
-            beacon_entropy: None,
-            local_entropy,
-            timestamp: crate::core::utils::get_current_timestamp(),
-            combined_hash: Buffer::from(combined_entropy.to_vec()),
-        };
+        let entropy = crate::core::utils::commitment_entropy(&self.prover_key, &block_hash);
+        let combined_entropy = entropy.combined_hash.clone();
 
         let total_chunks = chain.get_total_chunks();
         let selected_chunks = crate::core::utils::select_chunks_deterministic(
             &combined_entropy,
             total_chunks as f64,
-            CHUNKS_PER_BLOCK,
+            self.network_params.chunks_per_block,
         );
 
         // Read chunk data and compute hashes
         let mut chunk_hashes = Vec::new();
         for &chunk_idx in &selected_chunks {
-            let chunk_data = chain.read_chunk(chunk_idx).map_err(|e| {
-                Error::new(
-                    Status::GenericFailure,
-                    format!("Failed to read chunk {}: {:?}", chunk_idx, e),
-                )
-            })?;
+            let chunk_data = chain
+                .read_chunk(chunk_idx)
+                .map_err(|e| e.into_napi_error(format!("Failed to read chunk {}", chunk_idx)))?;
             let chunk_hash = crate::core::utils::compute_blake3(&chunk_data);
             chunk_hashes.push(Buffer::from(chunk_hash.to_vec()));
         }
 
         // Get data hash
         let data_hash = chain
-            .storage
-            .as_mut()
-            .unwrap()
-            .compute_file_hash()
-            .map_err(|e| {
-                Error::new(
-                    Status::GenericFailure,
-                    format!("Failed to compute data hash: {:?}", e),
-                )
-            })?;
+            .data_hash()
+            .map_err(|e| e.into_napi_error("Failed to compute data hash"))?;
 
         // Get current VDF state and sign block
         let (vdf_state, iterations) = self.vdf_processor.get_state();
 
         // Sign the block using the private key
         let block_signature = sign_block(
-            &self.prover_private_key,
+            self.signer.as_ref(),
             block_height as u64,
             &block_hash,
             &vdf_state,
             iterations,
         )
-        .map_err(|e| {
-            Error::new(
-                Status::GenericFailure,
-                format!("Failed to sign block: {:?}", e),
-            )
-        })?;
+        .map_err(|e| e.into_napi_error("Failed to sign block"))?;
 
         let vdf_signature = self
             .vdf_processor
             .sign_block(
                 block_height as u64,
-                block_hash.to_vec().try_into().unwrap(),
+                block_hash_to_array(&block_hash)?,
                 iterations,
             )
-            .map_err(|e| Error::new(Status::GenericFailure, e))?;
+            .map_err(|e| coded_error(Status::GenericFailure, error_codes::E_VDF_NOT_READY, e))?;
 
         // Create commitment with VDF signature
         let _commitment = StorageCommitment {
@@ -595,17 +686,14 @@ impl ProofOfStorageProver {
                 )
                 .to_vec(),
             ),
+            merkle_root: None,
+            total_chunks: None,
         };
 
         // Update chain with the commitment
         chain
             .add_commitment(block_hash.clone(), block_height as u64, selected_chunks)
-            .map_err(|e| {
-                Error::new(
-                    Status::GenericFailure,
-                    format!("Failed to add commitment: {:?}", e),
-                )
-            })?;
+            .map_err(|e| e.into_napi_error("Failed to add commitment"))?;
 
         self.total_blocks_processed += 1;
         Ok(format!(
@@ -616,13 +704,18 @@ impl ProofOfStorageProver {
         ))
     }
 
-    /// Generate storage commitment for current block with real data
+    /// Generate storage commitment for current block with real data.
+    /// Fails with `E_VDF_NOT_READY` before reading any chunks if the VDF has not yet
+    /// computed the minimum iterations.
     #[napi]
     pub fn generate_commitment(
         &mut self,
         block_height: Option<u32>,
         block_hash: Option<Buffer>,
     ) -> Result<StorageCommitment> {
+        if let Some(block_hash) = &block_hash {
+            validate_block_hash(block_hash)?;
+        }
         let block_height = block_height.unwrap_or(0);
         let block_hash = block_hash.unwrap_or_else(|| {
             // Generate deterministic block hash
@@ -635,18 +728,24 @@ impl ProofOfStorageProver {
 
         // Must have at least one active chain
         if self.active_chains.is_empty() {
-            return Err(Error::new(
+            return Err(coded_error(
                 Status::GenericFailure,
+                error_codes::E_NO_ACTIVE_CHAINS,
                 "No active chains available for commitment generation",
             ));
         }
 
+        // NETWORK CONSENSUS REQUIREMENT: the block needs a VDF signature. Check the VDF
+        // has enough iterations before any chunk reads or chain updates, which would
+        // otherwise be wasted (and leave a commitment behind) when signing fails.
+        let required_iterations = self.network_params.min_vdf_iterations as u64;
+        self.vdf_processor
+            .ensure_iterations(required_iterations)
+            .map_err(|e| e.into_napi_error("Cannot generate commitment"))?;
+
         // Select primary chain for commitment generation based on highest block count
-        let (_chain_id, chain) = self
-            .active_chains
-            .iter_mut()
-            .max_by_key(|(_, chain)| chain.chain_length)
-            .unwrap();
+        let (chain_id, chain) = select_primary_chain(&mut self.active_chains).unwrap();
+        check_integrity_flag(&self.corrupt_chains, chain_id)?;
 
         // Log chain selection for monitoring and debugging
         log::debug!(
@@ -656,102 +755,67 @@ impl ProofOfStorageProver {
         );
 
         // Generate real multi-source entropy
-        let blockchain_entropy = Buffer::from(crate::core::utils::generate_deterministic_bytes(
-            &block_hash,
-            32,
-        ));
-        let local_entropy =
-            Buffer::from(crate::core::utils::generate_secure_entropy(&self.prover_key).to_vec());
-        let combined_entropy = crate::core::utils::generate_multi_source_entropy(
-            &blockchain_entropy,
-            None,
-            &local_entropy,
-        );
-
-        let entropy = MultiSourceEntropy {
-            blockchain_entropy,
-            beacon_entropy: None,
-            local_entropy,
-            timestamp: crate::core::utils::get_current_timestamp(),
-            combined_hash: Buffer::from(combined_entropy.to_vec()),
-        };
+        let entropy = crate::core::utils::commitment_entropy(&self.prover_key, &block_hash);
+        let combined_entropy = entropy.combined_hash.clone();
 
         // Select chunks using deterministic algorithm
         let total_chunks = chain.get_total_chunks();
         let selected_chunks = crate::core::utils::select_chunks_deterministic(
             &combined_entropy,
             total_chunks as f64,
-            CHUNKS_PER_BLOCK,
+            self.network_params.chunks_per_block,
         );
 
         // Read actual chunk data and compute real hashes
         let mut chunk_hashes = Vec::new();
         for &chunk_idx in &selected_chunks {
-            let chunk_data = chain.read_chunk(chunk_idx).map_err(|e| {
-                Error::new(
-                    Status::GenericFailure,
-                    format!("Failed to read chunk {}: {:?}", chunk_idx, e),
-                )
-            })?;
+            let chunk_data = chain
+                .read_chunk(chunk_idx)
+                .map_err(|e| e.into_napi_error(format!("Failed to read chunk {}", chunk_idx)))?;
             let chunk_hash = crate::core::utils::compute_blake3(&chunk_data);
             chunk_hashes.push(Buffer::from(chunk_hash.to_vec()));
         }
 
-        // Add commitment to chain and verify it's properly stored
-        let commitment_result = chain
-            .add_commitment(
-                block_hash.clone(),
-                block_height as u64,
-                selected_chunks.clone(),
-            )
-            .map_err(|e| {
-                Error::new(
-                    Status::GenericFailure,
-                    format!("Failed to add commitment: {:?}", e),
-                )
-            })?;
-
-        // Verify commitment was properly added and update chain length tracking
-        log::debug!(
-            "Added commitment to chain {}: block {} -> {} (commitment hash: {})",
-            hex::encode(chain.get_chain_id()),
-            block_height,
-            chain.chain_length,
-            hex::encode(&commitment_result.commitment_hash)
-        );
-
-        // Update availability prover with new commitment for challenge readiness
-        if let Some(storage) = &chain.storage {
-            self.availability_prover.register_chain(
-                hex::encode(chain.get_chain_id()),
-                storage.data_file_path.clone(),
-                chain.get_total_chunks() as u32,
-            );
-        }
-
-        // NETWORK CONSENSUS REQUIREMENT: Get VDF signature for this block
-        let required_iterations = 1000; // Minimum 1000 iterations (~1 second)
-
-        let block_hash_array: [u8; 32] = if block_hash.len() == 32 {
-            let mut array = [0u8; 32];
-            array.copy_from_slice(&block_hash);
-            array
-        } else {
-            [0u8; 32] // Default for invalid hash
-        };
+        let block_hash_array = block_hash_to_array(&block_hash)?;
 
-        let vdf_signature = self
+        // Capture signature and matching state in one short lock hold; the file hash
+        // and commitment hashing below run without touching the shared VDF
+        let snapshot = self
             .vdf_processor
-            .sign_block(block_height as u64, block_hash_array, required_iterations)
+            .snapshot_for_block(block_height as u64, block_hash_array, required_iterations)
             .map_err(|e| {
-                Error::new(
+                coded_error(
                     Status::GenericFailure,
+                    error_codes::E_VDF_NOT_READY,
                     format!("VDF signature required by network consensus: {}", e),
                 )
             })?;
+        log::debug!(
+            "VDF lock held {}us for block {} snapshot",
+            snapshot.lock_hold_micros,
+            block_height
+        );
+        let vdf_signature = snapshot.signature;
+        let vdf_state = snapshot.vdf_state;
+        let total_iterations = snapshot.total_iterations;
 
-        // Get current VDF state for proof
-        let (vdf_state, total_iterations) = self.vdf_processor.get_state();
+        // Reject commitments for a later block that reuse the previous block's VDF output
+        let consensus_validator = crate::consensus::NetworkConsensusValidator::from_parameters(
+            self.network_params.clone(),
+        );
+        if let Some((last_height, last_iterations)) = self.last_commitment_vdf {
+            if block_height > last_height {
+                consensus_validator
+                    .validate_vdf_progress(
+                        last_iterations,
+                        total_iterations,
+                        block_height - last_height,
+                    )
+                    .map_err(|e| {
+                        coded_error(Status::GenericFailure, error_codes::E_VDF_NOT_READY, e)
+                    })?;
+            }
+        }
 
         // Create VDF proof with continuous VDF signature (NETWORK CONSENSUS STANDARD)
         let vdf_proof = MemoryHardVDFProof {
@@ -765,16 +829,11 @@ impl ProofOfStorageProver {
 
         // Get data hash from chain
         let data_hash = chain
-            .storage
-            .as_mut()
-            .unwrap()
-            .compute_file_hash()
-            .map_err(|e| {
-                Error::new(
-                    Status::GenericFailure,
-                    format!("Failed to compute data hash: {:?}", e),
-                )
-            })?;
+            .data_hash()
+            .map_err(|e| e.into_napi_error("Failed to compute data hash"))?;
+        let merkle_root = chain
+            .merkle_root()
+            .map_err(|e| e.into_napi_error("Failed to compute Merkle root"))?;
 
         // Compute real commitment hash
         let commitment_hash =
@@ -787,6 +846,7 @@ impl ProofOfStorageProver {
                 chunk_hashes: &chunk_hashes.iter().map(|h| h.to_vec()).collect::<Vec<_>>(),
                 vdf_output: &vdf_signature, // Use VDF signature in commitment
                 entropy_hash: &combined_entropy,
+                merkle_root: Some((&merkle_root, total_chunks)),
             });
 
         let commitment = StorageCommitment {
@@ -799,19 +859,56 @@ impl ProofOfStorageProver {
             vdf_proof,
             entropy,
             commitment_hash: Buffer::from(commitment_hash.to_vec()),
+            merkle_root: Some(Buffer::from(merkle_root.to_vec())),
+            total_chunks: Some(total_chunks as f64),
         };
 
         // Validate commitment meets network consensus before returning
-        let consensus_validator = crate::consensus::NetworkConsensusValidator::new_production();
         if let Err(error) =
             consensus_validator.validate_full_consensus(&commitment, total_chunks as u32)
         {
-            return Err(Error::new(
+            return Err(coded_error(
                 Status::GenericFailure,
+                error_codes::E_CONSENSUS,
                 format!("Commitment failed consensus validation: {}", error),
             ));
         }
 
+        // Record the commitment only once it passed every check, so a rejected
+        // commitment leaves no trace in the chain log or availability tracking
+        let commitment_result = chain
+            .add_commitment(
+                commitment.block_hash.clone(),
+                block_height as u64,
+                commitment.selected_chunks.clone(),
+            )
+            .map_err(|e| e.into_napi_error("Failed to add commitment"))?;
+
+        // Verify commitment was properly added and update chain length tracking
+        log::debug!(
+            "Added commitment to chain {}: block {} -> {} (commitment hash: {})",
+            hex::encode(chain.get_chain_id()),
+            block_height,
+            chain.chain_length,
+            hex::encode(&commitment_result.commitment_hash)
+        );
+
+        // Update availability prover with new commitment for challenge readiness
+        if let Some(storage) = &chain.storage {
+            self.availability_prover.register_chain(
+                hex::encode(chain.get_chain_id()),
+                storage.data_file_path.clone(),
+                chain.get_total_chunks() as u32,
+            );
+        }
+
+        if self
+            .last_commitment_vdf
+            .is_none_or(|(last_height, _)| block_height >= last_height)
+        {
+            self.last_commitment_vdf = Some((block_height, total_iterations));
+        }
+
         Ok(commitment)
     }
 
@@ -822,20 +919,15 @@ impl ProofOfStorageProver {
         block_height: Option<u32>,
     ) -> Result<CompactStorageProof> {
         if self.active_chains.is_empty() {
-            return Err(Error::new(
+            return Err(coded_error(
                 Status::GenericFailure,
+                error_codes::E_NO_ACTIVE_CHAINS,
                 "No active chains available",
             ));
         }
 
         let commitment = self.generate_commitment(block_height, None)?;
 
-        // Generate real chunk proofs by sampling from the selected chunks
-        let mut chunk_proofs = Vec::new();
-        for chunk_hash in &commitment.chunk_hashes {
-            chunk_proofs.push(chunk_hash.clone());
-        }
-
         // Generate real network position based on prover key and network topology
         let chain_count = self.active_chains.len() as u32;
         let group_id = crate::core::utils::generate_group_id(chain_count);
@@ -848,43 +940,33 @@ impl ProofOfStorageProver {
             &region_id,
         );
 
-        Ok(CompactStorageProof {
-            prover_key: self.prover_key.clone(),
-            commitment_hash: commitment.commitment_hash,
-            block_height: commitment.block_height,
-            chunk_proofs,
-            vdf_proof: commitment.vdf_proof,
-            network_position: Buffer::from(network_position.to_vec()),
-            timestamp: commitment.entropy.timestamp,
-        })
+        Ok(crate::consensus::compact_proof_from_commitment(
+            commitment,
+            Buffer::from(network_position.to_vec()),
+        ))
     }
 
     /// Create real full proof with complete verification data
     #[napi]
     pub fn create_full_proof(&mut self, block_height: Option<u32>) -> Result<FullStorageProof> {
         if self.active_chains.is_empty() {
-            return Err(Error::new(
+            return Err(coded_error(
                 Status::GenericFailure,
+                error_codes::E_NO_ACTIVE_CHAINS,
                 "No active chains available",
             ));
         }
 
         let commitment = self.generate_commitment(block_height, None)?;
-        let (_, chain) = self.active_chains.iter_mut().next().unwrap();
+        let (_, chain) = select_primary_chain(&mut self.active_chains).unwrap();
 
-        // Generate real chunk hashes for all chunks
-        let mut all_chunk_hashes = Vec::new();
-        let total_chunks = chain.get_total_chunks();
-        for i in 0..total_chunks {
-            let chunk_data = chain.read_chunk(i as u32).map_err(|e| {
-                Error::new(
-                    Status::GenericFailure,
-                    format!("Failed to read chunk {}: {:?}", i, e),
-                )
-            })?;
-            let chunk_hash = crate::core::utils::compute_blake3(&chunk_data);
-            all_chunk_hashes.push(Buffer::from(chunk_hash.to_vec()));
-        }
+        // Generate real chunk hashes for all chunks, in chunk index order
+        let all_chunk_hashes: Vec<Buffer> = chain
+            .compute_all_chunk_hashes()
+            .map_err(|e| e.into_napi_error("Failed to hash chunks"))?
+            .into_iter()
+            .map(|chunk_hash| Buffer::from(chunk_hash.to_vec()))
+            .collect();
 
         // Generate real Merkle tree with proper intermediate nodes
         let chunk_hash_refs: Vec<&[u8]> = all_chunk_hashes.iter().map(|h| h.as_ref()).collect();
@@ -943,70 +1025,178 @@ impl ProofOfStorageProver {
         })
     }
 
-    /// Respond to storage challenge with real data
+    /// Answer an availability challenge for one of this prover's chains, signed with
+    /// the prover key so the response can be used as dispute evidence
+    #[napi]
+    pub fn respond_to_availability_challenge(
+        &mut self,
+        challenge: AvailabilityChallenge,
+    ) -> Result<AvailabilityResponse> {
+        let mut response = self.availability_prover.respond_to_challenge(&challenge)?;
+        crate::core::availability::sign_availability_response(
+            &mut response,
+            &self.prover_key,
+            self.signer.as_ref(),
+        )
+        .map_err(|e| e.into_napi_error("Failed to sign availability response"))?;
+        Ok(response)
+    }
+
+    /// Respond to storage challenge with real data.
+    /// `measuredLatencyMs` is a fresh round-trip measurement to the challenger; when
+    /// given it is bound into the access proof so the verifier can check the response
+    /// arrived within a matching window.
+    /// Fails with `E_CHALLENGE_EXPIRED` before any work once the deadline has passed
+    /// or the access proof VDF cannot finish before it.
     #[napi]
     pub fn respond_to_challenge(
         &mut self,
         challenge: StorageChallenge,
+        measured_latency_ms: Option<f64>,
     ) -> Result<ChallengeResponse> {
+        // A response that cannot arrive by the deadline is rejected anyway; skip the
+        // access proof VDF, estimated from the calibrated VDF speed, when it cannot
+        let estimated_seconds = self.vdf_processor.get_calibrated_rate().map(|rate| {
+            crate::core::utils::estimate_memory_hard_vdf_seconds(
+                CHALLENGE_ACCESS_PROOF_ITERATIONS,
+                CHALLENGE_ACCESS_PROOF_MEMORY_KB,
+                rate,
+            )
+        });
+        crate::core::utils::check_challenge_deadline(
+            challenge.deadline,
+            crate::core::utils::get_current_timestamp(),
+            estimated_seconds,
+        )
+        .map_err(|e| e.into_napi_error("Challenge not answered"))?;
+
+        if measured_latency_ms.is_some_and(|latency| !latency.is_finite() || latency <= 0.0) {
+            return Err(coded_error(
+                Status::InvalidArg,
+                error_codes::E_INVALID_INPUT,
+                "Measured latency must be positive",
+            ));
+        }
+
+        // Paused for maintenance: temporarily unavailable rather than failing the challenge
+        if self.maintenance_started_at.is_some() {
+            return Err(coded_error(
+                Status::GenericFailure,
+                error_codes::E_MAINTENANCE_MODE,
+                "Prover is in maintenance mode and not answering challenges",
+            ));
+        }
+
+        // Reject unauthorized challenges before any expensive work
+        if self.require_signed_challenges {
+            let authorized = challenge
+                .verifier_key
+                .as_ref()
+                .is_some_and(|key| self.authorized_verifiers.contains(key.as_ref()))
+                && crate::core::utils::verify_storage_challenge_signature(&challenge);
+            if !authorized {
+                return Err(coded_error(
+                    Status::GenericFailure,
+                    error_codes::E_UNAUTHORIZED,
+                    "Challenge is not signed by an authorized verifier",
+                ));
+            }
+        }
+
         let challenge_id_str = hex::encode(&challenge.challenge_id);
 
         // Find the chain being challenged
         let chain_id = hex::encode(&challenge.prover_key);
-        let chain = self
-            .active_chains
-            .get_mut(&chain_id)
-            .ok_or_else(|| Error::new(Status::GenericFailure, "Chain not found for challenge"))?;
+        let chain = self.active_chains.get_mut(&chain_id).ok_or_else(|| {
+            coded_error(
+                Status::GenericFailure,
+                error_codes::E_CHAIN_NOT_FOUND,
+                "Chain not found for challenge",
+            )
+        })?;
 
         // Read actual chunk data for the challenge
         let mut chunk_data = Vec::new();
-        let mut merkle_proofs = Vec::new();
-
-        for &chunk_idx in &challenge.challenged_chunks {
-            // Read real chunk data
-            let chunk = chain.read_chunk(chunk_idx).map_err(|e| {
-                Error::new(
-                    Status::GenericFailure,
-                    format!("Failed to read challenged chunk {}: {:?}", chunk_idx, e),
+        let mut sub_chunk_proofs = Vec::new();
+
+        for (position, &chunk_idx) in challenge.challenged_chunks.iter().enumerate() {
+            // Read real chunk data, riding out momentary storage glitches
+            let chunk = chain
+                .read_chunk_with_retry(
+                    chunk_idx,
+                    CHUNK_READ_MAX_ATTEMPTS,
+                    CHUNK_READ_RETRY_BACKOFF_MS,
                 )
-            })?;
-            chunk_data.push(chunk);
-
-            // Generate real Merkle proof for this chunk
-            let proof_data = format!("merkle_proof_chunk_{}", chunk_idx);
-            let proof_hash = crate::core::utils::compute_blake3(proof_data.as_bytes());
-            merkle_proofs.push(Buffer::from(proof_hash.to_vec()));
+                .map_err(|e| {
+                    e.into_napi_error(format!("Failed to read challenged chunk {}", chunk_idx))
+                })?;
+
+            // Sub-chunk challenges reveal only the requested byte range
+            match challenge
+                .byte_offsets
+                .as_ref()
+                .and_then(|offsets| offsets.get(position))
+            {
+                Some(&byte_offset) => {
+                    let (prefix, revealed, suffix) =
+                        crate::core::utils::split_sub_chunk(&chunk, byte_offset);
+                    sub_chunk_proofs.push(SubChunkProof {
+                        chunk_index: chunk_idx,
+                        byte_offset,
+                        revealed_bytes: Buffer::from(revealed.to_vec()),
+                        prefix: Buffer::from(prefix.to_vec()),
+                        suffix: Buffer::from(suffix.to_vec()),
+                    });
+                }
+                None => chunk_data.push(chunk),
+            }
         }
 
+        // Merkle inclusion path from each challenged chunk's hash to the chain's root
+        let (_, merkle_paths) = chain
+            .merkle_inclusion_paths(&challenge.challenged_chunks)
+            .map_err(|e| e.into_napi_error("Failed to build Merkle proofs"))?;
+        let merkle_proofs = merkle_paths
+            .iter()
+            .map(|path| Buffer::from(crate::core::utils::encode_merkle_path(path)))
+            .collect();
+
         // Generate access proof using VDF
-        let access_input = format!("access_proof_{}", challenge_id_str);
+        let access_input = match measured_latency_ms {
+            Some(latency) => crate::consensus::network_latency::latency_bound_access_input(
+                &challenge.challenge_id,
+                latency,
+            ),
+            None => format!("access_proof_{}", challenge_id_str).into_bytes(),
+        };
         let (vdf_output, computation_time, memory_usage) =
             crate::core::utils::compute_memory_hard_vdf(
-                access_input.as_bytes(),
-                50000,
-                (MEMORY_HARD_VDF_MEMORY / 2 / 1024) as u32,
+                &access_input,
+                CHALLENGE_ACCESS_PROOF_ITERATIONS,
+                CHALLENGE_ACCESS_PROOF_MEMORY_KB,
                 1,
             )
             .map_err(|e| {
-                Error::new(
+                coded_error(
                     Status::GenericFailure,
+                    error_codes::E_VDF_FAILED,
                     format!("Access proof VDF failed: {}", e),
                 )
             })?;
 
         let access_proof = MemoryHardVDFProof {
-            input_state: Buffer::from(access_input.as_bytes().to_vec()),
+            input_state: Buffer::from(access_input.clone()),
             output_state: Buffer::from(vdf_output.to_vec()),
-            iterations: 50000,
+            iterations: CHALLENGE_ACCESS_PROOF_ITERATIONS,
             memory_access_samples: {
                 // Generate real memory access samples for challenge response verification
                 let mut samples = Vec::new();
-                for i in (0u32..50000).step_by(2500) {
+                for i in (0u32..CHALLENGE_ACCESS_PROOF_ITERATIONS).step_by(2500) {
                     // 20 samples for challenge response
                     let read_addr = ((i * 1543) % (128 * 1024 * 1024)) as f64; // 128MB for challenge
                     let write_addr = ((i * 2741) % (128 * 1024 * 1024)) as f64;
                     let memory_hash = crate::core::utils::compute_blake3(
-                        &[&i.to_be_bytes(), access_input.as_bytes()].concat(),
+                        &[&i.to_be_bytes(), access_input.as_slice()].concat(),
                     );
                     samples.push(MemoryAccessSample {
                         iteration: i,
@@ -1027,16 +1217,68 @@ impl ProofOfStorageProver {
             merkle_proofs,
             timestamp: crate::core::utils::get_current_timestamp(),
             access_proof,
+            sub_chunk_proofs: challenge.byte_offsets.is_some().then_some(sub_chunk_proofs),
+            measured_latency_ms,
         })
     }
 
+    /// Produce one segment of the response to a range availability audit. Call once per
+    /// segment, in order, and send each as it is produced so the whole range is never
+    /// held in memory.
+    #[napi]
+    pub fn respond_to_range_challenge_segment(
+        &mut self,
+        challenge: RangeAvailabilityChallenge,
+        segment_index: u32,
+    ) -> Result<ChallengeResponseSegment> {
+        if self.maintenance_started_at.is_some() {
+            return Err(coded_error(
+                Status::GenericFailure,
+                error_codes::E_MAINTENANCE_MODE,
+                "Prover is in maintenance mode and not answering challenges",
+            ));
+        }
+
+        let (first, end) =
+            crate::core::availability::range_segment_bounds(&challenge, segment_index).ok_or_else(
+                || {
+                    coded_error(
+                        Status::InvalidArg,
+                        error_codes::E_INVALID_INPUT,
+                        format!("Segment {} is outside the challenged range", segment_index),
+                    )
+                },
+            )?;
+        let chain_id = hex::encode(&challenge.chain_id);
+        let chain = self.active_chains.get_mut(&chain_id).ok_or_else(|| {
+            coded_error(
+                Status::InvalidArg,
+                error_codes::E_CHAIN_NOT_FOUND,
+                format!("Chain {} is not active", chain_id),
+            )
+        })?;
+        if chain.get_total_chunks() != challenge.total_chunks as u64 {
+            return Err(coded_error(
+                Status::InvalidArg,
+                error_codes::E_INVALID_INPUT,
+                "Chunk count differs from the challenge",
+            ));
+        }
+
+        let chunks = chain
+            .segment_chunks(first, end)
+            .map_err(|e| e.into_napi_error("Failed to read segment chunks"))?;
+        crate::core::availability::build_range_segment(&challenge, segment_index, chunks)
+    }
+
     /// Get real prover statistics
     #[napi]
     pub fn get_prover_stats(&self) -> String {
         format!(
-            r#"{{"prover_key": "{}", "active_chains": {}, "data_stored_bytes": {}, "total_chunks": {}, "total_blocks_processed": {}, "last_processing_time_ms": {}}}"#,
+            r#"{{"prover_key": "{}", "active_chains": {}, "max_chains": {}, "data_stored_bytes": {}, "total_chunks": {}, "total_blocks_processed": {}, "last_processing_time_ms": {}}}"#,
             hex::encode(&self.prover_key),
             self.active_chains.len(),
+            self.max_chains,
             self.active_chains
                 .values()
                 .map(|c| c.get_total_chunks() * CHUNK_SIZE_BYTES as u64)
@@ -1050,380 +1292,2779 @@ impl ProofOfStorageProver {
         )
     }
 
-    /// Verify own data integrity with real checks
+    /// Total storage in bytes confirmed on the blockchain across all active chains.
+    /// Sums `blockchainData.getConfirmedStorageSize(chainId)` for each chain rather than
+    /// the locally reported chunk counts used by `getProverStats`.
+    #[napi]
+    pub fn get_confirmed_storage(&self, env: Env) -> Result<f64> {
+        let mut confirmed_bytes = 0u64;
+        for chain_id in self.active_chains.keys() {
+            let size = self
+                .callbacks
+                .blockchain_data
+                .get_confirmed_storage_size
+                .call(None, &[env.create_string(chain_id)?])?
+                .coerce_to_number()?
+                .get_double()?;
+            if !size.is_finite() || size < 0.0 {
+                return Err(coded_error(
+                    Status::GenericFailure,
+                    error_codes::E_INVALID_INPUT,
+                    format!(
+                        "Invalid confirmed storage size for chain {}: {}",
+                        chain_id, size
+                    ),
+                ));
+            }
+            confirmed_bytes += size as u64;
+        }
+        Ok(confirmed_bytes as f64)
+    }
+
+    /// Verify own data integrity with real checks.
+    /// Every failing chain is flagged and refused by commitment generation until
+    /// `clearIntegrityFlag` is called after repair.
     #[napi]
     pub fn verify_self_integrity(&mut self) -> bool {
+        self.verify_all_chains_detailed()
+            .iter()
+            .all(|report| report.valid)
+    }
+
+    /// Check every active chain, reporting each one's outcome in chain id order so
+    /// only the damaged chains need repair. Failing chains are flagged as by
+    /// `verifySelfIntegrity`.
+    #[napi]
+    pub fn verify_all_chains_detailed(&mut self) -> Vec<ChainIntegrityReport> {
+        let mut reports = Vec::with_capacity(self.active_chains.len());
         for (chain_id, chain) in &mut self.active_chains {
-            match chain.verify_chain() {
-                Ok(is_valid) => {
-                    if !is_valid {
-                        log::error!("Chain {} failed integrity check", chain_id);
-                        return false;
-                    }
+            let error = match chain.verify_chain() {
+                Ok(true) => None,
+                Ok(false) => {
+                    log::error!("Chain {} failed integrity check", chain_id);
+                    Some("Commitment linkage or hashes do not match".to_string())
                 }
                 Err(e) => {
                     log::error!("Chain {} integrity check error: {:?}", chain_id, e);
-                    return false;
+                    Some(e.to_string())
                 }
+            };
+            if error.is_some() {
+                self.corrupt_chains.insert(chain_id.clone());
             }
+            reports.push(ChainIntegrityReport {
+                chain_id: chain_id.clone(),
+                valid: error.is_none(),
+                error,
+            });
         }
-        true
+        reports
     }
 
-    /// Get number of active chains
+    /// Chains flagged by a failed integrity check, sorted by chain id
     #[napi]
-    pub fn get_active_chain_count(&self) -> u32 {
-        self.active_chains.len() as u32
+    pub fn get_integrity_flagged_chains(&self) -> Vec<String> {
+        self.corrupt_chains.iter().cloned().collect()
     }
 
-    /// Get chain information
+    /// Re-enable commitment generation for a repaired chain.
+    /// Returns false if the chain was not flagged.
     #[napi]
-    pub fn get_chain_info(&self, chain_id: String) -> Result<String> {
-        let chain = self
-            .active_chains
-            .get(&chain_id)
-            .ok_or_else(|| Error::new(Status::GenericFailure, "Chain not found"))?;
+    pub fn clear_integrity_flag(&mut self, chain_id: String) -> bool {
+        let cleared = self.corrupt_chains.remove(&chain_id);
+        if cleared {
+            info!("Cleared integrity flag for chain {}", chain_id);
+        }
+        cleared
+    }
+
+    /// Pause or resume answering challenges for maintenance. While enabled, storage
+    /// challenges fail with E_MAINTENANCE_MODE and availability challenges get a
+    /// maintenance notice, which verifiers score down without slashing.
+    #[napi]
+    pub fn set_maintenance_mode(&mut self, enabled: bool) {
+        let now = crate::core::utils::get_current_timestamp();
+        match (enabled, self.maintenance_started_at) {
+            (true, None) => {
+                self.maintenance_started_at = Some(now);
+                info!("Entered maintenance mode");
+            }
+            (false, Some(started_at)) => {
+                let duration = (now - started_at).max(0.0);
+                self.completed_maintenance_seconds += duration;
+                self.maintenance_started_at = None;
+                info!("Left maintenance mode after {:.1}s", duration);
+            }
+            _ => {}
+        }
+        self.availability_prover.set_maintenance_mode(enabled);
+    }
 
-        let stats = chain.get_file_stats().map_err(|e| {
-            Error::new(
+    /// Get whether the prover is in maintenance and how long it has been
+    #[napi]
+    pub fn get_maintenance_status(&self) -> MaintenanceStatus {
+        let current_duration_seconds = self.maintenance_started_at.map_or(0.0, |started_at| {
+            (crate::core::utils::get_current_timestamp() - started_at).max(0.0)
+        });
+        MaintenanceStatus {
+            enabled: self.maintenance_started_at.is_some(),
+            current_duration_seconds,
+            total_duration_seconds: self.completed_maintenance_seconds + current_duration_seconds,
+        }
+    }
+
+    /// Record that a chain's data is an erasure-coded dataset with the given layout,
+    /// so coded shard challenges can target its raw shards
+    #[napi]
+    pub fn set_chain_erasure_coding(
+        &mut self,
+        chain_id: String,
+        erasure_coding: ErasureCodingParams,
+    ) -> Result<()> {
+        let chain = self.active_chains.get_mut(&chain_id).ok_or_else(|| {
+            coded_error(
                 Status::GenericFailure,
-                format!("Failed to get stats: {:?}", e),
+                error_codes::E_CHAIN_NOT_FOUND,
+                "Chain not found",
             )
         })?;
 
-        Ok(format!(
-            r#"{{"chain_id": "{}", "total_chunks": {}, "chain_length": {}, "data_file_size": {}, "hashchain_file_size": {}}}"#,
-            chain_id,
-            chain.get_total_chunks(),
-            chain.chain_length,
-            stats.data_file_size,
-            stats.hashchain_file_size.unwrap_or(0)
-        ))
+        chain.set_erasure_coding(erasure_coding).map_err(|e| {
+            e.into_napi_error(format!("Failed to set erasure coding for {}", chain_id))
+        })
     }
 
-    /// Update callbacks
+    /// Rewrite a chain's .hashchain log keeping the most recent `keepRecent` commitments
+    /// (at least `PROOF_WINDOW_BLOCKS`) plus a checkpoint hash of the truncated prefix.
+    /// Returns the number of bytes reclaimed.
     #[napi]
-    pub fn update_callbacks(&mut self, callbacks: ProverCallbacks) {
-        self.callbacks = callbacks;
+    pub fn compact_hashchain_log(&mut self, chain_id: String, keep_recent: u32) -> Result<f64> {
+        let chain = self.active_chains.get_mut(&chain_id).ok_or_else(|| {
+            coded_error(
+                Status::GenericFailure,
+                error_codes::E_CHAIN_NOT_FOUND,
+                "Chain not found",
+            )
+        })?;
+
+        let reclaimed = chain
+            .compact_log(keep_recent)
+            .map_err(|e| e.into_napi_error(format!("Failed to compact chain {}", chain_id)))?;
+        Ok(reclaimed as f64)
     }
 
-    /// Get the latest shared VDF proof
+    /// Read up to `count` decoded chunks of a chain starting at `startIndex`, for tools
+    /// that process a whole chain. The final chunk is returned at its stored length.
     #[napi]
-    pub fn get_latest_shared_vdf_proof(&self) -> Result<String> {
-        if let Some(proof) = self.vdf_processor.get_latest_shared_proof() {
-            Ok(serde_json::to_string(&serde_json::json!({
-                "vdf_state": hex::encode(proof.vdf_state),
-                "total_iterations": proof.total_iterations,
-                "timestamp": proof.timestamp,
-                "signature": hex::encode(&proof.signature),
-                "proof_chain_hash": hex::encode(proof.proof_chain_hash)
-            }))
-            .unwrap_or_else(|_| "Failed to serialize proof".to_string()))
-        } else {
-            Err(Error::new(
+    pub fn read_chunk_batch(
+        &mut self,
+        chain_id: String,
+        start_index: u32,
+        count: u32,
+    ) -> Result<Vec<Buffer>> {
+        check_scale_limit(count, MAX_CHUNK_BATCH, "chunks")?;
+        let chain = self.active_chains.get_mut(&chain_id).ok_or_else(|| {
+            coded_error(
                 Status::GenericFailure,
-                "No shared VDF proof available yet",
-            ))
-        }
+                error_codes::E_CHAIN_NOT_FOUND,
+                "Chain not found",
+            )
+        })?;
+
+        chain
+            .read_chunk_batch(start_index, count)
+            .map_err(|e| e.into_napi_error(format!("Failed to read chunks from {}", start_index)))
     }
 
-    /// Verify the shared VDF proof chain integrity
+    /// Set the verifier keys whose signed storage challenges are answered
     #[napi]
-    pub fn verify_shared_vdf_proof_chain(&self) -> bool {
-        self.vdf_processor
-            .verify_shared_proof_chain(&self.prover_key)
+    pub fn set_authorized_verifiers(&mut self, verifier_keys: Vec<Buffer>) -> Result<()> {
+        for key in &verifier_keys {
+            validate_public_key(key)?;
+        }
+        self.authorized_verifiers = verifier_keys.iter().map(|key| key.to_vec()).collect();
+        Ok(())
     }
 
-    /// Get VDF performance statistics
+    /// Require storage challenges to be signed by an authorized verifier. When
+    /// enabled, other challenges are rejected before any chunk reads or VDF work.
     #[napi]
-    pub fn get_vdf_performance_stats(&self) -> Result<String> {
-        let stats = self.vdf_processor.get_performance_stats();
-        Ok(serde_json::to_string(&serde_json::json!({
-            "total_iterations": stats.total_iterations,
-            "elapsed_seconds": stats.elapsed_seconds,
-            "target_iterations_per_second": stats.target_iterations_per_second,
-            "actual_iterations_per_second": stats.actual_iterations_per_second,
-            "shared_proofs_count": stats.shared_proofs_count,
-            "efficiency_percentage": (stats.actual_iterations_per_second / stats.target_iterations_per_second as f64) * 100.0
-        })).unwrap_or_else(|_| "Failed to serialize stats".to_string()))
+    pub fn set_require_signed_challenges(&mut self, required: bool) {
+        self.require_signed_challenges = required;
     }
-}
-
-// ====================================================================
-// MAIN VERIFIER IMPLEMENTATION
-// ====================================================================
-
-/// Proof of Storage Verifier - Production Implementation
-/// Handles proof verification, challenge generation, and network monitoring
-#[napi]
-pub struct ProofOfStorageVerifier {
-    verifier_key: Buffer,
-    callbacks: VerifierCallbacks,
-    active_challenges: std::collections::HashMap<String, StorageChallenge>,
-    verification_cache: std::collections::HashMap<String, bool>,
-    total_verifications: u32,
-}
-
-#[napi]
-impl ProofOfStorageVerifier {
-    /// Create new verifier instance
-    #[napi(constructor)]
-    pub fn new(verifier_key: Buffer, callbacks: VerifierCallbacks) -> Result<Self> {
-        validate_public_key(&verifier_key)?;
 
-        Ok(Self {
-            verifier_key,
-            callbacks,
-            active_challenges: std::collections::HashMap::new(),
-            verification_cache: std::collections::HashMap::new(),
-            total_verifications: 0,
-        })
+    /// Chains whose latest commitment is more than `INACTIVE_CHAIN_TIMEOUT_BLOCKS` behind
+    /// `current_height`, sorted by chain id
+    #[napi]
+    pub fn find_inactive_chains(&self, current_height: u32) -> Vec<String> {
+        self.active_chains
+            .iter()
+            .filter(|(_, chain)| {
+                (current_height as u64).saturating_sub(chain.last_activity_height)
+                    > INACTIVE_CHAIN_TIMEOUT_BLOCKS as u64
+            })
+            .map(|(chain_id, _)| chain_id.clone())
+            .collect()
     }
 
-    /// Verify compact storage proof with production consensus validation
+    /// Remove a chain from the active set and stop answering its availability challenges.
+    /// Returns false if the chain is not active.
     #[napi]
-    pub fn verify_compact_proof(&mut self, proof: CompactStorageProof) -> bool {
-        self.total_verifications += 1;
-
-        // Initialize production consensus validator
-        let consensus_validator = crate::consensus::NetworkConsensusValidator::new_production();
-
-        // 1. Verify prover key format
-        if proof.prover_key.len() != 32 {
-            return false;
-        }
-
-        // 2. Verify VDF proof meets consensus requirements
-        if consensus_validator
-            .validate_vdf_consensus(&proof.vdf_proof)
-            .is_err()
-        {
+    pub fn retire_chain(&mut self, chain_id: String) -> bool {
+        if self.detach_chain(&chain_id).is_none() {
             return false;
         }
+        info!("Retired chain {}", chain_id);
+        true
+    }
 
-        // 3. NETWORK CONSENSUS REQUIREMENT: Verify continuous VDF signature
-        // The output_state should be a valid VDF signature, not a recomputed VDF
-        if proof.vdf_proof.output_state.len() != 32 {
-            return false;
+    /// Drop a chain, e.g. after its data file was deleted, releasing its memory map.
+    /// With `deleteFiles` its `.data` and `.hashchain` files are deleted as well.
+    #[napi]
+    pub fn remove_chain(&mut self, chain_id: String, delete_files: Option<bool>) -> Result<()> {
+        let chain = self.detach_chain(&chain_id).ok_or_else(|| {
+            coded_error(
+                Status::InvalidArg,
+                error_codes::E_CHAIN_NOT_FOUND,
+                format!("Chain {} is not active", chain_id),
+            )
+        })?;
+
+        if delete_files.unwrap_or(false) {
+            if let Some(storage) = &chain.storage {
+                for path in [&storage.data_file_path, &storage.hashchain_file_path] {
+                    match std::fs::remove_file(path) {
+                        Ok(()) => {}
+                        // Already gone, e.g. the data file whose deletion prompted removal
+                        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                        Err(e) => {
+                            return Err(HashChainError::Io(e)
+                                .into_napi_error(format!("Failed to delete {}", path)))
+                        }
+                    }
+                }
+            }
+        }
+        info!("Removed chain {}", chain_id);
+        Ok(())
+    }
+
+    /// Rehash a chain's data file, replacing the cached data hash that commitments
+    /// reuse while the file's size and mtime are unchanged
+    #[napi]
+    pub fn recompute_data_hash(&mut self, chain_id: String) -> Result<Buffer> {
+        let chain = self.active_chains.get_mut(&chain_id).ok_or_else(|| {
+            coded_error(
+                Status::InvalidArg,
+                error_codes::E_CHAIN_NOT_FOUND,
+                format!("Chain {} is not active", chain_id),
+            )
+        })?;
+        let data_hash = chain
+            .recompute_data_hash()
+            .map_err(|e| e.into_napi_error("Failed to compute data hash"))?;
+        Ok(Buffer::from(data_hash.to_vec()))
+    }
+
+    /// Export VDF progress, active chains and availability registrations so the
+    /// prover can be resumed elsewhere with `importState`. Chain files stay on disk
+    /// and must be reachable at the same paths.
+    #[napi]
+    pub fn export_state(&self) -> Result<Buffer> {
+        let mut chains = Vec::with_capacity(self.active_chains.len());
+        for (chain_id, chain) in &self.active_chains {
+            let storage = chain.storage.as_ref().ok_or_else(|| {
+                coded_error(
+                    Status::GenericFailure,
+                    error_codes::E_CHAIN_STATE,
+                    format!("Chain {} has no file storage to export", chain_id),
+                )
+            })?;
+            chains.push(ChainStateRecord {
+                chain_id: chain_id.clone(),
+                hashchain_file_path: storage.hashchain_file_path.clone(),
+                chain_length: chain.chain_length,
+                last_activity_height: chain.last_activity_height,
+                current_commitment: chain.current_commitment.as_ref().map(|c| c.to_vec()),
+            });
+        }
+
+        let availability = self
+            .availability_prover
+            .file_registrations()
+            .into_iter()
+            .map(|(chain_id, file_path, total_chunks)| AvailabilityRecord {
+                chain_id,
+                file_path,
+                total_chunks,
+            })
+            .collect();
+
+        let snapshot = ProverStateSnapshot {
+            prover_key: self.prover_key.to_vec(),
+            vdf: self
+                .vdf_processor
+                .capture_state()
+                .map_err(|e| e.into_napi_error("Failed to capture VDF state"))?,
+            chains,
+            availability,
+        };
+        info!(
+            "Exported prover state: {} chains at {} VDF iterations",
+            snapshot.chains.len(),
+            snapshot.vdf.total_iterations
+        );
+        Ok(Buffer::from(snapshot.to_bytes()))
+    }
+
+    /// Resume a prover from `exportState` output. The VDF continues from the exported
+    /// state and iteration count, which must carry a checkpoint signed by this
+    /// prover's key, and chains are reloaded from their files. Only a prover with
+    /// the same key and no active chains can import.
+    #[napi]
+    pub fn import_state(&mut self, state: Buffer) -> Result<()> {
+        let snapshot = ProverStateSnapshot::from_bytes(&state)
+            .map_err(|e| e.into_napi_error("Invalid prover state"))?;
+
+        if snapshot.prover_key.as_slice() != self.prover_key.as_ref() {
+            return Err(coded_error(
+                Status::InvalidArg,
+                error_codes::E_INVALID_INPUT,
+                "Prover state was exported by a different prover key",
+            ));
+        }
+        if !self.active_chains.is_empty() {
+            return Err(coded_error(
+                Status::GenericFailure,
+                error_codes::E_CHAIN_STATE,
+                "Cannot import state into a prover with active chains",
+            ));
+        }
+        check_scale_limit(snapshot.chains.len() as u32, self.max_chains, "chains")?;
+
+        // Load every chain before touching prover state so a failure leaves it unchanged
+        let mut chains = std::collections::BTreeMap::new();
+        for record in &snapshot.chains {
+            let mut chain = IndividualHashChain::load_from_file(record.hashchain_file_path.clone())
+                .map_err(|e| {
+                    e.into_napi_error(format!("Failed to load chain {}", record.chain_id))
+                })?;
+            if hex::encode(chain.get_chain_id()) != record.chain_id {
+                return Err(coded_error(
+                    Status::GenericFailure,
+                    error_codes::E_CORRUPTED,
+                    format!(
+                        "Chain file {} does not match chain {}",
+                        record.hashchain_file_path, record.chain_id
+                    ),
+                ));
+            }
+            chain.chain_length = record.chain_length;
+            chain.last_activity_height = record.last_activity_height;
+            chain.current_commitment = record.current_commitment.clone().map(Buffer::from);
+            chains.insert(record.chain_id.clone(), chain);
+        }
+
+        let total_iterations = snapshot.vdf.total_iterations;
+        self.vdf_processor
+            .restore_state(snapshot.vdf, &self.prover_key)
+            .map_err(|e| e.into_napi_error("Failed to restore VDF state"))?;
+        // The previous commitment was made against a different VDF
+        self.last_commitment_vdf = None;
+
+        self.active_chains = chains;
+        for record in snapshot.availability {
+            self.availability_prover.register_chain(
+                record.chain_id,
+                record.file_path,
+                record.total_chunks,
+            );
+        }
+
+        if !self.active_chains.is_empty() && !self.vdf_processor.is_running() {
+            self.vdf_processor.start();
+        }
+
+        info!(
+            "Imported prover state: {} chains at {} VDF iterations",
+            self.active_chains.len(),
+            total_iterations
+        );
+        Ok(())
+    }
+
+    /// Checkpoint the VDF as a JSON snapshot that `importVdfSnapshot` can resume from
+    /// after a restart. It carries the signed state, memory buffer, last signed block
+    /// and shared proof history, but not the chains that `exportState` includes.
+    #[napi]
+    pub fn export_vdf_snapshot(&self) -> Result<String> {
+        let snapshot = self
+            .vdf_processor
+            .capture_state()
+            .map_err(|e| e.into_napi_error("Failed to snapshot VDF"))?;
+        Ok(snapshot.to_json())
+    }
+
+    /// Resume the VDF from `exportVdfSnapshot` output. The snapshot state and its
+    /// shared proofs must be signed by this prover's key; otherwise the VDF is left
+    /// unchanged and an error is returned.
+    #[napi]
+    pub fn import_vdf_snapshot(&mut self, snapshot: String) -> Result<()> {
+        let snapshot = VDFStateSnapshot::from_json(&snapshot)
+            .map_err(|e| e.into_napi_error("Invalid VDF snapshot"))?;
+        self.vdf_processor
+            .restore_state(snapshot, &self.prover_key)
+            .map_err(|e| e.into_napi_error("Failed to restore VDF snapshot"))?;
+        // The previous commitment was made against a different VDF
+        self.last_commitment_vdf = None;
+        Ok(())
+    }
+
+    /// Reopen this prover's chains stored in an output directory, using its index.json
+    /// when present and current and scanning for .hashchain files otherwise. Chains of
+    /// other provers, retired or already active chains and unreadable files are skipped.
+    /// A rescan rebuilds the index for every prover in the directory. Returns the number
+    /// of chains loaded; if they would exceed the chain limit, none are loaded and an
+    /// error is returned.
+    #[napi]
+    pub fn load_existing_chains(&mut self, output_directory: String) -> Result<u32> {
+        self.load_chains(output_directory, false)
+    }
+
+    /// Like `loadExistingChains`, but each chain's commitment log and data file are
+    /// checked against its header first and mismatched chains are skipped with a
+    /// warning. Reads every data file in full.
+    #[napi]
+    pub fn load_chains_from_directory(&mut self, dir: String) -> Result<u32> {
+        self.load_chains(dir, true)
+    }
+
+    /// Get number of active chains
+    #[napi]
+    pub fn get_active_chain_count(&self) -> u32 {
+        self.active_chains.len() as u32
+    }
+
+    /// Ids of all active chains, sorted
+    #[napi]
+    pub fn get_chain_ids(&self) -> Vec<String> {
+        self.active_chains.keys().cloned().collect()
+    }
+
+    /// Get maximum number of active chains allowed for this prover
+    #[napi]
+    pub fn get_max_chains(&self) -> u32 {
+        self.max_chains
+    }
+
+    /// Set maximum number of active chains allowed for this prover
+    #[napi]
+    pub fn set_max_chains(&mut self, max_chains: u32) -> Result<()> {
+        validate_max_chains(max_chains, self.active_chains.len())?;
+        self.max_chains = max_chains;
+        Ok(())
+    }
+
+    /// Get chain information
+    #[napi]
+    pub fn get_chain_info(&self, chain_id: String) -> Result<String> {
+        let chain = self.active_chains.get(&chain_id).ok_or_else(|| {
+            coded_error(
+                Status::GenericFailure,
+                error_codes::E_CHAIN_NOT_FOUND,
+                "Chain not found",
+            )
+        })?;
+
+        let stats = chain
+            .get_file_stats()
+            .map_err(|e| e.into_napi_error("Failed to get stats"))?;
+
+        Ok(format!(
+            r#"{{"chain_id": "{}", "total_chunks": {}, "chain_length": {}, "data_file_size": {}, "hashchain_file_size": {}}}"#,
+            chain_id,
+            chain.get_total_chunks(),
+            chain.chain_length,
+            stats.data_file_size,
+            stats.hashchain_file_size.unwrap_or(0)
+        ))
+    }
+
+    /// Disk consumed by every active chain's data and hashchain files, plus sidecar
+    /// files (chain index, leftover `_original.data` staging files) and free space in
+    /// each output directory
+    #[napi]
+    pub fn get_disk_usage_report(&self) -> Result<DiskUsageReport> {
+        let mut chains = Vec::with_capacity(self.active_chains.len());
+        let mut directories = std::collections::BTreeSet::new();
+        for (chain_id, chain) in &self.active_chains {
+            let Some(storage) = &chain.storage else {
+                continue;
+            };
+            let stats = chain
+                .get_file_stats()
+                .map_err(|e| e.into_napi_error(format!("Failed to get stats for {}", chain_id)))?;
+            let hashchain_file_size = stats.hashchain_file_size.unwrap_or(0);
+            chains.push(ChainDiskUsage {
+                chain_id: chain_id.clone(),
+                data_file_bytes: stats.data_file_size as f64,
+                hashchain_file_bytes: hashchain_file_size as f64,
+                total_bytes: (stats.data_file_size + hashchain_file_size) as f64,
+            });
+            if let Some(directory) = std::path::Path::new(&storage.data_file_path).parent() {
+                directories.insert(directory.to_path_buf());
+            }
+        }
+
+        let directories: Vec<DirectoryDiskUsage> = directories
+            .into_iter()
+            .map(|directory| {
+                // Staging files are normally deleted; any left behind still use disk
+                let sidecar_bytes: u64 = std::fs::read_dir(&directory)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|entry| entry.ok())
+                    .filter(|entry| {
+                        let name = entry.file_name();
+                        let name = name.to_string_lossy();
+                        name == CHAIN_INDEX_FILE_NAME || name.ends_with("_original.data")
+                    })
+                    .filter_map(|entry| entry.metadata().ok())
+                    .map(|metadata| metadata.len())
+                    .sum();
+                let path = directory.to_string_lossy().into_owned();
+                DirectoryDiskUsage {
+                    free_bytes: crate::core::utils::available_disk_space(&path)
+                        .map(|bytes| bytes as f64),
+                    path,
+                    sidecar_bytes: sidecar_bytes as f64,
+                }
+            })
+            .collect();
+
+        let data_file_bytes: f64 = chains.iter().map(|c| c.data_file_bytes).sum();
+        let hashchain_file_bytes: f64 = chains.iter().map(|c| c.hashchain_file_bytes).sum();
+        let sidecar_bytes: f64 = directories.iter().map(|d| d.sidecar_bytes).sum();
+        Ok(DiskUsageReport {
+            chains,
+            directories,
+            data_file_bytes,
+            hashchain_file_bytes,
+            sidecar_bytes,
+            total_bytes: data_file_bytes + hashchain_file_bytes + sidecar_bytes,
+        })
+    }
+
+    /// Update callbacks
+    #[napi]
+    pub fn update_callbacks(&mut self, callbacks: ProverCallbacks) {
+        self.callbacks = callbacks;
+    }
+
+    /// Get the latest shared VDF proof
+    #[napi]
+    pub fn get_latest_shared_vdf_proof(&self) -> Result<String> {
+        if let Some(proof) = self.vdf_processor.get_latest_shared_proof() {
+            Ok(serde_json::to_string(&serde_json::json!({
+                "vdf_state": hex::encode(proof.vdf_state),
+                "total_iterations": proof.total_iterations,
+                "timestamp": proof.timestamp,
+                "signature": hex::encode(&proof.signature),
+                "proof_chain_hash": hex::encode(proof.proof_chain_hash)
+            }))
+            .unwrap_or_else(|_| "Failed to serialize proof".to_string()))
+        } else {
+            Err(coded_error(
+                Status::GenericFailure,
+                error_codes::E_VDF_NOT_READY,
+                "No shared VDF proof available yet",
+            ))
+        }
+    }
+
+    /// Verify the shared VDF proof chain integrity
+    #[napi]
+    pub fn verify_shared_vdf_proof_chain(&self) -> bool {
+        self.vdf_processor
+            .verify_shared_proof_chain(&self.prover_key)
+    }
+
+    /// Get the serialized shared VDF proof chain for independent verification
+    #[napi]
+    pub fn get_shared_vdf_proof_chain(&self) -> Buffer {
+        Buffer::from(crate::core::vdf_processor::serialize_vdf_proof_chain(
+            &self.vdf_processor.get_all_shared_proofs(),
+        ))
+    }
+
+    /// Respond to a VDF reproduction challenge by revealing the VDF state and memory
+    /// at the challenged iteration together with the state reached after the segment
+    #[napi]
+    pub fn respond_to_vdf_reproduction_challenge(
+        &self,
+        challenge: VDFReproductionChallenge,
+    ) -> Result<VDFReproductionResponse> {
+        if challenge.prover_key.as_ref() != self.prover_key.as_ref() {
+            return Err(coded_error(
+                Status::InvalidArg,
+                error_codes::E_INVALID_INPUT,
+                "Reproduction challenge is not addressed to this prover",
+            ));
+        }
+
+        if challenge.iteration_index < 0.0 || challenge.iteration_index.fract() != 0.0 {
+            return Err(coded_error(
+                Status::InvalidArg,
+                error_codes::E_INVALID_INPUT,
+                format!("Invalid iteration index: {}", challenge.iteration_index),
+            ));
+        }
+
+        let reproduction = self
+            .vdf_processor
+            .respond_to_reproduction_challenge(
+                challenge.iteration_index as u64,
+                challenge.segment_length as u64,
+            )
+            .map_err(|e| e.into_napi_error("VDF reproduction failed"))?;
+
+        Ok(VDFReproductionResponse {
+            iteration_index: reproduction.iteration_index as f64,
+            segment_length: reproduction.segment_length as u32,
+            start_state: Buffer::from(reproduction.start_state.to_vec()),
+            memory_snapshot: Buffer::from(reproduction.memory_snapshot),
+            end_state: Buffer::from(reproduction.end_state.to_vec()),
+        })
+    }
+
+    /// Reveal the VDF state and memory at `iteration`, so a verifier can recompute
+    /// from it to a later published shared VDF proof. Served from memory stored at
+    /// recent shared proofs; replays longer than `VDF_SEED_REPLAY_MAX_ITERATIONS`
+    /// are refused.
+    #[napi]
+    pub fn request_vdf_checkpoint(&self, iteration: f64) -> Result<VDFCheckpoint> {
+        if iteration < 0.0 || iteration.fract() != 0.0 {
+            return Err(coded_error(
+                Status::InvalidArg,
+                error_codes::E_INVALID_INPUT,
+                format!("Invalid iteration: {}", iteration),
+            ));
+        }
+
+        let (state, memory_snapshot) = self
+            .vdf_processor
+            .checkpoint_at(iteration as u64)
+            .map_err(|e| e.into_napi_error("VDF checkpoint unavailable"))?;
+
+        Ok(VDFCheckpoint {
+            iteration,
+            state: Buffer::from(state.to_vec()),
+            memory_snapshot: Buffer::from(memory_snapshot),
+        })
+    }
+
+    /// Attest to all active chains at once: their current commitment hashes are
+    /// Merkle-aggregated and signed once by the prover key, together with the VDF state
+    /// and a timestamp. Per-chain inclusion proofs come from `getPortfolioInclusionProof`.
+    #[napi]
+    pub fn generate_portfolio_proof(&self) -> Result<PortfolioProof> {
+        if self.active_chains.is_empty() {
+            return Err(coded_error(
+                Status::GenericFailure,
+                error_codes::E_NO_ACTIVE_CHAINS,
+                "No active chains to attest",
+            ));
+        }
+
+        let entries = portfolio_entries(&self.active_chains);
+        let chain_ids: Vec<Vec<u8>> = entries.iter().map(|e| e.chain_id.clone()).collect();
+        let portfolio_root = crate::core::portfolio::compute_portfolio_root(&entries);
+        let chain_set_hash = crate::core::portfolio::compute_portfolio_chain_set_hash(&chain_ids);
+        let chain_count = entries.len() as u32;
+
+        let (vdf_state, total_iterations) = self.vdf_processor.get_state();
+        if total_iterations < MIN_VDF_ITERATIONS_PER_BLOCK as u64 {
+            return Err(coded_error(
+                Status::GenericFailure,
+                error_codes::E_VDF_NOT_READY,
+                format!(
+                    "Insufficient VDF iterations: {} < {}",
+                    total_iterations, MIN_VDF_ITERATIONS_PER_BLOCK
+                ),
+            ));
+        }
+        let timestamp = crate::core::utils::get_current_timestamp();
+        let message = crate::core::portfolio::portfolio_signing_message(
+            &self.prover_key,
+            &vdf_state,
+            &portfolio_root,
+            &chain_set_hash,
+            chain_count,
+            timestamp,
+            total_iterations,
+        );
+        let signature = self
+            .signer
+            .sign(&message)
+            .map_err(|e| e.into_napi_error("Failed to sign portfolio"))?;
+
+        Ok(PortfolioProof {
+            prover_key: self.prover_key.clone(),
+            portfolio_root: Buffer::from(portfolio_root.to_vec()),
+            chain_set_hash: Buffer::from(chain_set_hash.to_vec()),
+            chain_count,
+            vdf_state: Buffer::from(vdf_state.to_vec()),
+            total_iterations: total_iterations as f64,
+            signature: Buffer::from(signature),
+            timestamp,
+        })
+    }
+
+    /// Inclusion proof for one chain under the portfolio root of the current commitments.
+    /// It matches a portfolio proof generated while the chain's commitment was unchanged.
+    #[napi]
+    pub fn get_portfolio_inclusion_proof(
+        &self,
+        chain_id: String,
+    ) -> Result<PortfolioInclusionProof> {
+        let chain = self.active_chains.get(&chain_id).ok_or_else(|| {
+            coded_error(
+                Status::InvalidArg,
+                error_codes::E_CHAIN_NOT_FOUND,
+                format!("Chain {} is not active", chain_id),
+            )
+        })?;
+
+        let entries = portfolio_entries(&self.active_chains);
+        let path =
+            crate::core::portfolio::compute_portfolio_inclusion_path(&entries, &chain.chain_id)
+                .ok_or_else(|| {
+                    coded_error(
+                        Status::GenericFailure,
+                        error_codes::E_CHAIN_STATE,
+                        format!("Chain {} is missing from the portfolio", chain_id),
+                    )
+                })?;
+        let commitment_hash = entries
+            .iter()
+            .find(|entry| entry.chain_id == chain.chain_id)
+            .map(|entry| entry.commitment_hash)
+            .unwrap_or([0u8; 32]);
+
+        Ok(PortfolioInclusionProof {
+            chain_id,
+            commitment_hash: Buffer::from(commitment_hash.to_vec()),
+            merkle_path: Buffer::from(crate::core::utils::encode_merkle_path(&path)),
+        })
+    }
+
+    /// Set the VDF target pace in iterations per second to match local hardware and block
+    /// cadence. Consensus minimum iteration checks are unaffected.
+    #[napi]
+    pub fn set_vdf_target_rate(&mut self, iterations_per_second: u32) -> Result<()> {
+        self.vdf_processor
+            .set_target_rate(iterations_per_second as u64)
+            .map_err(|e| coded_error(Status::InvalidArg, error_codes::E_INVALID_INPUT, e))
+    }
+
+    /// Forecast when the VDF will have `requiredIterations` total iterations, to decide
+    /// whether a block can be signed now or VDF-heavy work should wait
+    #[napi]
+    pub fn get_vdf_iterations_forecast(&self, required_iterations: f64) -> VDFIterationsForecast {
+        let forecast = self
+            .vdf_processor
+            .iterations_until_ready(required_iterations.max(0.0) as u64);
+        VDFIterationsForecast {
+            current_iterations: forecast.current_iterations as f64,
+            shortfall: forecast.shortfall as f64,
+            iterations_per_second: forecast.iterations_per_second,
+            estimated_seconds: forecast.estimated_seconds,
+        }
+    }
+
+    /// Whether the current VDF rate can produce the iterations required per block within
+    /// one block interval (defaults: `BLOCK_TIME_SECONDS`, `MIN_VDF_ITERATIONS_PER_BLOCK`).
+    /// Logs a warning when it cannot.
+    #[napi]
+    pub fn is_vdf_viable_for_block_time(
+        &self,
+        block_time_seconds: Option<f64>,
+        min_iterations_per_block: Option<f64>,
+    ) -> bool {
+        self.vdf_processor.is_viable_for_block_time(
+            block_time_seconds.unwrap_or(BLOCK_TIME_SECONDS as f64),
+            min_iterations_per_block.map_or(MIN_VDF_ITERATIONS_PER_BLOCK as u64, |iterations| {
+                iterations.max(0.0) as u64
+            }),
+        )
+    }
+
+    /// Get VDF performance statistics
+    #[napi]
+    pub fn get_vdf_performance_stats(&self) -> Result<String> {
+        let stats = self.vdf_processor.get_performance_stats();
+        Ok(serde_json::to_string(&serde_json::json!({
+            "total_iterations": stats.total_iterations,
+            "elapsed_seconds": stats.elapsed_seconds,
+            "target_iterations_per_second": stats.target_iterations_per_second,
+            "calibrated_iterations_per_second": stats.calibrated_iterations_per_second,
+            "actual_iterations_per_second": stats.actual_iterations_per_second,
+            "shared_proofs_count": stats.shared_proofs_count,
+            "efficiency_percentage": (stats.actual_iterations_per_second / stats.target_iterations_per_second as f64) * 100.0
+        })).unwrap_or_else(|_| "Failed to serialize stats".to_string()))
+    }
+}
+
+impl ProofOfStorageProver {
+    /// Create a prover whose signatures come from `signer`, e.g. one delegating to a
+    /// hardware security module, so the private key never has to be in process memory.
+    /// The signer must produce signatures for `prover_key`.
+    pub fn with_signer(
+        prover_key: Buffer,
+        signer: std::sync::Arc<dyn crate::core::signer::Signer>,
+        callbacks: ProverCallbacks,
+    ) -> Result<Self> {
+        validate_public_key(&prover_key)?;
+        crate::core::signer::check_signer_key(signer.as_ref(), &prover_key)
+            .map_err(|e| e.into_napi_error("Signer cannot sign for the prover key"))?;
+
+        let vdf_processor = VDFProcessor::with_signer(
+            compute_blake3(&prover_key),
+            CONTINUOUS_VDF_MEMORY_KB,
+            DEFAULT_VDF_TARGET_ITERATIONS_PER_SECOND as u64,
+            signer.clone(),
+        );
+
+        // Pace the VDF to this machine's measured speed, then start it
+        vdf_processor.calibrate();
+        vdf_processor.start();
+
+        Ok(Self {
+            prover_key: prover_key.clone(),
+            signer,
+            callbacks,
+            active_chains: std::collections::BTreeMap::new(),
+            availability_prover: crate::core::availability::AvailabilityProver::new(),
+            vdf_processor,
+            total_blocks_processed: 0,
+            last_processing_time_ms: 0.0,
+            max_chains: MAX_CHAINS_PER_INSTANCE,
+            last_commitment_vdf: None,
+            corrupt_chains: std::collections::BTreeSet::new(),
+            authorized_verifiers: std::collections::BTreeSet::new(),
+            require_signed_challenges: false,
+            maintenance_started_at: None,
+            completed_maintenance_seconds: 0.0,
+            network_params: crate::consensus::NetworkParameters::current(),
+        })
+    }
+
+    /// Load this prover's chains from an output directory, as `loadExistingChains`.
+    /// With `verify_files`, chains whose files fail verification are skipped.
+    fn load_chains(&mut self, output_directory: String, verify_files: bool) -> Result<u32> {
+        let active_chains = &self.active_chains;
+        let chains = open_prover_chains(&output_directory, &self.prover_key, verify_files, |id| {
+            active_chains.contains_key(id)
+        })
+        .map_err(|e| e.into_napi_error(format!("Failed to scan {}", output_directory)))?;
+
+        // Check the chain limit for the whole directory before loading any, so a
+        // directory over the limit leaves the prover unchanged
+        let chains: Vec<_> = chains
+            .into_iter()
+            .filter(|(_, chain)| chain.storage.is_some())
+            .collect();
+        check_scale_limit(
+            (self.active_chains.len() + chains.len()) as u32,
+            self.max_chains,
+            "chains",
+        )?;
+
+        let mut loaded = 0u32;
+        for (chain_id, chain) in chains {
+            let Some(storage) = &chain.storage else {
+                continue;
+            };
+
+            self.availability_prover.register_chain(
+                chain_id.clone(),
+                storage.data_file_path.clone(),
+                chain.get_total_chunks() as u32,
+            );
+            self.active_chains.insert(chain_id, chain);
+            loaded += 1;
+        }
+
+        if !self.active_chains.is_empty() && !self.vdf_processor.is_running() {
+            self.vdf_processor.start();
+        }
+
+        info!(
+            "Loaded {} existing chains from {}",
+            loaded, output_directory
+        );
+        Ok(loaded)
+    }
+
+    /// Take a chain out of the active set: close its memory map, drop it from the
+    /// chain index and stop answering its availability challenges
+    fn detach_chain(&mut self, chain_id: &str) -> Option<IndividualHashChain> {
+        let mut chain = self.active_chains.remove(chain_id)?;
+
+        if let Some(storage) = chain.storage.as_mut() {
+            storage.close_mmap();
+            if let Some(directory) = std::path::Path::new(&storage.hashchain_file_path).parent() {
+                let directory = directory.to_string_lossy();
+                if let Err(e) = ChainIndex::retire_chain(&directory, chain_id) {
+                    log::warn!("Failed to update chain index in {}: {}", directory, e);
+                }
+            }
+        }
+        self.availability_prover.unregister_chain(chain_id);
+        self.corrupt_chains.remove(chain_id);
+        Some(chain)
+    }
+
+    /// Reject a new chain of `file_size` bytes that is below the minimum file size or
+    /// would exceed the per-prover chain limit, before any storage is allocated
+    fn check_new_chain(&self, file_size: u64) -> Result<()> {
+        let min_file_size = self.network_params.min_file_size();
+        if file_size < min_file_size {
+            return Err(coded_error(
+                Status::InvalidArg,
+                error_codes::E_CHUNK_COUNT,
+                format!(
+                    "File too small: {} bytes (minimum: {} bytes)",
+                    file_size, min_file_size
+                ),
+            ));
+        }
+
+        // Enforce the per-prover chain limit
+        check_scale_limit(
+            self.active_chains.len() as u32 + 1,
+            self.max_chains,
+            "chains",
+        )?;
+        Ok(())
+    }
+
+    /// Start the VDF for the first chain and wait for the minimum iterations
+    fn ensure_vdf_started(&mut self) {
+        // CRITICAL: Start VDF immediately when first chain is created
+        if self.active_chains.is_empty() {
+            info!("🚀 Starting VDF processor for first chain - Network Consensus Requirement");
+            self.vdf_processor.start();
+
+            // Wait for VDF to reach minimum iterations required by network consensus
+            let required_iterations = self.network_params.min_vdf_iterations as u64;
+            info!(
+                "⏳ Waiting for VDF to reach minimum {} iterations...",
+                required_iterations
+            );
+            if self.vdf_processor.wait_for_iterations(required_iterations) {
+                info!(
+                    "✅ VDF reached {} iterations - Network Consensus Met",
+                    self.vdf_processor.get_iteration_count()
+                );
+            }
+        }
+    }
+
+    /// Register a newly created chain, generate its genesis commitment and make it active.
+    /// `entropy_seed` deterministically derives the commitment's blockchain entropy.
+    fn commit_new_chain(
+        &mut self,
+        chain: IndividualHashChain,
+        output_directory: String,
+        entropy_seed: &[u8],
+        start_time: std::time::Instant,
+    ) -> Result<StorageCommitment> {
+        let chain_id = hex::encode(chain.get_chain_id());
+        let total_chunks = chain.get_total_chunks();
+
+        // Register chain for availability proving
+        if let Some(storage) = &chain.storage {
+            self.availability_prover.register_chain(
+                chain_id.clone(),
+                storage.data_file_path.clone(),
+                total_chunks as u32,
+            );
+        }
+
+        // Generate real multi-source entropy
+        let entropy = crate::core::utils::commitment_entropy(&self.prover_key, entropy_seed);
+        let combined_entropy = entropy.combined_hash.clone();
+
+        // Select chunks using deterministic algorithm
+        let selected_chunks = crate::core::utils::select_chunks_deterministic(
+            &combined_entropy,
+            total_chunks as f64,
+            self.network_params.chunks_per_block,
+        );
+
+        // Read actual chunk data and compute real hashes
+        let mut chunk_hashes = Vec::new();
+        let mut chain_mut = chain;
+        for &chunk_idx in &selected_chunks {
+            let chunk_data = chain_mut
+                .read_chunk(chunk_idx)
+                .map_err(|e| e.into_napi_error(format!("Failed to read chunk {}", chunk_idx)))?;
+            let chunk_hash = crate::core::utils::compute_blake3(&chunk_data);
+            chunk_hashes.push(Buffer::from(chunk_hash.to_vec()));
+        }
+
+        // NETWORK CONSENSUS REQUIREMENT: Get VDF signature for this block
+        let block_height = 0u64;
+        let block_hash = [0u8; 32]; // Genesis block hash
+        let required_iterations = self.network_params.min_vdf_iterations as u64;
+
+        let vdf_signature = self
+            .vdf_processor
+            .sign_block(block_height, block_hash, required_iterations)
+            .map_err(|e| {
+                coded_error(
+                    Status::GenericFailure,
+                    error_codes::E_VDF_NOT_READY,
+                    format!("VDF signature required by network consensus: {}", e),
+                )
+            })?;
+
+        // Get current VDF state for proof
+        let (vdf_state, total_iterations) = self.vdf_processor.get_state();
+
+        // Create VDF proof with continuous VDF signature (NETWORK CONSENSUS STANDARD)
+        let vdf_proof = MemoryHardVDFProof {
+            input_state: Buffer::from(vdf_state.to_vec()),
+            output_state: Buffer::from(vdf_signature.to_vec()), // VDF signature as output
+            iterations: total_iterations as u32,
+            memory_access_samples: Vec::new(), // Not needed for continuous VDF
+            computation_time_ms: 0.0, // Continuous VDF doesn't have discrete computation time
+            memory_usage_bytes: 256.0 * 1024.0, // 256KB constant memory
+        };
+
+        // Compute real commitment hash
+        let data_hash = chain_mut
+            .data_hash()
+            .map_err(|e| e.into_napi_error("Failed to compute data hash"))?;
+        let merkle_root = chain_mut
+            .merkle_root()
+            .map_err(|e| e.into_napi_error("Failed to compute Merkle root"))?;
+
+        let commitment_hash =
+            crate::core::utils::compute_commitment_hash(&crate::core::utils::CommitmentParams {
+                prover_key: &self.prover_key,
+                data_hash: &data_hash,
+                block_height: 0,
+                block_hash: &[0u8; 32],
+                selected_chunks: &selected_chunks,
+                chunk_hashes: &chunk_hashes.iter().map(|h| h.to_vec()).collect::<Vec<_>>(),
+                vdf_output: &vdf_signature, // Use VDF signature in commitment
+                entropy_hash: &combined_entropy,
+                merkle_root: Some((&merkle_root, total_chunks)),
+            });
+
+        let commitment = StorageCommitment {
+            prover_key: self.prover_key.clone(),
+            data_hash: Buffer::from(data_hash.to_vec()),
+            block_height: 0,
+            block_hash: Buffer::from([0u8; 32].to_vec()),
+            selected_chunks,
+            chunk_hashes,
+            vdf_proof,
+            entropy,
+            commitment_hash: Buffer::from(commitment_hash.to_vec()),
+            merkle_root: Some(Buffer::from(merkle_root.to_vec())),
+            total_chunks: Some(total_chunks as f64),
+        };
+
+        // Record the chain's files so loadExistingChains can find them without a scan;
+        // the index is only a cache, so a failed write does not fail the store
+        if let Some(entry) = ChainIndexEntry::for_chain(&chain_mut) {
+            if let Err(e) = ChainIndex::record_chain(&output_directory, chain_id.clone(), entry) {
+                log::warn!(
+                    "Failed to update chain index in {}: {}",
+                    output_directory,
+                    e
+                );
+            }
+        }
+
+        // Store the chain
+        self.active_chains.insert(chain_id, chain_mut);
+
+        // Update performance metrics
+        let elapsed_ms = start_time.elapsed().as_millis() as f64;
+        self.last_processing_time_ms = elapsed_ms;
+        self.total_blocks_processed += 1;
+
+        info!("✅ Block created with VDF signature - Network Consensus Validated");
+        Ok(commitment)
+    }
+}
+
+// ====================================================================
+// MAIN VERIFIER IMPLEMENTATION
+// ====================================================================
+
+/// Verification results by commitment hash, shareable between verifiers and threads
+pub type VerificationCache =
+    std::sync::Arc<std::sync::RwLock<std::collections::HashMap<String, bool>>>;
+
+/// Proof of Storage Verifier - Production Implementation
+/// Handles proof verification, challenge generation, and network monitoring
+#[napi]
+pub struct ProofOfStorageVerifier {
+    verifier_key: Buffer,
+    // NAPI callbacks; absent for verifiers built around a native blockchain adapter
+    callbacks: Option<VerifierCallbacks>,
+    blockchain_adapter: Option<Box<dyn BlockchainAdapter>>,
+    active_challenges: std::collections::HashMap<String, StorageChallenge>,
+    // Locked so verifications run through `&self` and can proceed concurrently
+    verification_cache: VerificationCache,
+    // Replay protection: (prover key, data hash) -> (highest accepted block height,
+    // commitment hash, VDF iterations). Only the latest height per chain is kept,
+    // bounding memory by chain count.
+    seen_proofs: std::sync::RwLock<crate::consensus::SeenProofs>,
+    // Whether commitments must carry beacon entropy verified through the beacon callback
+    beacon_required: bool,
+    // Which optional consensus checks (latency, beacon, location) are applied
+    strictness: crate::consensus::ValidationStrictness,
+    // Private key matching `verifier_key`, used to sign issued storage challenges
+    signing_key: Option<Buffer>,
+    // Network parameters by activation height; proofs are checked against those
+    // in force at their block height
+    parameter_schedule: crate::consensus::ParameterSchedule,
+    // Provers found through the discovery callback, cached with a TTL
+    prover_discovery: crate::core::discovery::ProverDiscovery,
+    // Block height each recently accepted VDF signature was bound to, per prover
+    vdf_signatures: std::sync::Mutex<crate::core::signature_cache::VdfSignatureCache>,
+    total_verifications: std::sync::atomic::AtomicU32,
+}
+
+#[napi]
+impl ProofOfStorageVerifier {
+    /// Create new verifier instance. `consensusParams` must match the values the
+    /// network's provers were created with.
+    #[napi(constructor)]
+    pub fn new(
+        verifier_key: Buffer,
+        callbacks: VerifierCallbacks,
+        consensus_params: Option<ConsensusParams>,
+    ) -> Result<Self> {
+        validate_public_key(&verifier_key)?;
+        let network_params = network_parameters_from(consensus_params)?;
+
+        Ok(Self {
+            verifier_key,
+            callbacks: Some(callbacks),
+            blockchain_adapter: None,
+            active_challenges: std::collections::HashMap::new(),
+            verification_cache: VerificationCache::default(),
+            seen_proofs: std::sync::RwLock::default(),
+            beacon_required: BEACON_ENTROPY_REQUIRED,
+            strictness: crate::consensus::ValidationStrictness::Production,
+            signing_key: None,
+            parameter_schedule: crate::consensus::ParameterSchedule::new(network_params),
+            prover_discovery: crate::core::discovery::ProverDiscovery::default(),
+            vdf_signatures: std::sync::Mutex::default(),
+            total_verifications: std::sync::atomic::AtomicU32::new(0),
+        })
+    }
+
+    /// Verify compact storage proof with production consensus validation.
+    /// Proofs at or below the last accepted block height of the prover's chain (its data
+    /// hash) are rejected as replays.
+    /// Proofs showing less than `MIN_VDF_ITERATIONS_PER_BLOCK` of VDF progress per block since
+    /// the chain's previous proof are rejected as reusing a stale VDF output.
+    #[napi]
+    pub fn verify_compact_proof(&self, proof: CompactStorageProof) -> bool {
+        self.count_verification();
+        self.check_compact_proof(proof, crate::consensus::accept_compact_proof)
+    }
+
+    /// Verify many compact proofs in one call, with the same results, in the same order,
+    /// as calling `verifyCompactProof` on each in turn. Stateless checks run in parallel
+    /// and once per distinct commitment hash; a repeated commitment hash is rejected as
+    /// a replay. Accepted proofs enter the verification cache together.
+    #[napi]
+    pub fn verify_compact_proofs_batch(&self, proofs: Vec<CompactStorageProof>) -> Vec<bool> {
+        self.total_verifications
+            .fetch_add(proofs.len() as u32, std::sync::atomic::Ordering::Relaxed);
+
+        let consensus_validators: Vec<_> = proofs
+            .iter()
+            .map(|proof| self.validator_at(proof.block_height))
+            .collect();
+        let results = crate::consensus::verify_compact_proofs_batch(
+            &mut self.seen_proofs.write().unwrap(),
+            &proofs,
+            &consensus_validators,
+            crate::core::utils::get_current_timestamp(),
+            |block_height| self.check_block_anchor(block_height, None),
+        );
+
+        let accepted = proofs
+            .iter()
+            .zip(&results)
+            .filter(|(_, &accepted)| accepted)
+            .map(|(proof, _)| (hex::encode(&proof.commitment_hash), true));
+        self.verification_cache.write().unwrap().extend(accepted);
+
+        results
+    }
+
+    /// Verify full storage proof, optionally against the prover's chain header
+    #[napi]
+    pub fn verify_full_proof(
+        &self,
+        env: Env,
+        proof: FullStorageProof,
+        header: Option<HashChainHeader>,
+    ) -> bool {
+        self.count_verification();
+
+        // Verify basic structure
+        if proof.all_chunk_hashes.is_empty() {
+            return false;
+        }
+
+        // Link the committed data hash to verifiable chunk content
+        if let Some(header) = &header {
+            if !self.verify_data_hash_binding(proof.commitment.clone(), header.clone()) {
+                return false;
+            }
+        }
+
+        // Anchor the committed block hash to the native blockchain, when one is attached
+        if let Err(e) = self.check_block_anchor(
+            proof.commitment.block_height,
+            Some(&proof.commitment.block_hash),
+        ) {
+            log::warn!("Full proof rejected: {}", e);
+            return false;
+        }
+        if let Err(e) = self.check_beacon_entropy(
+            &env,
+            &proof.commitment.entropy,
+            proof.commitment.block_height,
+        ) {
+            log::warn!("Full proof rejected: {}", e);
+            return false;
+        }
+        match crate::consensus::verify_chunk_hashes_merkle_binding(
+            &proof.all_chunk_hashes,
+            &proof.merkle_tree,
+            header.as_ref(),
+        ) {
+            Ok(true) => {}
+            Ok(false) => return false,
+            Err(e) => {
+                log::warn!("Full proof rejected: {}", e);
+                return false;
+            }
+        }
+
+        // The tree's shape must match the claimed chunk count, not just its root
+        let total_chunks = header
+            .as_ref()
+            .map_or(proof.all_chunk_hashes.len() as u64, |header| {
+                header.total_chunks as u64
+            });
+        if !crate::consensus::verify_merkle_chunk_count(
+            &proof.all_chunk_hashes,
+            &proof.merkle_tree,
+            total_chunks,
+        ) {
+            log::warn!(
+                "Full proof rejected: Merkle tree is not built from {} chunks",
+                total_chunks
+            );
+            return false;
+        }
+
+        // Reject unsorted or duplicate chunk selections before deeper checks
+        if let Err(e) = crate::consensus::validate_chunk_ordering(&proof.commitment.selected_chunks)
+        {
+            log::warn!("Full proof rejected: {}", e);
+            return false;
+        }
+
+        if let Err(e) = self.check_vdf_inputs(&proof) {
+            log::warn!("Full proof rejected: {}", e);
+            return false;
+        }
+
+        // VDF work must cover the amount of data the proof claims
+        let validator = self.validator_at(proof.commitment.block_height);
+        if let Err(e) = validator.validate_vdf_work_for_data(
+            proof.commitment.vdf_proof.iterations,
+            proof.all_chunk_hashes.len() as u64,
+        ) {
+            log::warn!("Full proof rejected: {}", e);
+            return false;
+        }
+
+        // Verify commitment; a compact proof of it may already have been accepted
+        let compact_proof = crate::consensus::compact_proof_from_commitment(
+            proof.commitment,
+            Buffer::from([0u8; 32].to_vec()),
+        );
+        self.check_compact_proof(
+            compact_proof,
+            crate::consensus::accept_full_proof_commitment,
+        )
+    }
+
+    /// Verify a full proof against the chunk count registered on the blockchain rather
+    /// than the count the prover reports. The count is fetched through
+    /// `blockchainData.getDataFileMetadata(dataHashHex)`, whose result must carry
+    /// `totalChunks`. A proof or header claiming another count is rejected, and the
+    /// chunk selection must follow from the registered count.
+    #[napi]
+    pub fn verify_full_proof_against_registration(
+        &self,
+        env: Env,
+        proof: FullStorageProof,
+        header: Option<HashChainHeader>,
+    ) -> bool {
+        let registered_total_chunks =
+            match self.registered_total_chunks(&env, &proof.commitment.data_hash) {
+                Ok(total_chunks) => total_chunks,
+                Err(e) => {
+                    log::warn!("Full proof rejected: {}", e);
+                    return false;
+                }
+            };
+        if let Err(e) = crate::consensus::verify_registered_chunk_count(
+            &proof.all_chunk_hashes,
+            header.as_ref(),
+            registered_total_chunks,
+        ) {
+            log::warn!("Full proof rejected: chunk count violation: {}", e);
+            return false;
+        }
+
+        let validator = self.validator_at(proof.commitment.block_height);
+        if let Err(e) = validator.validate_chunk_selection_consensus(
+            &proof.commitment.entropy,
+            registered_total_chunks as u32,
+            &proof.commitment.selected_chunks,
+        ) {
+            log::warn!("Full proof rejected: {}", e);
+            return false;
+        }
+
+        self.verify_full_proof(env, proof, header)
+    }
+
+    /// Verify a full proof for a chain whose Merkle tree was verified in an earlier block.
+    /// When the tree root matches `previous_verified_root` the unchanged tree is skipped and only
+    /// the new commitment, its chunk selection and VDF continuity are checked.
+    #[napi]
+    pub fn verify_full_proof_incremental(
+        &self,
+        env: Env,
+        proof: FullStorageProof,
+        previous_verified_root: Buffer,
+    ) -> VerificationResult {
+        self.count_verification();
+
+        let merkle_root = proof
+            .merkle_tree
+            .first()
+            .cloned()
+            .unwrap_or_else(|| Buffer::from(Vec::new()));
+        let reject = |reason: String, tree_reverified: bool| {
+            log::warn!("Incremental full proof rejected: {}", reason);
+            VerificationResult {
+                valid: false,
+                merkle_root: merkle_root.clone(),
+                tree_reverified,
+                failure_reason: Some(reason),
+                strictness: self.strictness.name().to_string(),
+            }
+        };
+
+        if proof.all_chunk_hashes.is_empty() {
+            return reject("Proof has no chunk hashes".to_string(), false);
+        }
+
+        // Skip the tree only when it is the one already verified for this chain
+        let tree_reverified = previous_verified_root.len() != HASH_SIZE
+            || merkle_root.as_ref() != previous_verified_root.as_ref();
+        if tree_reverified {
+            match crate::consensus::verify_chunk_hashes_merkle_binding(
+                &proof.all_chunk_hashes,
+                &proof.merkle_tree,
+                None,
+            ) {
+                Ok(true) => {}
+                Ok(false) => {
+                    return reject("Merkle tree does not match chunk hashes".to_string(), true)
+                }
+                Err(e) => return reject(e.to_string(), true),
+            }
+            if !crate::consensus::verify_merkle_chunk_count(
+                &proof.all_chunk_hashes,
+                &proof.merkle_tree,
+                proof.all_chunk_hashes.len() as u64,
+            ) {
+                return reject(
+                    "Merkle tree shape does not match the chunk count".to_string(),
+                    true,
+                );
+            }
+        }
+
+        // Chunk selection for the new block must be ordered and within the verified data
+        let selected_chunks = &proof.commitment.selected_chunks;
+        if let Err(e) = crate::consensus::validate_chunk_ordering(selected_chunks) {
+            return reject(e, tree_reverified);
+        }
+        let total_chunks = proof.all_chunk_hashes.len() as u32;
+        if let Some(&chunk_idx) = selected_chunks.iter().find(|&&idx| idx >= total_chunks) {
+            return reject(
+                format!(
+                    "Selected chunk {} out of range [0, {})",
+                    chunk_idx, total_chunks
+                ),
+                tree_reverified,
+            );
+        }
+
+        // The commitment's chunk hashes must be the selected leaves under the verified
+        // root; when the tree was skipped this is the only binding to it
+        if !crate::consensus::verify_selected_chunks_in_tree(
+            &proof.all_chunk_hashes,
+            &proof.merkle_tree,
+            selected_chunks,
+            &proof.commitment.chunk_hashes,
+            &merkle_root,
+        ) {
+            return reject(
+                "Commitment chunk hashes are not included under the Merkle root".to_string(),
+                tree_reverified,
+            );
+        }
+
+        // VDF chain must end at the new commitment's VDF proof
+        match proof.vdf_chain.last() {
+            Some(last)
+                if last.output_state.as_ref()
+                    == proof.commitment.vdf_proof.output_state.as_ref() => {}
+            _ => {
+                return reject(
+                    "VDF chain does not end at the commitment's VDF proof".to_string(),
+                    tree_reverified,
+                )
+            }
+        }
+        if let Err(e) = self.check_vdf_inputs(&proof) {
+            return reject(e, tree_reverified);
+        }
+
+        if let Err(e) = self.check_block_anchor(
+            proof.commitment.block_height,
+            Some(&proof.commitment.block_hash),
+        ) {
+            return reject(e, tree_reverified);
+        }
+        if let Err(e) = self.check_beacon_entropy(
+            &env,
+            &proof.commitment.entropy,
+            proof.commitment.block_height,
+        ) {
+            return reject(e, tree_reverified);
+        }
+
+        // New commitment, VDF proof and replay protection; a compact proof of the
+        // commitment may already have been accepted
+        let compact_proof = crate::consensus::compact_proof_from_commitment(
+            proof.commitment,
+            Buffer::from([0u8; 32].to_vec()),
+        );
+        if !self.check_compact_proof(
+            compact_proof,
+            crate::consensus::accept_full_proof_commitment,
+        ) {
+            return reject(
+                "Commitment failed compact verification".to_string(),
+                tree_reverified,
+            );
+        }
+
+        VerificationResult {
+            valid: true,
+            merkle_root: merkle_root.clone(),
+            tree_reverified,
+            failure_reason: None,
+            strictness: self.strictness.name().to_string(),
+        }
+    }
+
+    /// Require every verified commitment to carry beacon entropy
+    #[napi]
+    pub fn set_beacon_required(&mut self, required: bool) {
+        self.beacon_required = required;
+    }
+
+    /// Set how strictly proofs are validated: "development" accepts short VDFs and
+    /// skips latency, beacon and location checks, "staging" checks latency but does
+    /// not require beacon entropy or location proofs, and "production" applies all
+    /// checks (the default)
+    #[napi]
+    pub fn set_validation_strictness(&mut self, strictness: String) -> Result<()> {
+        self.strictness = crate::consensus::ValidationStrictness::from_name(&strictness)
+            .ok_or_else(|| {
+                coded_error(
+                    Status::InvalidArg,
+                    error_codes::E_INVALID_INPUT,
+                    format!("Unknown validation strictness: {}", strictness),
+                )
+            })?;
+        if self.strictness != crate::consensus::ValidationStrictness::Production {
+            log::warn!(
+                "Verifier validating proofs at {} strictness",
+                self.strictness.name()
+            );
+        }
+        Ok(())
+    }
+
+    /// Strictness proofs are validated under, which tags verification results
+    #[napi]
+    pub fn get_validation_strictness(&self) -> String {
+        self.strictness.name().to_string()
+    }
+
+    /// Verify a commitment's beacon entropy is a genuine beacon output for its block,
+    /// using the `beacon.verifyRandomness` callback
+    #[napi]
+    pub fn verify_commitment_beacon(&self, env: Env, commitment: StorageCommitment) -> bool {
+        match self.check_beacon_entropy(&env, &commitment.entropy, commitment.block_height) {
+            Ok(()) => true,
+            Err(e) => {
+                log::warn!("Commitment beacon rejected: {}", e);
+                false
+            }
+        }
+    }
+
+    /// Verify a commitment's data hash matches the data binding recorded in a chain header
+    #[napi]
+    pub fn verify_data_hash_binding(
+        &self,
+        commitment: StorageCommitment,
+        header: HashChainHeader,
+    ) -> bool {
+        match crate::consensus::verify_data_hash_binding_internal(&commitment, &header) {
+            Ok(valid) => valid,
+            Err(e) => {
+                log::warn!("Data hash binding rejected: {}", e);
+                false
+            }
+        }
+    }
+
+    /// Verify a compact proof's Merkle paths connect its latest commitment through its
+    /// group and regional proofs to the expected global root
+    #[napi]
+    pub fn verify_compact_proof_hierarchy(
+        &self,
+        proof: CompactProof,
+        expected_global: Buffer,
+    ) -> bool {
+        crate::hierarchy::verify_compact_proof_hierarchy(&proof, &expected_global)
+    }
+
+    /// Verify an ultra-compact proof against the expected global root: the proof must
+    /// serialize to its fixed size, reference `expected_global`, and carry the hash of
+    /// a `position` that is possible under the parameters at its block height
+    #[napi]
+    pub fn verify_ultra_compact_proof(
+        &self,
+        proof: UltraCompactProof,
+        position: HierarchicalPosition,
+        expected_global: Buffer,
+    ) -> bool {
+        self.count_verification();
+        if let Err(e) = proof.serialize() {
+            log::warn!("Ultra-compact proof rejected: {}", e);
+            return false;
+        }
+        if proof.global_proof_reference.as_ref() != expected_global.as_ref() {
+            log::warn!("Ultra-compact proof rejected: global proof reference mismatch");
+            return false;
+        }
+        self.verify_ultra_compact_position(proof, position)
+    }
+
+    /// Verify an ultra-compact proof's claimed hierarchical position: the proof's
+    /// position hash must commit to `position`, which must be within bounds of its
+    /// level, under the right parent, and consistent with the proof's total chain count
+    #[napi]
+    pub fn verify_ultra_compact_position(
+        &self,
+        proof: UltraCompactProof,
+        position: HierarchicalPosition,
+    ) -> bool {
+        let expected =
+            crate::core::utils::compute_ultra_compact_position_hash(&proof.chain_hash, &position);
+        if proof.hierarchical_position.as_ref() != expected.as_slice() {
+            log::warn!("Ultra-compact proof rejected: hierarchical position hash mismatch");
+            return false;
+        }
+        let validator = self.validator_at(proof.global_block_height as u32);
+        match validator.validate_hierarchical_position(&position, proof.total_chains_count) {
+            Ok(()) => true,
+            Err(e) => {
+                log::warn!("Ultra-compact proof position rejected: {}", e);
+                false
+            }
+        }
+    }
+
+    /// Fingerprint of the network parameters this verifier applies to proofs at
+    /// `block_height`, for checking a node's upgrade schedule against the network's
+    #[napi]
+    pub fn get_parameters_fingerprint_at(&self, block_height: u32) -> Buffer {
+        Buffer::from(
+            self.parameter_schedule
+                .parameters_at(block_height as u64)
+                .fingerprint()
+                .to_vec(),
+        )
+    }
+
+    /// Verify a proof was generated within `PROOF_WINDOW_BLOCKS` of its committed block,
+    /// rejecting stale submissions for blocks whose window has closed
+    #[napi]
+    pub fn verify_proof_window_timing(
+        &self,
+        proof_block_height: u32,
+        proof_timestamp: f64,
+        current_block_height: u32,
+    ) -> bool {
+        let validator = self.validator_at(proof_block_height);
+        match validator.validate_proof_window_timing(
+            proof_block_height,
+            proof_timestamp,
+            current_block_height,
+            crate::core::utils::get_current_timestamp(),
+        ) {
+            Ok(()) => true,
+            Err(e) => {
+                log::warn!("Proof window timing rejected: {}", e);
+                false
+            }
+        }
+    }
+
+    /// Verify a proof's VDF iteration count is consistent with the time elapsed since
+    /// its chain started at `chain_start_time`, rejecting proofs claiming more VDF
+    /// work than the fastest hardware could have done in that time
+    #[napi]
+    pub fn verify_vdf_elapsed_time(
+        &self,
+        proof: CompactStorageProof,
+        chain_start_time: f64,
+    ) -> bool {
+        let validator = self.validator_at(proof.block_height);
+        match validator.validate_vdf_elapsed_time(
+            proof.vdf_proof.iterations as u64,
+            chain_start_time,
+            proof.timestamp,
+        ) {
+            Ok(()) => true,
+            Err(e) => {
+                log::warn!(
+                    "VDF timing rejected for prover {}: {}",
+                    hex::encode(&proof.prover_key),
+                    e
+                );
+                false
+            }
+        }
+    }
+
+    /// Verify the consensus-relevant fields of full proof metadata against the proof's VDF.
+    /// Advisory fields (system stats, performance metrics, guide, features) are ignored.
+    #[napi]
+    pub fn verify_full_proof_metadata(
+        &self,
+        metadata: FullProofMetadata,
+        vdf_proof: MemoryHardVDFProof,
+    ) -> bool {
+        let validator =
+            crate::consensus::NetworkConsensusValidator::for_strictness(self.strictness);
+        match validator.validate_proof_metadata_consensus(&metadata.consensus_fields(), &vdf_proof)
+        {
+            Ok(()) => true,
+            Err(e) => {
+                log::warn!("Full proof metadata rejected: {}", e);
+                false
+            }
+        }
+    }
+
+    /// Verify challenge response against the prover's `commitment`.
+    /// Each returned chunk must hash to the commitment's chunk hash at the position of
+    /// that chunk in its selected chunks. Chunks the commitment did not select need a
+    /// committed Merkle root instead. When the commitment has a root, every challenged
+    /// chunk's hash is also rebuilt up to it with its Merkle proof. Sub-chunk responses
+    /// need each revealed byte range to rebuild the committed hash of its chunk.
+    #[napi]
+    pub fn verify_challenge_response(
+        &self,
+        response: ChallengeResponse,
+        original_challenge: StorageChallenge,
+        commitment: StorageCommitment,
+    ) -> bool {
+        crate::core::utils::verify_challenge_response_data(
+            &response,
+            &original_challenge,
+            &commitment,
+        )
+    }
+
+    /// Verify a challenge response came from the prover's claimed network position.
+    /// The latency bound into its access proof must agree with `verifierRttMs`, the
+    /// verifier's own round-trip measurement to the prover. The access proof VDF is
+    /// recomputed, which bounds the computation time the prover may claim. The
+    /// response must have been received (`receivedAt`) within a window consistent with
+    /// the measured round trip.
+    #[napi]
+    pub fn verify_challenge_response_latency(
+        &self,
+        response: ChallengeResponse,
+        original_challenge: StorageChallenge,
+        received_at: f64,
+        verifier_rtt_ms: f64,
+    ) -> bool {
+        let Some(measured_latency_ms) = response.measured_latency_ms else {
+            log::warn!("Challenge response is not bound to a latency measurement");
+            return false;
+        };
+        if response.challenge_id.as_ref() != original_challenge.challenge_id.as_ref() {
+            return false;
+        }
+        if !crate::consensus::network_latency::latency_matches_measurement(
+            measured_latency_ms,
+            verifier_rtt_ms,
+        ) {
+            log::warn!(
+                "Challenge response claims {}ms latency, verifier measured {}ms",
+                measured_latency_ms,
+                verifier_rtt_ms
+            );
+            return false;
+        }
+
+        let expected_input = crate::consensus::network_latency::latency_bound_access_input(
+            &original_challenge.challenge_id,
+            measured_latency_ms,
+        );
+        let computation_time_ms = match crate::consensus::network_latency::verify_access_proof_work(
+            &response.access_proof,
+            &expected_input,
+            CHALLENGE_ACCESS_PROOF_ITERATIONS,
+            CHALLENGE_ACCESS_PROOF_MEMORY_KB,
+        ) {
+            Ok(computation_time_ms) => computation_time_ms,
+            Err(e) => {
+                log::warn!("Challenge response access proof rejected: {}", e);
+                return false;
+            }
+        };
+
+        let consistent = crate::consensus::network_latency::verify_response_latency_window(
+            verifier_rtt_ms,
+            original_challenge.timestamp,
+            received_at,
+            computation_time_ms,
+        );
+        if !consistent {
+            log::warn!(
+                "Challenge response timing is inconsistent with the {}ms measured round trip",
+                verifier_rtt_ms
+            );
+        }
+        consistent
+    }
+
+    /// Generate challenge for prover.
+    /// With `constrainToCommitted`, chunks are chosen only from `committedChunks`
+    /// (the commitment's selected chunks) instead of independently.
+    /// With `subChunk`, the prover must reveal the bytes at a random offset within
+    /// each chunk instead of returning whole chunks.
+    #[napi]
+    pub fn generate_challenge(
+        &mut self,
+        prover_key: Buffer,
+        commitment_hash: Buffer,
+        constrain_to_committed: Option<bool>,
+        committed_chunks: Option<Vec<u32>>,
+        sub_chunk: Option<bool>,
+    ) -> Result<StorageChallenge> {
+        validate_public_key(&prover_key)?;
+        validate_hash_buffer(&commitment_hash, "Commitment hash")?;
+
+        let committed_chunks = if constrain_to_committed.unwrap_or(false) {
+            match committed_chunks {
+                Some(chunks) if !chunks.is_empty() => Some(chunks),
+                _ => {
+                    return Err(coded_error(
+                        Status::InvalidArg,
+                        error_codes::E_INVALID_INPUT,
+                        "Committed chunks are required to constrain a challenge",
+                    ))
+                }
+            }
+        } else {
+            None
+        };
+
+        // Generate challenge ID
+        let challenge_nonce = crate::core::utils::generate_proof_nonce(&prover_key);
+        let challenge_id = crate::core::utils::compute_blake3(
+            &[&prover_key[..], &commitment_hash[..], &challenge_nonce[..]].concat(),
+        );
+
+        // Select chunks to challenge (typically 4 out of 16) using deterministic algorithm
+        let challenge_seed = crate::core::utils::generate_multi_source_entropy(
+            &prover_key,
+            Some(&commitment_hash),
+            &challenge_nonce,
+        );
+        let challenged_chunks = match &committed_chunks {
+            Some(committed) => crate::core::utils::select_chunks_from_committed(
+                &challenge_seed,
+                committed,
+                4, // Challenge 4 chunks for efficiency
+            ),
+            None => crate::core::utils::select_chunks_deterministic(
+                &challenge_seed,
+                16.0, // Assume 16 chunks per block from specification
+                4,    // Challenge 4 chunks for efficiency
+            ),
+        };
+
+        let byte_offsets = sub_chunk.unwrap_or(false).then(|| {
+            crate::core::utils::select_sub_chunk_offsets(&challenge_seed, &challenged_chunks)
+        });
+
+        let challenge = StorageChallenge {
+            challenge_id: Buffer::from(challenge_id.to_vec()),
+            prover_key,
+            commitment_hash,
+            challenged_chunks,
+            nonce: Buffer::from(challenge_nonce.to_vec()),
+            timestamp: crate::core::utils::get_current_timestamp(),
+            deadline: crate::core::utils::get_current_timestamp() + 30.0, // 30 second deadline
+            byte_offsets,
+            verifier_key: None,
+            verifier_signature: None,
+            erasure_coding: None,
+        };
+        let challenge = self.sign_challenge(challenge)?;
+
+        // Store active challenge
+        let challenge_key = hex::encode(&challenge.challenge_id);
+        self.active_challenges
+            .insert(challenge_key, challenge.clone());
+
+        Ok(challenge)
+    }
+
+    /// Generate a challenge for one whole stripe of an erasure-coded chain. The prover
+    /// must return the raw coded shards (data and parity chunks) as stored, which
+    /// `verifyCodedShardResponse` checks against the coding parameters.
+    #[napi]
+    pub fn generate_coded_shard_challenge(
+        &mut self,
+        prover_key: Buffer,
+        commitment_hash: Buffer,
+        erasure_coding: ErasureCodingParams,
+        total_chunks: f64,
+    ) -> Result<StorageChallenge> {
+        validate_public_key(&prover_key)?;
+        validate_hash_buffer(&commitment_hash, "Commitment hash")?;
+        crate::core::erasure::validate_erasure_params(&erasure_coding)
+            .map_err(|e| e.into_napi_error("Invalid erasure coding parameters"))?;
+
+        let total_stripes =
+            total_chunks as u64 / crate::core::erasure::stripe_size(&erasure_coding) as u64;
+        if total_stripes == 0 {
+            return Err(coded_error(
+                Status::InvalidArg,
+                error_codes::E_CHUNK_COUNT,
+                "Chain is smaller than one erasure coding stripe",
+            ));
+        }
+
+        let challenge_nonce = crate::core::utils::generate_proof_nonce(&prover_key);
+        let challenge_id = crate::core::utils::compute_blake3(
+            &[&prover_key[..], &commitment_hash[..], &challenge_nonce[..]].concat(),
+        );
+
+        // Pick one stripe; all of its shards are challenged
+        let challenge_seed = crate::core::utils::generate_multi_source_entropy(
+            &prover_key,
+            Some(&commitment_hash),
+            &challenge_nonce,
+        );
+        let stripe_index = crate::core::utils::select_chunks_deterministic(
+            &challenge_seed,
+            total_stripes.min(u32::MAX as u64) as f64,
+            1,
+        )[0];
+
+        let challenge = StorageChallenge {
+            challenge_id: Buffer::from(challenge_id.to_vec()),
+            prover_key,
+            commitment_hash,
+            challenged_chunks: crate::core::erasure::stripe_chunks(&erasure_coding, stripe_index),
+            nonce: Buffer::from(challenge_nonce.to_vec()),
+            timestamp: crate::core::utils::get_current_timestamp(),
+            deadline: crate::core::utils::get_current_timestamp() + 30.0, // 30 second deadline
+            byte_offsets: None,
+            verifier_key: None,
+            verifier_signature: None,
+            erasure_coding: Some(erasure_coding),
+        };
+        let challenge = self.sign_challenge(challenge)?;
+
+        self.active_challenges
+            .insert(hex::encode(&challenge.challenge_id), challenge.clone());
+
+        Ok(challenge)
+    }
+
+    /// Verify a response to a coded shard challenge: every returned shard must be
+    /// included under the commitment's Merkle root at its challenged index, and the
+    /// shards must form a complete stripe whose parity matches its data under the
+    /// challenge's coding parameters
+    #[napi]
+    pub fn verify_coded_shard_response(
+        &self,
+        response: ChallengeResponse,
+        original_challenge: StorageChallenge,
+        commitment: StorageCommitment,
+    ) -> bool {
+        let Some(erasure_coding) = &original_challenge.erasure_coding else {
+            return false;
+        };
+        let (Some(merkle_root), Some(total_chunks)) =
+            (&commitment.merkle_root, commitment.total_chunks)
+        else {
+            log::warn!("Coded shard response rejected: commitment has no Merkle root");
+            return false;
+        };
+        if response.challenge_id.as_ref() != original_challenge.challenge_id.as_ref()
+            || original_challenge.commitment_hash.as_ref() != commitment.commitment_hash.as_ref()
+            || response.chunk_data.len() != original_challenge.challenged_chunks.len()
+            || response.merkle_proofs.len() != original_challenge.challenged_chunks.len()
+        {
+            return false;
+        }
+
+        // Shards must be the committed chunks, not any internally consistent stripe
+        let committed = original_challenge
+            .challenged_chunks
+            .iter()
+            .zip(&response.chunk_data)
+            .zip(&response.merkle_proofs)
+            .all(|((&chunk_index, shard), merkle_proof)| {
+                crate::core::utils::verify_merkle_inclusion(
+                    crate::core::utils::compute_blake3(shard),
+                    chunk_index as usize,
+                    total_chunks as usize,
+                    merkle_proof,
+                    merkle_root,
+                )
+            });
+        if !committed {
+            log::warn!("Coded shard response rejected: shard not under the committed root");
+            return false;
+        }
+
+        let shards: Vec<&[u8]> = response
+            .chunk_data
+            .iter()
+            .map(|shard| shard.as_ref())
+            .collect();
+        crate::core::erasure::verify_coded_stripe(erasure_coding, &shards)
+    }
+
+    /// Sign issued storage challenges so provers can reject ones from unknown
+    /// verifiers. The key must belong to this verifier's public key.
+    #[napi]
+    pub fn set_signing_key(&mut self, private_key: Buffer) -> Result<()> {
+        let public_key = crate::core::utils::derive_public_key(&private_key)
+            .map_err(|e| e.into_napi_error("Invalid verifier signing key"))?;
+        if public_key.as_slice() != self.verifier_key.as_ref() {
+            return Err(coded_error(
+                Status::InvalidArg,
+                error_codes::E_INVALID_INPUT,
+                "Signing key does not match the verifier key",
+            ));
+        }
+        self.signing_key = Some(private_key);
+        Ok(())
+    }
+
+    /// Verify `later` continues `earlier` on the same chain (same prover and data) with
+    /// enough sequential VDF work between the two blocks, its VDF state derived from
+    /// `earlier`'s by the prover's VDF
+    #[napi]
+    pub fn verify_vdf_continuity(
+        &self,
+        earlier: StorageCommitment,
+        later: StorageCommitment,
+    ) -> bool {
+        let validator = self.validator_at(later.block_height);
+        match validator.validate_vdf_continuity(&earlier, &later) {
+            Ok(()) => true,
+            Err(e) => {
+                log::warn!("VDF continuity rejected: {}", e);
+                false
+            }
+        }
+    }
+
+    /// Test whether a chain's chunk selections across `commitments`, its commitment
+    /// history in block order, are spread uniformly over its registered file. A
+    /// significant skew suggests the entropy driving selection was steered toward
+    /// chunks the prover actually stores.
+    #[napi]
+    pub fn analyze_selection_uniformity(
+        &self,
+        env: Env,
+        commitments: Vec<StorageCommitment>,
+    ) -> Result<BiasReport> {
+        let first = commitments.first().ok_or_else(|| {
+            coded_error(
+                Status::InvalidArg,
+                error_codes::E_INVALID_INPUT,
+                "Commitment history is empty",
+            )
+        })?;
+        let total_chunks = self
+            .registered_total_chunks(&env, &first.data_hash)
+            .map_err(|e| coded_error(Status::GenericFailure, error_codes::E_CHAIN_STATE, e))?;
+
+        crate::consensus::SelectionBiasDetector::default()
+            .analyze_history(&commitments, total_chunks)
+            .map_err(|e| coded_error(Status::InvalidArg, error_codes::E_INVALID_INPUT, e))
+    }
+
+    /// Verify a challenge targets the given commitment and only chunks it selected
+    #[napi]
+    pub fn verify_challenge_within_commitment(
+        &self,
+        challenge: StorageChallenge,
+        commitment: StorageCommitment,
+    ) -> bool {
+        if challenge.commitment_hash.as_ref() != commitment.commitment_hash.as_ref() {
+            log::warn!("Challenge does not reference the given commitment");
+            return false;
+        }
+
+        let validator = self.validator_at(commitment.block_height);
+        match validator.validate_challenge_within_commitment(
+            &challenge.challenged_chunks,
+            &commitment.selected_chunks,
+        ) {
+            Ok(()) => true,
+            Err(e) => {
+                log::warn!("Challenge outside commitment rejected: {}", e);
+                false
+            }
+        }
+    }
+
+    /// Audit prover data availability with real verification
+    #[napi]
+    pub fn audit_prover(&self, prover_key: Buffer) -> bool {
+        // Verify prover key format
+        if prover_key.len() != 32 {
+            return false;
+        }
+
+        // Real audit based on cryptographic verification
+        // Check if this verifier has cached verification results for this prover
+        let prover_key_hex = hex::encode(&prover_key);
+
+        // Audit based on recent verification history and key validity
+        let recent_verifications = self
+            .verification_cache
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|(commitment_hash, _)| {
+                // Check if this commitment hash could belong to this prover
+                commitment_hash.starts_with(&prover_key_hex[..8]) // First 8 chars match
+            })
+            .count();
+
+        // Prover passes audit if they have recent valid commitments
+        recent_verifications > 0
+            && self
+                .total_verifications
+                .load(std::sync::atomic::Ordering::Relaxed)
+                > 0
+    }
+
+    /// Get verifier statistics
+    #[napi]
+    pub fn get_verifier_stats(&self) -> String {
+        format!(
+            r#"{{"verifier_key": "{}", "total_verifications": {}, "active_challenges": {}, "cache_size": {}, "tracked_chains": {}}}"#,
+            hex::encode(&self.verifier_key),
+            self.total_verifications
+                .load(std::sync::atomic::Ordering::Relaxed),
+            self.active_challenges.len(),
+            self.verification_cache.read().unwrap().len(),
+            self.seen_proofs.read().unwrap().len()
+        )
+    }
+
+    /// Update verifier callbacks
+    #[napi]
+    pub fn update_callbacks(&mut self, callbacks: VerifierCallbacks) {
+        self.callbacks = Some(callbacks);
+    }
+
+    /// Refresh the prover cache through `network.discoverProvers()`, which must return
+    /// an array of NetworkNode. Calls are rate-limited: within
+    /// `PROVER_DISCOVERY_MIN_INTERVAL_SECONDS` of the last refresh the cached provers
+    /// are returned without calling discovery. Results of a refresh cancelled with
+    /// `cancelProverDiscovery` are discarded.
+    #[napi]
+    pub fn refresh_provers(&mut self) -> Result<Vec<NetworkNode>> {
+        let now = crate::core::utils::get_current_timestamp();
+        if !self.prover_discovery.can_refresh(now) {
+            return Ok(self.prover_discovery.cached_provers(now));
         }
+        let callbacks = self.callbacks.as_ref().ok_or_else(|| {
+            coded_error(
+                Status::GenericFailure,
+                error_codes::E_INVALID_INPUT,
+                "Prover discovery needs verifier callbacks",
+            )
+        })?;
 
-        // 4. Verify VDF signature meets minimum iteration requirement (Network Consensus)
-        if proof.vdf_proof.iterations < 1000 {
-            return false; // Minimum 1000 iterations required by network consensus
+        let token = self.prover_discovery.begin_refresh(now);
+        let result = callbacks
+            .network
+            .discover_provers
+            .call_without_args(None)?
+            .coerce_to_object()?;
+        let nodes = (0..result.get_array_length()?)
+            .map(|i| network_node_from_js(&result.get_element::<Object>(i)?))
+            .collect::<Result<Vec<_>>>()?;
+
+        let now = crate::core::utils::get_current_timestamp();
+        if !self.prover_discovery.complete_refresh(token, nodes, now) {
+            log::debug!("Prover discovery cancelled; discarding results");
         }
+        Ok(self.prover_discovery.cached_provers(now))
+    }
+
+    /// Provers discovered within `PROVER_DISCOVERY_TTL_SECONDS`, ordered by node key
+    #[napi]
+    pub fn get_cached_provers(&self) -> Vec<NetworkNode> {
+        self.prover_discovery
+            .cached_provers(crate::core::utils::get_current_timestamp())
+    }
+
+    /// Cancel a prover discovery refresh in progress; its results are discarded
+    #[napi]
+    pub fn cancel_prover_discovery(&mut self) {
+        self.prover_discovery.cancel();
+    }
+
+    /// Generate a VDF reproduction challenge for a segment ending at `published_iteration`,
+    /// the iteration of a shared VDF proof the prover already published. The segment
+    /// starts at a random iteration within `VDF_REPRODUCTION_MAX_SEGMENT` before it, so
+    /// the revealed end state must match a state the prover signed.
+    #[napi]
+    pub fn generate_vdf_reproduction_challenge(
+        &self,
+        prover_key: Buffer,
+        published_iteration: f64,
+    ) -> Result<VDFReproductionChallenge> {
+        let iteration_index = self.checkpoint_iteration_before(&prover_key, published_iteration)?;
 
-        // 5. Verify VDF signature is properly formatted (32 bytes)
-        if proof.vdf_proof.input_state.len() != 32 {
+        Ok(VDFReproductionChallenge {
+            prover_key,
+            iteration_index: iteration_index as f64,
+            segment_length: (published_iteration as u64 - iteration_index) as u32,
+            timestamp: crate::core::utils::get_current_timestamp(),
+        })
+    }
+
+    /// Verify a VDF reproduction response against the prover's serialized shared VDF
+    /// proof chain (from `getSharedVdfProofChain`). The chain must be signed by the
+    /// challenged prover and contain a proof at the end of the segment whose state is
+    /// the revealed end state, and recomputing the segment from the revealed start state
+    /// and memory must reach it.
+    #[napi]
+    pub fn verify_vdf_reproduction(
+        &self,
+        challenge: VDFReproductionChallenge,
+        response: VDFReproductionResponse,
+        published_proofs: Buffer,
+    ) -> bool {
+        if response.iteration_index != challenge.iteration_index
+            || response.segment_length != challenge.segment_length
+        {
             return false;
         }
 
-        // 6. CRITICAL: Verify this is a continuous VDF proof (not old memory-hard VDF)
-        // Continuous VDF proofs have no memory access samples and constant memory usage
-        if !proof.vdf_proof.memory_access_samples.is_empty() {
-            return false; // Old memory-hard VDF format not accepted by network consensus
+        let proofs = match self.published_vdf_proofs(&challenge.prover_key, &published_proofs) {
+            Ok(proofs) => proofs,
+            Err(e) => {
+                log::warn!("VDF reproduction rejected: {}", e);
+                return false;
+            }
+        };
+        let end_iteration = response.iteration_index + response.segment_length as f64;
+        let Some(published) = proofs
+            .iter()
+            .find(|proof| proof.total_iterations as f64 == end_iteration)
+        else {
+            log::warn!(
+                "VDF reproduction rejected: no published proof at iteration {}",
+                end_iteration
+            );
+            return false;
+        };
+        if published.vdf_state.as_slice() != response.end_state.as_ref() {
+            log::warn!("VDF reproduction rejected: end state differs from the published state");
+            return false;
         }
 
-        if proof.vdf_proof.memory_usage_bytes != 256.0 * 1024.0 {
-            return false; // Must be exactly 256KB for continuous VDF
+        let checkpoint = VDFCheckpoint {
+            iteration: response.iteration_index,
+            state: response.start_state,
+            memory_snapshot: response.memory_snapshot,
+        };
+        let validator =
+            crate::consensus::NetworkConsensusValidator::for_strictness(self.strictness);
+        match validator.validate_vdf_checkpoint(&challenge.prover_key, published, &checkpoint) {
+            Ok(()) => true,
+            Err(e) => {
+                log::warn!("VDF reproduction rejected: {}", e);
+                false
+            }
         }
+    }
 
-        // 4. Verify chunk proofs meet consensus requirements
-        if proof.chunk_proofs.len() != CHUNKS_PER_BLOCK as usize {
-            return false;
-        }
+    /// Pick a random checkpoint iteration to spot-check, within
+    /// `VDF_REPRODUCTION_MAX_SEGMENT` iterations before `published_iteration`, the
+    /// iteration of a shared VDF proof the prover already published. The prover is
+    /// then asked for its checkpoint at the picked iteration, which must recompute to
+    /// the published state.
+    #[napi]
+    pub fn select_vdf_checkpoint_iteration(
+        &self,
+        prover_key: Buffer,
+        published_iteration: f64,
+    ) -> Result<f64> {
+        Ok(self.checkpoint_iteration_before(&prover_key, published_iteration)? as f64)
+    }
 
-        for chunk_proof in &proof.chunk_proofs {
-            if chunk_proof.len() != 32 {
+    /// Verify a prover's VDF checkpoint against its serialized shared VDF proof chain
+    /// (from `getSharedVdfProofChain`). The chain must be signed by `prover_key`, and
+    /// recomputing from the checkpoint must reach the state of the first published
+    /// proof after it. Rejects VDFs whose reported iterations were not actually computed.
+    #[napi]
+    pub fn verify_vdf_checkpoint(
+        &self,
+        prover_key: Buffer,
+        published_proofs: Buffer,
+        checkpoint: VDFCheckpoint,
+    ) -> bool {
+        let proofs = match self.published_vdf_proofs(&prover_key, &published_proofs) {
+            Ok(proofs) => proofs,
+            Err(e) => {
+                log::warn!("VDF checkpoint rejected: {}", e);
                 return false;
             }
+        };
+        let Some(published) = proofs
+            .iter()
+            .find(|proof| proof.total_iterations as f64 > checkpoint.iteration)
+        else {
+            log::warn!(
+                "VDF checkpoint rejected: no published proof after iteration {}",
+                checkpoint.iteration
+            );
+            return false;
+        };
+
+        let validator =
+            crate::consensus::NetworkConsensusValidator::for_strictness(self.strictness);
+        match validator.validate_vdf_checkpoint(&prover_key, published, &checkpoint) {
+            Ok(()) => true,
+            Err(e) => {
+                log::warn!("VDF checkpoint rejected: {}", e);
+                false
+            }
         }
+    }
 
-        // 5. Verify commitment hash structure
-        if proof.commitment_hash.len() != 32 {
+    /// NETWORK CONSENSUS: Verify VDF signature against prover's continuous VDF
+    /// This is a critical network consensus validation that ensures blocks are properly signed.
+    /// Given the prover's VDF state at signing, the signature is recomputed for this
+    /// exact block hash, so a signature made for another block is rejected.
+    /// A signature already accepted for a different block height is rejected as a replay.
+    #[napi]
+    pub fn verify_vdf_signature(
+        &self,
+        prover_public_key: Buffer,
+        block_height: u32,
+        block_hash: Buffer,
+        vdf_signature: Buffer,
+        required_iterations: u32,
+        signing_state: Option<VDFSigningState>,
+    ) -> bool {
+        // Validate input parameters
+        if prover_public_key.len() != 32 || block_hash.len() != 32 || vdf_signature.len() != 32 {
             return false;
         }
 
-        // 6. Verify network position is properly formatted
-        if proof.network_position.len() != 32 {
+        if required_iterations < self.validator_at(block_height).min_vdf_iterations() {
+            return false; // Network consensus minimum at this height
+        }
+
+        // Without the prover's VDF state only the signature format and requirements are checked
+        // Verify signature is not all zeros (invalid)
+        if vdf_signature.iter().all(|&b| b == 0) {
             return false;
         }
 
-        // 7. Verify timestamp is reasonable (not too old or in future)
-        let current_time = crate::core::utils::get_current_timestamp();
-        let twenty_four_hours = 24.0 * 60.0 * 60.0;
+        // Verify block hash is properly formatted
+        if block_hash.iter().all(|&b| b == 0) && block_height > 0 {
+            return false; // Non-genesis blocks cannot have zero hash
+        }
+
+        // Recompute the expected signature for this block hash from the prover's VDF state
+        if let Some(signing_state) = signing_state {
+            let (Ok(vdf_state), Ok(block_hash)) = (
+                <[u8; 32]>::try_from(signing_state.vdf_state.as_ref()),
+                <[u8; 32]>::try_from(block_hash.as_ref()),
+            ) else {
+                return false;
+            };
+            let total_iterations = signing_state.total_iterations as u64;
+            if total_iterations < required_iterations as u64 {
+                return false;
+            }
 
-        if proof.timestamp > current_time + 300.0
-            || current_time - proof.timestamp > twenty_four_hours
-        {
-            return false;
+            let expected_signature = crate::core::utils::compute_vdf_block_signature(
+                &vdf_state,
+                block_height as u64,
+                &block_hash,
+                total_iterations,
+            );
+            if vdf_signature.as_ref() != expected_signature.as_slice() {
+                return false;
+            }
         }
 
-        // Cache result
-        let cache_key = hex::encode(&proof.commitment_hash);
-        self.verification_cache.insert(cache_key, true);
+        // The same signature bytes must not be reused for another block height
+        let (Ok(prover_key), Ok(signature)) = (
+            <[u8; 32]>::try_from(prover_public_key.as_ref()),
+            <[u8; 32]>::try_from(vdf_signature.as_ref()),
+        ) else {
+            return false;
+        };
+        if !self.vdf_signatures.lock().unwrap().check_and_record(
+            prover_key,
+            signature,
+            block_height as u64,
+        ) {
+            log::warn!(
+                "Replayed VDF signature rejected for prover {} at height {}",
+                hex::encode(prover_key),
+                block_height
+            );
+            return false;
+        }
 
+        // Network consensus validation passed
         true
     }
 
-    /// Verify full storage proof
+    /// Forget the block heights of previously accepted VDF signatures, e.g. after a
+    /// chain reorganization
     #[napi]
-    pub fn verify_full_proof(&mut self, proof: FullStorageProof) -> bool {
-        self.total_verifications += 1;
+    pub fn clear_vdf_signature_cache(&self) {
+        self.vdf_signatures.lock().unwrap().clear();
+    }
 
-        // Verify basic structure
-        if proof.all_chunk_hashes.is_empty() {
+    /// Verify a portfolio proof covers exactly `expectedChainIds` (hex) and is signed by
+    /// its prover key. Individual commitments are checked with `verifyPortfolioInclusion`.
+    #[napi]
+    pub fn verify_portfolio_proof(
+        &self,
+        proof: PortfolioProof,
+        expected_chain_ids: Vec<String>,
+    ) -> bool {
+        let (Ok(portfolio_root), Ok(chain_set_hash), Ok(vdf_state)) = (
+            <[u8; 32]>::try_from(proof.portfolio_root.as_ref()),
+            <[u8; 32]>::try_from(proof.chain_set_hash.as_ref()),
+            <[u8; 32]>::try_from(proof.vdf_state.as_ref()),
+        ) else {
+            return false;
+        };
+        if proof.prover_key.len() != 32 {
             return false;
         }
 
-        // Verify commitment
-        let compact_proof = CompactStorageProof {
-            prover_key: proof.prover_key.clone(),
-            commitment_hash: proof.commitment.commitment_hash.clone(),
-            block_height: proof.commitment.block_height,
-            chunk_proofs: proof.commitment.chunk_hashes.clone(),
-            vdf_proof: proof.commitment.vdf_proof.clone(),
-            network_position: Buffer::from([0u8; 32].to_vec()),
-            timestamp: proof.commitment.entropy.timestamp,
-        };
+        let mut chain_ids = Vec::with_capacity(expected_chain_ids.len());
+        for chain_id in &expected_chain_ids {
+            match hex::decode(chain_id) {
+                Ok(bytes) => chain_ids.push(bytes),
+                Err(_) => return false,
+            }
+        }
+        chain_ids.sort();
+        chain_ids.dedup();
+        if chain_ids.len() != expected_chain_ids.len()
+            || proof.chain_count as usize != chain_ids.len()
+        {
+            return false;
+        }
+        if crate::core::portfolio::compute_portfolio_chain_set_hash(&chain_ids) != chain_set_hash {
+            log::warn!("Portfolio proof rejected: chain set does not match expected chains");
+            return false;
+        }
+
+        let total_iterations = proof.total_iterations as u64;
+        if total_iterations < MIN_VDF_ITERATIONS_PER_BLOCK as u64 {
+            return false;
+        }
+        // Signed in the future beyond clock skew
+        if proof.timestamp > crate::core::utils::get_current_timestamp() + 300.0 {
+            return false;
+        }
 
-        self.verify_compact_proof(compact_proof)
+        let message = crate::core::portfolio::portfolio_signing_message(
+            &proof.prover_key,
+            &vdf_state,
+            &portfolio_root,
+            &chain_set_hash,
+            proof.chain_count,
+            proof.timestamp,
+            total_iterations,
+        );
+        crate::core::utils::verify_signature(&proof.prover_key, &message, &proof.signature)
+            .unwrap_or(false)
     }
 
-    /// Verify challenge response
+    /// Verify one chain's commitment is covered by a portfolio proof's root
     #[napi]
-    pub fn verify_challenge_response(
+    pub fn verify_portfolio_inclusion(
         &self,
-        response: ChallengeResponse,
-        original_challenge: StorageChallenge,
+        proof: PortfolioProof,
+        inclusion: PortfolioInclusionProof,
     ) -> bool {
-        response.challenge_id.len() == original_challenge.challenge_id.len()
-            && response.chunk_data.len() == original_challenge.challenged_chunks.len()
-            && response.access_proof.iterations > 0
+        let (Ok(portfolio_root), Ok(commitment_hash), Ok(chain_id), Some(path)) = (
+            <[u8; 32]>::try_from(proof.portfolio_root.as_ref()),
+            <[u8; 32]>::try_from(inclusion.commitment_hash.as_ref()),
+            hex::decode(&inclusion.chain_id),
+            crate::core::utils::decode_merkle_path(&inclusion.merkle_path),
+        ) else {
+            return false;
+        };
+        crate::core::portfolio::verify_portfolio_inclusion(
+            &portfolio_root,
+            &chain_id,
+            &commitment_hash,
+            &path,
+        )
     }
+}
 
-    /// Generate challenge for prover
-    #[napi]
-    pub fn generate_challenge(
-        &mut self,
-        prover_key: Buffer,
-        commitment_hash: Buffer,
-    ) -> Result<StorageChallenge> {
-        // Generate challenge ID
-        let challenge_nonce = crate::core::utils::generate_proof_nonce(&prover_key);
-        let challenge_id = crate::core::utils::compute_blake3(
-            &[&prover_key[..], &commitment_hash[..], &challenge_nonce[..]].concat(),
-        );
+impl ProofOfStorageVerifier {
+    /// Create verifier backed by a native blockchain adapter instead of NAPI callbacks
+    pub fn with_blockchain_adapter(
+        verifier_key: Buffer,
+        adapter: Box<dyn BlockchainAdapter>,
+    ) -> Result<Self> {
+        validate_public_key(&verifier_key)?;
 
-        // Select chunks to challenge (typically 4 out of 16) using deterministic algorithm
-        let challenge_seed = crate::core::utils::generate_multi_source_entropy(
-            &prover_key,
-            Some(&commitment_hash),
-            &challenge_nonce,
-        );
-        let challenged_chunks = crate::core::utils::select_chunks_deterministic(
-            &challenge_seed,
-            16.0, // Assume 16 chunks per block from specification
-            4,    // Challenge 4 chunks for efficiency
-        );
+        Ok(Self {
+            verifier_key,
+            callbacks: None,
+            blockchain_adapter: Some(adapter),
+            active_challenges: std::collections::HashMap::new(),
+            verification_cache: VerificationCache::default(),
+            seen_proofs: std::sync::RwLock::default(),
+            beacon_required: BEACON_ENTROPY_REQUIRED,
+            strictness: crate::consensus::ValidationStrictness::Production,
+            signing_key: None,
+            parameter_schedule: crate::consensus::ParameterSchedule::default(),
+            prover_discovery: crate::core::discovery::ProverDiscovery::default(),
+            vdf_signatures: std::sync::Mutex::default(),
+            total_verifications: std::sync::atomic::AtomicU32::new(0),
+        })
+    }
 
-        let challenge = StorageChallenge {
-            challenge_id: Buffer::from(challenge_id.to_vec()),
-            prover_key,
-            commitment_hash,
-            challenged_chunks,
-            nonce: Buffer::from(challenge_nonce.to_vec()),
-            timestamp: crate::core::utils::get_current_timestamp(),
-            deadline: crate::core::utils::get_current_timestamp() + 30.0, // 30 second deadline
-        };
+    /// Cache of verified commitment hashes. Verifiers given the same handle through
+    /// `use_verification_cache` share results across threads.
+    pub fn verification_cache(&self) -> VerificationCache {
+        self.verification_cache.clone()
+    }
 
-        // Store active challenge
-        let challenge_key = hex::encode(&challenge.challenge_id);
-        self.active_challenges
-            .insert(challenge_key, challenge.clone());
+    /// Record verification results in `cache`, e.g. one shared with other verifiers
+    pub fn use_verification_cache(&mut self, cache: VerificationCache) {
+        self.verification_cache = cache;
+    }
 
-        Ok(challenge)
+    fn count_verification(&self) {
+        self.total_verifications
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
     }
 
-    /// Audit prover data availability with real verification
-    #[napi]
-    pub fn audit_prover(&self, prover_key: Buffer) -> bool {
-        // Verify prover key format
-        if prover_key.len() != 32 {
-            return false;
+    /// Chunk count registered on the blockchain for the data file with `data_hash`
+    fn registered_total_chunks(
+        &self,
+        env: &Env,
+        data_hash: &Buffer,
+    ) -> std::result::Result<u64, String> {
+        let callbacks = self
+            .callbacks
+            .as_ref()
+            .ok_or_else(|| "No blockchain data callbacks configured".to_string())?;
+        let total_chunks = env
+            .create_string(&hex::encode(data_hash))
+            .and_then(|data_hash| {
+                callbacks
+                    .blockchain_data
+                    .get_data_file_metadata
+                    .call(None, &[data_hash])
+            })
+            .and_then(|metadata| {
+                metadata
+                    .coerce_to_object()?
+                    .get_named_property::<f64>("totalChunks")
+            })
+            .map_err(|e| format!("Data file metadata callback failed: {}", e))?;
+        if !total_chunks.is_finite()
+            || total_chunks < 0.0
+            || total_chunks.fract() != 0.0
+            || total_chunks > u32::MAX as f64
+        {
+            return Err(format!("Invalid registered chunk count {}", total_chunks));
         }
+        Ok(total_chunks as u64)
+    }
 
-        // Real audit based on cryptographic verification
-        // Check if this verifier has cached verification results for this prover
-        let prover_key_hex = hex::encode(&prover_key);
-
-        // Audit based on recent verification history and key validity
-        let recent_verifications = self
-            .verification_cache
-            .iter()
-            .filter(|(commitment_hash, _)| {
-                // Check if this commitment hash could belong to this prover
-                commitment_hash.starts_with(&prover_key_hex[..8]) // First 8 chars match
-            })
-            .count();
+    /// Check beacon entropy through the beacon callback. Entropy that cannot be
+    /// verified because no beacon callback is configured is rejected.
+    fn check_beacon_entropy(
+        &self,
+        env: &Env,
+        entropy: &MultiSourceEntropy,
+        round: u32,
+    ) -> std::result::Result<(), String> {
+        let validator =
+            crate::consensus::NetworkConsensusValidator::for_strictness(self.strictness);
+        validator.validate_beacon_entropy(
+            entropy,
+            round,
+            self.beacon_required,
+            |randomness, round| {
+                let beacon = self
+                    .callbacks
+                    .as_ref()
+                    .and_then(|callbacks| callbacks.beacon.as_ref())
+                    .ok_or_else(|| "No beacon callback configured".to_string())?;
+                let randomness = env
+                    .create_buffer_with_data(randomness.to_vec())
+                    .map_err(|e| e.to_string())?
+                    .into_raw();
+                let round = env.create_uint32(round).map_err(|e| e.to_string())?;
+                beacon
+                    .verify_randomness
+                    .call(None, &[randomness.into_unknown(), round.into_unknown()])
+                    .and_then(|result| result.coerce_to_bool()?.get_value())
+                    .map_err(|e| format!("Beacon verification callback failed: {}", e))
+            },
+        )
+    }
 
-        // Prover passes audit if they have recent valid commitments
-        recent_verifications > 0 && self.total_verifications > 0
+    /// Verify proofs against `schedule`, so proofs from before a network upgrade are
+    /// checked with the parameters in force at their block height
+    pub fn set_parameter_schedule(&mut self, schedule: crate::consensus::ParameterSchedule) {
+        self.parameter_schedule = schedule;
     }
 
-    /// Get verifier statistics
-    #[napi]
-    pub fn get_verifier_stats(&self) -> String {
-        format!(
-            r#"{{"verifier_key": "{}", "total_verifications": {}, "active_challenges": {}, "cache_size": {}}}"#,
-            hex::encode(&self.verifier_key),
-            self.total_verifications,
-            self.active_challenges.len(),
-            self.verification_cache.len()
+    /// Consensus validator for the network parameters in force at `block_height`
+    fn validator_at(&self, block_height: u32) -> crate::consensus::NetworkConsensusValidator {
+        crate::consensus::NetworkConsensusValidator::for_strictness(self.strictness).with_params(
+            self.parameter_schedule
+                .parameters_at(block_height as u64)
+                .clone(),
         )
     }
 
-    /// Update verifier callbacks
-    #[napi]
-    pub fn update_callbacks(&mut self, callbacks: VerifierCallbacks) {
-        self.callbacks = callbacks;
+    /// Random iteration within `VDF_REPRODUCTION_MAX_SEGMENT` before `published_iteration`,
+    /// drawn from entropy bound to the prover and this verifier
+    fn checkpoint_iteration_before(
+        &self,
+        prover_key: &Buffer,
+        published_iteration: f64,
+    ) -> Result<u64> {
+        validate_public_key(prover_key)?;
+
+        if published_iteration < 1.0 || published_iteration.fract() != 0.0 {
+            return Err(coded_error(
+                Status::InvalidArg,
+                error_codes::E_INVALID_INPUT,
+                format!("Invalid published iteration: {}", published_iteration),
+            ));
+        }
+
+        let published_iteration = published_iteration as u64;
+        let window = published_iteration.min(VDF_REPRODUCTION_MAX_SEGMENT as u64);
+        let entropy = crate::core::utils::generate_secure_entropy(
+            &[&prover_key[..], &self.verifier_key[..]].concat(),
+        );
+        let mut offset_bytes = [0u8; 8];
+        offset_bytes.copy_from_slice(&entropy[..8]);
+        let offset = u64::from_be_bytes(offset_bytes) % window + 1;
+
+        Ok(published_iteration - offset)
     }
 
-    /// NETWORK CONSENSUS: Verify VDF signature against prover's continuous VDF
-    /// This is a critical network consensus validation that ensures blocks are properly signed
-    #[napi]
-    pub fn verify_vdf_signature(
+    /// Deserialize a prover's shared VDF proof chain, requiring it to be signed by `prover_key`
+    fn published_vdf_proofs(
         &self,
-        prover_public_key: Buffer,
-        block_height: u32,
-        block_hash: Buffer,
-        vdf_signature: Buffer,
-        required_iterations: u32,
+        prover_key: &[u8],
+        published_proofs: &[u8],
+    ) -> std::result::Result<Vec<crate::core::vdf_processor::SharedVDFProof>, String> {
+        let proofs = crate::core::vdf_processor::deserialize_vdf_proof_chain(published_proofs)
+            .map_err(|e| e.to_string())?;
+        if !crate::core::vdf_processor::verify_vdf_proof_chain(&proofs, prover_key).unwrap_or(false)
+        {
+            return Err("shared proof chain is not signed by the prover".to_string());
+        }
+        Ok(proofs)
+    }
+
+    /// Checks of `verifyCompactProof`, with `accept` applying replay protection
+    fn check_compact_proof(
+        &self,
+        proof: CompactStorageProof,
+        accept: fn(
+            &mut crate::consensus::SeenProofs,
+            &CompactStorageProof,
+            &crate::consensus::NetworkConsensusValidator,
+        ) -> bool,
     ) -> bool {
-        // Validate input parameters
-        if prover_public_key.len() != 32 || block_hash.len() != 32 || vdf_signature.len() != 32 {
+        // Consensus validator for the parameters in force at the proof's block
+        let consensus_validator = self.validator_at(proof.block_height);
+
+        // 1-8. Formats, VDF consensus, chunk proofs and timestamp
+        if !crate::consensus::compact_proof_well_formed(
+            &proof,
+            &consensus_validator,
+            crate::core::utils::get_current_timestamp(),
+        ) {
             return false;
         }
 
-        if required_iterations < 1000 {
-            return false; // Network consensus minimum
+        // 9. Block must exist on the native blockchain, when one is attached
+        if let Err(e) = self.check_block_anchor(proof.block_height, None) {
+            log::warn!("Compact proof rejected: {}", e);
+            return false;
+        }
+
+        // 10. Replay protection: block heights must strictly increase per chain, with
+        // sequential VDF work since the previous block. The write lock is held from
+        // check to insert so concurrent replays cannot both pass.
+        if !accept(
+            &mut self.seen_proofs.write().unwrap(),
+            &proof,
+            &consensus_validator,
+        ) {
+            return false;
+        }
+
+        // Cache result
+        let cache_key = hex::encode(&proof.commitment_hash);
+        self.verification_cache
+            .write()
+            .unwrap()
+            .insert(cache_key, true);
+
+        true
+    }
+
+    /// Attach this verifier's key and signature to a challenge when a signing key is set
+    fn sign_challenge(&self, mut challenge: StorageChallenge) -> Result<StorageChallenge> {
+        let Some(signing_key) = &self.signing_key else {
+            return Ok(challenge);
+        };
+        challenge.verifier_key = Some(self.verifier_key.clone());
+        let signature = crate::core::utils::sign_data(
+            signing_key,
+            &crate::core::utils::storage_challenge_signing_data(&challenge),
+        )
+        .map_err(|e| e.into_napi_error("Failed to sign challenge"))?;
+        challenge.verifier_signature = Some(Buffer::from(signature));
+        Ok(challenge)
+    }
+
+    /// Check a full proof's VDF proofs start from legitimate states: each VDF chain
+    /// entry continues the previous one, and a genesis commitment's VDF derives from
+    /// the prover key seed
+    fn check_vdf_inputs(&self, proof: &FullStorageProof) -> std::result::Result<(), String> {
+        let validator = self.validator_at(proof.commitment.block_height);
+        for pair in proof.vdf_chain.windows(2) {
+            validator.validate_vdf_input_link(&proof.prover_key, &pair[0], &pair[1])?;
         }
+        if proof.commitment.block_height == 0 {
+            validator
+                .validate_vdf_seed_derivation(&proof.prover_key, &proof.commitment.vdf_proof)?;
+        }
+        Ok(())
+    }
 
-        // For now, we validate the signature format and requirements
-        // In a full implementation, this would verify against the prover's actual VDF state
-        // through network communication or shared VDF proof chain
+    /// Check a proof's block against the native blockchain adapter, if one is attached
+    fn check_block_anchor(
+        &self,
+        block_height: u32,
+        block_hash: Option<&[u8]>,
+    ) -> std::result::Result<(), String> {
+        let Some(adapter) = &self.blockchain_adapter else {
+            return Ok(());
+        };
 
-        // Verify signature is not all zeros (invalid)
-        if vdf_signature.iter().all(|&b| b == 0) {
-            return false;
+        let current_height = adapter.get_current_height().map_err(|e| e.to_string())?;
+        if block_height as u64 > current_height {
+            return Err(format!(
+                "Block {} is ahead of the blockchain (height {})",
+                block_height, current_height
+            ));
         }
 
-        // Verify block hash is properly formatted
-        if block_hash.iter().all(|&b| b == 0) && block_height > 0 {
-            return false; // Non-genesis blocks cannot have zero hash
+        if let Some(block_hash) = block_hash {
+            if !adapter
+                .validate_block_hash(block_height as u64, block_hash)
+                .map_err(|e| e.to_string())?
+            {
+                return Err(format!("Block hash does not match block {}", block_height));
+            }
         }
 
-        // Network consensus validation passed
-        true
+        Ok(())
     }
 }
 
@@ -1439,23 +4080,58 @@ pub struct HierarchicalNetworkManager {
     node_type: String,
     inner_manager: HierarchicalGlobalChainManager,
     active_nodes: Vec<NetworkNode>,
+    availability_challenger: crate::core::availability::AvailabilityChallenger,
+    data_registry: crate::core::data_registry::DataRegistry,
+    /// Private key for `node_key`, signing exported challenge evidence when set
+    signing_key: Option<Buffer>,
 }
 
 #[napi]
 impl HierarchicalNetworkManager {
-    /// Create new network manager
+    /// Create new network manager, refusing to start if its consensus parameters
+    /// do not match the expected network fingerprint
     #[napi(constructor)]
-    pub fn new(node_key: Buffer, node_type: String) -> Result<Self> {
+    pub fn new(
+        node_key: Buffer,
+        node_type: String,
+        expected_network_fingerprint: Option<Buffer>,
+    ) -> Result<Self> {
         validate_public_key(&node_key)?;
 
+        if let Some(expected) = expected_network_fingerprint {
+            crate::consensus::NetworkParameters::current()
+                .verify_fingerprint(&expected)
+                .map_err(|e| coded_error(Status::InvalidArg, error_codes::E_CONSENSUS, e))?;
+        }
+
         Ok(Self {
             node_key,
             node_type,
             inner_manager: HierarchicalGlobalChainManager::new(3, CHAINS_PER_GROUP),
             active_nodes: Vec::new(),
+            availability_challenger: crate::core::availability::AvailabilityChallenger::new(),
+            data_registry: crate::core::data_registry::DataRegistry::new(),
+            signing_key: None,
         })
     }
 
+    /// Sign exported challenge evidence as this node, the challenger named in the
+    /// challenges it issues. The key must belong to the node key.
+    #[napi]
+    pub fn set_signing_key(&mut self, private_key: Buffer) -> Result<()> {
+        let public_key = crate::core::utils::derive_public_key(&private_key)
+            .map_err(|e| e.into_napi_error("Invalid node signing key"))?;
+        if public_key.as_slice() != self.node_key.as_ref() {
+            return Err(coded_error(
+                Status::InvalidArg,
+                error_codes::E_INVALID_INPUT,
+                "Signing key does not match the node key",
+            ));
+        }
+        self.signing_key = Some(private_key);
+        Ok(())
+    }
+
     /// Register prover in network
     #[napi]
     pub fn register_prover(&mut self, _prover: &ProofOfStorageProver) -> bool {
@@ -1468,28 +4144,525 @@ impl HierarchicalNetworkManager {
         true
     }
 
-    /// Remove node from network
+    /// Register a node, or refresh an existing registration. The node's position must
+    /// be `compute_hierarchical_position(node_key, group, region)` for the group and
+    /// region its chain is placed in; nodes without a placed chain or with any other
+    /// position are rejected.
+    #[napi]
+    pub fn register_node(&mut self, node: NetworkNode) -> Result<()> {
+        validate_public_key(&node.node_key)?;
+        if !matches!(node.node_type.as_str(), "prover" | "verifier" | "both") {
+            return Err(coded_error(
+                Status::InvalidArg,
+                error_codes::E_INVALID_INPUT,
+                format!("Unknown node type: {}", node.node_type),
+            ));
+        }
+
+        let chain_id = HierarchicalGlobalChainManager::chain_id_for_key(&node.node_key);
+        let (group_id, region_id) =
+            self.inner_manager
+                .chain_placement(&chain_id)
+                .ok_or_else(|| {
+                    coded_error(
+                        Status::GenericFailure,
+                        error_codes::E_CHAIN_NOT_FOUND,
+                        "Node has no chain placed in the hierarchy",
+                    )
+                })?;
+        let expected_position = crate::core::utils::compute_hierarchical_position(
+            &node.node_key.to_vec(),
+            &group_id,
+            &region_id,
+        );
+        if node.position.as_ref() != expected_position.as_slice() {
+            return Err(coded_error(
+                Status::InvalidArg,
+                error_codes::E_VERIFICATION_FAILED,
+                format!(
+                    "Node position does not match its placement in {}/{}",
+                    region_id, group_id
+                ),
+            ));
+        }
+
+        self.active_nodes
+            .retain(|existing| existing.node_key.as_ref() != node.node_key.as_ref());
+        self.active_nodes.push(node);
+        Ok(())
+    }
+
+    /// Remove node from network, dropping its data registrations
     #[napi]
     pub fn remove_node(&mut self, node_key: Buffer) -> bool {
+        if node_key.len() != 32 {
+            return false;
+        }
+
         self.active_nodes
             .retain(|node| node.node_key.as_ref() != node_key.as_ref());
+        self.data_registry.remove_prover(&node_key);
         true
     }
 
-    /// Process network block
+    /// Record that a prover claims the data with `data_hash`. Returns false if the
+    /// prover had already registered it. Data already claimed by another prover is
+    /// accepted and surfaces in `findDuplicateDataRegistrations`.
+    #[napi]
+    pub fn register_data(&mut self, prover_key: Buffer, data_hash: Buffer) -> Result<bool> {
+        validate_public_key(&prover_key)?;
+        if data_hash.len() != HASH_SIZE {
+            return Err(coded_error(
+                Status::InvalidArg,
+                error_codes::E_INVALID_INPUT,
+                format!("Data hash must be {} bytes", HASH_SIZE),
+            ));
+        }
+
+        let registered = self.data_registry.register(&data_hash, &prover_key);
+        let claimants = self.data_registry.claimants(&data_hash).len();
+        if registered && claimants > 1 {
+            info!(
+                "Data {} is now claimed by {} provers",
+                hex::encode(&data_hash),
+                claimants
+            );
+        }
+        Ok(registered)
+    }
+
+    /// Data hashes claimed by more than one prover, with the claiming prover keys in
+    /// registration order, so the network can tell replication from double-claiming
+    #[napi]
+    pub fn find_duplicate_data_registrations(&self) -> Vec<DuplicateDataRegistration> {
+        self.data_registry
+            .duplicates()
+            .into_iter()
+            .map(|(data_hash, prover_keys)| DuplicateDataRegistration {
+                data_hash: Buffer::from(data_hash),
+                prover_keys: prover_keys.into_iter().map(Buffer::from).collect(),
+            })
+            .collect()
+    }
+
+    /// Process network block. Every `GLOBAL_STATE_UPDATE_INTERVAL` blocks a global state
+    /// checkpoint is returned for submission; every `STATE_CLEANUP_INTERVAL` blocks chains
+    /// inactive for `INACTIVE_CHAIN_TIMEOUT_BLOCKS` are removed. The block hash becomes
+    /// the beacon for availability challenges issued at this height.
     #[napi]
-    pub fn process_network_block(&mut self, block_height: u32, block_hash: Buffer) -> Result<()> {
+    pub fn process_network_block(
+        &mut self,
+        block_height: u32,
+        block_hash: Buffer,
+    ) -> Result<Option<EnhancedCheckpoint>> {
         validate_block_hash(&block_hash)?;
 
         self.inner_manager
-            .process_new_block_hierarchical(block_hash, block_height as u64)
-            .map_err(|e| {
-                Error::new(
+            .process_new_block_hierarchical(block_hash.clone(), block_height as u64)
+            .map_err(|e| e.into_napi_error("Block processing error"))?;
+
+        // The block hash is the beacon that keeps this block's challenges unpredictable
+        let mut beacon = [0u8; 32];
+        beacon.copy_from_slice(&block_hash);
+        self.availability_challenger
+            .set_challenge_beacon(block_height as u64, beacon);
+
+        if block_height.is_multiple_of(STATE_CLEANUP_INTERVAL) {
+            let removed = self
+                .inner_manager
+                .cleanup_inactive_chains(block_height as u64)
+                .map_err(|e| e.into_napi_error("Inactive chain cleanup failed"))?;
+            if !removed.is_empty() {
+                info!(
+                    "Removed {} inactive chains at block {}",
+                    removed.len(),
+                    block_height
+                );
+            }
+        }
+
+        if !block_height.is_multiple_of(GLOBAL_STATE_UPDATE_INTERVAL) {
+            return Ok(None);
+        }
+
+        let checkpoint = self
+            .inner_manager
+            .create_checkpoint(&block_hash, block_height as u64, &self.node_key)
+            .map_err(|e| e.into_napi_error("Checkpoint creation failed"))?;
+        Ok(Some(checkpoint))
+    }
+
+    /// Bound the worker threads used for per-chain block processing
+    #[napi]
+    pub fn set_block_processing_threads(&mut self, threads: u32) -> Result<()> {
+        self.inner_manager
+            .set_block_processing_threads(threads)
+            .map_err(|e| e.into_napi_error("Invalid block processing threads"))
+    }
+
+    /// Processing time of the most recent block against the block processing target
+    #[napi]
+    pub fn get_block_processing_stats(&self) -> Option<BlockProcessingStats> {
+        self.inner_manager.last_block_processing.clone()
+    }
+
+    /// Member counts, proof freshness and unhealthy chains of a group in the hierarchy
+    #[napi]
+    pub fn get_group_health(&self, group_id: String) -> Result<GroupHealth> {
+        self.inner_manager
+            .get_group_health(&group_id)
+            .map_err(|e| e.into_napi_error("Group health unavailable"))
+    }
+
+    /// Member counts, proof freshness and lagging groups of a region in the hierarchy
+    #[napi]
+    pub fn get_region_health(&self, region_id: String) -> Result<RegionHealth> {
+        self.inner_manager
+            .get_region_health(&region_id)
+            .map_err(|e| e.into_napi_error("Region health unavailable"))
+    }
+
+    /// Hierarchical proof tree behind the latest checkpoint, if one has been created
+    #[napi]
+    pub fn get_proof_tree(&self) -> Option<ProofTree> {
+        self.inner_manager
+            .last_hierarchical_proof
+            .as_ref()
+            .map(|proof| proof.as_tree())
+    }
+
+    /// Compact proof placing a chain in the global root of the latest checkpoint.
+    /// `proofWindow` is the chain's latest commitments and must end at the commitment
+    /// the checkpoint recorded for it.
+    #[napi]
+    pub fn get_compact_proof(
+        &self,
+        chain_id: Buffer,
+        chain_length: f64,
+        proof_window: Vec<PhysicalAccessCommitment>,
+    ) -> Result<CompactProof> {
+        self.inner_manager
+            .generate_compact_proof(&chain_id, chain_length, proof_window)
+            .map_err(|e| e.into_napi_error("Compact proof unavailable"))
+    }
+
+    /// Issue availability challenges for all known chains selected at this block height,
+    /// using the block's hash as beacon once it has been processed. With `light`, chunks already audited in full are probed with a single
+    /// authenticated slice instead of the whole chunk.
+    #[napi]
+    pub fn issue_block_challenges(
+        &mut self,
+        block_height: u32,
+        light: Option<bool>,
+    ) -> Result<Vec<AvailabilityChallenge>> {
+        // Drop challenges from previous blocks; unanswered ones count against the chain
+        let expired = self.availability_challenger.cleanup_expired_challenges()?;
+        let scorer = self.availability_challenger.get_scorer();
+        for unanswered in expired.iter().filter(|e| {
+            e.outcome == crate::core::availability::ExpiredChallengeOutcome::ExpiredUnanswered
+        }) {
+            if scorer.is_slashable(&unanswered.chain_id) {
+                log::warn!(
+                    "Availability challenge {} for chain {} expired without response; \
+                     chain exceeded its failure tolerance",
+                    unanswered.challenge_id,
+                    unanswered.chain_id
+                );
+            } else {
+                info!(
+                    "Availability challenge {} for chain {} expired without response \
+                     (within failure tolerance)",
+                    unanswered.challenge_id, unanswered.chain_id
+                );
+            }
+        }
+
+        // Refresh registry availability scores from challenge outcomes
+        for (chain_id, chain) in self.inner_manager.chain_registry.iter_mut() {
+            chain.availability_score = scorer.get_score(&hex::encode(chain_id));
+        }
+
+        // Iterate chains in a stable order so every node issues the same challenges
+        let mut chains: Vec<(&Vec<u8>, &LightweightHashChain)> =
+            self.inner_manager.chain_registry.iter().collect();
+        chains.sort_by(|a, b| a.0.cmp(b.0));
+
+        let mut challenges = Vec::new();
+        for (chain_id, chain) in chains {
+            if chain.total_chunks == 0 {
+                continue;
+            }
+
+            let chain_id = Buffer::from(chain_id.clone());
+            let total_chunks = chain.total_chunks.min(u32::MAX as u64) as u32;
+            let challenge = if light.unwrap_or(false) {
+                self.availability_challenger.create_light_challenge(
+                    chain_id,
+                    total_chunks,
+                    self.node_key.clone(),
+                    block_height as u64,
+                )?
+            } else {
+                self.availability_challenger.create_challenge(
+                    chain_id,
+                    total_chunks,
+                    self.node_key.clone(),
+                    block_height as u64,
+                )?
+            };
+            challenges.extend(challenge);
+        }
+
+        info!(
+            "Issued {} availability challenges for block {} ({} chains)",
+            challenges.len(),
+            block_height,
+            self.inner_manager.chain_registry.len()
+        );
+
+        Ok(challenges)
+    }
+
+    /// Process a prover's response to an issued availability challenge
+    #[napi]
+    pub fn process_availability_response(
+        &mut self,
+        response: AvailabilityResponse,
+    ) -> Result<bool> {
+        let challenge_id = hex::encode(&response.challenge_id);
+        let chain_id = self
+            .availability_challenger
+            .get_active_challenge(&challenge_id)
+            .map(|challenge| challenge.chain_id.to_vec());
+        let result = self
+            .availability_challenger
+            .process_response(challenge_id, response)?;
+
+        let success = matches!(
+            result,
+            crate::core::availability::AvailabilityResult::Success { .. }
+        );
+        if let (true, Some(chain_id)) = (success, chain_id) {
+            self.inner_manager.record_chain_activity(&chain_id);
+        }
+        Ok(success)
+    }
+
+    /// Verify responses to many availability challenges in parallel, returning one
+    /// outcome per challenge in challenge order: "success", "timeout", "invalid_data"
+    /// or "maintenance_mode". Unanswered challenges time out. Challenges are not
+    /// resolved and scores are not updated, unlike `processAvailabilityResponse`.
+    #[napi]
+    pub fn verify_availability_responses_batch(
+        &self,
+        challenges: Vec<AvailabilityChallenge>,
+        responses: Vec<AvailabilityResponse>,
+    ) -> Result<Vec<String>> {
+        let results = self
+            .availability_challenger
+            .verify_availability_responses_batch(&challenges, &responses)?;
+        Ok(results
+            .iter()
+            .map(|result| result.outcome_label().to_string())
+            .collect())
+    }
+
+    /// Start a streamed availability audit of chunks `startChunk..endChunk` of a
+    /// registered chain, checked against its chunk Merkle root. The prover answers with
+    /// one segment per entry of `segmentDeadlines`.
+    #[napi]
+    pub fn create_range_availability_challenge(
+        &mut self,
+        chain_id: Buffer,
+        chunk_root: Buffer,
+        start_chunk: u32,
+        end_chunk: u32,
+    ) -> Result<RangeAvailabilityChallenge> {
+        let total_chunks = self
+            .inner_manager
+            .chain_registry
+            .get(chain_id.as_ref())
+            .map(|chain| chain.total_chunks.min(u32::MAX as u64) as u32)
+            .ok_or_else(|| {
+                coded_error(
                     Status::GenericFailure,
-                    format!("Block processing error: {:?}", e),
+                    error_codes::E_CHAIN_NOT_FOUND,
+                    "Chain not found",
                 )
             })?;
 
+        self.availability_challenger.create_range_challenge(
+            chain_id,
+            chunk_root,
+            total_chunks,
+            start_chunk,
+            end_chunk,
+            self.node_key.clone(),
+        )
+    }
+
+    /// Verify one segment of a streamed availability audit. Returns "accepted" while
+    /// segments remain, "complete" once all have verified, and "timeout" or
+    /// "invalid_data" when the audit fails.
+    #[napi]
+    pub fn process_availability_segment(
+        &mut self,
+        segment: ChallengeResponseSegment,
+    ) -> Result<String> {
+        let chain_id = self
+            .availability_challenger
+            .get_range_challenge(&hex::encode(&segment.challenge_id))
+            .map(|challenge| challenge.chain_id.to_vec());
+        let result = self.availability_challenger.process_segment(&segment)?;
+
+        if let (crate::core::availability::SegmentResult::Complete, Some(chain_id)) =
+            (&result, chain_id)
+        {
+            self.inner_manager.record_chain_activity(&chain_id);
+        }
+        Ok(result.outcome_label().to_string())
+    }
+
+    /// Segments of a streamed availability audit still awaiting verification, or null
+    /// once the audit has completed or failed
+    #[napi]
+    pub fn get_outstanding_segments(&self, challenge_id: String) -> Option<Vec<u32>> {
+        self.availability_challenger
+            .outstanding_segments(&challenge_id)
+    }
+
+    /// Configure the availability response-time SLA tracker (resets collected samples)
+    #[napi]
+    pub fn configure_availability_sla(&mut self, sample_window: u32, sla_ms: u32) -> Result<()> {
+        if sample_window == 0 || sla_ms == 0 {
+            return Err(coded_error(
+                Status::InvalidArg,
+                error_codes::E_INVALID_INPUT,
+                "Sample window and SLA must be positive",
+            ));
+        }
+
+        self.availability_challenger.set_latency_stats(
+            crate::core::availability::AvailabilityLatencyStats::new(
+                sample_window as usize,
+                sla_ms,
+            ),
+        );
+        Ok(())
+    }
+
+    /// Configure how many failed availability challenges a chain may have within its
+    /// recent window before it is slashable
+    #[napi]
+    pub fn configure_challenge_failure_policy(
+        &mut self,
+        policy: ChallengeFailurePolicy,
+    ) -> Result<()> {
+        if policy.window == 0 || policy.tolerated_failures >= policy.window {
+            return Err(coded_error(
+                Status::InvalidArg,
+                error_codes::E_INVALID_INPUT,
+                "Failure window must be positive and larger than the tolerated failures",
+            ));
+        }
+
+        self.availability_challenger.set_failure_policy(policy);
+        Ok(())
+    }
+
+    /// Get the current availability challenge failure tolerance
+    #[napi]
+    pub fn get_challenge_failure_policy(&self) -> ChallengeFailurePolicy {
+        self.availability_challenger
+            .get_scorer()
+            .get_failure_policy()
+    }
+
+    /// Whether a chain's recent availability failures exceed the tolerance
+    #[napi]
+    pub fn is_chain_slashable(&self, chain_id: Buffer) -> bool {
+        self.availability_challenger
+            .get_scorer()
+            .is_slashable(&hex::encode(&chain_id))
+    }
+
+    /// Get availability response-time percentiles for a chain
+    #[napi]
+    pub fn get_availability_latency_stats(
+        &self,
+        chain_id: Buffer,
+    ) -> Option<AvailabilityLatencyPercentiles> {
+        self.availability_challenger
+            .get_latency_stats()
+            .get_chain_percentiles(&hex::encode(&chain_id))
+    }
+
+    /// Export the retained record of a completed availability challenge for disputes,
+    /// signed by this node when a signing key is set
+    #[napi]
+    pub fn export_challenge_evidence(&self, challenge_id: String) -> Result<ChallengeEvidence> {
+        let mut evidence = self
+            .availability_challenger
+            .export_challenge_evidence(&challenge_id)?;
+        if let Some(signing_key) = &self.signing_key {
+            crate::core::availability::sign_challenge_evidence(&mut evidence, signing_key)
+                .map_err(|e| e.into_napi_error("Failed to sign challenge evidence"))?;
+        }
+        Ok(evidence)
+    }
+
+    /// Recheck that a challenge issued at `block_height` was selected by the
+    /// challenge probability under the block beacon it carries
+    #[napi]
+    pub fn verify_challenge_selection(
+        &self,
+        challenge: AvailabilityChallenge,
+        block_height: u32,
+        total_chunks: u32,
+    ) -> bool {
+        let selected = self.availability_challenger.verify_challenge_selection(
+            &challenge,
+            block_height as u64,
+            total_chunks,
+        );
+        if !selected {
+            log::warn!(
+                "Availability challenge for chain {} was not selected at block {}",
+                hex::encode(&challenge.chain_id),
+                block_height
+            );
+        }
+        selected
+    }
+
+    /// Check an exported challenge record's id, hash, outcome and any challenger or
+    /// prover signatures. With `requireSigned`, the challenger's signature and the
+    /// prover's signature on any response must be present.
+    #[napi]
+    pub fn verify_challenge_evidence(
+        &self,
+        evidence: ChallengeEvidence,
+        require_signed: Option<bool>,
+    ) -> bool {
+        crate::core::availability::verify_challenge_evidence(
+            &evidence,
+            require_signed.unwrap_or(false),
+        )
+    }
+
+    /// Set how long completed availability challenge records are retained
+    #[napi]
+    pub fn set_challenge_evidence_retention(&mut self, retention_seconds: f64) -> Result<()> {
+        if !retention_seconds.is_finite() || retention_seconds <= 0.0 {
+            return Err(coded_error(
+                Status::InvalidArg,
+                error_codes::E_INVALID_INPUT,
+                "Evidence retention must be positive",
+            ));
+        }
+
+        self.availability_challenger
+            .set_evidence_retention(retention_seconds);
         Ok(())
     }
 
@@ -1564,27 +4737,59 @@ pub fn generate_multi_source_entropy(
     block_hash: Buffer,
     beacon_data: Option<Buffer>,
 ) -> Result<MultiSourceEntropy> {
+    validate_block_hash(&block_hash)?;
+
     // Generate cryptographically secure local entropy
     let local_entropy =
         Buffer::from(crate::core::utils::generate_secure_entropy(&block_hash).to_vec());
 
-    // Use production entropy combination
-    let beacon_slice = beacon_data.as_ref().map(|b| b.as_ref());
-    let combined_hash = crate::core::utils::generate_multi_source_entropy(
+    // Use the same reproducible combination as every other entropy path
+    let timestamp = crate::core::utils::get_current_timestamp();
+    let combined_hash = crate::core::utils::combine_entropy_sources(
         &block_hash,
-        beacon_slice,
+        beacon_data.as_deref(),
         &local_entropy,
+        timestamp,
     );
 
     Ok(MultiSourceEntropy {
         blockchain_entropy: block_hash,
         beacon_entropy: beacon_data,
         local_entropy,
-        timestamp: crate::core::utils::get_current_timestamp(),
+        timestamp,
         combined_hash: Buffer::from(combined_hash.to_vec()),
     })
 }
 
+/// Get the fingerprint of this build's consensus parameters
+#[napi]
+pub fn get_network_parameters_fingerprint() -> Buffer {
+    Buffer::from(
+        crate::consensus::NetworkParameters::current()
+            .fingerprint()
+            .to_vec(),
+    )
+}
+
+/// Fingerprint of this build's consensus behavior (parameters, chunk selection, entropy
+/// combination, commitment hashing and VDF test vectors), for comparison on handshake
+#[napi]
+pub fn crate_consensus_fingerprint() -> Buffer {
+    Buffer::from(crate::consensus::consensus_fingerprint().to_vec())
+}
+
+/// Verify a serialized shared VDF proof chain's linkage and signatures for a prover
+#[napi]
+pub fn verify_vdf_proof_chain(serialized_proofs: Buffer, prover_key: Buffer) -> Result<bool> {
+    validate_public_key(&prover_key)?;
+
+    let proofs = crate::core::vdf_processor::deserialize_vdf_proof_chain(&serialized_proofs)?;
+    Ok(crate::core::vdf_processor::verify_vdf_proof_chain(
+        &proofs,
+        &prover_key,
+    )?)
+}
+
 /// Create memory-hard VDF proof
 #[napi]
 pub fn create_memory_hard_vdf_proof(input: Buffer, iterations: u32) -> Result<MemoryHardVDFProof> {
@@ -1596,8 +4801,9 @@ pub fn create_memory_hard_vdf_proof(input: Buffer, iterations: u32) -> Result<Me
             1,
         )
         .map_err(|e| {
-            Error::new(
+            coded_error(
                 Status::GenericFailure,
+                error_codes::E_VDF_FAILED,
                 format!("VDF computation failed: {}", e),
             )
         })?;
@@ -1643,8 +4849,9 @@ pub fn select_chunks_from_entropy(
     count: u32,
 ) -> Result<Vec<u32>> {
     if count > total_chunks {
-        return Err(Error::new(
+        return Err(coded_error(
             Status::InvalidArg,
+            error_codes::E_CHUNK_OOB,
             "Count cannot exceed total chunks".to_string(),
         ));
     }
@@ -1672,6 +4879,17 @@ pub fn verify_chunk_selection(
     }
 }
 
+/// Position hash an ultra-compact proof for `chain_hash` carries for `position`
+#[napi]
+pub fn compute_ultra_compact_position_hash(
+    chain_hash: Buffer,
+    position: HierarchicalPosition,
+) -> Buffer {
+    Buffer::from(
+        crate::core::utils::compute_ultra_compact_position_hash(&chain_hash, &position).to_vec(),
+    )
+}
+
 /// Create storage commitment hash
 #[napi]
 pub fn create_commitment_hash(commitment: StorageCommitment) -> Buffer {
@@ -1688,17 +4906,45 @@ pub fn create_commitment_hash(commitment: StorageCommitment) -> Buffer {
             chunk_hashes: &chunk_hashes_vec,
             vdf_output: &commitment.vdf_proof.output_state,
             entropy_hash: &commitment.entropy.combined_hash,
+            merkle_root: commitment
+                .merkle_root
+                .as_deref()
+                .zip(commitment.total_chunks)
+                .map(|(merkle_root, total_chunks)| (merkle_root, total_chunks as u64)),
         });
 
     Buffer::from(commitment_hash.to_vec())
 }
 
+/// Serialize a storage commitment to its versioned binary format for storage
+#[napi]
+pub fn serialize_storage_commitment(commitment: StorageCommitment) -> Result<Buffer> {
+    commitment.serialize()
+}
+
+/// Parse a storage commitment written by `serializeStorageCommitment`
+#[napi]
+pub fn deserialize_storage_commitment(data: Buffer) -> Result<StorageCommitment> {
+    StorageCommitment::deserialize(data)
+}
+
 /// Verify commitment integrity
 #[napi]
 pub fn verify_commitment_integrity(commitment: StorageCommitment) -> bool {
     commitment.prover_key.len() == 32 && !commitment.chunk_hashes.is_empty()
 }
 
+/// Check that a compact proof matches the commitment it claims to summarize: prover
+/// key, data hash, commitment hash, block height, timestamp, chunk hashes and VDF
+/// proof must all be the commitment's own
+#[napi]
+pub fn verify_compact_matches_commitment(
+    compact: CompactStorageProof,
+    commitment: StorageCommitment,
+) -> bool {
+    crate::consensus::verify_compact_matches_commitment(&compact, &commitment)
+}
+
 // ====================================================================
 // VDF QUEUE MANAGEMENT STRUCTURES
 // ====================================================================
@@ -1735,9 +4981,12 @@ pub struct PendingBlock {
 pub struct VdfQueue {
     pending_blocks: std::collections::VecDeque<PendingBlock>,
     current_vdf: Option<PendingBlock>,
-    completed_blocks: std::collections::HashMap<String, StorageCommitment>, // block_hash -> commitment
+    completed_blocks: std::collections::HashMap<String, (StorageCommitment, f64)>, // block_hash -> (commitment, completed at)
     max_queue_size: usize,
     vdf_timeout_seconds: f64,
+    // Completed commitments kept until cleanup, by count and by age
+    completed_retention: usize,
+    completed_max_age_seconds: f64,
 }
 
 impl VdfQueue {
@@ -1748,14 +4997,24 @@ impl VdfQueue {
             completed_blocks: std::collections::HashMap::new(),
             max_queue_size,
             vdf_timeout_seconds,
+            completed_retention: VDF_QUEUE_COMPLETED_RETENTION as usize,
+            completed_max_age_seconds: VDF_QUEUE_COMPLETED_MAX_AGE_SECONDS,
         }
     }
 
+    /// Keep at most `max_blocks` completed commitments, none older than `max_age_seconds`,
+    /// so a slowly polling consumer can size retention to its drain interval
+    pub fn set_completed_retention(&mut self, max_blocks: usize, max_age_seconds: f64) {
+        self.completed_retention = max_blocks;
+        self.completed_max_age_seconds = max_age_seconds;
+    }
+
     /// Submit a block for VDF computation
     pub fn submit_block(&mut self, block: PendingBlock) -> Result<()> {
         if self.pending_blocks.len() >= self.max_queue_size {
-            return Err(Error::new(
+            return Err(coded_error(
                 Status::GenericFailure,
+                error_codes::E_CAPACITY,
                 "VDF queue is full. Cannot accept new blocks until current VDFs complete.",
             ));
         }
@@ -1773,11 +5032,28 @@ impl VdfQueue {
     /// Get completed commitment for a block
     pub fn get_completed_commitment(&self, block_hash: &[u8]) -> Option<&StorageCommitment> {
         let block_hash_hex = hex::encode(block_hash);
-        self.completed_blocks.get(&block_hash_hex)
+        self.completed_blocks
+            .get(&block_hash_hex)
+            .map(|(commitment, _)| commitment)
+    }
+
+    /// Block hash hex and completion timestamp of every retained completed commitment,
+    /// oldest first
+    pub fn list_completed_blocks(&self) -> Vec<(String, f64)> {
+        let mut blocks: Vec<(String, f64)> = self
+            .completed_blocks
+            .iter()
+            .map(|(block_hash_hex, (_, completed_at))| (block_hash_hex.clone(), *completed_at))
+            .collect();
+        blocks.sort_by(|a, b| a.1.total_cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+        blocks
     }
 
-    /// Process VDF queue - start next VDF if none running
+    /// Process VDF queue - drop expired completed commitments, then start next VDF
+    /// if none running
     pub fn process_queue(&mut self) -> Result<Option<String>> {
+        self.cleanup_old_blocks();
+
         // Check if current VDF timed out
         if let Some(ref current) = self.current_vdf {
             let current_time = crate::core::utils::get_current_timestamp();
@@ -1815,10 +5091,13 @@ impl VdfQueue {
         vdf_proof: MemoryHardVDFProof,
         prover_key: &[u8],
     ) -> Result<StorageCommitment> {
-        let current_block = self
-            .current_vdf
-            .take()
-            .ok_or_else(|| Error::new(Status::GenericFailure, "No VDF currently computing"))?;
+        let current_block = self.current_vdf.take().ok_or_else(|| {
+            coded_error(
+                Status::GenericFailure,
+                error_codes::E_VDF_NOT_READY,
+                "No VDF currently computing",
+            )
+        })?;
 
         // Create final commitment with completed VDF
         let commitment_hash =
@@ -1835,6 +5114,7 @@ impl VdfQueue {
                     .collect::<Vec<_>>(),
                 vdf_output: &vdf_proof.output_state,
                 entropy_hash: &current_block.entropy.combined_hash,
+                merkle_root: None,
             });
 
         let commitment = StorageCommitment {
@@ -1847,12 +5127,19 @@ impl VdfQueue {
             vdf_proof,
             entropy: current_block.entropy.clone(),
             commitment_hash: Buffer::from(commitment_hash.to_vec()),
+            merkle_root: None,
+            total_chunks: None,
         };
 
         // Store completed commitment
         let block_hash_hex = hex::encode(&current_block.block_hash);
-        self.completed_blocks
-            .insert(block_hash_hex, commitment.clone());
+        self.completed_blocks.insert(
+            block_hash_hex,
+            (
+                commitment.clone(),
+                crate::core::utils::get_current_timestamp(),
+            ),
+        );
 
         Ok(commitment)
     }
@@ -1875,24 +5162,19 @@ impl VdfQueue {
         }
     }
 
-    /// Clean up old completed blocks
-    pub fn cleanup_old_blocks(&mut self, _max_age_seconds: f64) {
-        let _current_time = crate::core::utils::get_current_timestamp();
-
-        // Note: In a real implementation, we'd track timestamps for completed blocks
-        // For now, we'll just limit the size of completed blocks
-        if self.completed_blocks.len() > 100 {
-            // Keep only the last 50 blocks
-            let keys_to_remove: Vec<String> = self
-                .completed_blocks
-                .keys()
-                .take(self.completed_blocks.len() - 50)
-                .cloned()
-                .collect();
-
-            for key in keys_to_remove {
-                self.completed_blocks.remove(&key);
-            }
+    /// Drop completed commitments past the retention age, then the oldest beyond the
+    /// retention count
+    pub fn cleanup_old_blocks(&mut self) {
+        let cutoff = crate::core::utils::get_current_timestamp() - self.completed_max_age_seconds;
+        self.completed_blocks
+            .retain(|_, (_, completed_at)| *completed_at >= cutoff);
+
+        let excess = self
+            .completed_blocks
+            .len()
+            .saturating_sub(self.completed_retention);
+        for (block_hash_hex, _) in self.list_completed_blocks().into_iter().take(excess) {
+            self.completed_blocks.remove(&block_hash_hex);
         }
     }
 }
@@ -1905,3 +5187,82 @@ pub struct VdfQueueStatus {
     pub completed_count: u32,
     pub queue_capacity: u32,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pending_block(block_height: u32, block_hash: u8) -> PendingBlock {
+        PendingBlock {
+            block_height,
+            block_hash: Buffer::from(vec![block_hash; 32]),
+            entropy: crate::core::utils::commitment_entropy(&[1u8; 32], &[block_hash; 32]),
+            selected_chunks: vec![0, 1],
+            chunk_hashes: vec![Buffer::from(vec![2u8; 32]), Buffer::from(vec![3u8; 32])],
+            data_hash: Buffer::from(vec![4u8; 32]),
+            vdf_input: Buffer::from(vec![5u8; 32]),
+            vdf_status: VdfStatus::Pending,
+            submission_time: crate::core::utils::get_current_timestamp(),
+            chain_id: "chain".to_string(),
+        }
+    }
+
+    fn vdf_proof() -> MemoryHardVDFProof {
+        MemoryHardVDFProof {
+            input_state: Buffer::from(vec![5u8; 32]),
+            output_state: Buffer::from(vec![6u8; 32]),
+            iterations: 1,
+            memory_access_samples: Vec::new(),
+            computation_time_ms: 0.0,
+            memory_usage_bytes: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_chain_limit_validation() {
+        // The limit itself must lie between 1 and MAX_CHAINS_PER_INSTANCE
+        for invalid in [0, MAX_CHAINS_PER_INSTANCE + 1] {
+            let error = validate_max_chains(invalid, 0).unwrap_err();
+            assert!(error.reason.starts_with(error_codes::E_CAPACITY));
+        }
+        assert!(validate_max_chains(1, 0).is_ok());
+        assert!(validate_max_chains(MAX_CHAINS_PER_INSTANCE, 0).is_ok());
+
+        // It cannot drop below the chains already active
+        assert!(validate_max_chains(2, 3).is_err());
+        assert!(validate_max_chains(3, 3).is_ok());
+
+        // Creating a chain is refused once the limit is reached
+        assert!(check_scale_limit(3, 3, "chains").is_ok());
+        assert!(matches!(
+            check_scale_limit(3 + 1, 3, "chains"),
+            Err(HashChainError::ScaleLimit { count: 4, limit: 3 })
+        ));
+    }
+
+    #[test]
+    fn test_vdf_queue_cleans_up_completed_blocks() {
+        let mut queue = VdfQueue::new(10, 60.0);
+        queue.set_completed_retention(2, 3600.0);
+        for block_hash in 1..=3u8 {
+            queue
+                .submit_block(pending_block(100 + block_hash as u32, block_hash))
+                .unwrap();
+            assert!(queue.process_queue().unwrap().is_some());
+            queue.complete_current_vdf(vdf_proof(), &[1u8; 32]).unwrap();
+        }
+        assert_eq!(queue.get_status().completed_count, 3);
+
+        // Processing trims to the retention count, dropping the oldest
+        assert!(queue.process_queue().unwrap().is_none());
+        assert_eq!(queue.get_status().completed_count, 2);
+        assert!(!queue.is_block_ready(&[1u8; 32]));
+        assert!(queue.is_block_ready(&[2u8; 32]));
+        assert!(queue.is_block_ready(&[3u8; 32]));
+
+        // And drops everything past the retention age
+        queue.set_completed_retention(2, -1.0);
+        queue.process_queue().unwrap();
+        assert_eq!(queue.get_status().completed_count, 0);
+    }
+}